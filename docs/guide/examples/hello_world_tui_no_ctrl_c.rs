@@ -1,7 +1,7 @@
-// todo remove deprecated
-#![allow(non_snake_case, deprecated)]
+#![allow(non_snake_case)]
 
-use dioxus::events::{KeyCode, KeyboardEvent};
+use dioxus::events::KeyboardEvent;
+use dioxus::html::input_data::keyboard_types::Code;
 use dioxus::prelude::*;
 use dioxus_tui::TuiContext;
 
@@ -26,7 +26,7 @@ fn App(cx: Scope) -> Element {
             background_color: "red",
             justify_content: "center",
             align_items: "center",
-            onkeydown: move |k: KeyboardEvent| if let KeyCode::Q = k.key_code {
+            onkeydown: move |k: KeyboardEvent| if k.code() == Code::KeyQ {
                 tui_ctx.quit();
             },
 
@@ -98,6 +98,17 @@ pub fn CustomAttributes(cx: Scope) -> Element {
     // ANCHOR_END: custom_attributes
 }
 
+#[rustfmt::skip]
+pub fn DataAttributes(cx: Scope) -> Element {
+    let id = 7;
+    // ANCHOR: data_attributes
+    cx.render(rsx!(li {
+        data_kind: "video",
+        data_video_id: "{id}",
+    }))
+    // ANCHOR_END: data_attributes
+}
+
 #[rustfmt::skip]
 pub fn Formatting(cx: Scope) -> Element {
     // ANCHOR: formatting
@@ -31,9 +31,7 @@ fn app(cx: Scope) -> Element {
                         app_tx: tx.clone()
                     });
 
-                    // this returns a weak reference to the other window
-                    // Be careful not to keep a strong reference to the other window or it will never be dropped
-                    // and the window will never close.
+                    // this returns a handle to the other window, which doesn't keep it open
                     window.new_window(dom, Default::default());
                 },
                 "Click to compose a new email"
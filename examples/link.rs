@@ -14,8 +14,7 @@ fn app(cx: Scope) -> Element {
             p {
                 a {
                     href: "http://dioxuslabs.com/",
-                    prevent_default: "onclick",
-                    onclick: |_| println!("Hello Dioxus"),
+                    onclick: prevent_default(|_| println!("Hello Dioxus")),
                     "Custom event link - links inside of your app",
                 }
             }
@@ -34,8 +34,8 @@ fn app(cx: Scope) -> Element {
     cx.render(rsx! {
         h1 { "Login" }
         form {
-            onsubmit: onsubmit,
-            prevent_default: "onsubmit", // Prevent the default behavior of <form> to post
+            // Prevent the default behavior of <form> to post
+            onsubmit: prevent_default(onsubmit),
             input { r#type: "text", id: "username", name: "username" }
             label { "Username" }
             br {}
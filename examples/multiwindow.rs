@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use dioxus_desktop::WindowHandle;
 
 fn main() {
     dioxus_desktop::launch(app);
@@ -6,16 +7,41 @@ fn main() {
 
 fn app(cx: Scope) -> Element {
     let window = dioxus_desktop::use_window(cx);
+    let popups = use_ref(cx, Vec::<WindowHandle>::new);
 
     cx.render(rsx! {
         div {
             button {
                 onclick: move |_| {
                     let dom = VirtualDom::new(popup);
-                    window.new_window(dom, Default::default());
+                    let handle = window.new_window(dom, Default::default());
+
+                    // forget about the popup once it is closed
+                    let id = handle.id();
+                    handle.on_close({
+                        to_owned![popups];
+                        move || popups.write().retain(|popup| popup.id() != id)
+                    });
+                    popups.write().push(handle);
                 },
                 "New Window"
             }
+            button {
+                onclick: move |_| {
+                    if let Some(popup) = popups.read().last() {
+                        popup.focus();
+                    }
+                },
+                "Focus the last window"
+            }
+            button {
+                onclick: move |_| {
+                    for popup in popups.read().iter() {
+                        popup.close();
+                    }
+                },
+                "Close all {popups.read().len()} windows"
+            }
         }
     })
 }
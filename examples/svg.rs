@@ -83,8 +83,7 @@ pub fn Die<'a>(cx: Scope<'a, DieProps<'a>>) -> Element {
 
     cx.render(rsx! {
       svg {
-        onclick: move |e| cx.props.onclick.call(e),
-        prevent_default: "onclick",
+        onclick: prevent_default(move |e| cx.props.onclick.call(e)),
         class: "die",
         view_box: "-1000 -1000 2000 2000",
 
@@ -139,8 +139,7 @@ pub fn TodoEntry<'a>(cx: Scope<'a, TodoEntryProps<'a>>) -> Element {
 
                 label {
                     r#for: "cbg-{todo.id}",
-                    onclick: move |_| is_editing.set(true),
-                    prevent_default: "onclick",
+                    onclick: prevent_default(move |_| is_editing.set(true)),
                     "{todo.contents}"
                 }
             }
@@ -31,6 +31,10 @@ pub fn impl_my_derive(ast: &syn::DeriveInput) -> Result<TokenStream, Error> {
                     .map(|f| struct_info.required_field_impl(f))
                     .collect::<Result<Vec<_>, _>>()?;
                 let build_method = struct_info.build_method_impl();
+                let owned_props = match struct_info.builder_attr.owned {
+                    true => struct_info.owned_props_impl()?,
+                    false => quote!(),
+                };
 
                 quote! {
                     #builder_creation
@@ -38,6 +42,7 @@ pub fn impl_my_derive(ast: &syn::DeriveInput) -> Result<TokenStream, Error> {
                     #( #fields )*
                     #( #required_fields )*
                     #build_method
+                    #owned_props
                 }
             }
             syn::Fields::Unnamed(_) => {
@@ -459,10 +464,11 @@ fn type_from_inside_option(ty: &syn::Type, check_option_name: bool) -> Option<&s
 
 mod struct_info {
     use proc_macro2::TokenStream;
-    use quote::quote;
+    use quote::{quote, ToTokens};
     use syn::parse::Error;
 
     use super::field_info::{FieldBuilderAttr, FieldInfo};
+    use super::type_from_inside_option;
     use super::util::{
         empty_type, empty_type_tuple, expr_to_single_string, make_punctuated_single,
         modify_types_generics_hack, path_to_single_string, strip_raw_ident_prefix, type_tuple,
@@ -1057,6 +1063,149 @@ Finally, call `.build()` to create the instance of `{name}`.
                 }
             )
         }
+
+        /// Generate an `Owned{Name}` struct that holds owned versions of every field, so the props can be moved into
+        /// futures and across await points
+        pub fn owned_props_impl(&self) -> Result<TokenStream, Error> {
+            let StructInfo {
+                ref vis, ref name, ..
+            } = *self;
+
+            if let Some(param) = self
+                .generics
+                .params
+                .iter()
+                .find(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+            {
+                return Err(Error::new_spanned(
+                    param,
+                    "#[props(owned)] only supports structs that are generic over lifetimes",
+                ));
+            }
+
+            let owned_name = syn::Ident::new(&format!("Owned{name}"), name.span());
+            let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+            let (field_defs, field_conversions): (Vec<_>, Vec<_>) = self
+                .fields
+                .iter()
+                .map(|field| {
+                    let field_name = field.name;
+                    let (ty, conversion) = owned_field(field.ty, quote!(self.#field_name))?;
+                    Ok((
+                        quote!(pub #field_name: #ty),
+                        quote!(#field_name: #conversion),
+                    ))
+                })
+                .collect::<Result<Vec<_>, Error>>()?
+                .into_iter()
+                .unzip();
+
+            let doc =
+                format!("An owned version of [`{name}`] that can be held across await points");
+            let method_doc = format!("Convert these props into an owned [`{owned_name}`]");
+
+            Ok(quote! {
+                #[doc = #doc]
+                #[allow(missing_docs)]
+                #vis struct #owned_name {
+                    #( #field_defs, )*
+                }
+
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #[doc = #method_doc]
+                    pub fn to_owned_props(&self) -> #owned_name {
+                        #owned_name {
+                            #( #field_conversions, )*
+                        }
+                    }
+                }
+
+                impl #impl_generics ::std::convert::From<&#name #ty_generics> for #owned_name #where_clause {
+                    fn from(props: &#name #ty_generics) -> Self {
+                        props.to_owned_props()
+                    }
+                }
+            })
+        }
+    }
+
+    /// Get the owned version of a borrowed field type, and the expression that converts `value` into it
+    ///
+    /// References (and options of references) are converted with `ToOwned`, everything else is cloned. Fields that
+    /// would still borrow something once converted are rejected, since the owned struct has no lifetimes.
+    fn owned_field(
+        ty: &syn::Type,
+        value: TokenStream,
+    ) -> Result<(TokenStream, TokenStream), Error> {
+        let reference = match ty {
+            syn::Type::Reference(reference) => Some(reference),
+            _ => match type_from_inside_option(ty, true) {
+                Some(syn::Type::Reference(reference)) => Some(reference),
+                _ => None,
+            },
+        };
+
+        let owned = match reference {
+            Some(reference) => {
+                let inner = &reference.elem;
+                if let syn::Type::TraitObject(_) = **inner {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "#[props(owned)] can't own a reference to a trait object. Use an Rc or an owned type for this field instead",
+                    ));
+                }
+                if contains_lifetime(inner.to_token_stream()) {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "#[props(owned)] can only own references to types without lifetimes, since the owned props can't borrow anything",
+                    ));
+                }
+
+                let owned_ty = quote!(<#inner as ::std::borrow::ToOwned>::Owned);
+                match ty {
+                    syn::Type::Reference(_) => {
+                        (owned_ty, quote!(::std::borrow::ToOwned::to_owned(#value)))
+                    }
+                    _ => (
+                        quote!(::std::option::Option<#owned_ty>),
+                        quote!(#value.map(::std::borrow::ToOwned::to_owned)),
+                    ),
+                }
+            }
+            None => {
+                if contains_lifetime(ty.to_token_stream()) {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "#[props(owned)] can't clone a field that borrows with a lifetime, like an EventHandler or Element. Only plain references (and options of references) are converted to owned values",
+                    ));
+                }
+
+                (quote!(#ty), quote!(::std::clone::Clone::clone(&#value)))
+            }
+        };
+
+        Ok(owned)
+    }
+
+    /// Check if a type mentions any lifetime other than `'static`, like `'a` in `EventHandler<'a>` or `Vec<&'a str>`
+    fn contains_lifetime(tokens: TokenStream) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                    if !matches!(tokens.peek(), Some(proc_macro2::TokenTree::Ident(ident)) if ident == "static")
+                    {
+                        return true;
+                    }
+                }
+                proc_macro2::TokenTree::Group(group) if contains_lifetime(group.stream()) => {
+                    return true
+                }
+                _ => {}
+            }
+        }
+        false
     }
 
     #[derive(Debug, Default)]
@@ -1076,13 +1225,19 @@ Finally, call `.build()` to create the instance of `{name}`.
         pub build_method_doc: Option<syn::Expr>,
 
         pub field_defaults: FieldBuilderAttr,
+
+        /// Whether to generate an `Owned{Name}` variant of the props with a `to_owned_props` conversion.
+        pub owned: bool,
     }
 
     impl TypeBuilderAttr {
         pub fn new(attrs: &[syn::Attribute]) -> Result<TypeBuilderAttr, Error> {
             let mut result = TypeBuilderAttr::default();
             for attr in attrs {
-                if path_to_single_string(&attr.path).as_deref() != Some("builder") {
+                if !matches!(
+                    path_to_single_string(&attr.path).as_deref(),
+                    Some("builder" | "props")
+                ) {
                     continue;
                 }
 
@@ -1143,6 +1298,10 @@ Finally, call `.build()` to create the instance of `{name}`.
                             self.doc = true;
                             Ok(())
                         }
+                        "owned" => {
+                            self.owned = true;
+                            Ok(())
+                        }
                         _ => Err(Error::new_spanned(
                             &path,
                             format!("Unknown parameter {name:?}"),
@@ -2,14 +2,19 @@ use std::ptr::NonNull;
 
 use crate::{
     innerlude::DirtyScope, nodes::RenderReturn, nodes::VNode, virtual_dom::VirtualDom,
-    AttributeValue, DynamicNode, ScopeId,
+    AttributeValue, DynamicNode, Mutations, ScopeId,
 };
 use bumpalo::boxed::Box as BumpBox;
+#[cfg(debug_assertions)]
+use rustc_hash::FxHashMap;
 
 /// An Element's unique identifier.
 ///
 /// `ElementId` is a `usize` that is unique across the entire VirtualDOM - but not unique across time. If a component is
-/// unmounted, then the `ElementId` will be reused for a new component.
+/// unmounted, then the `ElementId` will be reused for a new component. The VirtualDom bumps a generation counter every
+/// time an id is reclaimed, so lookups can tell the current owner of an id apart from an old one.
+///
+/// Freed ids are reported in [`crate::Mutations::freed_ids`] so renderers can release anything they keep for that id.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct ElementId(pub usize);
@@ -37,6 +42,97 @@ impl ElementRef {
     }
 }
 
+/// The arena of every mounted element, indexed by [`ElementId`]
+///
+/// Reclaimed ids go on a free list and are handed out again most-recently-freed first. Each slot also keeps a
+/// generation that is bumped every time its id is reclaimed.
+#[derive(Default)]
+pub(crate) struct ElementSlab {
+    slots: Vec<ElementSlot>,
+    free: Vec<usize>,
+}
+
+#[derive(Default)]
+struct ElementSlot {
+    element: Option<ElementRef>,
+    generation: u32,
+    // Where this id is in the pending `Mutations::freed_ids`, if it was freed since the mutations were last taken
+    freed_at: Option<usize>,
+}
+
+impl ElementSlab {
+    pub(crate) fn insert(&mut self, element: ElementRef) -> ElementId {
+        let id = match self.free.pop() {
+            Some(id) => id,
+            None => {
+                self.slots.push(ElementSlot::default());
+                self.slots.len() - 1
+            }
+        };
+
+        self.slots[id].element = Some(element);
+        ElementId(id)
+    }
+
+    pub(crate) fn get(&self, id: ElementId) -> Option<&ElementRef> {
+        self.slots.get(id.0)?.element.as_ref()
+    }
+
+    pub(crate) fn get_mut(&mut self, id: ElementId) -> Option<&mut ElementRef> {
+        self.slots.get_mut(id.0)?.element.as_mut()
+    }
+
+    /// The generation of the element currently (or most recently) stored at this id
+    pub(crate) fn generation(&self, id: ElementId) -> Option<u32> {
+        self.slots.get(id.0).map(|slot| slot.generation)
+    }
+
+    fn try_remove(&mut self, id: ElementId) -> Option<ElementRef> {
+        let slot = self.slots.get_mut(id.0)?;
+        let removed = slot.element.take()?;
+
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.0);
+
+        Some(removed)
+    }
+}
+
+/// Tracks which scope registered listeners on each element in debug builds.
+///
+/// If the renderer doesn't apply a `Remove` mutation, the real element (and its listeners) stays alive and can keep
+/// sending events for an id the VirtualDom has already reclaimed. We remember who owned those listeners, and the
+/// generation of the id when they were added, so the leak can be traced back to a scope.
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub(crate) struct ListenerTracker {
+    mounted: FxHashMap<ElementId, (ScopeId, u32)>,
+}
+
+#[cfg(debug_assertions)]
+impl ListenerTracker {
+    pub(crate) fn listen(&mut self, id: ElementId, scope: ScopeId, generation: u32) {
+        self.mounted.insert(id, (scope, generation));
+    }
+
+    fn reuse(&mut self, id: ElementId) {
+        self.mounted.remove(&id);
+    }
+
+    /// Log a warning if an event was dispatched to an element whose listeners should have been removed
+    pub(crate) fn check(&self, name: &str, id: ElementId, generation: Option<u32>) {
+        match self.mounted.get(&id) {
+            Some((scope, listened)) if Some(*listened) != generation => {
+                log::warn!(
+                    "Received a {name:?} event for {id:?}, which was removed from {scope:?}. \
+                    The renderer is likely leaking listeners because it didn't apply the Remove mutation for this element."
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
 impl VirtualDom {
     pub(crate) fn next_element(&mut self, template: &VNode, path: &'static [u8]) -> ElementId {
         self.next_reference(template, ElementPath::Deep(path))
@@ -47,23 +143,43 @@ impl VirtualDom {
     }
 
     pub(crate) fn next_null(&mut self) -> ElementId {
-        let entry = self.elements.vacant_entry();
-        let id = entry.key();
-
-        entry.insert(ElementRef::none());
-        ElementId(id)
+        let id = self.elements.insert(ElementRef::none());
+        self.unfree(id)
     }
 
     fn next_reference(&mut self, template: &VNode, path: ElementPath) -> ElementId {
-        let entry = self.elements.vacant_entry();
-        let id = entry.key();
-
-        entry.insert(ElementRef {
+        let id = self.elements.insert(ElementRef {
             // We know this is non-null because it comes from a reference
             template: Some(unsafe { NonNull::new_unchecked(template as *const _ as *mut _) }),
             path,
         });
-        ElementId(id)
+        self.unfree(id)
+    }
+
+    // If an id is freed and handed out again in the same set of edits, the renderer will overwrite its entry when the
+    // new node is created, so it must not be told to free it afterwards
+    fn unfree(&mut self, id: ElementId) -> ElementId {
+        if let Some(idx) = self.elements.slots[id.0].freed_at.take() {
+            let freed = &mut self.mutations.freed_ids;
+            freed.swap_remove(idx);
+
+            // The last freed id was moved into the hole, so it needs to know its new position
+            if let Some(moved) = freed.get(idx) {
+                self.elements.slots[moved.0].freed_at = Some(idx);
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.listeners.reuse(id);
+
+        id
+    }
+
+    /// Forget where the freed ids are in the mutations once the mutations are handed to the renderer
+    pub(crate) fn release_freed_ids(&mut self, mutations: &Mutations) {
+        for id in &mutations.freed_ids {
+            self.elements.slots[id.0].freed_at = None;
+        }
     }
 
     pub(crate) fn reclaim(&mut self, el: ElementId) {
@@ -79,12 +195,25 @@ impl VirtualDom {
             );
         }
 
-        self.elements.try_remove(el.0)
+        let removed = self.elements.try_remove(el);
+
+        if removed.is_some() {
+            self.elements.slots[el.0].freed_at = Some(self.mutations.freed_ids.len());
+            self.mutations.freed_ids.push(el);
+        }
+
+        removed
     }
 
     pub(crate) fn update_template(&mut self, el: ElementId, node: &VNode) {
         let node: *const VNode = node as *const _;
-        self.elements[el.0].template = unsafe { std::mem::transmute(node) };
+        let element = self.elements.get_mut(el).unwrap_or_else(|| {
+            panic!(
+                "cannot update the template of {:?}, which was reclaimed",
+                el
+            )
+        });
+        element.template = unsafe { std::mem::transmute(node) };
     }
 
     // Drop a scope and all its children
@@ -108,6 +237,11 @@ impl VirtualDom {
 
         let scope = &mut self.scopes[id];
 
+        // Run the cleanup callbacks once the children are dropped, in the order they were registered
+        for callback in scope.drop_callbacks.get_mut().drain(..) {
+            callback();
+        }
+
         // Drop all the hooks once the children are dropped
         // this means we'll drop hooks bottom-up
         for hook in scope.hook_list.get_mut().drain(..) {
@@ -175,10 +309,11 @@ impl VirtualDom {
 }
 
 impl ElementPath {
-    pub(crate) fn is_ascendant(&self, big: &&[u8]) -> bool {
+    /// Is this path the same as or nested inside of the given path?
+    pub(crate) fn is_descendant(&self, small: &&[u8]) -> bool {
         match *self {
-            ElementPath::Deep(small) => small.len() <= big.len() && small == &big[..small.len()],
-            ElementPath::Root(r) => big.len() == 1 && big[0] == r as u8,
+            ElementPath::Deep(big) => small.len() <= big.len() && *small == &big[..small.len()],
+            ElementPath::Root(r) => small.len() == 1 && small[0] == r as u8,
         }
     }
 }
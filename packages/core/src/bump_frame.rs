@@ -2,9 +2,40 @@ use crate::nodes::RenderReturn;
 use bumpalo::Bump;
 use std::cell::{Cell, UnsafeCell};
 
+/// Controls how much memory the per-component bump arenas hold on to between renders.
+///
+/// By default, arenas grow to fit the largest render ever performed and never shrink. For long-lived apps (desktop,
+/// liveview) this means a single huge render pins its peak memory forever. A policy lets the VirtualDom release that
+/// memory once it is no longer needed.
+///
+/// ```rust, ignore
+/// let dom = VirtualDom::new(app).with_arena_policy(ArenaPolicy {
+///     max_retained_bytes: Some(64 * 1024),
+///     shrink_after: Some(100),
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArenaPolicy {
+    /// The maximum number of bytes an arena may keep allocated once it is reset.
+    ///
+    /// Arenas that grew larger than this are released and reallocated at this size the next time the component renders.
+    pub max_retained_bytes: Option<usize>,
+
+    /// Shrink an arena to the largest render it has seen after this many renders.
+    ///
+    /// This lets arenas recover from a one-off spike without reallocating on every render.
+    pub shrink_after: Option<usize>,
+}
+
 pub(crate) struct BumpFrame {
     pub bump: UnsafeCell<Bump>,
     pub node: Cell<*const RenderReturn<'static>>,
+
+    /// The largest number of bytes used by a render since the arena was last shrunk
+    peak: Cell<usize>,
+
+    /// The number of times this arena has been reset since it was last shrunk
+    generations: Cell<usize>,
 }
 
 impl BumpFrame {
@@ -13,6 +44,8 @@ impl BumpFrame {
         Self {
             bump: UnsafeCell::new(bump),
             node: Cell::new(std::ptr::null()),
+            peak: Cell::new(0),
+            generations: Cell::new(0),
         }
     }
 
@@ -35,4 +68,38 @@ impl BumpFrame {
     pub(crate) unsafe fn bump_mut(&self) -> &mut Bump {
         unsafe { &mut *self.bump.get() }
     }
+
+    /// Reset the arena, releasing any memory the policy says we shouldn't hold on to
+    ///
+    /// Like [`Bump::reset`], this invalidates every reference into the arena.
+    pub(crate) unsafe fn reset(&self, policy: &ArenaPolicy) {
+        let bump = self.bump_mut();
+
+        let used: usize = bump.iter_allocated_chunks().map(|chunk| chunk.len()).sum();
+        let peak = self.peak.get().max(used);
+        let generations = self.generations.get() + 1;
+
+        let retained = bump.allocated_bytes();
+        let over_budget = matches!(policy.max_retained_bytes, Some(max) if retained > max);
+        let stale = matches!(policy.shrink_after, Some(after) if generations >= after);
+
+        if over_budget || (stale && retained > peak) {
+            let capacity = match policy.max_retained_bytes {
+                Some(max) => peak.min(max),
+                None => peak,
+            };
+
+            *bump = Bump::with_capacity(capacity);
+        } else {
+            bump.reset();
+        }
+
+        if over_budget || stale {
+            self.peak.set(0);
+            self.generations.set(0);
+        } else {
+            self.peak.set(peak);
+            self.generations.set(generations);
+        }
+    }
 }
@@ -304,11 +304,31 @@ impl<'b> VirtualDom {
 
         match &attribute.value {
             AttributeValue::Listener(_) => {
+                #[cfg(debug_assertions)]
+                if let Some(scope) = self.scope_stack.last() {
+                    let generation = self.elements.generation(id).unwrap_or_default();
+                    self.listeners.listen(id, *scope, generation);
+                }
+
                 self.mutations.push(NewEventListener {
                     // all listeners start with "on"
-                    name: &unbounded_name[2..],
+                    // capture listeners are dispatched by the VirtualDom, so renderers only need to listen for the event
+                    name: unbounded_name[2..]
+                        .trim_end_matches("_prevent_default")
+                        .trim_end_matches("_capture"),
                     id,
-                })
+                });
+
+                // Renderers that can't wait for the handler to run find the listeners that always prevent the default
+                // action through an attribute named after the listener, like `onclick_prevent_default`
+                if unbounded_name.ends_with("_prevent_default") {
+                    self.mutations.push(SetAttribute {
+                        name: unbounded_name,
+                        value: BorrowedAttributeValue::Bool(true),
+                        ns: None,
+                        id,
+                    })
+                }
             }
             _ => {
                 // Safety: we promise not to re-alias this text later on after committing it to the mutation
@@ -1,7 +1,10 @@
-use crate::{ScopeId, ScopeState};
+use crate::{
+    innerlude::BoxedCellSlice, DynamicNode, Element, ScopeId, ScopeState, Template, TemplateNode,
+    VNode, VText,
+};
 use std::{
     any::{Any, TypeId},
-    cell::RefCell,
+    cell::{Cell, RefCell},
     fmt::Debug,
 };
 
@@ -46,6 +49,80 @@ impl ErrorBoundary {
     }
 }
 
+/// A panic caught while rendering a component.
+///
+/// When a panic fallback is set on the VirtualDom, the panic is also injected into the nearest error boundary as a
+/// `CapturedPanic`.
+#[derive(Debug, Clone)]
+pub struct CapturedPanic {
+    /// The message the component panicked with, if it was a string
+    pub message: String,
+
+    /// The scope that panicked
+    pub scope: ScopeId,
+
+    /// The name of the component that panicked
+    pub name: &'static str,
+}
+
+impl CapturedPanic {
+    pub(crate) fn new(scope: ScopeId, name: &'static str, payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "Box<dyn Any>".to_string(),
+            },
+        };
+
+        Self {
+            message,
+            scope,
+            name,
+        }
+    }
+}
+
+/// Renders the UI shown in place of a component that panicked while rendering.
+///
+/// Set one with [`crate::VirtualDom::with_panic_fallback`].
+pub type PanicFallback = for<'a> fn(&'a ScopeState, &CapturedPanic) -> Element<'a>;
+
+/// A fallback that renders the panic message and the component that panicked in a `pre` element
+pub fn default_panic_fallback<'a>(cx: &'a ScopeState, panic: &CapturedPanic) -> Element<'a> {
+    let value = bumpalo::format!(
+        in cx.bump(),
+        "component `{}` ({:?}) panicked: {}",
+        panic.name,
+        panic.scope,
+        panic.message
+    );
+
+    Some(VNode {
+        key: None,
+        parent: None,
+        template: Cell::new(PANIC_TEMPLATE),
+        root_ids: BoxedCellSlice::default(),
+        dynamic_nodes: cx.bump().alloc([DynamicNode::Text(VText {
+            value: value.into_bump_str(),
+            id: Default::default(),
+        })]),
+        dynamic_attrs: &[],
+    })
+}
+
+const PANIC_TEMPLATE: Template<'static> = Template {
+    name: concat!(file!(), ":", line!(), ":", column!(), ":0"),
+    roots: &[TemplateNode::Element {
+        tag: "pre",
+        namespace: None,
+        attrs: &[],
+        children: &[TemplateNode::DynamicText { id: 0 }],
+    }],
+    node_paths: &[&[0, 0]],
+    attr_paths: &[],
+};
+
 /// A trait to allow results to be thrown upwards to the nearest Error Boundary
 ///
 /// The canonical way of using this trait is to throw results from hooks, aborting rendering
@@ -1,5 +1,7 @@
+use crate::{nodes::AsyncMarker, ScopeState};
 use std::{
     cell::{Cell, RefCell},
+    future::Future,
     rc::Rc,
 };
 
@@ -24,6 +26,8 @@ pub struct Event<T: 'static + ?Sized> {
     /// The data associated with this event
     pub data: Rc<T>,
     pub(crate) propagates: Rc<Cell<bool>>,
+    pub(crate) propagates_immediately: Rc<Cell<bool>>,
+    pub(crate) prevents_default: Rc<Cell<bool>>,
 }
 
 impl<T> Event<T> {
@@ -62,6 +66,58 @@ impl<T> Event<T> {
         self.propagates.set(false);
     }
 
+    /// Prevent this event from reaching any other listeners, including other listeners on the current element.
+    ///
+    /// Unlike [`Event::stop_propagation`], this also prevents the bubbling listener on the same element from running
+    /// when called from a capture listener.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// rsx! {
+    ///     button {
+    ///         onclick_capture: move |evt: Event<MouseData>| {
+    ///             evt.stop_immediate_propagation();
+    ///         },
+    ///         onclick: move |_| unreachable!(),
+    ///     }
+    /// }
+    /// ```
+    pub fn stop_immediate_propagation(&self) {
+        self.propagates.set(false);
+        self.propagates_immediately.set(false);
+    }
+
+    /// Prevent the default action of this event, like following a link or submitting a form.
+    ///
+    /// The renderer finds out once the handlers have run, so this only works where the event is dispatched
+    /// synchronously: in the TUI renderer, and in the web renderer when the app runs on the page itself. Desktop,
+    /// liveview and web apps started with `launch_in_worker` hand the event to the VirtualDom after the webview or the
+    /// page has already handled it: prevent the default for every call of the handler with
+    /// [`prevent_default`](crate::prevent_default) there instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// rsx! {
+    ///     input {
+    ///         onkeydown: move |evt: Event<KeyboardData>| {
+    ///             if evt.key() == Key::Tab {
+    ///                 evt.prevent_default();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn prevent_default(&self) {
+        self.prevents_default.set(true);
+    }
+
+    /// Check if a listener has called [`Event::prevent_default`] on this event
+    pub fn default_prevented(&self) -> bool {
+        self.prevents_default.get()
+    }
+
     /// Get a reference to the inner data from this event
     ///
     /// ```rust, ignore
@@ -85,6 +141,8 @@ impl<T: ?Sized> Clone for Event<T> {
     fn clone(&self) -> Self {
         Self {
             propagates: self.propagates.clone(),
+            propagates_immediately: self.propagates_immediately.clone(),
+            prevents_default: self.prevents_default.clone(),
             data: self.data.clone(),
         }
     }
@@ -101,6 +159,8 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Event<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UiEvent")
             .field("bubble_state", &self.propagates)
+            .field("immediate_bubble_state", &self.propagates_immediately)
+            .field("prevents_default", &self.prevents_default)
             .field("data", &self.data)
             .finish()
     }
@@ -165,3 +225,77 @@ impl<T> EventHandler<'_, T> {
         self.callback.replace(None);
     }
 }
+
+/// The return type of an event handler
+///
+/// Handlers may either return nothing or a future. Futures are spawned onto the scope that created the handler, so
+/// they are automatically cancelled when that scope is unmounted.
+///
+/// ```rust, ignore
+/// rsx! {
+///     button {
+///         onclick: move |_| async move {
+///             let data = fetch_data().await;
+///             value.set(data);
+///         }
+///     }
+/// }
+/// ```
+#[doc(hidden)]
+pub trait EventReturn<P>: Sized {
+    fn spawn(self, _cx: &ScopeState) {}
+
+    /// If the renderer should prevent the default action of the event every time the handler is called
+    fn prevents_default() -> bool {
+        false
+    }
+}
+
+impl EventReturn<()> for () {}
+
+impl<T> EventReturn<AsyncMarker> for T
+where
+    T: Future<Output = ()> + 'static,
+{
+    #[inline]
+    fn spawn(self, cx: &ScopeState) {
+        cx.spawn(self);
+    }
+}
+
+/// The return type of a handler wrapped with [`prevent_default`]
+#[doc(hidden)]
+pub struct PreventDefault<E>(E);
+
+impl<P, E: EventReturn<P>> EventReturn<P> for PreventDefault<E> {
+    #[inline]
+    fn spawn(self, cx: &ScopeState) {
+        self.0.spawn(cx);
+    }
+
+    fn prevents_default() -> bool {
+        true
+    }
+}
+
+/// Prevent the default action of the event, like following a link or submitting a form, every time the handler is
+/// called.
+///
+/// The renderer prevents it when the event fires, before the handler runs, so this works even when the handler is
+/// called asynchronously. The data of the event can't be inferred through the wrapper, so name its type if the
+/// handler uses it.
+///
+/// ```rust, ignore
+/// rsx! {
+///     form {
+///         onsubmit: prevent_default(move |evt: FormEvent| {
+///             log::info!("submitted {:?}", evt.values);
+///         }),
+///     }
+/// }
+/// ```
+pub fn prevent_default<T: 'static, E>(
+    mut handler: impl FnMut(Event<T>) -> E,
+) -> impl FnMut(Event<T>) -> PreventDefault<E> {
+    move |evt| PreventDefault(handler(evt))
+}
@@ -0,0 +1,189 @@
+use crate::innerlude::*;
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+};
+
+/// A future that resolves to a component, returned by the loader of a [`Lazy`] component.
+pub type LazyComponent = Pin<Box<dyn Future<Output = Component>>>;
+
+/// Render a component that isn't available until a future resolves.
+///
+/// ## Details
+///
+/// The loader is called once when the `Lazy` component is first rendered. Once the component is loaded, it is
+/// rendered as a child of the `Lazy` scope and is never loaded again for the lifetime of that scope.
+///
+/// If there is a suspense boundary above the `Lazy` component (a scope that provides a [`SuspenseContext`] other than
+/// the root), the pending loader suspends that boundary like any async component would: the boundary holds back its
+/// subtree until the component is loaded, and [`VirtualDom::is_scope_suspended`] reports it as suspended in the
+/// meantime. Without a boundary, the future is spawned as a task on the `Lazy` scope and the `fallback` is rendered in
+/// its place (or a placeholder if there is no fallback).
+///
+/// This is useful for code splitting on the web or loading plugins on desktop, where the component is only known
+/// after some async work completes.
+///
+/// If the `Lazy` component is unmounted before the loader resolves, the future is dropped with the rest of the scope.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn load_settings() -> LazyComponent {
+///     Box::pin(async {
+///         load_wasm_chunk("settings").await;
+///         Settings as Component
+///     })
+/// }
+///
+/// rsx!{
+///     Lazy { loader: load_settings, fallback: cx.render(rsx!("Loading...")) }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn Lazy<'a>(cx: Scope<'a, LazyProps<'a>>) -> Element<'a> {
+    let state = cx.use_hook(|| {
+        let state = Rc::new(LazyState {
+            suspends: cx
+                .consume_context::<Rc<SuspenseContext>>()
+                .map_or(false, |boundary| boundary.id != ScopeId(0)),
+            loaded: Cell::new(None),
+            pending: RefCell::new(None),
+        });
+
+        let fut = (cx.props.loader)();
+        if state.suspends {
+            // The loader child awaits the future, so the boundary waits on it
+            *state.pending.borrow_mut() = Some(fut);
+        } else {
+            let slot = state.clone();
+            let update = cx.schedule_update();
+            cx.spawn(async move {
+                slot.loaded.set(Some(fut.await));
+                update();
+            });
+        }
+
+        state
+    });
+
+    if state.suspends {
+        let props = LazyLoaderProps {
+            state: state.clone(),
+        };
+        return render_lazy(cx, cx.component(LazyLoader, props, "LazyLoader"));
+    }
+
+    match state.loaded.get() {
+        Some(component) => render_lazy(cx, cx.component(component, (), "Lazy")),
+        None => cx.props.fallback.clone(),
+    }
+}
+
+/// What a [`Lazy`] component knows about its loader
+struct LazyState {
+    // Whether the loader suspends a boundary instead of rendering the fallback
+    suspends: bool,
+    loaded: Cell<Option<Component>>,
+    // The loader's future until the loader child starts waiting on it
+    pending: RefCell<Option<LazyComponent>>,
+}
+
+// An async component that suspends until the loader resolves and then renders the loaded component
+#[allow(non_snake_case)]
+async fn LazyLoader(cx: Scope<'_, LazyLoaderProps>) -> Element {
+    let state = &cx.props.state;
+
+    let pending = state.pending.borrow_mut().take();
+    if let Some(fut) = pending {
+        state.loaded.set(Some(fut.await));
+    }
+
+    let component = state.loaded.get()?;
+    render_lazy(cx, cx.component(component, (), "Lazy"))
+}
+
+struct LazyLoaderProps {
+    state: Rc<LazyState>,
+}
+
+impl Properties for LazyLoaderProps {
+    type Builder = ();
+    const IS_STATIC: bool = false;
+    fn builder() -> Self::Builder {}
+    unsafe fn memoize(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+fn render_lazy<'a>(cx: &'a ScopeState, node: DynamicNode<'a>) -> Element<'a> {
+    Some(VNode {
+        key: None,
+        parent: None,
+        template: Cell::new(LAZY_TEMPLATE),
+        root_ids: Default::default(),
+        dynamic_nodes: cx.bump().alloc([node]),
+        dynamic_attrs: &[],
+    })
+}
+
+// A template with a single dynamic root that holds the loaded component
+const LAZY_TEMPLATE: Template<'static> = Template {
+    name: concat!(file!(), ":", line!(), ":", column!(), ":0"),
+    roots: &[TemplateNode::Dynamic { id: 0 }],
+    node_paths: &[&[0]],
+    attr_paths: &[],
+};
+
+/// The props for the [`Lazy`] component.
+pub struct LazyProps<'a> {
+    loader: fn() -> LazyComponent,
+    fallback: Element<'a>,
+}
+
+pub struct LazyBuilder<'a, const LOADER: bool> {
+    loader: Option<fn() -> LazyComponent>,
+    fallback: Element<'a>,
+}
+
+impl<'a> LazyBuilder<'a, false> {
+    pub fn loader(self, loader: fn() -> LazyComponent) -> LazyBuilder<'a, true> {
+        LazyBuilder {
+            loader: Some(loader),
+            fallback: self.fallback,
+        }
+    }
+}
+
+impl<'a, const LOADER: bool> LazyBuilder<'a, LOADER> {
+    pub fn fallback(self, fallback: Element<'a>) -> Self {
+        LazyBuilder {
+            loader: self.loader,
+            fallback,
+        }
+    }
+}
+
+impl<'a> LazyBuilder<'a, true> {
+    pub fn build(self) -> LazyProps<'a> {
+        LazyProps {
+            loader: self.loader.unwrap(),
+            fallback: self.fallback,
+        }
+    }
+}
+
+impl<'a> Properties for LazyProps<'a> {
+    type Builder = LazyBuilder<'a, false>;
+    const IS_STATIC: bool = false;
+    fn builder() -> Self::Builder {
+        LazyBuilder {
+            loader: None,
+            fallback: None,
+        }
+    }
+    unsafe fn memoize(&self, _other: &Self) -> bool {
+        false
+    }
+}
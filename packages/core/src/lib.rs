@@ -10,6 +10,7 @@ mod dirty_scope;
 mod error_boundary;
 mod events;
 mod fragment;
+mod lazy;
 mod lazynodes;
 mod mutations;
 mod nodes;
@@ -21,10 +22,12 @@ mod virtual_dom;
 
 pub(crate) mod innerlude {
     pub use crate::arena::*;
+    pub use crate::bump_frame::ArenaPolicy;
     pub use crate::dirty_scope::*;
     pub use crate::error_boundary::*;
     pub use crate::events::*;
     pub use crate::fragment::*;
+    pub use crate::lazy::*;
     pub use crate::lazynodes::*;
     pub use crate::mutations::*;
     pub use crate::nodes::RenderReturn;
@@ -70,9 +73,11 @@ pub(crate) mod innerlude {
 }
 
 pub use crate::innerlude::{
-    fc_to_builder, AnyValue, Attribute, AttributeValue, BorrowedAttributeValue, CapturedError,
-    Component, DynamicNode, Element, ElementId, Event, Fragment, IntoDynNode, LazyNodes, Mutation,
-    Mutations, Properties, RenderReturn, Scope, ScopeId, ScopeState, Scoped, SuspenseContext,
+    default_panic_fallback, fc_to_builder, prevent_default, AnyValue, ArenaPolicy, AsyncMarker,
+    Attribute, AttributeValue, BorrowedAttributeValue, CapturedError, CapturedPanic, Component,
+    DynamicNode, Element, ElementId, Event, EventReturn, Fragment, IntoDynNode, Lazy,
+    LazyComponent, LazyNodes, LazyProps, Mutation, Mutations, PanicFallback, PreventDefault,
+    Properties, RenderReturn, SchedulerHandle, Scope, ScopeId, ScopeState, Scoped, SuspenseContext,
     TaskId, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VPlaceholder, VText,
     VirtualDom,
 };
@@ -82,9 +87,10 @@ pub use crate::innerlude::{
 /// This includes types like [`Scope`], [`Element`], and [`Component`].
 pub mod prelude {
     pub use crate::innerlude::{
-        fc_to_builder, AnyValue, Component, Element, Event, EventHandler, Fragment,
-        IntoAttributeValue, LazyNodes, Properties, Scope, ScopeId, ScopeState, Scoped, TaskId,
-        Template, TemplateAttribute, TemplateNode, Throw, VNode, VirtualDom,
+        fc_to_builder, prevent_default, AnyValue, Component, Element, Event, EventHandler,
+        EventReturn, Fragment, IntoAttributeValue, Lazy, LazyComponent, LazyNodes, Properties,
+        Scope, ScopeId, ScopeState, Scoped, TaskId, Template, TemplateAttribute, TemplateNode,
+        Throw, VNode, VirtualDom,
     };
 }
 
@@ -30,6 +30,12 @@ pub struct Mutations<'a> {
 
     /// Any mutations required to patch the renderer to match the layout of the VirtualDom
     pub edits: Vec<Mutation<'a>>,
+
+    /// The ElementIds that were released while producing these edits.
+    ///
+    /// Renderers that keep side tables keyed by ElementId should free these entries once the edits are applied. An id
+    /// listed here is not handed out again within the same set of edits, so it's always safe to drop its entry.
+    pub freed_ids: Vec<ElementId>,
 }
 
 impl<'a> Mutations<'a> {
@@ -632,19 +632,26 @@ impl<'a> std::fmt::Debug for AttributeValue<'a> {
     }
 }
 
+/// Two attribute values are equal if setting one after the other would be a no-op in the renderer
 impl<'a> PartialEq for AttributeValue<'a> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Text(l0), Self::Text(r0)) => l0 == r0,
-            (Self::Float(l0), Self::Float(r0)) => l0 == r0,
+            // Static strings and strings reused between renders often point to the same memory
+            (Self::Text(l0), Self::Text(r0)) => std::ptr::eq(*l0, *r0) || l0 == r0,
+            // Compare the bits so NaN doesn't always look changed, and 0.0 and -0.0 (which format differently) do
+            (Self::Float(l0), Self::Float(r0)) => l0.to_bits() == r0.to_bits(),
             (Self::Int(l0), Self::Int(r0)) => l0 == r0,
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
             (Self::Listener(_), Self::Listener(_)) => true,
             (Self::Any(l0), Self::Any(r0)) => {
                 let l0 = l0.borrow();
                 let r0 = r0.borrow();
-                l0.as_ref().unwrap().any_cmp(&**r0.as_ref().unwrap())
+                match (l0.as_ref(), r0.as_ref()) {
+                    (Some(l0), Some(r0)) => l0.any_cmp(&**r0),
+                    _ => false,
+                }
             }
+            (Self::None, Self::None) => true,
             _ => false,
         }
     }
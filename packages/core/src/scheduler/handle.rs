@@ -0,0 +1,83 @@
+use super::SchedulerMsg;
+use crate::{ElementId, ScopeId};
+use std::any::Any;
+
+/// A cheap, thread-safe handle to the scheduler of a [`crate::VirtualDom`].
+///
+/// The handle can be cloned and sent to background threads. It lets those threads mark scopes as dirty or inject
+/// events without owning the VirtualDom itself. Every message wakes up [`crate::VirtualDom::wait_for_work`].
+///
+/// Messages sent after the VirtualDom has been dropped are silently discarded.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let dom = VirtualDom::new(app);
+/// let handle = dom.scheduler_handle();
+///
+/// std::thread::spawn(move || {
+///     handle.mark_dirty(ScopeId(0));
+/// });
+/// ```
+#[derive(Clone)]
+pub struct SchedulerHandle {
+    pub(crate) tx: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
+}
+
+impl SchedulerHandle {
+    /// Mark a scope as requiring a re-render
+    ///
+    /// If the scope no longer exists by the time the message is processed, it is ignored.
+    pub fn mark_dirty(&self, id: ScopeId) {
+        _ = self.tx.unbounded_send(SchedulerMsg::Immediate(id));
+    }
+
+    /// Mark a scope as requiring a re-render once the renderer is idle
+    ///
+    /// See [`crate::ScopeState::schedule_idle_update`] for how idle updates are scheduled.
+    pub fn mark_idle(&self, id: ScopeId) {
+        _ = self.tx.unbounded_send(SchedulerMsg::Idle(id));
+    }
+
+    /// Queue an event to be dispatched into the VirtualDom
+    ///
+    /// The event is handled with the same semantics as [`crate::VirtualDom::handle_event`] the next time the VirtualDom
+    /// processes its queue.
+    pub fn send_event(
+        &self,
+        name: impl Into<String>,
+        data: impl Any + Send,
+        element: ElementId,
+        bubbles: bool,
+    ) {
+        _ = self.tx.unbounded_send(SchedulerMsg::Event(ExternalEvent {
+            name: name.into(),
+            data: Box::new(data),
+            element,
+            bubbles,
+        }));
+    }
+
+    /// Check if the VirtualDom this handle points to is still alive
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+/// An event injected into the VirtualDom from outside the thread that owns it
+pub(crate) struct ExternalEvent {
+    pub name: String,
+    pub data: Box<dyn Any + Send>,
+    pub element: ElementId,
+    pub bubbles: bool,
+}
+
+impl std::fmt::Debug for ExternalEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExternalEvent")
+            .field("name", &self.name)
+            .field("element", &self.element)
+            .field("bubbles", &self.bubbles)
+            .finish()
+    }
+}
@@ -1,10 +1,12 @@
 use crate::ScopeId;
 use slab::Slab;
 
+mod handle;
 mod suspense;
 mod task;
 mod wait;
 
+pub use handle::*;
 pub use suspense::*;
 pub use task::*;
 
@@ -16,11 +18,17 @@ pub(crate) enum SchedulerMsg {
     /// Immediate updates from Components that mark them as dirty
     Immediate(ScopeId),
 
+    /// Low priority updates that can wait until the renderer is idle
+    Idle(ScopeId),
+
     /// A task has woken and needs to be progressed
     TaskNotified(TaskId),
 
     /// A task has woken and needs to be progressed
     SuspenseNotified(SuspenseId),
+
+    /// An event was injected from outside the VirtualDom, potentially from another thread
+    Event(ExternalEvent),
 }
 
 use std::cell::{Cell, RefCell};
@@ -47,4 +55,11 @@ impl Scheduler {
             sequence: Cell::new(0),
         })
     }
+
+    /// Create a thread-safe handle to this scheduler
+    pub fn handle(&self) -> SchedulerHandle {
+        SchedulerHandle {
+            tx: self.sender.clone(),
+        }
+    }
 }
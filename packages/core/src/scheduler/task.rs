@@ -16,7 +16,7 @@ use std::task::Waker;
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct TaskId {
     pub(crate) entry: usize,
-    sequence: usize,
+    pub(crate) sequence: usize,
 }
 
 /// the task itself is the waker
@@ -24,7 +24,7 @@ pub(crate) struct LocalTask {
     pub scope: ScopeId,
     pub(super) task: RefCell<Pin<Box<dyn Future<Output = ()> + 'static>>>,
     pub waker: Waker,
-    sequence: usize,
+    pub(super) sequence: usize,
 }
 
 impl Scheduler {
@@ -71,7 +71,15 @@ impl Scheduler {
     ///
     /// This does not abort the task, so you'll want to wrap it in an aborthandle if that's important to you
     pub fn remove(&self, id: TaskId) {
-        self.tasks.borrow_mut().try_remove(id.entry);
+        let mut tasks = self.tasks.borrow_mut();
+
+        // The slot may have been reused by a newer task if this one already finished
+        if tasks
+            .get(id.entry)
+            .map_or(false, |local| local.sequence == id.sequence)
+        {
+            tasks.remove(id.entry);
+        }
     }
 
     pub fn has(&self, id: TaskId) -> bool {
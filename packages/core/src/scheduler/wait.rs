@@ -21,9 +21,9 @@ impl VirtualDom {
         let mut tasks = self.scheduler.tasks.borrow_mut();
 
         let task = match tasks.get(id.entry) {
-            Some(task) => task,
-            // The task was removed from the scheduler, so we can just ignore it
-            None => return,
+            Some(task) if task.sequence == id.sequence => task,
+            // The task was removed from the scheduler (and maybe replaced), so we can just ignore it
+            _ => return,
         };
 
         let mut cx = Context::from_waker(&task.waker);
@@ -45,6 +45,7 @@ impl VirtualDom {
             .unwrap()
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
     pub(crate) fn handle_suspense_wakeup(&mut self, id: SuspenseId) {
         let leaves = self.scheduler.leaves.borrow_mut();
         let leaf = leaves.get(id.0).unwrap();
@@ -103,6 +104,9 @@ impl VirtualDom {
                 std::mem::swap(&mut self.mutations, mutations);
 
                 if fiber.waiting_on.borrow().is_empty() {
+                    #[cfg(feature = "tracing")]
+                    tracing::trace!(boundary = ?fiber.id, "suspense boundary resolved");
+
                     self.finished_fibers.push(fiber.id);
                 }
             }
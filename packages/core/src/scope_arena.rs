@@ -2,6 +2,7 @@ use crate::{
     any_props::AnyProps,
     bump_frame::BumpFrame,
     innerlude::DirtyScope,
+    innerlude::{CapturedPanic, ComponentReturn},
     innerlude::{SuspenseHandle, SuspenseId, SuspenseLeaf},
     nodes::RenderReturn,
     scopes::{ScopeId, ScopeState},
@@ -10,6 +11,7 @@ use crate::{
 use futures_util::FutureExt;
 use std::{
     mem,
+    panic::{catch_unwind, AssertUnwindSafe},
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
@@ -44,6 +46,7 @@ impl VirtualDom {
             shared_contexts: Default::default(),
             borrowed_props: Default::default(),
             attributes_to_drop: Default::default(),
+            drop_callbacks: Default::default(),
         })
     }
 
@@ -53,6 +56,10 @@ impl VirtualDom {
         Some(scope)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self), fields(name = self.scopes[scope_id].name))
+    )]
     pub(crate) fn run_scope(&mut self, scope_id: ScopeId) -> &RenderReturn {
         // Cycle to the next frame and then reset it
         // This breaks any latent references, invalidating every pointer referencing into it.
@@ -60,7 +67,9 @@ impl VirtualDom {
         self.ensure_drop_safety(scope_id);
 
         let mut new_nodes = unsafe {
-            self.scopes[scope_id].previous_frame().bump_mut().reset();
+            self.scopes[scope_id]
+                .previous_frame()
+                .reset(&self.arena_policy);
 
             let scope = &self.scopes[scope_id];
 
@@ -70,7 +79,25 @@ impl VirtualDom {
             let props: &dyn AnyProps = scope.props.as_ref().unwrap().as_ref();
             let props: &dyn AnyProps = mem::transmute(props);
 
-            props.render(scope).extend_lifetime()
+            match (
+                catch_unwind(AssertUnwindSafe(|| props.render(scope))),
+                self.panic_fallback,
+            ) {
+                (Ok(nodes), _) => nodes.extend_lifetime(),
+
+                // Without a fallback, a component that panics renders nothing
+                (Err(_), None) => RenderReturn::default(),
+
+                // Render the fallback in place of the component if it panics
+                (Err(payload), Some(fallback)) => {
+                    let panic = CapturedPanic::new(scope_id, scope.name, payload);
+                    log::error!("{} panicked while rendering: {}", panic.name, panic.message);
+
+                    let nodes = fallback(scope, &panic);
+                    scope.throw(panic);
+                    nodes.into_return(scope).extend_lifetime()
+                }
+            }
         };
 
         // immediately resolve futures that can be resolved
@@ -3,7 +3,7 @@ use crate::{
     any_props::VProps,
     arena::ElementId,
     bump_frame::BumpFrame,
-    innerlude::{DynamicNode, EventHandler, VComponent, VText},
+    innerlude::{DynamicNode, EventHandler, EventReturn, VComponent, VText},
     innerlude::{ErrorBoundary, Scheduler, SchedulerMsg},
     lazynodes::LazyNodes,
     nodes::{ComponentReturn, IntoAttributeValue, IntoDynNode, RenderReturn},
@@ -184,6 +184,8 @@ pub struct ScopeState {
 
     pub(crate) props: Option<Box<dyn AnyProps<'static>>>,
     pub(crate) placeholder: Cell<Option<ElementId>>,
+
+    pub(crate) drop_callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
 }
 
 impl<'src> ScopeState {
@@ -329,6 +331,17 @@ impl<'src> ScopeState {
         })
     }
 
+    /// Create a subscription that schedules a low priority render for the reference component
+    ///
+    /// Idle updates are for work the user isn't waiting on, like rendering prefetched data or a list that is off
+    /// screen. Renderers that support it render them once the platform is idle, so they never delay input handling.
+    /// Other renderers render them like any other update. If the component is marked dirty in the meantime, the idle
+    /// update is rendered with it.
+    pub fn schedule_idle_update(&self) -> Arc<dyn Fn() + Send + Sync + 'static> {
+        let (chan, id) = (self.tasks.sender.clone(), self.scope_id());
+        Arc::new(move || drop(chan.unbounded_send(SchedulerMsg::Idle(id))))
+    }
+
     /// Mark this scope as dirty, and schedule a render for it.
     pub fn needs_update(&self) {
         self.needs_update_any(self.scope_id());
@@ -404,6 +417,18 @@ impl<'src> ScopeState {
         value2
     }
 
+    /// Expose state to every component in the [`crate::VirtualDom`] by providing it on the root scope.
+    ///
+    /// This is useful for hooks that need app-wide state, but may be called from any component.
+    pub fn provide_root_context<T: 'static + Clone>(&self, value: T) -> T {
+        let mut root = self;
+        while let Some(parent) = root.parent {
+            // safety: all parent pointers are valid thanks to the bump arena
+            root = unsafe { &*parent };
+        }
+        root.provide_context(value)
+    }
+
     /// Pushes the future onto the poll queue to be polled after the component renders.
     pub fn push_future(&self, fut: impl Future<Output = ()> + 'static) -> TaskId {
         let id = self.tasks.spawn(self.id, fut);
@@ -434,10 +459,38 @@ impl<'src> ScopeState {
         id
     }
 
+    /// Register a callback that runs when this scope is unmounted
+    ///
+    /// Callbacks run in a deterministic order:
+    /// - every child scope runs its callbacks before its parent
+    /// - within a scope, callbacks run in the order they were registered
+    ///
+    /// All callbacks run before any of the scope's hooks or tasks are dropped. This makes them a good fit for hooks that
+    /// own OS resources (terminal handles, file watchers, sockets) and need to clean up reliably.
+    ///
+    /// Every call registers a new callback, so this should generally be called inside of [`ScopeState::use_hook`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn app(cx: Scope) -> Element {
+    ///     cx.use_hook(|| {
+    ///         let watcher = start_watching();
+    ///         cx.on_drop(move || watcher.stop());
+    ///     });
+    ///
+    ///     render!(div {})
+    /// }
+    /// ```
+    pub fn on_drop(&self, f: impl FnOnce() + 'static) {
+        self.drop_callbacks.borrow_mut().push(Box::new(f));
+    }
+
     /// Informs the scheduler that this task is no longer needed and should be removed.
     ///
     /// This drops the task immediately.
     pub fn remove_future(&self, id: TaskId) {
+        self.spawned_tasks.borrow_mut().remove(&id);
         self.tasks.remove(id);
     }
 
@@ -571,8 +624,14 @@ impl<'src> ScopeState {
     }
 
     /// Create a new [`EventHandler`] from an [`FnMut`]
-    pub fn event_handler<T>(&'src self, f: impl FnMut(T) + 'src) -> EventHandler<'src, T> {
-        let handler: &mut dyn FnMut(T) = self.bump().alloc(f);
+    ///
+    /// If the callback returns a future, the future is spawned onto this scope every time the handler is called and
+    /// will be cancelled if this scope is unmounted.
+    pub fn event_handler<T, R: EventReturn<M>, M>(
+        &'src self,
+        mut f: impl FnMut(T) -> R + 'src,
+    ) -> EventHandler<'src, T> {
+        let handler: &mut dyn FnMut(T) = self.bump().alloc(move |event: T| f(event).spawn(self));
         let caller = unsafe { BumpBox::from_raw(handler as *mut dyn FnMut(T)) };
         let callback = RefCell::new(Some(caller));
         EventHandler { callback }
@@ -594,6 +653,8 @@ impl<'src> ScopeState {
                 if let Ok(data) = event.data.downcast::<T>() {
                     callback(Event {
                         propagates: event.propagates,
+                        propagates_immediately: event.propagates_immediately,
+                        prevents_default: event.prevents_default,
                         data,
                     });
                 }
@@ -2,20 +2,26 @@
 //!
 //! This module provides the primary mechanics to create a hook-based, concurrent VDOM for Rust.
 
+#[cfg(debug_assertions)]
+use crate::arena::ListenerTracker;
 use crate::{
     any_props::VProps,
-    arena::{ElementId, ElementRef},
-    innerlude::{DirtyScope, ErrorBoundary, Mutations, Scheduler, SchedulerMsg, ScopeSlab},
+    arena::{ElementId, ElementPath, ElementRef, ElementSlab},
+    bump_frame::ArenaPolicy,
+    innerlude::{
+        DirtyScope, ErrorBoundary, ExternalEvent, Mutations, PanicFallback, Scheduler,
+        SchedulerHandle, SchedulerMsg, ScopeSlab,
+    },
     mutations::Mutation,
     nodes::RenderReturn,
-    nodes::{Template, TemplateId},
+    nodes::{Template, TemplateAttribute, TemplateId, TemplateNode},
     scheduler::SuspenseId,
     scopes::{ScopeId, ScopeState},
-    AttributeValue, Element, Event, Scope, SuspenseContext,
+    AttributeValue, BorrowedAttributeValue, DynamicNode, Element, Event, Scope, SuspenseContext,
+    VNode,
 };
-use futures_util::{pin_mut, StreamExt};
+use futures_util::{pin_mut, FutureExt, StreamExt};
 use rustc_hash::FxHashMap;
-use slab::Slab;
 use std::{any::Any, borrow::BorrowMut, cell::Cell, collections::BTreeSet, future::Future, rc::Rc};
 
 /// A virtual node system that progresses user events and diffs UI trees.
@@ -179,10 +185,12 @@ pub struct VirtualDom {
     pub(crate) templates: FxHashMap<TemplateId, FxHashMap<usize, Template<'static>>>,
     pub(crate) scopes: ScopeSlab,
     pub(crate) dirty_scopes: BTreeSet<DirtyScope>,
+    // Scopes waiting for idle time, if the renderer schedules idle updates
+    pub(crate) idle_scopes: Option<BTreeSet<DirtyScope>>,
     pub(crate) scheduler: Rc<Scheduler>,
 
     // Every element is actually a dual reference - one to the template and the other to the dynamic node in that template
-    pub(crate) elements: Slab<ElementRef>,
+    pub(crate) elements: ElementSlab,
 
     // While diffing we need some sort of way of breaking off a stream of suspended mutations.
     pub(crate) scope_stack: Vec<ScopeId>,
@@ -195,6 +203,16 @@ pub struct VirtualDom {
     pub(crate) rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
 
     pub(crate) mutations: Mutations<'static>,
+
+    pub(crate) arena_policy: ArenaPolicy,
+
+    pub(crate) panic_fallback: Option<PanicFallback>,
+
+    // Elements the user edited through an input or change event, with the generation of their id at the time
+    pub(crate) volatile_targets: Vec<(ElementId, Option<u32>)>,
+
+    #[cfg(debug_assertions)]
+    pub(crate) listeners: ListenerTracker,
 }
 
 impl VirtualDom {
@@ -262,9 +280,15 @@ impl VirtualDom {
             elements: Default::default(),
             scope_stack: Vec::new(),
             dirty_scopes: BTreeSet::new(),
+            idle_scopes: None,
             collected_leaves: Vec::new(),
             finished_fibers: Vec::new(),
             mutations: Mutations::default(),
+            arena_policy: ArenaPolicy::default(),
+            panic_fallback: None,
+            volatile_targets: Vec::new(),
+            #[cfg(debug_assertions)]
+            listeners: Default::default(),
         };
 
         let root = dom.new_scope(
@@ -309,13 +333,119 @@ impl VirtualDom {
         self
     }
 
+    /// Build the virtualdom with a policy that limits how much memory component arenas retain between renders
+    ///
+    /// See [`ArenaPolicy`] for the available options. By default, arenas never shrink.
+    pub fn with_arena_policy(mut self, policy: ArenaPolicy) -> Self {
+        self.set_arena_policy(policy);
+        self
+    }
+
+    /// Change the policy that limits how much memory component arenas retain between renders
+    ///
+    /// The new policy takes effect the next time each component renders.
+    pub fn set_arena_policy(&mut self, policy: ArenaPolicy) {
+        self.arena_policy = policy;
+    }
+
+    /// Build the virtualdom with a fallback that is rendered in place of any component that panics while rendering
+    ///
+    /// Panics while a component renders are always caught. Without a fallback, the component renders an empty
+    /// placeholder in place of its subtree. With one, the panic is logged, injected into the nearest error boundary,
+    /// and the fallback is rendered in place of the component's subtree. Either way, the component renders normally
+    /// again the next time it's marked dirty.
+    ///
+    /// ```rust, ignore
+    /// let dom = VirtualDom::new(app).with_panic_fallback(default_panic_fallback);
+    /// ```
+    pub fn with_panic_fallback(mut self, fallback: PanicFallback) -> Self {
+        self.panic_fallback = Some(fallback);
+        self
+    }
+
+    /// Build the virtualdom with a separate lane for idle updates
+    ///
+    /// Idle updates, scheduled with [`ScopeState::schedule_idle_update`], wait in the lane until the renderer calls
+    /// [`VirtualDom::promote_idle_work`], which it should do when the platform is idle. Without the lane, idle updates
+    /// are rendered like any other update.
+    ///
+    /// ```rust, ignore
+    /// let mut dom = VirtualDom::new(app).with_idle_lane();
+    ///
+    /// loop {
+    ///     select! {
+    ///         _ = dom.wait_for_work() => {}
+    ///         _ = idle_time(), if dom.has_idle_work() => dom.promote_idle_work(),
+    ///     }
+    ///     real_dom.apply(dom.render_immediate());
+    /// }
+    /// ```
+    pub fn with_idle_lane(mut self) -> Self {
+        self.idle_scopes = Some(BTreeSet::new());
+        self
+    }
+
     /// Manually mark a scope as requiring a re-render
     ///
     /// Whenever the VirtualDom "works", it will re-render this scope
     pub fn mark_dirty(&mut self, id: ScopeId) {
         if let Some(scope) = self.scopes.get(id) {
-            let height = scope.height;
-            self.dirty_scopes.insert(DirtyScope { height, id });
+            let dirty = DirtyScope {
+                height: scope.height,
+                id,
+            };
+
+            // The scope is rendered now, so any idle update is rendered with it
+            if let Some(idle) = &mut self.idle_scopes {
+                idle.remove(&dirty);
+            }
+
+            self.dirty_scopes.insert(dirty);
+        }
+    }
+
+    /// Mark a scope as requiring a re-render once the renderer is idle
+    ///
+    /// If the VirtualDom was not built [`VirtualDom::with_idle_lane`], this is the same as [`VirtualDom::mark_dirty`].
+    pub fn mark_idle(&mut self, id: ScopeId) {
+        let idle = match &mut self.idle_scopes {
+            Some(idle) => idle,
+            None => return self.mark_dirty(id),
+        };
+
+        if let Some(scope) = self.scopes.get(id) {
+            let dirty = DirtyScope {
+                height: scope.height,
+                id,
+            };
+
+            if !self.dirty_scopes.contains(&dirty) {
+                idle.insert(dirty);
+            }
+        }
+    }
+
+    /// Check if any scopes are waiting to be rendered in the regular lane
+    ///
+    /// Updates that were scheduled since the VirtualDom last ran are only seen after [`VirtualDom::process_events`].
+    pub fn has_dirty_scopes(&self) -> bool {
+        !self.dirty_scopes.is_empty()
+    }
+
+    /// Check if any scopes are waiting in the idle lane
+    pub fn has_idle_work(&self) -> bool {
+        matches!(&self.idle_scopes, Some(idle) if !idle.is_empty())
+    }
+
+    /// Move the scopes waiting in the idle lane to the regular lane, so the next render includes them
+    pub fn promote_idle_work(&mut self) {
+        let idle = match &mut self.idle_scopes {
+            Some(idle) => std::mem::take(idle),
+            None => return,
+        };
+
+        for dirty in idle {
+            self.mark_dirty(dirty.id);
         }
     }
 
@@ -346,23 +476,35 @@ impl VirtualDom {
     ///
     /// It is up to the listeners themselves to mark nodes as dirty.
     ///
+    /// Like the DOM, events are dispatched in two phases. First, capture listeners (declared with a `_capture` suffix,
+    /// like `onclick_capture`) are called from the root down to the target element. Then, regular listeners are called
+    /// from the target element up to the root. Capture listeners are called even if the event doesn't bubble.
+    ///
+    /// Returns true if a listener called [`Event::prevent_default`] or was wrapped in
+    /// [`prevent_default`](crate::prevent_default). Renderers that dispatch events synchronously use this to prevent the
+    /// default action of the event.
+    ///
     /// If you have multiple events, you can call this method multiple times before calling "render_with_deadline"
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, data))
+    )]
     pub fn handle_event(
         &mut self,
         name: &str,
         data: Rc<dyn Any>,
         element: ElementId,
         bubbles: bool,
-    ) {
+    ) -> bool {
         /*
         ------------------------
-        The algorithm works by walking through the list of dynamic attributes, checking their paths, and breaking when
-        we find the target path.
+        The algorithm works by walking through every dynamic attribute in the template and keeping the listeners whose
+        path is the target path or one of its ascendants.
 
         With the target path, we try and move up to the parent until there is no parent.
-        Due to how bubbling works, we call the listeners before walking to the parent.
 
-        If we wanted to do capturing, then we would accumulate all the listeners and call them in reverse order.
+        Listeners are accumulated for the whole path up front. Capture listeners are then called in reverse order (root
+        to target) and bubbling listeners in order (target to root).
         ----------------------
 
         For a visual demonstration, here we present a tree on the left and whether or not a listener is collected on the
@@ -371,16 +513,30 @@ impl VirtualDom {
         |           <-- yes (is ascendant)
         | | |       <-- no  (is not direct ascendant)
         | |         <-- yes (is ascendant)
-        | | | | |   <--- target element, break early, don't check other listeners
-        | | |       <-- no, broke early
-        |           <-- no, broke early
+        | | | | |   <--- yes (target element)
+        | | |       <-- no  (is not ascendant)
+        |           <-- no  (is not ascendant)
         */
-        let mut parent_path = self.elements.get(element.0);
-        let mut listeners = vec![];
+
+        #[cfg(debug_assertions)]
+        self.listeners
+            .check(name, element, self.elements.generation(element));
+
+        // Controlled inputs go back to their rendered value unless the handler changes it
+        if matches!(name, "input" | "change") {
+            self.volatile_targets
+                .push((element, self.elements.generation(element)));
+        }
+
+        let mut parent_path = self.elements.get(element);
+        let mut capture_listeners = vec![];
+        let mut bubble_listeners = vec![];
 
         // We will clone this later. The data itself is wrapped in RC to be used in callbacks if required
         let uievent = Event {
-            propagates: Rc::new(Cell::new(bubbles)),
+            propagates: Rc::new(Cell::new(true)),
+            propagates_immediately: Rc::new(Cell::new(true)),
+            prevents_default: Rc::new(Cell::new(false)),
             data,
         };
 
@@ -391,44 +547,142 @@ impl VirtualDom {
             let node_template = template.template.get();
             let target_path = el_ref.path;
 
+            // Attributes are stored in tree order, so deeper listeners are at the end of this template
+            let capture_start = capture_listeners.len();
+            let bubble_start = bubble_listeners.len();
+
             for (idx, attr) in template.dynamic_attrs.iter().enumerate() {
                 let this_path = node_template.attr_paths[idx];
 
+                if !target_path.is_descendant(&this_path) {
+                    continue;
+                }
+
                 // Remove the "on" prefix if it exists, TODO, we should remove this and settle on one
-                if attr.name.trim_start_matches("on") == name
-                    && target_path.is_ascendant(&this_path)
-                {
-                    listeners.push(&attr.value);
-
-                    // Break if the event doesn't bubble anyways
-                    if !bubbles {
-                        break;
-                    }
+                let attr_name = attr.name.trim_start_matches("on");
+                let prevents_default = attr_name.ends_with("_prevent_default");
+                let attr_name = attr_name.trim_end_matches("_prevent_default");
 
-                    // Break if this is the exact target element.
-                    // This means we won't call two listeners with the same name on the same element. This should be
-                    // documented, or be rejected from the rsx! macro outright
-                    if target_path == this_path {
-                        break;
+                // Listeners on the same element share the same template and path
+                let element = (template as *const VNode, this_path);
+
+                if attr_name == name {
+                    // Non-bubbling events only call the listener on the target element
+                    if bubbles || target_path == this_path {
+                        bubble_listeners.push((element, &attr.value, prevents_default));
                     }
+                } else if attr_name.strip_suffix("_capture") == Some(name) {
+                    capture_listeners.push((element, &attr.value, prevents_default));
                 }
             }
 
-            // Now that we've accumulated all the parent attributes for the target element, call them in reverse order
-            // We check the bubble state between each call to see if the event has been stopped from bubbling
-            for listener in listeners.drain(..).rev() {
-                if let AttributeValue::Listener(listener) = listener {
-                    if let Some(cb) = listener.borrow_mut().as_deref_mut() {
-                        cb(uievent.clone());
-                    }
+            // Listeners deeper in the tree come first
+            capture_listeners[capture_start..].reverse();
+            bubble_listeners[bubble_start..].reverse();
+
+            parent_path = template.parent.and_then(|id| self.elements.get(id));
+        }
+
+        // Capture from the root down to the target, then bubble from the target back up to the root
+        // We check the propagation state between each call to see if the event has been stopped. Stopping propagation
+        // still lets the other listeners on the current element run, like the DOM does.
+        let listeners = capture_listeners
+            .into_iter()
+            .rev()
+            .chain(bubble_listeners.into_iter());
+
+        let mut stopped_on = None;
+        for (element, listener, prevents_default) in listeners {
+            if matches!(stopped_on, Some(stopped) if stopped != element) {
+                break;
+            }
+
+            if let AttributeValue::Listener(listener) = listener {
+                if prevents_default {
+                    uievent.prevents_default.set(true);
+                }
+
+                if let Some(cb) = listener.borrow_mut().as_deref_mut() {
+                    cb(uievent.clone());
+                }
+
+                if !uievent.propagates_immediately.get() {
+                    break;
+                }
 
-                    if !uievent.propagates.get() {
-                        return;
+                if !uievent.propagates.get() && stopped_on.is_none() {
+                    stopped_on = Some(element);
+                }
+            }
+        }
+
+        uievent.prevents_default.get()
+    }
+
+    /// Write the volatile attributes (like an input's `value`) of the elements the user edited back to the renderer
+    ///
+    /// The user can change these attributes without going through the VirtualDom, so if a handler rejects the change
+    /// by not updating its state, the renderer would keep showing the rejected value. This runs once the handlers and
+    /// any rerenders are done: if the rerender already wrote the attribute, that is the value the handler chose and
+    /// nothing else is written. Writing the old value first would reset the caret in the middle of typing.
+    ///
+    /// Static `value`, `checked` and `selected` attributes in the template are controlled too.
+    fn reassert_volatile_attributes(&mut self) {
+        for (element, generation) in std::mem::take(&mut self.volatile_targets) {
+            // The element may have been removed (and its id reused) by the handler
+            if self.elements.generation(element) != generation {
+                continue;
+            }
+
+            let el_ref = match self.elements.get(element) {
+                Some(el_ref) => el_ref,
+                None => continue,
+            };
+
+            // safety: we maintain references of all vnodes in the element slab
+            let template = match el_ref.template {
+                Some(template) => unsafe { template.as_ref() },
+                None => continue,
+            };
+            let attr_paths = template.template.get().attr_paths;
+
+            let mut values = vec![];
+            for (idx, attr) in template.dynamic_attrs.iter().enumerate() {
+                if attr.volatile && el_ref.path == attr_paths[idx] {
+                    let value: BorrowedAttributeValue = (&attr.value).into();
+                    values.push((attr.name, value, attr.namespace));
+                }
+            }
+            if let Some(TemplateNode::Element { attrs, .. }) =
+                template_node_at(template.template.get().roots, el_ref.path)
+            {
+                for attr in attrs.iter() {
+                    if let TemplateAttribute::Static {
+                        name: name @ ("value" | "checked" | "selected"),
+                        value,
+                        namespace: None,
+                    } = attr
+                    {
+                        values.push((*name, BorrowedAttributeValue::Text(value), None));
                     }
                 }
             }
 
-            parent_path = template.parent.and_then(|id| self.elements.get(id.0));
+            for (name, value, ns) in values {
+                let written = self.mutations.edits.iter().any(|edit| {
+                    matches!(edit, Mutation::SetAttribute { name: n, id, .. } if *id == element && *n == name)
+                });
+                if written {
+                    continue;
+                }
+
+                self.mutations.push(Mutation::SetAttribute {
+                    name: unsafe { std::mem::transmute(name) },
+                    value: unsafe { std::mem::transmute(value) },
+                    id: element,
+                    ns: unsafe { std::mem::transmute(ns) },
+                });
+            }
         }
     }
 
@@ -450,14 +704,20 @@ impl VirtualDom {
     /// ```
     pub async fn wait_for_work(&mut self) {
         let mut some_msg = None;
+        let mut new_idle_work = false;
 
         loop {
             match some_msg.take() {
                 // If a bunch of messages are ready in a sequence, try to pop them off synchronously
                 Some(msg) => match msg {
                     SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                    SchedulerMsg::Idle(id) => {
+                        self.mark_idle(id);
+                        new_idle_work = self.has_idle_work();
+                    }
                     SchedulerMsg::TaskNotified(task) => self.handle_task_wakeup(task),
                     SchedulerMsg::SuspenseNotified(id) => self.handle_suspense_wakeup(id),
+                    SchedulerMsg::Event(event) => self.handle_external_event(event),
                 },
 
                 // If they're not ready, then we should wait for them to be ready
@@ -467,7 +727,11 @@ impl VirtualDom {
                         Ok(None) => return,
                         Err(_) => {
                             // If we have any dirty scopes, or finished fiber trees then we should exit
-                            if !self.dirty_scopes.is_empty() || !self.finished_fibers.is_empty() {
+                            // New idle work also exits, so the renderer can wait for idle time to render it
+                            if !self.dirty_scopes.is_empty()
+                                || !self.finished_fibers.is_empty()
+                                || new_idle_work
+                            {
                                 return;
                             }
 
@@ -484,26 +748,58 @@ impl VirtualDom {
         while let Ok(Some(msg)) = self.rx.try_next() {
             match msg {
                 SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                SchedulerMsg::Idle(id) => self.mark_idle(id),
                 SchedulerMsg::TaskNotified(task) => self.handle_task_wakeup(task),
                 SchedulerMsg::SuspenseNotified(id) => self.handle_suspense_wakeup(id),
+                SchedulerMsg::Event(event) => self.handle_external_event(event),
             }
         }
     }
 
+    /// Get a thread-safe handle that can mark scopes dirty and inject events from outside the VirtualDom
+    ///
+    /// This is useful for integrations that receive events on background threads. Instead of funneling them through
+    /// a renderer-specific event loop, they can send them straight to the scheduler, waking up
+    /// [`VirtualDom::wait_for_work`].
+    ///
+    /// ```rust, ignore
+    /// let dom = VirtualDom::new(app);
+    /// let handle = dom.scheduler_handle();
+    ///
+    /// std::thread::spawn(move || loop {
+    ///     std::thread::sleep(Duration::from_secs(1));
+    ///     handle.mark_dirty(ScopeId(0));
+    /// });
+    /// ```
+    pub fn scheduler_handle(&self) -> SchedulerHandle {
+        self.scheduler.handle()
+    }
+
+    fn handle_external_event(&mut self, event: ExternalEvent) {
+        let data: Rc<dyn Any + Send> = event.data.into();
+        self.handle_event(&event.name, data, event.element, event.bubbles);
+    }
+
     /// Replace a template at runtime. This will re-render all components that use this template.
     /// This is the primitive that enables hot-reloading.
     ///
     /// The caller must ensure that the template refrences the same dynamic attributes and nodes as the original template.
     ///
-    /// This will only replace the the parent template, not any nested templates.
+    /// Any scope that renders the template - either as its root or nested inside of fragments - is marked dirty, so the
+    /// next call to [`VirtualDom::render_immediate`] or [`VirtualDom::render_with_deadline`] will diff it against the
+    /// new template. Renderers don't need to do anything special beyond forwarding the templates from their hot reload
+    /// channel and applying the resulting mutations.
+    ///
+    /// Note that the new template is only picked up during diffing in debug builds.
     pub fn replace_template(&mut self, template: Template<'static>) {
         self.register_template_first_byte_index(template);
+
+        let (path, _) = template.name.rsplit_once(':').unwrap();
+
         // iterating a slab is very inefficient, but this is a rare operation that will only happen during development so it's fine
         for scope in self.scopes.iter() {
             if let Some(RenderReturn::Ready(sync)) = scope.try_root_node() {
-                if sync.template.get().name.rsplit_once(':').unwrap().0
-                    == template.name.rsplit_once(':').unwrap().0
-                {
+                if uses_template(sync, path) {
                     let height = scope.height;
                     self.dirty_scopes.insert(DirtyScope {
                         height,
@@ -552,30 +848,126 @@ impl VirtualDom {
         self.finalize()
     }
 
+    /// Re-render a single scope and any dirty components beneath it, returning only the mutations for that subtree.
+    ///
+    /// This is useful when embedding Dioxus in a host app that owns the rest of the screen and only wants edits for
+    /// the region a component controls. Dirty components outside of the subtree are left dirty for the next render.
+    ///
+    /// Events are processed before rendering, but tasks are not polled and suspended subtrees are not waited on.
+    ///
+    /// ```rust, ignore
+    /// let mut dom = VirtualDom::new(app);
+    /// let _ = dom.rebuild();
+    ///
+    /// let sidebar_edits = dom.rebuild_subtree(sidebar_scope);
+    /// ```
+    pub fn rebuild_subtree(&mut self, id: ScopeId) -> Mutations {
+        self.process_events();
+        self.mark_dirty(id);
+
+        while let Some(dirty) = self
+            .dirty_scopes
+            .iter()
+            .find(|dirty| self.is_in_subtree(dirty.id, id))
+            .cloned()
+        {
+            self.dirty_scopes.remove(&dirty);
+            self.render_dirty_scope(dirty.id);
+        }
+
+        self.finalize()
+    }
+
     /// Render whatever the VirtualDom has ready as fast as possible without requiring an executor to progress
     /// suspended subtrees.
     pub fn render_immediate(&mut self) -> Mutations {
+        self.work_immediate();
+        self.finalize()
+    }
+
+    /// Synchronously process every pending message and dirty scope, returning all of the resulting mutations.
+    ///
+    /// Unlike [`VirtualDom::render_immediate`], this keeps going until the queue is drained: if a component marks
+    /// itself or another component as dirty while rendering, that work is included in the returned mutations too.
+    /// Suspended subtrees are not waited on.
+    ///
+    /// This is useful in tests and for imperative patterns that need the UI to be up to date before continuing, like
+    /// reading the layout of an element and then immediately updating the UI based on it.
+    ///
+    /// Note that a component that marks itself dirty on every render will cause this method to loop forever.
+    ///
+    /// ```rust, ignore
+    /// let mut dom = VirtualDom::new(app);
+    /// let _ = dom.rebuild();
+    ///
+    /// dom.handle_event("click", Rc::new(MouseData::default()), ElementId(1), true);
+    /// let mutations = dom.flush_sync();
+    /// ```
+    pub fn flush_sync(&mut self) -> Mutations {
+        loop {
+            self.process_events();
+
+            if self.dirty_scopes.is_empty() && self.finished_fibers.is_empty() {
+                break;
+            }
+
+            self.work_immediate();
+        }
+
+        self.finalize()
+    }
+
+    /// Render what you can given the timeline and then move on
+    ///
+    /// It's generally a good idea to put some sort of limit on the suspense process in case a future is having issues.
+    ///
+    /// The deadline is checked between each dirty component. If it expires before all of them have been diffed, the
+    /// mutations produced so far are returned and the remaining components stay dirty. This lets renderers give the
+    /// VirtualDom a frame budget and pick up the rest of a large update in the next frame:
+    ///
+    /// ```rust, ignore
+    /// loop {
+    ///     dom.wait_for_work().await;
+    ///
+    ///     // spend at most 8ms diffing before handing control back to the browser
+    ///     let mutations = dom.render_with_deadline(sleep(Duration::from_millis(8))).await;
+    ///     apply(mutations);
+    /// }
+    /// ```
+    ///
+    /// [`VirtualDom::wait_for_work`] resolves immediately while there are leftover dirty components.
+    pub async fn render_with_deadline(&mut self, deadline: impl Future<Output = ()>) -> Mutations {
+        self.work_with_deadline(deadline, true).await;
+        self.finalize()
+    }
+
+    /// Perform any ready work without polling any async tasks, accumulating the mutations
+    fn work_immediate(&mut self) {
         // Build a waker that won't wake up since our deadline is already expired when it's polled
         let waker = futures_util::task::noop_waker();
         let mut cx = std::task::Context::from_waker(&waker);
 
         // Now run render with deadline but dont even try to poll any async tasks
-        let fut = self.render_with_deadline(std::future::ready(()));
+        // The deadline is only used for suspense, we always want to finish the synchronous work
+        let fut = self.work_with_deadline(std::future::ready(()), false);
         pin_mut!(fut);
 
         // The root component is not allowed to be async
         match fut.poll(&mut cx) {
-            std::task::Poll::Ready(mutations) => mutations,
+            std::task::Poll::Ready(()) => {}
             std::task::Poll::Pending => panic!("render_immediate should never return pending"),
         }
     }
 
-    /// Render what you can given the timeline and then move on
-    ///
-    /// It's generally a good idea to put some sort of limit on the suspense process in case a future is having issues.
+    /// Perform work until the deadline expires, accumulating the mutations
     ///
-    /// If no suspense trees are present
-    pub async fn render_with_deadline(&mut self, deadline: impl Future<Output = ()>) -> Mutations {
+    /// If `interruptible` is set, the deadline is checked between each dirty scope and any remaining scopes are left
+    /// dirty for the next call.
+    async fn work_with_deadline(
+        &mut self,
+        deadline: impl Future<Output = ()>,
+        interruptible: bool,
+    ) {
         pin_mut!(deadline);
 
         self.process_events();
@@ -602,59 +994,24 @@ impl VirtualDom {
             }
 
             // Next, diff any dirty scopes
-            // We choose not to poll the deadline since we complete pretty quickly anyways
             if let Some(dirty) = self.dirty_scopes.iter().next().cloned() {
                 self.dirty_scopes.remove(&dirty);
-
-                // If the scope doesn't exist for whatever reason, then we should skip it
-                if !self.scopes.contains(dirty.id) {
-                    continue;
-                }
-
-                // if the scope is currently suspended, then we should skip it, ignoring any tasks calling for an update
-                if self.is_scope_suspended(dirty.id) {
-                    continue;
-                }
-
-                // Save the current mutations length so we can split them into boundary
-                let mutations_to_this_point = self.mutations.edits.len();
-
-                // Run the scope and get the mutations
-                self.run_scope(dirty.id);
-                self.diff_scope(dirty.id);
-
-                // If suspended leaves are present, then we should find the boundary for this scope and attach things
-                // No placeholder necessary since this is a diff
-                if !self.collected_leaves.is_empty() {
-                    let mut boundary = self.scopes[dirty.id]
-                        .consume_context::<Rc<SuspenseContext>>()
-                        .unwrap();
-
-                    let boundary_mut = boundary.borrow_mut();
-
-                    // Attach mutations
-                    boundary_mut
-                        .mutations
-                        .borrow_mut()
-                        .edits
-                        .extend(self.mutations.edits.split_off(mutations_to_this_point));
-
-                    // Attach suspended leaves
-                    boundary
-                        .waiting_on
-                        .borrow_mut()
-                        .extend(self.collected_leaves.drain(..));
-                }
+                self.render_dirty_scope(dirty.id);
             }
 
             // If there's more work, then just continue, plenty of work to do
             if !self.dirty_scopes.is_empty() {
+                // Unless we've run out of time, in which case the rest of the work is yielded to the next frame
+                if interruptible && deadline.as_mut().now_or_never().is_some() {
+                    return;
+                }
+
                 continue;
             }
 
             // If there's no pending suspense, then we have no reason to wait for anything
             if self.scheduler.leaves.borrow().is_empty() {
-                return self.finalize();
+                return;
             }
 
             // Poll the suspense leaves in the meantime
@@ -668,15 +1025,111 @@ impl VirtualDom {
             if let Either::Left((_, _)) = select(&mut deadline, pinned).await {
                 // release the borrowed
                 drop(work);
-                return self.finalize();
+                return;
+            }
+        }
+    }
+
+    /// Re-render a dirty scope and diff it against its last render
+    fn render_dirty_scope(&mut self, id: ScopeId) {
+        // If the scope doesn't exist for whatever reason, then we should skip it
+        if !self.scopes.contains(id) {
+            return;
+        }
+
+        // if the scope is currently suspended, then we should skip it, ignoring any tasks calling for an update
+        if self.is_scope_suspended(id) {
+            return;
+        }
+
+        // Save the current mutations length so we can split them into boundary
+        let mutations_to_this_point = self.mutations.edits.len();
+
+        // Run the scope and get the mutations
+        self.run_scope(id);
+        self.diff_scope(id);
+
+        // If suspended leaves are present, then we should find the boundary for this scope and attach things
+        // No placeholder necessary since this is a diff
+        if !self.collected_leaves.is_empty() {
+            let mut boundary = self.scopes[id]
+                .consume_context::<Rc<SuspenseContext>>()
+                .unwrap();
+
+            let boundary_mut = boundary.borrow_mut();
+
+            // Attach mutations
+            boundary_mut
+                .mutations
+                .borrow_mut()
+                .edits
+                .extend(self.mutations.edits.split_off(mutations_to_this_point));
+
+            // Attach suspended leaves
+            boundary
+                .waiting_on
+                .borrow_mut()
+                .extend(self.collected_leaves.drain(..));
+        }
+    }
+
+    /// Is the scope the same as or nested inside of the given root?
+    fn is_in_subtree(&self, id: ScopeId, root: ScopeId) -> bool {
+        let mut current = self.scopes.get(id);
+
+        while let Some(scope) = current {
+            if scope.id == root {
+                return true;
             }
+
+            current = scope.parent.map(|parent| unsafe { &*parent });
         }
+
+        false
     }
 
     /// Swap the current mutations with a new
     fn finalize(&mut self) -> Mutations {
-        std::mem::take(&mut self.mutations)
+        self.reassert_volatile_attributes();
+        let mutations = std::mem::take(&mut self.mutations);
+        self.release_freed_ids(&mutations);
+        mutations
+    }
+}
+
+/// Find the node of a template that an element was created from
+fn template_node_at<'a>(
+    roots: &'a [TemplateNode<'a>],
+    path: ElementPath,
+) -> Option<&'a TemplateNode<'a>> {
+    let path = match path {
+        ElementPath::Root(idx) => return roots.get(idx),
+        ElementPath::Deep(path) => path,
+    };
+
+    let (first, rest) = path.split_first()?;
+    let mut node = roots.get(*first as usize)?;
+    for idx in rest {
+        node = match node {
+            TemplateNode::Element { children, .. } => children.get(*idx as usize)?,
+            _ => return None,
+        };
+    }
+    Some(node)
+}
+
+/// Check if a node or any of the fragments nested inside of it were created from the template with the given path
+///
+/// Components are not descended into since they are rendered by their own scope
+fn uses_template(node: &VNode, path: &str) -> bool {
+    if node.template.get().name.rsplit_once(':').unwrap().0 == path {
+        return true;
     }
+
+    node.dynamic_nodes.iter().any(|dynamic| match dynamic {
+        DynamicNode::Fragment(nodes) => nodes.iter().any(|node| uses_template(node, path)),
+        _ => false,
+    })
 }
 
 impl Drop for VirtualDom {
@@ -0,0 +1,56 @@
+//! Component arenas should release memory according to the VirtualDom's arena policy
+
+use dioxus::prelude::*;
+use dioxus_core::ArenaPolicy;
+
+const SPIKE: usize = 1 << 20;
+
+fn app(cx: Scope) -> Element {
+    let renders = cx.use_hook(|| 0);
+    *renders += 1;
+
+    // A single huge render that grows the arena
+    if *renders == 1 {
+        cx.bump().alloc_slice_fill_copy(SPIKE, 0u8);
+    }
+
+    cx.render(rsx!( div {} ))
+}
+
+fn render_a_few_times(dom: &mut VirtualDom) {
+    _ = dom.rebuild();
+    for _ in 0..5 {
+        dom.mark_dirty(ScopeId(0));
+        _ = dom.render_immediate();
+    }
+}
+
+#[test]
+fn arenas_keep_peak_memory_by_default() {
+    let mut dom = VirtualDom::new(app);
+    render_a_few_times(&mut dom);
+
+    assert!(dom.base_scope().bump().allocated_bytes() >= SPIKE);
+}
+
+#[test]
+fn arenas_shrink_past_max_retained_bytes() {
+    let mut dom = VirtualDom::new(app).with_arena_policy(ArenaPolicy {
+        max_retained_bytes: Some(16 * 1024),
+        shrink_after: None,
+    });
+    render_a_few_times(&mut dom);
+
+    assert!(dom.base_scope().bump().allocated_bytes() < SPIKE);
+}
+
+#[test]
+fn arenas_shrink_after_generations() {
+    let mut dom = VirtualDom::new(app).with_arena_policy(ArenaPolicy {
+        max_retained_bytes: None,
+        shrink_after: Some(1),
+    });
+    render_a_few_times(&mut dom);
+
+    assert!(dom.base_scope().bump().allocated_bytes() < SPIKE);
+}
@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+//! Event handlers that return futures should be spawned onto the scope that created them
+
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[tokio::test]
+async fn component_handlers_can_be_async() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Child {
+                onevent: move |_| async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    CALLS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        })
+    }
+
+    #[inline_props]
+    fn Child<'a>(cx: Scope<'a>, onevent: EventHandler<'a, ()>) -> Element {
+        cx.use_hook(|| onevent.call(()));
+        cx.render(rsx!( div {} ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    tokio::select! {
+        _ = async { loop { dom.wait_for_work().await } } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn async_handlers_are_cancelled_on_unmount() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn app(cx: Scope) -> Element {
+        let show = cx.use_hook(|| true);
+        let child = (*show).then(|| rsx!(Parent {}));
+        *show = false;
+        cx.render(rsx!(child))
+    }
+
+    fn Parent(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Child {
+                onevent: move |_| async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    CALLS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        })
+    }
+
+    #[inline_props]
+    fn Child<'a>(cx: Scope<'a>, onevent: EventHandler<'a, ()>) -> Element {
+        cx.use_hook(|| onevent.call(()));
+        cx.render(rsx!( div {} ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // unmount the parent which owns the spawned handler future
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+
+    tokio::select! {
+        _ = async { loop { dom.wait_for_work().await } } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,90 @@
+//! Volatile attributes like an input's value are written back after input events, so the renderer can't drift from
+//! the VirtualDom when a handler rejects the user's change
+
+use bumpalo::Bump;
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+fn input(dom: &mut VirtualDom, value: &str) {
+    let data = FormData {
+        value: value.to_string(),
+        values: Default::default(),
+        files: None,
+    };
+    dom.handle_event("input", Rc::new(data), ElementId(1), true);
+}
+
+#[test]
+fn rejected_input_restores_value() {
+    fn app(cx: Scope) -> Element {
+        let value = "abc";
+        cx.render(rsx! { input { value: "{value}", oninput: move |_| {} } })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    let bump = Bump::new();
+
+    input(&mut dom, "abcd");
+
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "value",
+            value: (&*bump.alloc("abc".into_value(&bump))).into(),
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+}
+
+#[test]
+fn accepted_input_writes_new_value() {
+    fn app(cx: Scope) -> Element {
+        let value = use_state(cx, || "abc".to_string());
+        cx.render(rsx! {
+            input { value: "{value}", oninput: move |evt| value.set(evt.value.to_uppercase()) }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    let bump = Bump::new();
+
+    input(&mut dom, "abcd");
+
+    // Only the value the handler chose is written, so the renderer doesn't reset the caret to write the old one
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "value",
+            value: (&*bump.alloc("ABCD".into_value(&bump))).into(),
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+}
+
+#[test]
+fn static_value_is_controlled() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! { input { value: "abc", oninput: move |_| {} } })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    let bump = Bump::new();
+
+    input(&mut dom, "abcd");
+
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "value",
+            value: (&*bump.alloc("abc".into_value(&bump))).into(),
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+}
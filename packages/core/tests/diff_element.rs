@@ -82,3 +82,28 @@ fn element_swap() {
         ]
     );
 }
+
+#[test]
+fn unchanged_attributes_are_skipped() {
+    fn app(cx: Scope) -> Element {
+        let gen = cx.generation();
+        let class = if gen < 2 { "a" } else { "b" };
+
+        cx.render(rsx!( div { class: "{class}", hidden: false, "hello {gen}" } ))
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    _ = vdom.rebuild();
+
+    // Only the text changed
+    vdom.mark_dirty(ScopeId(0));
+    assert_eq!(
+        vdom.render_immediate().edits,
+        [SetText { value: "hello 1", id: ElementId(2) }]
+    );
+
+    vdom.mark_dirty(ScopeId(0));
+    let edits = vdom.render_immediate().edits;
+    assert_eq!(edits.len(), 2);
+    assert!(matches!(edits[0], SetAttribute { name: "class", .. }));
+}
@@ -0,0 +1,62 @@
+#![allow(non_snake_case)]
+
+//! Cleanup callbacks registered with `on_drop` should run children-first, in registration order
+
+use dioxus::prelude::*;
+use std::cell::RefCell;
+
+thread_local! {
+    static DROPS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn record(name: &'static str) -> impl FnOnce() {
+    move || DROPS.with(|drops| drops.borrow_mut().push(name))
+}
+
+fn take_drops() -> Vec<&'static str> {
+    DROPS.with(|drops| drops.borrow_mut().drain(..).collect())
+}
+
+fn Parent(cx: Scope) -> Element {
+    cx.use_hook(|| {
+        cx.on_drop(record("parent 1"));
+        cx.on_drop(record("parent 2"));
+    });
+
+    cx.render(rsx! {
+        Child {}
+    })
+}
+
+fn Child(cx: Scope) -> Element {
+    cx.use_hook(|| cx.on_drop(record("child")));
+    cx.render(rsx!( div {} ))
+}
+
+#[test]
+fn drops_run_children_first_when_unmounted() {
+    fn app(cx: Scope) -> Element {
+        let show = cx.use_hook(|| true);
+        let parent = (*show).then(|| rsx!(Parent {}));
+        *show = false;
+        cx.render(rsx!(parent))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    assert!(take_drops().is_empty());
+
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+
+    assert_eq!(take_drops(), ["child", "parent 1", "parent 2"]);
+}
+
+#[test]
+fn drops_run_when_dom_is_dropped() {
+    let mut dom = VirtualDom::new(Parent);
+    _ = dom.rebuild();
+    drop(dom);
+
+    assert_eq!(take_drops(), ["child", "parent 1", "parent 2"]);
+}
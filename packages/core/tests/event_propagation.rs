@@ -0,0 +1,119 @@
+//! Events should be captured from the root down and then bubble back up, like the DOM
+
+use dioxus::core::ElementId;
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    static CALLS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn record(name: &'static str) {
+    CALLS.with(|calls| calls.borrow_mut().push(name));
+}
+
+fn take_calls() -> Vec<&'static str> {
+    CALLS.with(|calls| calls.borrow_mut().drain(..).collect())
+}
+
+fn click(dom: &mut VirtualDom, id: usize) {
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(id), true);
+}
+
+#[test]
+fn capture_runs_before_bubble() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclick_capture: move |_| record("outer capture"),
+                onclick: move |_| record("outer bubble"),
+                div {
+                    onclick_capture: move |_| record("inner capture"),
+                    onclick: move |_| record("inner bubble"),
+                }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    click(&mut dom, 2);
+
+    assert_eq!(
+        take_calls(),
+        ["outer capture", "inner capture", "inner bubble", "outer bubble"]
+    );
+}
+
+#[test]
+fn stop_propagation_in_capture() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclick_capture: move |evt| {
+                    record("outer capture");
+                    evt.stop_propagation();
+                },
+                onclick: move |_| record("outer bubble"),
+                div {
+                    onclick: move |_| record("inner bubble"),
+                }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    click(&mut dom, 2);
+
+    assert_eq!(take_calls(), ["outer capture"]);
+}
+
+#[test]
+fn stop_immediate_propagation_on_target() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclick_capture: move |evt| {
+                    record("capture");
+                    evt.stop_immediate_propagation();
+                },
+                onclick: move |_| record("bubble"),
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    click(&mut dom, 1);
+
+    assert_eq!(take_calls(), ["capture"]);
+}
+
+#[test]
+fn stop_propagation_finishes_current_element() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                onclick: move |_| record("outer bubble"),
+                div {
+                    onclick_capture: move |evt| {
+                        record("capture");
+                        evt.stop_propagation();
+                    },
+                    onclick: move |_| record("bubble"),
+                }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    click(&mut dom, 2);
+
+    assert_eq!(take_calls(), ["capture", "bubble"]);
+}
@@ -0,0 +1,41 @@
+//! flush_sync should drain every pending update before returning
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn flush_sync_settles_cascading_updates() {
+    fn app(cx: Scope) -> Element {
+        let count = &*cx.use_hook(|| Cell::new(0));
+
+        // After being clicked, schedule another render from within the render itself
+        if count.get() == 1 {
+            count.set(2);
+            cx.needs_update();
+        }
+
+        cx.render(rsx! {
+            div {
+                onclick: move |_| {
+                    count.set(1);
+                    cx.needs_update();
+                },
+                "{count.get()}"
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(1), true);
+
+    assert_eq!(
+        dom.flush_sync().santize().edits,
+        [SetText { value: "2", id: ElementId(2) }]
+    );
+
+    // Nothing should be left over for the next render
+    assert!(dom.render_immediate().edits.is_empty());
+}
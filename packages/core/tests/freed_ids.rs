@@ -0,0 +1,86 @@
+//! Reclaimed ElementIds should be reported to the renderer so it can release its side tables
+
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+#[test]
+fn swapped_elements_are_freed() {
+    fn app(cx: Scope) -> Element {
+        match cx.generation() % 2 {
+            0 => cx.render(rsx!( h1 { "hello 1" } )),
+            _ => cx.render(rsx!( h2 { "hello 2" } )),
+        }
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    assert!(vdom.rebuild().freed_ids.is_empty());
+
+    vdom.mark_dirty(ScopeId(0));
+    assert_eq!(vdom.render_immediate().freed_ids, [ElementId(1)]);
+
+    vdom.mark_dirty(ScopeId(0));
+    assert_eq!(vdom.render_immediate().freed_ids, [ElementId(2)]);
+}
+
+#[test]
+fn reused_ids_are_not_freed() {
+    fn app(cx: Scope) -> Element {
+        let gen = cx.generation();
+
+        cx.render(rsx! {
+            div {
+                (0..(gen % 2 + 1)).map(|i| rsx!( p { key: "{i}-{gen}" } ))
+            }
+        })
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    _ = vdom.rebuild();
+
+    // Every id freed in a render is either left unused or reported, never both
+    for _ in 0..4 {
+        vdom.mark_dirty(ScopeId(0));
+        let mutations = vdom.render_immediate();
+
+        for freed in &mutations.freed_ids {
+            assert!(!mutations.edits.iter().any(|edit| matches!(
+                edit,
+                dioxus_core::Mutation::LoadTemplate { id, .. } if id == freed
+            )));
+        }
+    }
+}
+
+#[test]
+fn freed_ids_are_unique_and_unused() {
+    fn app(cx: Scope) -> Element {
+        let gen = cx.generation();
+
+        cx.render(rsx! {
+            div {
+                (0..(gen % 3 * 2)).map(|i| rsx!( p { key: "{i}-{gen}" } ))
+            }
+        })
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    _ = vdom.rebuild();
+
+    // Lists shrink and grow with new keys, so ids are freed and handed out again in the same render
+    for _ in 0..6 {
+        vdom.mark_dirty(ScopeId(0));
+        let mutations = vdom.render_immediate();
+
+        let mut freed = mutations.freed_ids.clone();
+        freed.sort();
+        freed.dedup();
+        assert_eq!(freed.len(), mutations.freed_ids.len());
+
+        for freed in &mutations.freed_ids {
+            assert!(!mutations.edits.iter().any(|edit| matches!(
+                edit,
+                dioxus_core::Mutation::LoadTemplate { id, .. } if id == freed
+            )));
+        }
+    }
+}
@@ -1 +1,46 @@
 //! It should be possible to swap out templates at runtime, enabling hotreloading
+
+use dioxus::core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::{DynamicNode, RenderReturn};
+
+fn nested_template(dom: &VirtualDom) -> Template<'static> {
+    match dom.base_scope().root_node() {
+        RenderReturn::Ready(node) => match &node.dynamic_nodes[0] {
+            DynamicNode::Fragment(nodes) => nodes[0].template.get(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn replacing_nested_template_rerenders_scope() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div { (0..2).map(|i| rsx!( span { "{i}" } )) }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let template = nested_template(&dom);
+    dom.replace_template(Template {
+        roots: &[TemplateNode::Element {
+            tag: "p",
+            namespace: None,
+            attrs: &[],
+            children: &[TemplateNode::DynamicText { id: 0 }],
+        }],
+        ..template
+    });
+
+    let edits = dom.render_immediate();
+
+    assert_eq!(edits.templates.len(), 1);
+    assert!(edits
+        .edits
+        .iter()
+        .any(|edit| matches!(edit, LoadTemplate { .. })));
+}
@@ -0,0 +1,72 @@
+//! Idle updates wait in their own lane until the renderer promotes them
+
+use dioxus::prelude::*;
+use std::time::Duration;
+
+fn app(cx: Scope) -> Element {
+    let renders = cx.use_hook(|| 0);
+    *renders += 1;
+
+    let schedule = cx.use_hook(|| cx.schedule_idle_update());
+    cx.provide_context(schedule.clone());
+
+    cx.render(rsx!( div { "{renders}" } ))
+}
+
+fn schedule_idle(dom: &VirtualDom) {
+    let schedule = dom
+        .base_scope()
+        .consume_context::<std::sync::Arc<dyn Fn() + Send + Sync>>()
+        .unwrap();
+    schedule();
+}
+
+#[tokio::test]
+async fn idle_updates_wait_for_promotion() {
+    let mut dom = VirtualDom::new(app).with_idle_lane();
+    _ = dom.rebuild();
+
+    schedule_idle(&dom);
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(500)) => panic!("idle work never woke the dom"),
+    };
+
+    assert!(dom.has_idle_work());
+    assert!(dom.render_immediate().edits.is_empty());
+
+    dom.promote_idle_work();
+    assert!(!dom.has_idle_work());
+    assert!(!dom.render_immediate().edits.is_empty());
+}
+
+#[test]
+fn regular_updates_include_idle_work() {
+    let mut dom = VirtualDom::new(app).with_idle_lane();
+    _ = dom.rebuild();
+
+    schedule_idle(&dom);
+    dom.process_events();
+    assert!(dom.has_idle_work());
+
+    dom.mark_dirty(ScopeId(0));
+    assert!(!dom.has_idle_work());
+    assert!(!dom.render_immediate().edits.is_empty());
+}
+
+#[tokio::test]
+async fn idle_updates_are_regular_without_the_lane() {
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    schedule_idle(&dom);
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(500)) => panic!("idle work never woke the dom"),
+    };
+
+    assert!(!dom.has_idle_work());
+    assert!(!dom.render_immediate().edits.is_empty());
+}
@@ -0,0 +1,80 @@
+//! Lazy components render their fallback until the loader resolves, or suspend the boundary above them
+
+use dioxus::core::{ElementId, LazyComponent, Mutation::*, SuspenseContext};
+use dioxus::prelude::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn lazy_renders_loaded_component() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Lazy { loader: load, fallback: cx.render(rsx!( "loading" )) }
+        })
+    }
+
+    fn load() -> LazyComponent {
+        Box::pin(async { loaded as Component })
+    }
+
+    fn loaded(cx: Scope) -> Element {
+        cx.render(rsx!( div { "loaded" } ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+
+    assert_eq!(
+        dom.rebuild().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            AppendChildren { id: ElementId(0), m: 1 }
+        ]
+    );
+
+    dom.wait_for_work().await;
+
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(2) },
+            ReplaceWith { id: ElementId(1), m: 1 }
+        ]
+    );
+}
+
+#[tokio::test]
+async fn lazy_suspends_boundary() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx!( suspense_boundary {} ))
+    }
+
+    fn suspense_boundary(cx: Scope) -> Element {
+        cx.use_hook(|| cx.provide_context(Rc::new(SuspenseContext::new(cx.scope_id()))));
+
+        cx.render(rsx! {
+            Lazy { loader: load_slowly, fallback: cx.render(rsx!( "loading" )) }
+        })
+    }
+
+    fn load_slowly() -> LazyComponent {
+        Box::pin(async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            loaded as Component
+        })
+    }
+
+    fn loaded(cx: Scope) -> Element {
+        cx.render(rsx!( div { "loaded" } ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The boundary waits on the loader instead of the fallback being rendered
+    assert!(dom.is_scope_suspended(ScopeId(1)));
+
+    dom.wait_for_work().await;
+    _ = dom.render_immediate();
+
+    assert!(!dom.is_scope_suspended(ScopeId(1)));
+}
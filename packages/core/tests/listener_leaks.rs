@@ -0,0 +1,55 @@
+//! Debug builds warn when an event arrives for an element whose listeners were removed
+
+use dioxus::core::ElementId;
+use dioxus::prelude::*;
+use once_cell::sync::Lazy;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+static WARNINGS: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+struct WarningLogger;
+
+impl log::Log for WarningLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            WARNINGS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn events_for_reclaimed_ids_warn() {
+    log::set_logger(&WarningLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+
+    fn app(cx: Scope) -> Element {
+        match cx.generation() % 2 {
+            0 => cx.render(rsx!( button { onclick: |_| {} } )),
+            _ => cx.render(rsx!( h2 {} )),
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // the button is mounted, so its events are expected
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(1), true);
+    assert!(WARNINGS.lock().unwrap().is_empty());
+
+    // the button is replaced, but a renderer that didn't remove it could still send its events
+    dom.mark_dirty(ScopeId(0));
+    _ = dom.render_immediate();
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(1), true);
+
+    let warnings = WARNINGS.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("\"click\""));
+    assert!(warnings[0].contains("ElementId(1)"));
+}
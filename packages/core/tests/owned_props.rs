@@ -0,0 +1,66 @@
+#![allow(non_snake_case)]
+//! Props marked with `#[props(owned)]` can be converted into an owned variant and moved into futures
+
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+#[derive(Props)]
+#[props(owned)]
+struct ChildProps<'a> {
+    name: &'a str,
+    tags: &'a [u32],
+    nickname: Option<&'a str>,
+    age: u32,
+}
+
+#[test]
+fn owned_props_outlive_borrow() {
+    let name = String::from("dioxus");
+    let tags = vec![1, 2, 3];
+
+    let owned = {
+        let props = ChildProps { name: &name, tags: &tags, nickname: Some("dx"), age: 3 };
+        props.to_owned_props()
+    };
+
+    drop(name);
+    drop(tags);
+
+    let OwnedChildProps { name, tags, nickname, age } = owned;
+    assert_eq!(name, "dioxus");
+    assert_eq!(tags, vec![1, 2, 3]);
+    assert_eq!(nickname.as_deref(), Some("dx"));
+    assert_eq!(age, 3);
+}
+
+#[tokio::test]
+async fn owned_props_cross_await_points() {
+    static COMPLETED: AtomicBool = AtomicBool::new(false);
+
+    fn Child<'a>(cx: Scope<'a, ChildProps<'a>>) -> Element<'a> {
+        let props = cx.props.to_owned_props();
+
+        cx.spawn(async move {
+            tokio::task::yield_now().await;
+            assert_eq!(props.name, "dioxus");
+            COMPLETED.store(true, Ordering::SeqCst);
+        });
+
+        None
+    }
+
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx!(Child { name: "dioxus", tags: &[], age: 1 }))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The task doesn't mark anything dirty, so the dom polls it until the timeout runs out
+    _ = tokio::time::timeout(Duration::from_millis(500), dom.wait_for_work()).await;
+    assert!(
+        COMPLETED.load(Ordering::SeqCst),
+        "the task never ran past its await point"
+    );
+}
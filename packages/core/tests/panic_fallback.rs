@@ -0,0 +1,29 @@
+#![allow(non_snake_case)]
+//! Components that panic while rendering should be replaced by the panic fallback
+
+use dioxus::core::{default_panic_fallback, Mutation::*};
+use dioxus::prelude::*;
+
+#[test]
+fn renders_fallback_for_panicking_child() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                h1 { "Title" }
+                PanicChild {}
+            }
+        })
+    }
+
+    fn PanicChild(_cx: Scope) -> Element {
+        panic!("oh no");
+    }
+
+    let mut dom = VirtualDom::new(app).with_panic_fallback(default_panic_fallback);
+    let edits = dom.rebuild().edits;
+
+    assert!(edits.iter().any(|edit| matches!(
+        edit,
+        HydrateText { value, .. } if value.contains("PanicChild") && value.contains("oh no")
+    )));
+}
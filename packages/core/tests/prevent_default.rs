@@ -0,0 +1,94 @@
+//! Handlers can prevent the default action of an event, either every time or from inside the handler
+
+use dioxus::core::{BorrowedAttributeValue, ElementId, Mutation::*};
+use dioxus::prelude::*;
+use std::{cell::Cell, rc::Rc};
+
+fn click(dom: &mut VirtualDom, id: usize) -> bool {
+    dom.handle_event("click", Rc::new(MouseData::default()), ElementId(id), true)
+}
+
+#[test]
+fn wrapped_handlers_tell_the_renderer() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            a { onclick: prevent_default(move |_| {}) }
+            a { onclick: move |_| {} }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild().santize();
+
+    let listeners: Vec<_> = edits
+        .edits
+        .into_iter()
+        .filter(|edit| matches!(edit, NewEventListener { .. } | SetAttribute { .. }))
+        .collect();
+
+    // the first link is marked with an attribute named after its listener
+    assert_eq!(
+        listeners,
+        [
+            NewEventListener { name: "click", id: ElementId(1) },
+            SetAttribute {
+                name: "onclick_prevent_default",
+                value: BorrowedAttributeValue::Bool(true),
+                id: ElementId(1),
+                ns: None,
+            },
+            NewEventListener { name: "click", id: ElementId(2) },
+        ]
+    );
+}
+
+#[test]
+fn wrapped_handlers_still_run() {
+    fn app(cx: Scope) -> Element {
+        let calls = cx.use_hook(|| cx.provide_context(Rc::new(Cell::new(0))));
+        let capture = calls.clone();
+        let bubble = calls.clone();
+
+        cx.render(rsx! {
+            button {
+                onclick_capture: prevent_default(move |_| capture.set(capture.get() + 1)),
+                onclick: prevent_default(move |_| bubble.set(bubble.get() + 10)),
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // renderers that dispatch synchronously hear about it from the VirtualDom too
+    assert!(click(&mut dom, 1));
+
+    let calls = dom.base_scope().consume_context::<Rc<Cell<i32>>>().unwrap();
+    assert_eq!(calls.get(), 11);
+}
+
+#[test]
+fn handlers_can_prevent_the_default_conditionally() {
+    fn app(cx: Scope) -> Element {
+        let clicks = &*cx.use_hook(|| Cell::new(0));
+
+        cx.render(rsx! {
+            div {
+                onclick: move |evt| {
+                    clicks.set(clicks.get() + 1);
+                    if clicks.get() % 2 == 0 {
+                        evt.prevent_default();
+                    }
+                },
+                button { onclick: move |evt| assert!(!evt.default_prevented()) }
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert!(!click(&mut dom, 2));
+    assert!(click(&mut dom, 2));
+    assert!(!click(&mut dom, 1));
+}
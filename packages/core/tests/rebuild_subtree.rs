@@ -0,0 +1,38 @@
+#![allow(non_snake_case)]
+
+//! rebuild_subtree should only render the given scope and dirty scopes beneath it
+
+use dioxus::core::{ElementId, Mutation::*};
+use dioxus::prelude::*;
+
+#[test]
+fn only_the_subtree_is_rendered() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Child {}
+            Child {}
+        })
+    }
+
+    fn Child(cx: Scope) -> Element {
+        let gen = cx.generation();
+        cx.render(rsx!( div { "{gen}" } ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // The second child is dirty, but not part of the subtree we're rebuilding
+    dom.mark_dirty(ScopeId(2));
+
+    assert_eq!(
+        dom.rebuild_subtree(ScopeId(1)).edits,
+        [SetText { value: "1", id: ElementId(2) }]
+    );
+
+    // The second child is still waiting to be rendered
+    assert_eq!(
+        dom.render_immediate().edits,
+        [SetText { value: "1", id: ElementId(4) }]
+    );
+}
@@ -0,0 +1,39 @@
+#![allow(non_snake_case)]
+
+//! render_with_deadline should yield leftover dirty scopes to the next frame once the deadline expires
+
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CHILD_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+#[tokio::test]
+async fn expired_deadline_leaves_remaining_work() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            Child {}
+            Child {}
+        })
+    }
+
+    fn Child(cx: Scope) -> Element {
+        CHILD_RENDERS.fetch_add(1, Ordering::SeqCst);
+        cx.render(rsx! { div {} })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+    assert_eq!(CHILD_RENDERS.load(Ordering::SeqCst), 2);
+
+    dom.mark_dirty(ScopeId(1));
+    dom.mark_dirty(ScopeId(2));
+
+    // The deadline has already passed, so only one scope fits in this frame
+    _ = dom.render_with_deadline(std::future::ready(())).await;
+    assert_eq!(CHILD_RENDERS.load(Ordering::SeqCst), 3);
+
+    // The leftover scope is still dirty, so there's work waiting
+    dom.wait_for_work().await;
+    _ = dom.render_immediate();
+    assert_eq!(CHILD_RENDERS.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,68 @@
+//! Verify that the thread-safe scheduler handle can wake up the VirtualDom from other threads
+
+use dioxus::core::ElementId;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+#[tokio::test]
+async fn mark_dirty_from_thread() {
+    fn app(cx: Scope) -> Element {
+        let count = cx.use_hook(|| 0);
+        *count += 1;
+        cx.render(rsx!( div { "{count}" } ))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let handle = dom.scheduler_handle();
+    std::thread::spawn(move || handle.mark_dirty(ScopeId(0)));
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(500)) => panic!("handle never woke the dom"),
+    };
+
+    assert!(!dom.render_immediate().edits.is_empty());
+}
+
+#[tokio::test]
+async fn inject_event_from_thread() {
+    fn app(cx: Scope) -> Element {
+        let clicked = cx.use_hook(|| false);
+        cx.render(rsx! {
+            div {
+                onclick: move |_| {
+                    *clicked = true;
+                    cx.needs_update();
+                },
+                "{clicked}"
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let handle = dom.scheduler_handle();
+    std::thread::spawn(move || {
+        handle.send_event("click", MouseData::default(), ElementId(1), true)
+    });
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(500)) => panic!("handle never woke the dom"),
+    };
+
+    assert!(!dom.render_immediate().edits.is_empty());
+}
+
+#[test]
+fn handle_outlives_dom() {
+    let dom = VirtualDom::new(|cx| cx.render(rsx!(div {})));
+    let handle = dom.scheduler_handle();
+    drop(dom);
+
+    assert!(handle.is_closed());
+    handle.mark_dirty(ScopeId(0));
+}
@@ -40,3 +40,25 @@ fn app(cx: Scope) -> Element {
 
     cx.render(rsx!(()))
 }
+
+#[test]
+fn removing_a_stale_task_keeps_its_replacement() {
+    fn app(cx: Scope) -> Element {
+        cx.use_hook(|| {
+            let first = cx.push_future(std::future::pending());
+            cx.remove_future(first);
+
+            // The slab hands the freed slot to the next task
+            let second = cx.push_future(std::future::pending());
+            cx.remove_future(first);
+
+            assert!(!cx.has_future(first));
+            assert!(cx.has_future(second));
+        });
+
+        cx.render(rsx!(()))
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let _ = dom.rebuild();
+}
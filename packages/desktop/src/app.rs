@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+/// The name, version and identifier of the app, set with [`Config::with_app_metadata`](crate::Config::with_app_metadata)
+///
+/// The name is used for the directories of the app, so changing it moves them. Use [`app_metadata!`](crate::app_metadata)
+/// to take the name and version from the `Cargo.toml` of the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppMetadata {
+    name: String,
+    version: Option<String>,
+    identifier: Option<String>,
+}
+
+impl AppMetadata {
+    /// Describe an app called `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+            identifier: None,
+        }
+    }
+
+    /// Set the version of the app
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Set the identifier of the app, usually in reverse domain name notation like `com.example.app`
+    pub fn with_identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = Some(identifier.into());
+        self
+    }
+
+    /// The name of the app
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The version of the app, if it was set
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// The identifier of the app, or its name if no identifier was set
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Name the app after its executable, which is what it's called until the config says otherwise
+    pub(crate) fn from_exe() -> Self {
+        let name = std::env::current_exe()
+            .ok()
+            .and_then(|exe| {
+                exe.file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "dioxus".to_string());
+
+        Self::new(name)
+    }
+
+    /// The directory of the platform for `dir`, with a subdirectory for the app
+    pub(crate) fn dir(&self, dir: AppDir) -> Option<PathBuf> {
+        let base = match dir {
+            AppDir::Config => dirs::config_dir(),
+            AppDir::Cache => dirs::cache_dir(),
+            AppDir::Data => dirs::data_local_dir(),
+        };
+        base.map(|base| base.join(&self.name))
+    }
+}
+
+/// Describe the app with the name and version from its `Cargo.toml`
+///
+/// ```rust, ignore
+/// dioxus_desktop::launch_cfg(app, Config::new().with_app_metadata(app_metadata!()));
+/// ```
+#[macro_export]
+macro_rules! app_metadata {
+    () => {
+        $crate::AppMetadata::new(env!("CARGO_PKG_NAME")).with_version(env!("CARGO_PKG_VERSION"))
+    };
+}
+
+/// The directories of the platform where apps keep their files, each with a subdirectory for the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppDir {
+    /// Settings, like `~/.config/{app}` on Linux
+    Config,
+    /// Files that can be recreated, like `~/.cache/{app}` on Linux
+    Cache,
+    /// Everything else, like `~/.local/share/{app}` on Linux, or the directory set with
+    /// [`Config::with_data_directory`](crate::Config::with_data_directory)
+    Data,
+}
+
+/// The directories of the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserDir {
+    /// The home directory
+    Home,
+    /// The desktop
+    Desktop,
+    /// The documents directory
+    Documents,
+    /// The downloads directory
+    Downloads,
+    /// The pictures directory
+    Pictures,
+    /// The music directory
+    Music,
+    /// The videos directory
+    Videos,
+}
+
+impl UserDir {
+    pub(crate) fn path(self) -> Option<PathBuf> {
+        match self {
+            UserDir::Home => dirs::home_dir(),
+            UserDir::Desktop => dirs::desktop_dir(),
+            UserDir::Documents => dirs::document_dir(),
+            UserDir::Downloads => dirs::download_dir(),
+            UserDir::Pictures => dirs::picture_dir(),
+            UserDir::Music => dirs::audio_dir(),
+            UserDir::Videos => dirs::video_dir(),
+        }
+    }
+}
+
+/// What the windows of the app know about it
+pub(crate) struct AppInfo {
+    pub(crate) metadata: AppMetadata,
+    pub(crate) data_dir: PathBuf,
+}
@@ -0,0 +1,169 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use dioxus_core::{SchedulerHandle, ScopeId, ScopeState};
+use slab::Slab;
+use wry::application::event_loop::EventLoopProxy;
+use wry::application::window::WindowId;
+
+use crate::desktop_context::{use_latest_handler, EventData, UserWindowEvent};
+use crate::use_window;
+
+/// Sends events into the app from other threads, through the event loop
+///
+/// Get one with [`DesktopContext::app_proxy`](crate::DesktopContext::app_proxy) and move it to the thread that does
+/// the work. The events are delivered on the main thread to the handlers registered with [`use_app_event_handler`]
+/// for their type in the window the proxy came from, which can update the state of their component. Sending fails once
+/// the app has exited.
+///
+/// State that lives outside of the app, like a cache shared with the thread, can be shown by rendering the components
+/// that read it again with [`AppProxy::mark_dirty`].
+///
+/// ```rust, ignore
+/// #[derive(Clone)]
+/// struct FileChanged(PathBuf);
+///
+/// fn app(cx: Scope) -> Element {
+///     let changed = use_state(cx, || None);
+///
+///     use_app_event_handler(cx, {
+///         to_owned![changed];
+///         move |FileChanged(path)| changed.set(Some(path))
+///     });
+///
+///     let desktop = use_window(cx);
+///     cx.use_hook(|| {
+///         let proxy = desktop.app_proxy();
+///         std::thread::spawn(move || {
+///             for path in watch_files() {
+///                 if proxy.send(FileChanged(path)).is_err() {
+///                     break;
+///                 }
+///             }
+///         });
+///     });
+///
+///     cx.render(rsx!("Last change: {changed:?}"))
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AppProxy {
+    proxy: EventLoopProxy<UserWindowEvent>,
+    window: WindowId,
+    scheduler: SchedulerHandle,
+}
+
+/// The error returned when sending an event to an app that has exited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppExited;
+
+impl fmt::Display for AppExited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the app has exited")
+    }
+}
+
+impl std::error::Error for AppExited {}
+
+impl AppProxy {
+    pub(crate) fn new(
+        proxy: EventLoopProxy<UserWindowEvent>,
+        window: WindowId,
+        scheduler: SchedulerHandle,
+    ) -> Self {
+        Self {
+            proxy,
+            window,
+            scheduler,
+        }
+    }
+
+    /// Deliver an event to the handlers registered for its type in the window of the proxy
+    pub fn send<T: Clone + Send + 'static>(&self, event: T) -> Result<(), AppExited> {
+        let event = AppEvent(Arc::new(Mutex::new(Some(Box::new(event)))));
+        self.proxy
+            .send_event(UserWindowEvent(EventData::AppEvent(event), self.window))
+            .map_err(|_| AppExited)
+    }
+
+    /// Render a component of the window again, like one that reads state the thread changed
+    ///
+    /// Get the id of the component with [`ScopeState::scope_id`]. Nothing happens once the app has exited.
+    pub fn mark_dirty(&self, scope: ScopeId) {
+        self.scheduler.mark_dirty(scope);
+    }
+
+    /// The scheduler of the window's [`VirtualDom`](dioxus_core::VirtualDom), to mark components dirty or send it events
+    pub fn scheduler(&self) -> &SchedulerHandle {
+        &self.scheduler
+    }
+}
+
+/// An event sent with an [`AppProxy`], taken by the event loop when it's delivered
+#[derive(Clone)]
+pub struct AppEvent(Arc<Mutex<Option<Box<dyn Any + Send>>>>);
+
+impl fmt::Debug for AppEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AppEvent")
+    }
+}
+
+type Handler = Rc<RefCell<dyn FnMut(&dyn Any)>>;
+
+// Events are delivered on the main thread, where the event loop runs
+thread_local! {
+    static HANDLERS: RefCell<Slab<(TypeId, WindowId, Handler)>> = RefCell::new(Slab::new());
+}
+
+/// Send an event to the handlers registered for its type in a window
+pub(crate) fn dispatch(event: AppEvent, window: WindowId) {
+    let event = match event.0.lock().unwrap().take() {
+        Some(event) => event,
+        None => return,
+    };
+    let type_id = (*event).type_id();
+
+    let handlers: Vec<Handler> = HANDLERS.with(|handlers| {
+        handlers
+            .borrow()
+            .iter()
+            .filter(|(_, (id, target, _))| *id == type_id && *target == window)
+            .map(|(_, (_, _, handler))| handler.clone())
+            .collect()
+    });
+    for handler in handlers {
+        (handler.borrow_mut())(&*event);
+    }
+}
+
+/// Handle the events of type `T` sent into the app with an [`AppProxy`]
+///
+/// Every handler registered for the type in the window the proxy came from gets a clone of the event, passed to the
+/// closure of the component's latest render. The handler is removed when the component is unmounted.
+pub fn use_app_event_handler<T: Clone + 'static>(
+    cx: &ScopeState,
+    handler: impl FnMut(T) + 'static,
+) {
+    let handler = use_latest_handler::<dyn FnMut(T)>(cx, Box::new(handler));
+    let window = use_window(cx).id();
+
+    cx.use_hook(|| {
+        let erased: Handler = Rc::new(RefCell::new(move |event: &dyn Any| {
+            if let Some(event) = event.downcast_ref::<T>() {
+                (handler.borrow_mut())(event.clone());
+            }
+        }));
+        let id = HANDLERS.with(|handlers| {
+            handlers
+                .borrow_mut()
+                .insert((TypeId::of::<T>(), window, erased))
+        });
+        cx.on_drop(move || {
+            HANDLERS.with(|handlers| handlers.borrow_mut().try_remove(id));
+        });
+    });
+}
@@ -1,8 +1,12 @@
 use std::path::PathBuf;
 
+use crate::app::{AppDir, AppMetadata};
+use crate::protocol;
+
 use wry::application::window::Icon;
 use wry::{
     application::window::{Window, WindowBuilder},
+    http::header::{HeaderMap, HeaderName, HeaderValue},
     http::{Request as HttpRequest, Response as HttpResponse},
     webview::FileDropEvent,
     Result as WryResult,
@@ -18,9 +22,27 @@ pub struct Config {
     pub(crate) pre_rendered: Option<String>,
     pub(crate) disable_context_menu: bool,
     pub(crate) resource_dir: Option<PathBuf>,
+    pub(crate) data_dir: Option<PathBuf>,
     pub(crate) custom_head: Option<String>,
     pub(crate) custom_index: Option<String>,
     pub(crate) root_name: String,
+    pub(crate) menu: Option<crate::AppMenu>,
+    pub(crate) deep_link_scheme: Option<String>,
+    pub(crate) window_state: Option<PathBuf>,
+    pub(crate) remember_window_state: bool,
+    pub(crate) app: AppMetadata,
+    pub(crate) download_handler: Option<crate::download::DownloadHandler>,
+    pub(crate) show_on_first_render: bool,
+    pub(crate) devtools: bool,
+    pub(crate) window_effect: Option<crate::WindowEffect>,
+    pub(crate) background_color: Option<(u8, u8, u8, u8)>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) custom_headers: HeaderMap,
+    pub(crate) dev_shortcuts: bool,
+    pub(crate) splash: Option<(WindowBuilder, String)>,
+    #[cfg(feature = "tray")]
+    pub(crate) tray: Option<crate::TrayConfig>,
 }
 
 type DropHandler = Box<dyn Fn(&Window, FileDropEvent) -> bool>;
@@ -44,9 +66,27 @@ impl Config {
             pre_rendered: None,
             disable_context_menu: !cfg!(debug_assertions),
             resource_dir: None,
+            data_dir: None,
             custom_head: None,
             custom_index: None,
             root_name: "main".to_string(),
+            menu: None,
+            deep_link_scheme: None,
+            window_state: None,
+            remember_window_state: false,
+            app: AppMetadata::from_exe(),
+            download_handler: None,
+            show_on_first_render: false,
+            devtools: cfg!(debug_assertions),
+            window_effect: None,
+            background_color: None,
+            proxy: None,
+            user_agent: None,
+            custom_headers: HeaderMap::new(),
+            dev_shortcuts: false,
+            splash: None,
+            #[cfg(feature = "tray")]
+            tray: None,
         }
     }
 
@@ -56,12 +96,78 @@ impl Config {
         self
     }
 
+    /// Set the directory where values from `use_persistent` are stored
+    ///
+    /// Defaults to a directory named after the app in the platform's local data directory.
+    pub fn with_data_directory(mut self, path: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(path.into());
+        self
+    }
+
+    pub(crate) fn data_directory(&self) -> PathBuf {
+        if let Some(dir) = &self.data_dir {
+            return dir.clone();
+        }
+
+        self.app
+            .dir(AppDir::Data)
+            .unwrap_or_else(|| std::env::temp_dir().join(self.app.name()))
+    }
+
+    /// Where to remember the state of the window, if it should be remembered
+    pub(crate) fn window_state_path(&self) -> Option<PathBuf> {
+        if let Some(path) = &self.window_state {
+            return Some(path.clone());
+        }
+
+        self.remember_window_state.then(|| {
+            self.app
+                .dir(AppDir::Config)
+                .unwrap_or_else(|| std::env::temp_dir().join(self.app.name()))
+                .join("window-state.json")
+        })
+    }
+
+    /// Describe the app, which names the directories it keeps its files in
+    ///
+    /// Without metadata, the app is named after its executable.
+    pub fn with_app_metadata(mut self, metadata: AppMetadata) -> Self {
+        self.app = metadata;
+        self
+    }
+
     /// Set whether or not the right-click context menu should be disabled.
+    ///
+    /// Elements can override this for themselves and their children with the `data-native-context-menu` attribute:
+    /// `"true"` shows the native menu, for example on text inputs, and `"false"` hides it. To draw a menu of your own,
+    /// see [`use_context_menu`](crate::use_context_menu).
     pub fn with_disable_context_menu(mut self, disable: bool) -> Self {
         self.disable_context_menu = disable;
         self
     }
 
+    /// Show or hide the title bar and borders the OS draws around the window
+    ///
+    /// Without them, the app can draw its own title bar. Mark the elements that should move the window when dragged
+    /// with a `data-drag-region` attribute, double clicking them maximizes the window. Mark buttons with a
+    /// `data-window-control` attribute of `"minimize"`, `"maximize"` or `"close"` to control the window. Buttons,
+    /// inputs and links inside a drag region can still be clicked.
+    ///
+    /// ```rust, ignore
+    /// rsx! {
+    ///     div { "data-drag-region": "true", class: "titlebar",
+    ///         "My app"
+    ///         button { "data-window-control": "minimize", "—" }
+    ///         button { "data-window-control": "maximize", "□" }
+    ///         button { "data-window-control": "close", "×" }
+    ///     }
+    /// }
+    /// ```
+    pub fn with_decorations(mut self, decorations: bool) -> Self {
+        self.window = self.window.with_decorations(decorations);
+        self
+    }
+
     /// Set the pre-rendered HTML content
     pub fn with_prerendered(mut self, content: String) -> Self {
         self.pre_rendered = Some(content);
@@ -94,6 +200,37 @@ impl Config {
         self
     }
 
+    /// Serve files to the webview from a custom protocol, so local images, fonts and other assets load without a
+    /// server or data URIs
+    ///
+    /// The handler gets the path of every request to the protocol, like `images/logo.png`, and returns the file to
+    /// serve, or `None` to answer with a 404. Reference the files with `{scheme}://localhost/images/logo.png`, or
+    /// `https://{scheme}.localhost/images/logo.png` on Windows.
+    ///
+    /// ```rust, ignore
+    /// let config = Config::new().with_asset_handler("app", |path| match path {
+    ///     "logo.png" => Some(Asset::new(include_bytes!("../assets/logo.png").as_slice(), "image/png")),
+    ///     _ => None,
+    /// });
+    /// ```
+    pub fn with_asset_handler(
+        mut self,
+        scheme: impl Into<String>,
+        handler: impl Fn(&str) -> Option<crate::Asset> + 'static,
+    ) -> Self {
+        self.protocols
+            .push((scheme.into(), Box::new(protocol::asset_handler(handler))));
+        self
+    }
+
+    /// Serve the files of a directory from a custom protocol
+    ///
+    /// This is [`Config::with_asset_handler`] with a handler that reads the files from `dir`. Paths that would leave
+    /// the directory get a 404.
+    pub fn with_asset_dir(self, scheme: impl Into<String>, dir: impl Into<PathBuf>) -> Self {
+        self.with_asset_handler(scheme, protocol::asset_dir(dir.into()))
+    }
+
     /// Set a custom icon for this application
     pub fn with_icon(mut self, icon: Icon) -> Self {
         self.window.window.window_icon = Some(icon);
@@ -126,6 +263,181 @@ impl Config {
         self.root_name = name.into();
         self
     }
+
+    /// Replace the default menu bar of the window with a native menu
+    ///
+    /// Use [`use_menu_event_handler`](crate::use_menu_event_handler) to react to its items.
+    pub fn with_menu(mut self, menu: crate::AppMenu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Remember the size, position and maximized state of the window when it closes, and restore them when it opens
+    ///
+    /// The state is kept in the config directory of the platform. Use [`Config::with_window_state_path`] to keep it
+    /// somewhere else, for example to remember several windows separately.
+    pub fn with_remembered_window_state(mut self, remember: bool) -> Self {
+        self.remember_window_state = remember;
+        if !remember {
+            self.window_state = None;
+        }
+        self
+    }
+
+    /// Remember the state of the window like [`Config::with_remembered_window_state`], in the file at `path`
+    pub fn with_window_state_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.window_state = Some(path.into());
+        self
+    }
+
+    /// Allow opening the devtools, which is the default in debug builds
+    ///
+    /// Release builds also need the `devtools` feature of this crate. Open them with
+    /// [`DesktopContext::open_devtools`](crate::DesktopContext::open_devtools), or with the shortcut enabled by
+    /// [`Config::with_dev_shortcuts`].
+    pub fn with_devtools(mut self, devtools: bool) -> Self {
+        self.devtools = devtools;
+        self
+    }
+
+    /// Bind the keyboard shortcuts of browsers for the zoom and devtools
+    ///
+    /// Ctrl+Shift+I toggles the devtools, and Ctrl with +, - and 0 zooms in, out and back to 100%. On macOS, Cmd works
+    /// in place of Ctrl.
+    pub fn with_dev_shortcuts(mut self, dev_shortcuts: bool) -> Self {
+        self.dev_shortcuts = dev_shortcuts;
+        self
+    }
+
+    /// Send the requests of the webview through a proxy server, like `http://proxy.example.com:8080`
+    ///
+    /// The windows of an app share their network settings, so give every window the same proxy. This works on Windows
+    /// and Linux; on macOS the webview always uses the proxy of the system.
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Replace the user agent the webview sends with its requests and exposes as `navigator.userAgent`
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Add a header to every response of the `dioxus://` protocol that serves the app and its assets
+    ///
+    /// Use it to set a `Content-Security-Policy`, or to give the scripts of the app a token in a cookie. Invalid
+    /// headers are skipped with a warning.
+    pub fn with_custom_header(mut self, name: &str, value: &str) -> Self {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                self.custom_headers.append(name, value);
+            }
+            _ => log::warn!("Skipping the invalid header {name}: {value}"),
+        }
+        self
+    }
+
+    /// Make the window translucent, with a backdrop the OS draws behind the webview
+    ///
+    /// This makes the window and the webview transparent, so the backdrop shows wherever the app has no background
+    /// of its own. On macOS, transparent windows need the `transparent` feature of this crate.
+    ///
+    /// ```rust, ignore
+    /// let config = Config::new().with_window_effect(if cfg!(target_os = "macos") {
+    ///     WindowEffect::Vibrancy(Vibrancy::Sidebar)
+    /// } else {
+    ///     WindowEffect::Mica
+    /// });
+    /// ```
+    pub fn with_window_effect(mut self, effect: crate::WindowEffect) -> Self {
+        self.window = self.window.with_transparent(true);
+        self.window_effect = Some(effect);
+        self
+    }
+
+    /// Set the RGBA color the webview shows before the app renders, and behind it if the app has no background
+    ///
+    /// A transparent window defaults to a transparent background, and other windows to the default of the webview.
+    pub fn with_background_color(mut self, color: (u8, u8, u8, u8)) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Keep the window hidden until the app rendered for the first time
+    ///
+    /// Without this, the window shows an empty page while the webview loads and the app renders, which flashes white
+    /// on slow machines.
+    pub fn with_show_on_first_render(mut self, show_on_first_render: bool) -> Self {
+        self.show_on_first_render = show_on_first_render;
+        self
+    }
+
+    /// Show a splash screen while the app renders for the first time, then replace it with the window of the app
+    ///
+    /// The splash screen is a window of its own, with the given HTML as its content. It's centered on the screen unless
+    /// `window` sets a position. This implies [`Config::with_show_on_first_render`].
+    ///
+    /// ```rust, ignore
+    /// let config = Config::new().with_splash_screen(
+    ///     WindowBuilder::new()
+    ///         .with_decorations(false)
+    ///         .with_inner_size(LogicalSize::new(400.0, 250.0)),
+    ///     include_str!("splash.html"),
+    /// );
+    /// ```
+    pub fn with_splash_screen(mut self, window: WindowBuilder, html: impl Into<String>) -> Self {
+        self.show_on_first_render = true;
+        self.splash = Some((window, html.into()));
+        self
+    }
+
+    /// Choose where the files the webview downloads are saved, or cancel them
+    ///
+    /// Without a handler, downloads are saved to the downloads folder of the user. The handler runs when a download
+    /// starts, so it can ask the user for a path with a blocking dialog. Use
+    /// [`use_download_event_handler`](crate::use_download_event_handler) to follow the downloads from components.
+    ///
+    /// ```rust, ignore
+    /// let config = Config::new().with_download_handler(|download| {
+    ///     match rfd::FileDialog::new().set_file_name(&download.suggested_path().file_name().unwrap().to_string_lossy()).save_file() {
+    ///         Some(path) => DownloadAction::SaveTo(path),
+    ///         None => DownloadAction::Cancel,
+    ///     }
+    /// });
+    /// ```
+    pub fn with_download_handler(
+        mut self,
+        handler: impl FnMut(&crate::Download) -> crate::DownloadAction + 'static,
+    ) -> Self {
+        self.download_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Open the app for links with a custom scheme, like `myapp://invite/123`
+    ///
+    /// The app registers itself for the scheme when it launches on Windows and Linux, if the scheme isn't registered
+    /// to it yet. On macOS the scheme has to be declared with `CFBundleURLTypes` in the `Info.plist` of the bundle.
+    /// Only one instance of the app runs: launching it again brings the running instance to the front and hands it
+    /// the link over a socket in the data directory that only the current user can connect to. Use
+    /// [`use_deep_link_handler`](crate::use_deep_link_handler) to receive the links.
+    pub fn with_deep_link_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.deep_link_scheme = Some(scheme.into());
+        self
+    }
+
+    /// Show an icon in the system tray while the app is running
+    ///
+    /// The app has at most one tray: a window created with a config that has a tray replaces the current one. Use
+    /// [`use_tray_event_handler`](crate::use_tray_event_handler) to react to clicks on it.
+    #[cfg(feature = "tray")]
+    pub fn with_tray(mut self, tray: crate::TrayConfig) -> Self {
+        self.tray = Some(tray);
+        self
+    }
 }
 
 impl Default for Config {
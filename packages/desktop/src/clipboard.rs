@@ -0,0 +1,104 @@
+use dioxus_hooks::{ClipboardBackend, ClipboardError};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+// Every window shares the clipboard, which lives on the main thread with the event loop
+thread_local! {
+    static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = RefCell::new(None);
+}
+
+/// Accesses the native clipboard with arboard
+///
+/// The clipboard is opened on first use and kept open afterwards: on Linux, the text copied by the app is only
+/// available to other apps while the clipboard that wrote it is alive.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct NativeClipboard;
+
+impl NativeClipboard {
+    fn with<T>(
+        &self,
+        f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+    ) -> Result<T, ClipboardError> {
+        CLIPBOARD.with(|clipboard| {
+            let mut clipboard = clipboard.borrow_mut();
+            if clipboard.is_none() {
+                *clipboard = Some(arboard::Clipboard::new().map_err(failed)?);
+            }
+            f(clipboard.as_mut().unwrap()).map_err(failed)
+        })
+    }
+
+    pub(crate) fn get_text(&self) -> Result<String, ClipboardError> {
+        self.with(|clipboard| clipboard.get_text())
+    }
+
+    pub(crate) fn set_text(&self, text: String) -> Result<(), ClipboardError> {
+        self.with(|clipboard| clipboard.set_text(text))
+    }
+
+    pub(crate) fn get_image(&self) -> Result<ClipboardImage, ClipboardError> {
+        self.with(|clipboard| clipboard.get_image())
+            .map(|image| ClipboardImage {
+                width: image.width,
+                height: image.height,
+                rgba: image.bytes.into_owned(),
+            })
+    }
+
+    pub(crate) fn set_image(&self, image: ClipboardImage) -> Result<(), ClipboardError> {
+        self.with(|clipboard| {
+            clipboard.set_image(arboard::ImageData {
+                width: image.width,
+                height: image.height,
+                bytes: Cow::Owned(image.rgba),
+            })
+        })
+    }
+}
+
+/// Write the contents the handlers of a copy or cut event set with [`ClipboardData::set_data`](dioxus_html::ClipboardData::set_data)
+pub(crate) fn write_event_data(data: &dioxus_html::ClipboardData) {
+    let written = data.written_data();
+    let text = written.iter().find(|(format, _)| format == "text/plain");
+    let html = written.iter().find(|(format, _)| format == "text/html");
+
+    let result = match (html, text) {
+        (Some((_, html)), text) => NativeClipboard.with(|clipboard| {
+            clipboard.set_html(html.as_str(), text.map(|(_, text)| text.as_str()))
+        }),
+        (None, Some((_, text))) => NativeClipboard.set_text(text.clone()),
+        (None, None) => return,
+    };
+    if let Err(err) = result {
+        log::error!("Could not write to the clipboard: {err}");
+    }
+}
+
+/// An image on the clipboard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// The width in pixels
+    pub width: usize,
+    /// The height in pixels
+    pub height: usize,
+    /// The pixels row by row from the top left corner, four bytes per pixel in RGBA order
+    pub rgba: Vec<u8>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl ClipboardBackend for NativeClipboard {
+    async fn read_text(&self) -> Result<String, ClipboardError> {
+        self.get_text()
+    }
+
+    async fn write_text(&self, text: String) -> Result<(), ClipboardError> {
+        self.set_text(text)
+    }
+}
+
+fn failed(err: arboard::Error) -> ClipboardError {
+    match err {
+        arboard::Error::ClipboardNotSupported => ClipboardError::Unsupported,
+        err => ClipboardError::Failed(err.to_string()),
+    }
+}
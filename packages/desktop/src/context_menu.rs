@@ -0,0 +1,114 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus_core::ScopeState;
+use dioxus_html::geometry::ClientPoint;
+use dioxus_html::MouseData;
+use wry::application::event::{Event, WindowEvent};
+
+use crate::desktop_context::use_wry_event_handler;
+use crate::use_window;
+
+/// A context menu drawn by the app, returned by [`use_context_menu`]
+pub struct ContextMenu {
+    position: Rc<Cell<Option<ClientPoint>>>,
+    update: Arc<dyn Fn()>,
+}
+
+impl ContextMenu {
+    /// Open the menu where the user clicked
+    pub fn open(&self, event: &MouseData) {
+        self.open_at(event.client_coordinates());
+    }
+
+    /// Open the menu at a point of the window
+    pub fn open_at(&self, point: ClientPoint) {
+        self.position.set(Some(point));
+        (self.update)();
+    }
+
+    /// Close the menu
+    pub fn close(&self) {
+        if self.position.take().is_some() {
+            (self.update)();
+        }
+    }
+
+    /// Where the menu was opened, relative to the top left corner of the window, or `None` while it's closed
+    pub fn position(&self) -> Option<ClientPoint> {
+        self.position.get()
+    }
+
+    /// `true` while the menu is open
+    pub fn is_open(&self) -> bool {
+        self.position.get().is_some()
+    }
+}
+
+/// Replace the right click menu of the webview with one drawn in rsx
+///
+/// The hook keeps track of where the menu is open, and closes it when the window loses focus. Open it from an
+/// `oncontextmenu` handler wrapped in `prevent_default`, which prevents the native menu of that element. The items of the menu
+/// are elements like any other, so their `onclick` handlers are the events of the menu. Draw a backdrop under the menu
+/// to close it when the user clicks elsewhere.
+///
+/// To hide the native menu without drawing another one, see
+/// [`Config::with_disable_context_menu`](crate::Config::with_disable_context_menu).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let menu = use_context_menu(cx);
+///
+///     cx.render(rsx! {
+///         div {
+///             oncontextmenu: prevent_default(move |event| menu.open(&event)),
+///             "Right click me"
+///         }
+///         if let Some(point) = menu.position() {
+///             rsx! {
+///                 div {
+///                     style: "position: fixed; inset: 0",
+///                     onclick: move |_| menu.close(),
+///                     oncontextmenu: prevent_default(move |_| menu.close()),
+///                 }
+///                 ul {
+///                     style: "position: fixed; left: {point.x}px; top: {point.y}px",
+///                     li { onclick: move |_| { copy(); menu.close() }, "Copy" }
+///                     li { onclick: move |_| { paste(); menu.close() }, "Paste" }
+///                 }
+///             }
+///         }
+///     })
+/// }
+/// ```
+pub fn use_context_menu(cx: &ScopeState) -> &ContextMenu {
+    let desktop = use_window(cx);
+
+    let menu = cx.use_hook(|| ContextMenu {
+        position: Rc::new(Cell::new(None)),
+        update: cx.schedule_update(),
+    });
+
+    use_wry_event_handler(cx, {
+        let id = desktop.id();
+        let position = menu.position.clone();
+        let update = menu.update.clone();
+        move |event, _| {
+            if let Event::WindowEvent {
+                window_id,
+                event: WindowEvent::Focused(false),
+                ..
+            } = event
+            {
+                if *window_id == id && position.take().is_some() {
+                    update();
+                }
+            }
+        }
+    });
+
+    menu
+}
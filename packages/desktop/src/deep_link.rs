@@ -0,0 +1,511 @@
+use std::cell::RefCell;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use dioxus_core::ScopeState;
+use slab::Slab;
+use wry::application::window::WindowId;
+
+use crate::desktop_context::{
+    use_latest_handler, EventData, LatestHandler, ProxyType, UserWindowEvent,
+};
+
+type Handler = LatestHandler<dyn FnMut(String)>;
+
+// The handlers and links belong to the event loop, which always runs on the main thread
+thread_local! {
+    static HANDLERS: RefCell<Slab<Handler>> = RefCell::new(Slab::new());
+    static PENDING: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// The reply of the running instance, so an endpoint reused by another program isn't mistaken for it
+const ACK: &str = "dioxus-deep-link";
+
+/// Become the instance of the app that receives the deep links, or hand the links this instance was launched with to
+/// the instance that is already running
+///
+/// Returns `None` if another instance took the links, in which case this one should exit.
+pub(crate) fn claim(scheme: &str, data_dir: &Path) -> Option<DeepLinkListener> {
+    let urls = urls_in_args(scheme);
+
+    if let Err(err) = std::fs::create_dir_all(data_dir) {
+        log::warn!("Deep links won't reach this instance once it runs: {err}");
+    }
+
+    let listener = match endpoint::claim(&data_dir.join(format!("{scheme}-deep-links")), &urls) {
+        endpoint::Claim::Forwarded => return None,
+        endpoint::Claim::Listening(listener) => Some(listener),
+        endpoint::Claim::Failed(err) => {
+            log::warn!("Deep links won't reach this instance once it runs: {err}");
+            None
+        }
+    };
+
+    register(scheme);
+    PENDING.with(|pending| pending.borrow_mut().extend(urls));
+
+    Some(DeepLinkListener { listener })
+}
+
+/// The links the app was launched with, which the OS passes as arguments on Windows and Linux
+fn urls_in_args(scheme: &str) -> Vec<String> {
+    let prefix = format!("{scheme}://");
+    std::env::args()
+        .skip(1)
+        .filter(|arg| arg.starts_with(&prefix))
+        .collect()
+}
+
+/// Send the links to the running instance over `stream`. An empty message asks it to come to the front.
+fn forward(mut stream: impl Read + Write, token: &str, urls: &[String]) -> std::io::Result<bool> {
+    writeln!(stream, "{token} {}", urls.join(" "))?;
+
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    Ok(reply.trim() == ACK)
+}
+
+/// Read the links another instance sent, if it knows the token
+fn receive(stream: impl Read + Write + Copy, token: &str) -> Option<Vec<String>> {
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).ok()?;
+    let urls = line.strip_prefix(token)?.strip_prefix(' ')?;
+
+    let mut stream = stream;
+    let _ = writeln!(stream, "{ACK}");
+    Some(urls.split_whitespace().map(String::from).collect())
+}
+
+/// The running instance listens on a Unix socket in the data directory of the user. Binding the socket is atomic, so
+/// only one of several instances launched at once can claim it, and its permissions keep other users out.
+#[cfg(unix)]
+mod endpoint {
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::time::Duration;
+
+    pub(crate) enum Claim {
+        Forwarded,
+        Listening(Listener),
+        Failed(std::io::Error),
+    }
+
+    pub(crate) struct Listener {
+        listener: UnixListener,
+    }
+
+    pub(crate) fn claim(path: &Path, urls: &[String]) -> Claim {
+        let path = path.with_extension("sock");
+
+        // A second attempt is made if the socket was left behind by an instance that crashed
+        let mut result = UnixListener::bind(&path);
+        if matches!(&result, Err(err) if err.kind() == std::io::ErrorKind::AddrInUse) {
+            let forwarded = UnixStream::connect(&path).and_then(|stream| {
+                stream.set_read_timeout(Some(super::FORWARD_TIMEOUT))?;
+                super::forward(&stream, "", urls)
+            });
+            if let Ok(true) = forwarded {
+                return Claim::Forwarded;
+            }
+
+            let _ = std::fs::remove_file(&path);
+            result = UnixListener::bind(&path);
+        }
+
+        let listener = match result {
+            Ok(listener) => listener,
+            Err(err) => return Claim::Failed(err),
+        };
+        if let Err(err) = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)) {
+            return Claim::Failed(err);
+        }
+
+        Claim::Listening(Listener { listener })
+    }
+
+    impl Listener {
+        pub(crate) fn for_each(self, mut f: impl FnMut(Vec<String>)) {
+            for stream in self.listener.incoming().flatten() {
+                let _ = stream.set_read_timeout(Some(Duration::from_secs(1)));
+                if let Some(urls) = super::receive(&stream, "") {
+                    f(urls);
+                }
+            }
+        }
+    }
+}
+
+/// Windows has no Unix sockets in std, so the running instance listens on a localhost port. The port is written to a
+/// file in the data directory of the user with a random token, and connections that don't start with the token are
+/// ignored, so other users and web pages can't send links.
+#[cfg(not(unix))]
+mod endpoint {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::net::{Ipv4Addr, TcpListener, TcpStream};
+    use std::path::Path;
+    use std::time::Duration;
+
+    pub(crate) enum Claim {
+        Forwarded,
+        Listening(Listener),
+        Failed(std::io::Error),
+    }
+
+    pub(crate) struct Listener {
+        listener: TcpListener,
+        token: String,
+    }
+
+    pub(crate) fn claim(path: &Path, urls: &[String]) -> Claim {
+        let path = path.with_extension("port");
+
+        // Another instance may have just created the file and not have written its port yet
+        for _ in 0..5 {
+            if forward(&path, urls) {
+                return Claim::Forwarded;
+            }
+            match std::fs::metadata(&path).and_then(|meta| meta.modified()) {
+                Ok(modified) if modified.elapsed().unwrap_or_default() < Duration::from_secs(1) => {
+                    std::thread::sleep(Duration::from_millis(100))
+                }
+                _ => break,
+            }
+        }
+
+        let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, 0)) {
+            Ok(listener) => listener,
+            Err(err) => return Claim::Failed(err),
+        };
+        let token = random_token();
+        let written = listener
+            .local_addr()
+            .and_then(|addr| std::fs::write(&path, format!("{} {token}", addr.port())));
+        if let Err(err) = written {
+            return Claim::Failed(err);
+        }
+
+        Claim::Listening(Listener { listener, token })
+    }
+
+    fn forward(path: &Path, urls: &[String]) -> bool {
+        let contents = std::fs::read_to_string(path).unwrap_or_default();
+        let (port, token) = match contents.trim().split_once(' ') {
+            Some((port, token)) => match port.parse::<u16>() {
+                Ok(port) => (port, token),
+                Err(_) => return false,
+            },
+            None => return false,
+        };
+
+        TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+            .and_then(|stream| {
+                stream.set_read_timeout(Some(super::FORWARD_TIMEOUT))?;
+                super::forward(&stream, token, urls)
+            })
+            .unwrap_or(false)
+    }
+
+    // std seeds every RandomState from the OS, which is enough to keep the token from being guessed
+    fn random_token() -> String {
+        (0..2)
+            .map(|_| format!("{:016x}", RandomState::new().build_hasher().finish()))
+            .collect()
+    }
+
+    impl Listener {
+        pub(crate) fn for_each(self, mut f: impl FnMut(Vec<String>)) {
+            for stream in self.listener.incoming().flatten() {
+                let _ = stream.set_read_timeout(Some(Duration::from_secs(1)));
+                if let Some(urls) = super::receive(&stream, &self.token) {
+                    f(urls);
+                }
+            }
+        }
+    }
+}
+
+/// How long a new instance waits for the running one to take its links. The running instance only starts listening
+/// once its window is created, so this covers an instance that was launched a moment earlier.
+const FORWARD_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Make the OS launch this executable for links with the scheme, unless it is already registered for it
+///
+/// Another executable registered for the scheme (like an older build in a different folder) is replaced. On macOS the scheme is declared with `CFBundleURLTypes` in the `Info.plist` of the bundle instead.
+fn register(scheme: &str) {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => return log::warn!("Could not register the {scheme}:// scheme: {err}"),
+    };
+    let scheme = scheme.to_string();
+
+    // Registering runs other programs, so keep it from slowing down the launch
+    std::thread::spawn(move || {
+        if let Err(err) = register_exe(&scheme, &exe) {
+            log::warn!("Could not register the {scheme}:// scheme: {err}");
+        }
+    });
+}
+
+#[cfg(target_os = "windows")]
+fn register_exe(scheme: &str, exe: &Path) -> std::io::Result<()> {
+    let registered = std::process::Command::new("reg")
+        .args([
+            "query",
+            &format!(r"{}\shell\open\command", reg_key(scheme)),
+            "/ve",
+        ])
+        .output()?;
+    let current = String::from_utf8_lossy(&registered.stdout);
+    if registered.status.success() && current.contains(&open_command(exe)) {
+        return Ok(());
+    }
+
+    for args in reg_add_args(scheme, exe) {
+        reg_add(&args)?;
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "windows", test))]
+fn reg_key(scheme: &str) -> String {
+    format!(r"HKCU\Software\Classes\{scheme}")
+}
+
+/// The command Windows runs for a link, with the link in place of `%1`
+#[cfg(any(target_os = "windows", test))]
+fn open_command(exe: &Path) -> String {
+    format!("\"{}\" \"%1\"", exe.display())
+}
+
+/// The arguments of the `reg add` calls that register the executable for the scheme
+#[cfg(any(target_os = "windows", test))]
+fn reg_add_args(scheme: &str, exe: &Path) -> [Vec<String>; 3] {
+    let key = reg_key(scheme);
+    let args = |args: &[&str]| -> Vec<String> { args.iter().map(|arg| arg.to_string()).collect() };
+    [
+        args(&[&key, "/ve", "/d", &format!("URL:{scheme}")]),
+        args(&[&key, "/v", "URL Protocol", "/d", ""]),
+        args(&[
+            &format!(r"{key}\shell\open\command"),
+            "/ve",
+            "/d",
+            &open_command(exe),
+        ]),
+    ]
+}
+
+#[cfg(target_os = "windows")]
+fn reg_add(args: &[String]) -> std::io::Result<()> {
+    let status = std::process::Command::new("reg")
+        .arg("add")
+        .args(args)
+        .arg("/f")
+        .status()?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("reg add failed with {status}"),
+        )),
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn register_exe(scheme: &str, exe: &Path) -> std::io::Result<()> {
+    let applications = dirs::data_local_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("~/.local/share"))
+        .join("applications");
+    let file_name = desktop_file_name(scheme);
+    let entry = desktop_entry(scheme, exe);
+
+    let default = std::process::Command::new("xdg-mime")
+        .args(["query", "default", &format!("x-scheme-handler/{scheme}")])
+        .output()?;
+    let is_default = String::from_utf8_lossy(&default.stdout).trim() == file_name;
+    let current = std::fs::read_to_string(applications.join(&file_name)).unwrap_or_default();
+    if is_default && current == entry {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&applications)?;
+    std::fs::write(applications.join(&file_name), entry)?;
+
+    std::process::Command::new("xdg-mime")
+        .args(["default", &file_name, &format!("x-scheme-handler/{scheme}")])
+        .status()
+        .map(drop)
+}
+
+#[cfg(any(all(unix, not(target_os = "macos")), test))]
+fn desktop_file_name(scheme: &str) -> String {
+    format!("{scheme}-handler.desktop")
+}
+
+/// The desktop entry that makes xdg-open launch the executable for links with the scheme
+#[cfg(any(all(unix, not(target_os = "macos")), test))]
+fn desktop_entry(scheme: &str, exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\nType=Application\nName={scheme}\nExec=\"{}\" %u\nMimeType=x-scheme-handler/{scheme};\nNoDisplay=true\n",
+        exe.display()
+    )
+}
+
+#[cfg(not(any(target_os = "windows", all(unix, not(target_os = "macos")))))]
+fn register_exe(_scheme: &str, _exe: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Receives the links of the instances launched while this one runs
+pub(crate) struct DeepLinkListener {
+    listener: Option<endpoint::Listener>,
+}
+
+impl DeepLinkListener {
+    /// Send every link to the event loop, aimed at `window`
+    pub(crate) fn spawn(self, proxy: ProxyType, window: WindowId) {
+        let listener = match self.listener {
+            Some(listener) => listener,
+            None => return,
+        };
+
+        std::thread::spawn(move || {
+            listener.for_each(|urls| {
+                let _ = proxy.send_event(UserWindowEvent(EventData::DeepLink(urls), window));
+            })
+        });
+    }
+}
+
+/// Send links to the handlers registered with [`use_deep_link_handler`], or keep them until one is registered
+pub(crate) fn deliver(urls: Vec<String>) {
+    let handlers: Vec<Handler> =
+        HANDLERS.with(|handlers| handlers.borrow().iter().map(|(_, h)| h.clone()).collect());
+
+    if handlers.is_empty() {
+        PENDING.with(|pending| pending.borrow_mut().extend(urls));
+        return;
+    }
+
+    for url in urls {
+        for handler in &handlers {
+            (handler.borrow_mut())(url.clone());
+        }
+    }
+}
+
+/// Listen to the links with the scheme set in [`Config::with_deep_link_scheme`](crate::Config::with_deep_link_scheme)
+///
+/// The handler receives the link the app was launched with, and every link opened while it runs, including the ones
+/// opened by launching the app again. Links that arrive before any component listens are kept for the first handler,
+/// which gets them after its component rendered. Each link goes to the closure of the component's latest render.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let invite = use_state(cx, || None);
+///
+///     use_deep_link_handler(cx, {
+///         to_owned![invite];
+///         move |url| {
+///             if let Some(code) = url.strip_prefix("myapp://invite/") {
+///                 invite.set(Some(code.to_string()));
+///             }
+///         }
+///     });
+///
+///     cx.render(rsx!("Invite: {invite:?}"))
+/// }
+/// ```
+pub fn use_deep_link_handler(cx: &ScopeState, handler: impl FnMut(String) + 'static) {
+    let handler = use_latest_handler::<dyn FnMut(String)>(cx, Box::new(handler));
+
+    cx.use_hook(|| {
+        let id = HANDLERS.with(|handlers| handlers.borrow_mut().insert(handler.clone()));
+        cx.on_drop(move || {
+            HANDLERS.with(|handlers| handlers.borrow_mut().try_remove(id));
+        });
+
+        // Hand over the links that arrived before the first handler, once the component has rendered
+        let pending = PENDING.with(|pending| pending.take());
+        if !pending.is_empty() {
+            cx.push_future(async move {
+                for url in pending {
+                    (handler.borrow_mut())(url);
+                }
+            });
+        }
+    });
+}
+
+#[cfg(unix)]
+#[test]
+fn links_reach_the_instance_that_knows_the_token() {
+    use std::os::unix::net::UnixStream;
+
+    let urls = vec![
+        "myapp://invite/abc".to_string(),
+        "myapp://open?file=notes.txt".to_string(),
+    ];
+
+    let (new_instance, running) = UnixStream::pair().unwrap();
+    let received = std::thread::spawn(move || receive(&running, "token"));
+    assert!(forward(&new_instance, "token", &urls).unwrap());
+    assert_eq!(received.join().unwrap(), Some(urls));
+
+    // An instance launched without links asks the running one to come to the front
+    let (new_instance, running) = UnixStream::pair().unwrap();
+    let received = std::thread::spawn(move || receive(&running, "token"));
+    assert!(forward(&new_instance, "token", &[]).unwrap());
+    assert_eq!(received.join().unwrap(), Some(Vec::new()));
+}
+
+#[cfg(unix)]
+#[test]
+fn links_with_the_wrong_token_are_ignored() {
+    use std::os::unix::net::UnixStream;
+
+    for token in ["guess", "tokens", ""] {
+        let (new_instance, running) = UnixStream::pair().unwrap();
+        // The running instance hangs up without the reply the new instance waits for
+        let received = std::thread::spawn(move || receive(&running, "token"));
+        assert!(!forward(&new_instance, token, &["myapp://a".to_string()]).unwrap());
+        assert_eq!(received.join().unwrap(), None);
+    }
+}
+
+#[test]
+fn registering_builds_the_reg_commands() {
+    let exe = Path::new(r"C:\Program Files\My App\app.exe");
+    assert_eq!(
+        reg_add_args("myapp", exe),
+        [
+            vec![r"HKCU\Software\Classes\myapp", "/ve", "/d", "URL:myapp"],
+            vec![
+                r"HKCU\Software\Classes\myapp",
+                "/v",
+                "URL Protocol",
+                "/d",
+                ""
+            ],
+            vec![
+                r"HKCU\Software\Classes\myapp\shell\open\command",
+                "/ve",
+                "/d",
+                r#""C:\Program Files\My App\app.exe" "%1""#,
+            ],
+        ]
+    );
+}
+
+#[test]
+fn registering_builds_the_desktop_entry() {
+    let entry = desktop_entry("myapp", Path::new("/opt/my app/app"));
+    assert_eq!(desktop_file_name("myapp"), "myapp-handler.desktop");
+    assert!(entry.starts_with("[Desktop Entry]\n"));
+    assert!(entry.contains("\nExec=\"/opt/my app/app\" %u\n"));
+    assert!(entry.contains("\nMimeType=x-scheme-handler/myapp;\n"));
+}
@@ -1,10 +1,16 @@
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::rc::Weak;
 
+use crate::app::{AppDir, AppInfo, AppMetadata, UserDir};
+use crate::app_proxy::AppProxy;
 use crate::create_new_window;
-use crate::eval::EvalResult;
+use crate::embedded::EmbeddedWebViews;
+use crate::eval::{EvalResult, PendingEvals};
 use crate::events::IpcMessage;
+use crate::invoke::InvokeHandlers;
 use crate::shortcut::IntoKeyCode;
 use crate::shortcut::IntoModifersState;
 use crate::shortcut::ShortcutId;
@@ -12,24 +18,31 @@ use crate::shortcut::ShortcutRegistry;
 use crate::shortcut::ShortcutRegistryError;
 use crate::Config;
 use crate::WebviewHandler;
+use dioxus_core::SchedulerHandle;
 use dioxus_core::ScopeState;
 use dioxus_core::VirtualDom;
 #[cfg(all(feature = "hot-reload", debug_assertions))]
 use dioxus_hot_reload::HotReloadMsg;
-use serde_json::Value;
 use slab::Slab;
+use wry::application::dpi::{LogicalPosition, LogicalSize};
 use wry::application::event::Event;
 use wry::application::event_loop::EventLoopProxy;
 use wry::application::event_loop::EventLoopWindowTarget;
 #[cfg(target_os = "ios")]
 use wry::application::platform::ios::WindowExtIOS;
 use wry::application::window::Fullscreen as WryFullscreen;
+use wry::application::window::Icon;
+use wry::application::window::UserAttentionType;
 use wry::application::window::Window;
 use wry::application::window::WindowId;
 use wry::webview::WebView;
 
 pub type ProxyType = EventLoopProxy<UserWindowEvent>;
 
+const ZOOM_STEP: f64 = 0.1;
+const MIN_ZOOM_LEVEL: f64 = 0.25;
+const MAX_ZOOM_LEVEL: f64 = 5.0;
+
 /// Get an imperative handle to the current window
 pub fn use_window(cx: &ScopeState) -> &DesktopContext {
     cx.use_hook(|| cx.consume_context::<DesktopContext>())
@@ -59,8 +72,14 @@ pub struct DesktopContext {
     /// The proxy to the event loop
     pub proxy: ProxyType,
 
-    /// The receiver for eval results since eval is async
-    pub(super) eval: tokio::sync::broadcast::Sender<Value>,
+    /// The evals waiting for their result, since eval is async
+    pub(super) evals: PendingEvals,
+
+    pub(crate) invoke_handlers: InvokeHandlers,
+
+    pub(crate) embedded_webviews: EmbeddedWebViews,
+
+    app: Rc<AppInfo>,
 
     pub(super) pending_windows: WebviewQueue,
 
@@ -70,6 +89,12 @@ pub struct DesktopContext {
 
     pub(crate) shortcut_manager: ShortcutRegistry,
 
+    // Lets the app proxies of other threads render components again
+    scheduler: SchedulerHandle,
+
+    // The webview can set the zoom level but not tell it
+    zoom_level: Rc<Cell<f64>>,
+
     #[cfg(target_os = "ios")]
     pub(crate) views: Rc<RefCell<Vec<*mut objc::runtime::Object>>>,
 }
@@ -84,6 +109,7 @@ impl std::ops::Deref for DesktopContext {
 }
 
 impl DesktopContext {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         webview: Rc<WebView>,
         proxy: ProxyType,
@@ -91,15 +117,22 @@ impl DesktopContext {
         webviews: WebviewQueue,
         event_handlers: WindowEventHandlers,
         shortcut_manager: ShortcutRegistry,
+        app: Rc<AppInfo>,
+        scheduler: SchedulerHandle,
     ) -> Self {
         Self {
             webview,
             proxy,
             event_loop,
-            eval: tokio::sync::broadcast::channel(8).0,
+            evals: Default::default(),
+            invoke_handlers: Default::default(),
+            embedded_webviews: Default::default(),
+            app,
             pending_windows: webviews,
             event_handlers,
             shortcut_manager,
+            scheduler,
+            zoom_level: Rc::new(Cell::new(1.0)),
             #[cfg(target_os = "ios")]
             views: Default::default(),
         }
@@ -107,12 +140,13 @@ impl DesktopContext {
 
     /// Create a new window using the props and window builder
     ///
-    /// Returns the webview handle for the new window.
+    /// The window gets its own webview and runs `dom` independently of the current window, on the same event loop.
     ///
-    /// You can use this to control other windows from the current window.
+    /// Returns a [`WindowHandle`] you can use to control the new window from the current window, and to find out when
+    /// it is closed.
     ///
     /// Be careful to not create a cycle of windows, or you might leak memory.
-    pub fn new_window(&self, dom: VirtualDom, cfg: Config) -> Weak<WebView> {
+    pub fn new_window(&self, dom: VirtualDom, cfg: Config) -> WindowHandle {
         let window = create_new_window(
             cfg,
             &self.event_loop,
@@ -133,11 +167,16 @@ impl DesktopContext {
             .send_event(UserWindowEvent(EventData::Poll, id))
             .unwrap();
 
-        let webview = window.webview.clone();
+        let handle = WindowHandle {
+            id,
+            webview: Rc::downgrade(&window.webview),
+            proxy: self.proxy.clone(),
+            lifecycle: window.lifecycle.clone(),
+        };
 
         self.pending_windows.borrow_mut().push(window);
 
-        Rc::downgrade(&webview)
+        handle
     }
 
     /// trigger the drag-window event
@@ -180,13 +219,81 @@ impl DesktopContext {
 
     /// change window to fullscreen
     pub fn set_fullscreen(&self, fullscreen: bool) {
-        if let Some(handle) = self.webview.window().current_monitor() {
-            self.webview
-                .window()
-                .set_fullscreen(fullscreen.then_some(WryFullscreen::Borderless(Some(handle))));
+        let window = self.webview.window();
+        let monitor = window.current_monitor();
+        window.set_fullscreen(fullscreen.then_some(WryFullscreen::Borderless(monitor)));
+    }
+
+    /// `true` if the window is fullscreen
+    pub fn is_fullscreen(&self) -> bool {
+        self.webview.window().fullscreen().is_some()
+    }
+
+    /// Set the text of the title bar
+    pub fn set_title(&self, title: &str) {
+        self.webview.window().set_title(title);
+    }
+
+    /// Resize the content of the window, in logical pixels
+    pub fn set_size(&self, width: f64, height: f64) {
+        self.webview
+            .window()
+            .set_inner_size(LogicalSize::new(width, height));
+    }
+
+    /// Keep the user from making the content of the window smaller than this, in logical pixels
+    pub fn set_min_size(&self, width: f64, height: f64) {
+        self.webview
+            .window()
+            .set_min_inner_size(Some(LogicalSize::new(width, height)));
+    }
+
+    /// Keep the user from making the content of the window larger than this, in logical pixels
+    pub fn set_max_size(&self, width: f64, height: f64) {
+        self.webview
+            .window()
+            .set_max_inner_size(Some(LogicalSize::new(width, height)));
+    }
+
+    /// Move the top left corner of the window, in logical pixels from the top left corner of the desktop
+    pub fn set_position(&self, x: f64, y: f64) {
+        self.webview
+            .window()
+            .set_outer_position(LogicalPosition::new(x, y));
+    }
+
+    /// Keep the window above every other window
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.webview.window().set_always_on_top(always_on_top);
+    }
+
+    /// Replace the icon of the window with an image of `width` by `height` pixels, four bytes per pixel in RGBA order
+    pub fn set_icon(&self, rgba: Vec<u8>, width: u32, height: u32) {
+        match Icon::from_rgba(rgba, width, height) {
+            Ok(icon) => self.webview.window().set_window_icon(Some(icon)),
+            Err(e) => log::warn!("Invalid window icon: {e}"),
         }
     }
 
+    /// Bring the window to the front and focus it
+    pub fn focus(&self) {
+        self.webview.window().set_focus();
+    }
+
+    /// Get the attention of the user without focusing the window, by bouncing the dock icon on macOS or flashing the
+    /// taskbar button on Windows
+    ///
+    /// A `critical` request keeps going until the window is focused, otherwise it only happens once. Nothing happens
+    /// if the window is already focused.
+    pub fn request_user_attention(&self, critical: bool) {
+        let kind = if critical {
+            UserAttentionType::Critical
+        } else {
+            UserAttentionType::Informational
+        };
+        self.webview.window().request_user_attention(Some(kind));
+    }
+
     /// launch print modal
     pub fn print(&self) {
         if let Err(e) = self.webview.print() {
@@ -194,44 +301,172 @@ impl DesktopContext {
         }
     }
 
-    /// Set the zoom level of the webview
+    /// launch print modal with a page setup, like the page size, orientation and margins
+    pub fn print_with(&self, options: crate::PrintOptions) {
+        // WKWebView ignores `window.print()`, so macOS opens the native print modal once the options are applied
+        let print_from_js = cfg!(not(target_os = "macos"));
+        if let Err(e) = self.webview.evaluate_script(&options.script(print_from_js)) {
+            log::warn!("Open print modal failed: {e}");
+            return;
+        }
+
+        if !print_from_js {
+            self.print();
+            // `afterprint` may never fire for a native print, so the options are removed once it started
+            crate::print::remove_style(&self.webview);
+        }
+    }
+
+    /// Write the page to a PDF file with a page setup, without showing a print dialog
+    ///
+    /// The PDF is written in the background, so the file may not exist yet when this returns.
+    pub fn export_pdf(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        options: crate::PrintOptions,
+    ) -> Result<(), crate::PdfError> {
+        if let Err(e) = self.webview.evaluate_script(&options.script(false)) {
+            log::warn!("Could not apply the print options: {e}");
+        }
+        let result = crate::print::export_pdf(&self.webview, path.as_ref());
+        crate::print::remove_style(&self.webview);
+        result
+    }
+
+    /// The name, version and identifier of the app
+    pub fn app_metadata(&self) -> &AppMetadata {
+        &self.app.metadata
+    }
+
+    /// Where the app keeps its files of a kind, in a directory named after the app
+    ///
+    /// The directory may not exist yet. Returns `None` if the platform has no such directory.
+    pub fn app_dir(&self, dir: AppDir) -> Option<PathBuf> {
+        match dir {
+            AppDir::Data => Some(self.app.data_dir.clone()),
+            dir => self.app.metadata.dir(dir),
+        }
+    }
+
+    /// A directory of the user, like their documents, or `None` if the platform has no such directory
+    pub fn user_dir(&self, dir: UserDir) -> Option<PathBuf> {
+        dir.path()
+    }
+
+    /// Get a proxy that other threads can use to send events into the app
+    pub fn app_proxy(&self) -> AppProxy {
+        AppProxy::new(self.proxy.clone(), self.id(), self.scheduler.clone())
+    }
+
+    /// Set the zoom level of the webview, where 1.0 is 100%
     pub fn set_zoom_level(&self, level: f64) {
+        let level = level.clamp(MIN_ZOOM_LEVEL, MAX_ZOOM_LEVEL);
+        self.zoom_level.set(level);
         self.webview.zoom(level);
     }
 
+    /// The zoom level of the webview, where 1.0 is 100%
+    pub fn zoom_level(&self) -> f64 {
+        self.zoom_level.get()
+    }
+
+    /// Zoom in by 10%
+    pub fn zoom_in(&self) {
+        self.set_zoom_level(self.zoom_level() + ZOOM_STEP);
+    }
+
+    /// Zoom out by 10%
+    pub fn zoom_out(&self) {
+        self.set_zoom_level(self.zoom_level() - ZOOM_STEP);
+    }
+
+    /// Zoom back to 100%
+    pub fn reset_zoom(&self) {
+        self.set_zoom_level(1.0);
+    }
+
     /// opens DevTool window
     pub fn devtool(&self) {
-        #[cfg(debug_assertions)]
+        self.open_devtools();
+    }
+
+    /// Open the devtools of the webview
+    ///
+    /// Devtools are only available in debug builds, or with the `devtools` feature, and when
+    /// [`Config::with_devtools`](crate::Config::with_devtools) didn't disable them.
+    pub fn open_devtools(&self) {
+        #[cfg(any(debug_assertions, feature = "devtools"))]
         self.webview.open_devtools();
 
-        #[cfg(not(debug_assertions))]
-        log::warn!("Devtools are disabled in release builds");
+        #[cfg(not(any(debug_assertions, feature = "devtools")))]
+        log::warn!(
+            "Devtools are disabled in release builds, enable the devtools feature to use them"
+        );
+    }
+
+    /// Close the devtools of the webview
+    ///
+    /// The devtools can't be closed programmatically on Windows.
+    pub fn close_devtools(&self) {
+        #[cfg(any(debug_assertions, feature = "devtools"))]
+        self.webview.close_devtools();
+    }
+
+    /// `true` if the devtools of the webview are open
+    pub fn is_devtools_open(&self) -> bool {
+        #[cfg(any(debug_assertions, feature = "devtools"))]
+        return self.webview.is_devtools_open();
+
+        #[cfg(not(any(debug_assertions, feature = "devtools")))]
+        false
+    }
+
+    /// Open the devtools if they are closed, and close them if they are open
+    pub fn toggle_devtools(&self) {
+        if self.is_devtools_open() {
+            self.close_devtools();
+        } else {
+            self.open_devtools();
+        }
     }
 
     /// Evaluate a javascript expression
+    ///
+    /// The result resolves to the value the code returns, or to the value of the promise it returns. Use
+    /// [`EvalResult::into_typed`] to deserialize it. If the code throws or the promise rejects, the result is an
+    /// [`EvalError::Script`](crate::EvalError::Script) with the error.
     pub fn eval(&self, code: &str) -> EvalResult {
-        // Embed the return of the eval in a function so we can send it back to the main thread
+        let (id, result) = self.evals.insert();
+
+        // Run the code in a promise chain, so errors and promises it returns send a result back to the main thread too
         let script = format!(
             r#"
-            window.ipc.postMessage(
-                JSON.stringify({{
-                    "method":"eval_result",
-                    "params": (
-                        function(){{
-                            {code}
-                        }}
-                    )()
-                }})
-            );
+            (() => {{
+                const send = (result) => {{
+                    let message;
+                    try {{
+                        message = JSON.stringify({{ method: "eval_result", params: {{ id: {id}, ...result }} }});
+                    }} catch (err) {{
+                        message = JSON.stringify({{ method: "eval_result", params: {{ id: {id}, err: String(err) }} }});
+                    }}
+                    window.ipc.postMessage(message);
+                }};
+                Promise.resolve()
+                    .then(() => (function(){{
+                        {code}
+                    }})())
+                    .then((ok) => send({{ ok }}), (err) => send({{ err: String(err) }}));
+            }})();
             "#
         );
 
         if let Err(e) = self.webview.evaluate_script(&script) {
-            // send an error to the eval receiver
+            // fail the eval instead of waiting for a result that never comes
             log::warn!("Eval script error: {e}");
+            self.evals.cancel(id);
         }
 
-        EvalResult::new(self.eval.clone())
+        result
     }
 
     /// Create a wry event handler that listens for wry events.
@@ -271,6 +506,57 @@ impl DesktopContext {
         self.shortcut_manager.remove_shortcut(id)
     }
 
+    /// Read the text on the system clipboard
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn clipboard_text(&self) -> Result<String, dioxus_hooks::ClipboardError> {
+        crate::clipboard::NativeClipboard.get_text()
+    }
+
+    /// Replace the contents of the system clipboard with `text`
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_clipboard_text(
+        &self,
+        text: impl Into<String>,
+    ) -> Result<(), dioxus_hooks::ClipboardError> {
+        crate::clipboard::NativeClipboard.set_text(text.into())
+    }
+
+    /// Read the image on the system clipboard
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn clipboard_image(&self) -> Result<crate::ClipboardImage, dioxus_hooks::ClipboardError> {
+        crate::clipboard::NativeClipboard.get_image()
+    }
+
+    /// Replace the contents of the system clipboard with `image`
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_clipboard_image(
+        &self,
+        image: crate::ClipboardImage,
+    ) -> Result<(), dioxus_hooks::ClipboardError> {
+        crate::clipboard::NativeClipboard.set_image(image)
+    }
+
+    /// Create a native dialog to pick files or folders, or to choose where to save a file, on top of this window
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn file_dialog(&self) -> crate::FileDialog {
+        crate::FileDialog::new(self.webview.window())
+    }
+
+    /// Show a native notification of the OS
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn notify(
+        &self,
+        notification: crate::Notification,
+    ) -> Result<(), crate::NotificationError> {
+        crate::notification::show(notification, &self.proxy, self.id())
+    }
+
+    /// Get a handle to update the system tray set with [`Config::with_tray`]
+    #[cfg(feature = "tray")]
+    pub fn tray(&self) -> crate::TrayHandle {
+        crate::TrayHandle::new()
+    }
+
     /// Push an objc view to the window
     #[cfg(target_os = "ios")]
     pub fn push_view(&self, view: objc_id::ShareId<objc::runtime::Object>) {
@@ -308,6 +594,85 @@ impl DesktopContext {
     }
 }
 
+/// A handle to a window created with [`DesktopContext::new_window`]
+///
+/// The handle doesn't keep the window open: it closes when the user closes it or when [`WindowHandle::close`] is called,
+/// even if handles to it are still around.
+#[derive(Clone)]
+pub struct WindowHandle {
+    id: WindowId,
+    webview: Weak<WebView>,
+    proxy: ProxyType,
+    lifecycle: Rc<WindowLifecycle>,
+}
+
+impl WindowHandle {
+    /// The id of the window
+    pub fn id(&self) -> WindowId {
+        self.id
+    }
+
+    /// The webview of the window, or `None` if it was closed
+    pub fn webview(&self) -> Option<Rc<WebView>> {
+        if self.is_open() {
+            self.webview.upgrade()
+        } else {
+            None
+        }
+    }
+
+    /// `true` until the window is closed
+    pub fn is_open(&self) -> bool {
+        !self.lifecycle.closed.get()
+    }
+
+    /// Close the window
+    pub fn close(&self) {
+        let _ = self
+            .proxy
+            .send_event(UserWindowEvent(EventData::CloseWindow, self.id));
+    }
+
+    /// Bring the window to the front and focus it
+    pub fn focus(&self) {
+        if let Some(webview) = self.webview() {
+            webview.window().set_focus();
+        }
+    }
+
+    /// Run `callback` once the window is closed, or right away if it already is
+    ///
+    /// The callback runs on the event loop, so it can update state in the window that opened this one.
+    pub fn on_close(&self, callback: impl FnOnce() + 'static) {
+        if self.is_open() {
+            self.lifecycle
+                .on_close
+                .borrow_mut()
+                .push(Box::new(callback));
+        } else {
+            callback();
+        }
+    }
+}
+
+/// Whether a window is still open, and who wants to know when it closes
+#[derive(Default)]
+pub(crate) struct WindowLifecycle {
+    closed: Cell<bool>,
+    on_close: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+impl WindowLifecycle {
+    pub(crate) fn close(&self) {
+        if !self.closed.replace(true) {
+            let callbacks = self.on_close.take();
+            for callback in callbacks {
+                callback();
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UserWindowEvent(pub EventData, pub WindowId);
 
@@ -323,6 +688,24 @@ pub enum EventData {
     NewWindow,
 
     CloseWindow,
+
+    DeepLink(Vec<String>),
+
+    Download(crate::DownloadEvent),
+
+    AppEvent(crate::app_proxy::AppEvent),
+
+    /// The files picked in the native dialog of a file input
+    FilesPicked {
+        element: dioxus_core::ElementId,
+        files: Vec<PathBuf>,
+    },
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    NotificationClosed {
+        id: usize,
+        clicked: bool,
+    },
 }
 
 #[cfg(target_os = "ios")]
@@ -445,3 +828,24 @@ impl Drop for WryEventHandler {
         self.handlers.remove(self.id);
     }
 }
+
+/// The closure a component passed to a handler hook on its latest render
+pub(crate) type LatestHandler<F> = Rc<RefCell<Box<F>>>;
+
+/// Keep the closure a handler hook got on the latest render of the component
+///
+/// Hooks register their handler once, but the closure captures the values of the render it was created in. Whatever
+/// the hook registers calls the closure through the returned slot, which is updated on every render.
+pub(crate) fn use_latest_handler<F: ?Sized + 'static>(
+    cx: &ScopeState,
+    handler: Box<F>,
+) -> LatestHandler<F> {
+    let mut handler = Some(handler);
+    let latest = cx.use_hook(|| Rc::new(RefCell::new(handler.take().unwrap())));
+
+    if let Some(handler) = handler {
+        *latest.borrow_mut() = handler;
+    }
+
+    latest.clone()
+}
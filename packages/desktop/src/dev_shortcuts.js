@@ -0,0 +1,20 @@
+// The keyboard shortcuts of browsers for zooming and the devtools, which webviews leave out
+window.addEventListener("keydown", (event) => {
+  if (!(event.ctrlKey || event.metaKey) || event.altKey) {
+    return;
+  }
+  let action = null;
+  if (event.shiftKey && event.code === "KeyI") {
+    action = "toggle_devtools";
+  } else if (event.key === "+" || event.key === "=") {
+    action = "zoom_in";
+  } else if (event.key === "-") {
+    action = "zoom_out";
+  } else if (event.key === "0") {
+    action = "reset_zoom";
+  }
+  if (action !== null) {
+    event.preventDefault();
+    window.ipc.postMessage(serializeIpcMessage("dev_shortcut", { action }));
+  }
+});
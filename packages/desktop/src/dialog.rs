@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+use rfd::{AsyncFileDialog, FileHandle};
+use wry::application::window::Window;
+
+/// A native dialog to pick files or folders, or to choose where to save a file
+///
+/// Unlike an HTML file input, the dialog gives back the real paths on the filesystem. Create one with
+/// [`DesktopContext::file_dialog`](crate::DesktopContext::file_dialog), which opens it on top of the window.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let window = use_window(cx);
+///     let picked = use_state(cx, || None);
+///
+///     cx.render(rsx! {
+///         button {
+///             onclick: move |_| {
+///                 let dialog = window.file_dialog().add_filter("Images", &["png", "jpg"]);
+///                 to_owned![picked];
+///                 cx.spawn(async move {
+///                     if let Some(path) = dialog.pick_file().await {
+///                         picked.set(Some(path));
+///                     }
+///                 });
+///             },
+///             "Open an image"
+///         }
+///     })
+/// }
+/// ```
+pub struct FileDialog {
+    dialog: AsyncFileDialog,
+}
+
+impl FileDialog {
+    pub(crate) fn new(window: &Window) -> Self {
+        Self {
+            dialog: AsyncFileDialog::new().set_parent(window),
+        }
+    }
+
+    /// Only show files with one of the given extensions, like `&["png", "jpg"]`
+    ///
+    /// Add several filters to let the user choose between them.
+    pub fn add_filter(mut self, name: &str, extensions: &[&str]) -> Self {
+        self.dialog = self.dialog.add_filter(name, extensions);
+        self
+    }
+
+    /// Open the dialog in this folder
+    pub fn set_directory(mut self, path: impl AsRef<Path>) -> Self {
+        self.dialog = self.dialog.set_directory(path);
+        self
+    }
+
+    /// Prefill the name of the file
+    pub fn set_file_name(mut self, file_name: &str) -> Self {
+        self.dialog = self.dialog.set_file_name(file_name);
+        self
+    }
+
+    /// Set the title of the dialog
+    pub fn set_title(mut self, title: &str) -> Self {
+        self.dialog = self.dialog.set_title(title);
+        self
+    }
+
+    /// Let the user pick one file. Resolves to `None` if the dialog was cancelled.
+    pub async fn pick_file(self) -> Option<PathBuf> {
+        self.dialog.pick_file().await.map(into_path)
+    }
+
+    /// Let the user pick any number of files. Resolves to `None` if the dialog was cancelled.
+    pub async fn pick_files(self) -> Option<Vec<PathBuf>> {
+        let files = self.dialog.pick_files().await?;
+        Some(files.into_iter().map(into_path).collect())
+    }
+
+    /// Let the user pick one folder. Resolves to `None` if the dialog was cancelled.
+    pub async fn pick_folder(self) -> Option<PathBuf> {
+        self.dialog.pick_folder().await.map(into_path)
+    }
+
+    /// Let the user choose where to save a file. Resolves to `None` if the dialog was cancelled.
+    ///
+    /// The dialog only returns the path: the app still has to write the file.
+    pub async fn save_file(self) -> Option<PathBuf> {
+        self.dialog.save_file().await.map(into_path)
+    }
+}
+
+fn into_path(file: FileHandle) -> PathBuf {
+    file.path().to_path_buf()
+}
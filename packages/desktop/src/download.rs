@@ -0,0 +1,236 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use dioxus_core::ScopeState;
+use wry::application::window::WindowId;
+
+use wry::application::event::Event;
+
+use crate::desktop_context::{
+    use_latest_handler, use_wry_event_handler, EventData, ProxyType, UserWindowEvent,
+};
+
+/// A download the webview is about to start, passed to the handler set with
+/// [`Config::with_download_handler`](crate::Config::with_download_handler)
+pub struct Download {
+    url: String,
+    suggested_path: PathBuf,
+}
+
+impl Download {
+    /// The url of the file
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Where the file is saved unless the handler picks another path: the downloads folder of the user, with the
+    /// name the server suggested
+    pub fn suggested_path(&self) -> &Path {
+        &self.suggested_path
+    }
+}
+
+/// What to do with a [`Download`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadAction {
+    /// Save the file at its suggested path
+    Save,
+    /// Save the file at another path
+    SaveTo(PathBuf),
+    /// Don't download the file
+    Cancel,
+}
+
+/// Something that happened to a download, sent to the handlers registered with [`use_download_event_handler`]
+///
+/// The webview only reports when downloads start and finish, so their progress is measured from the size of the
+/// file as it is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadEvent {
+    /// The file started downloading to `path`
+    Started {
+        /// The url of the file
+        url: String,
+        /// Where the file is saved
+        path: PathBuf,
+    },
+    /// More of the file was written. Sent at most a few times a second while the download runs.
+    Progress {
+        /// The url of the file
+        url: String,
+        /// Where the file is saved
+        path: PathBuf,
+        /// How many bytes were written so far
+        received_bytes: u64,
+    },
+    /// The download was cancelled before it started
+    Cancelled {
+        /// The url of the file
+        url: String,
+    },
+    /// The download finished, successfully or not
+    Finished {
+        /// The url of the file
+        url: String,
+        /// Where the file was saved, if the platform reports it
+        path: Option<PathBuf>,
+        /// `false` if the download failed
+        success: bool,
+    },
+}
+
+pub(crate) type DownloadHandler = Box<dyn FnMut(&Download) -> DownloadAction>;
+
+/// Decide where a download goes, and whether it happens at all
+///
+/// `path` is the destination the webview picked, which is replaced by the one the handler picks.
+pub(crate) fn start(
+    handler: Option<&mut DownloadHandler>,
+    url: String,
+    path: &mut PathBuf,
+) -> DownloadEvent {
+    let download = Download {
+        suggested_path: suggested_path(&url, path),
+        url,
+    };
+
+    let action = match handler {
+        Some(handler) => handler(&download),
+        None => DownloadAction::Save,
+    };
+
+    let Download {
+        url,
+        suggested_path,
+    } = download;
+    *path = match action {
+        DownloadAction::Save => suggested_path,
+        DownloadAction::SaveTo(destination) => destination,
+        DownloadAction::Cancel => return DownloadEvent::Cancelled { url },
+    };
+
+    DownloadEvent::Started {
+        url,
+        path: path.clone(),
+    }
+}
+
+/// Some webviews only suggest a file name, or nothing at all, so put the file in the downloads folder
+fn suggested_path(url: &str, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    let file_name = match path.file_name() {
+        Some(name) => name.to_string_lossy().into_owned(),
+        None => url
+            .split(['?', '#'])
+            .next()
+            .and_then(|url| url.rsplit('/').next())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("download")
+            .to_string(),
+    };
+
+    dirs::download_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(file_name)
+}
+
+// The webview reports downloads on the main thread, where the event loop runs
+thread_local! {
+    // Tells the threads watching the running downloads to stop, by url
+    static WATCHERS: RefCell<HashMap<String, Arc<AtomicBool>>> = RefCell::new(HashMap::new());
+}
+
+/// How often the size of a running download is checked
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Send [`DownloadEvent::Progress`] events for a download that started, until [`finished`] is called for its url
+pub(crate) fn watch(url: String, path: PathBuf, proxy: ProxyType, window: WindowId) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let previous =
+        WATCHERS.with(|watchers| watchers.borrow_mut().insert(url.clone(), stop.clone()));
+    if let Some(previous) = previous {
+        previous.store(true, Ordering::Relaxed);
+    }
+
+    // Chromium based webviews write to a temporary file next to the destination until the download is done
+    let mut partial = path.clone().into_os_string();
+    partial.push(".crdownload");
+    let partial = PathBuf::from(partial);
+
+    std::thread::spawn(move || {
+        let mut last = 0;
+        loop {
+            std::thread::sleep(PROGRESS_INTERVAL);
+            if stop.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let size = |path: &Path| std::fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+            let received_bytes = size(&path).max(size(&partial));
+            if received_bytes == last {
+                continue;
+            }
+            last = received_bytes;
+
+            let event = DownloadEvent::Progress {
+                url: url.clone(),
+                path: path.clone(),
+                received_bytes,
+            };
+            if proxy
+                .send_event(UserWindowEvent(EventData::Download(event), window))
+                .is_err()
+            {
+                return;
+            }
+        }
+    });
+}
+
+/// Stop reporting the progress of a download
+pub(crate) fn finished(url: &str) {
+    let stop = WATCHERS.with(|watchers| watchers.borrow_mut().remove(url));
+    if let Some(stop) = stop {
+        stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Listen to the downloads of the webview, to show them to the user
+///
+/// Use [`Config::with_download_handler`](crate::Config::with_download_handler) to choose where downloads go. Every
+/// component with a handler hears about every download, and the handler of its latest render is the one that is called.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let downloading = use_state(cx, Vec::new);
+///
+///     use_download_event_handler(cx, {
+///         to_owned![downloading];
+///         move |event| match event {
+///             DownloadEvent::Started { url, .. } => downloading.make_mut().push(url),
+///             DownloadEvent::Finished { url, .. } => downloading.make_mut().retain(|u| *u != url),
+///             DownloadEvent::Progress { .. } | DownloadEvent::Cancelled { .. } => {}
+///         }
+///     });
+///
+///     cx.render(rsx!("{downloading.len()} downloads in progress"))
+/// }
+/// ```
+pub fn use_download_event_handler(cx: &ScopeState, handler: impl FnMut(DownloadEvent) + 'static) {
+    let handler = use_latest_handler::<dyn FnMut(DownloadEvent)>(cx, Box::new(handler));
+
+    use_wry_event_handler(cx, move |event, _| {
+        if let Event::UserEvent(UserWindowEvent(EventData::Download(event), _)) = event {
+            (handler.borrow_mut())(event.clone());
+        }
+    });
+}
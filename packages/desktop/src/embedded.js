@@ -0,0 +1,71 @@
+// Report where the placeholders of the `WebView` component are, so their webviews can be placed over them
+window.dioxus = window.dioxus || {};
+(() => {
+  const reported = new Map();
+  let tracking = false;
+  let scheduled = false;
+
+  const report = () => {
+    scheduled = false;
+    const placeholders = document.querySelectorAll("[data-dioxus-webview]");
+    const seen = new Set();
+    for (const placeholder of placeholders) {
+      const id = Number(placeholder.getAttribute("data-dioxus-webview"));
+      const rect = placeholder.getBoundingClientRect();
+      const visible = rect.width > 0 && rect.height > 0 && document.visibilityState === "visible";
+      const bounds = visible
+        ? { x: rect.left, y: rect.top, width: rect.width, height: rect.height }
+        : null;
+      const key = JSON.stringify(bounds);
+      seen.add(id);
+      if (reported.get(id) !== key) {
+        reported.set(id, key);
+        window.ipc.postMessage(serializeIpcMessage("embedded_webview", { id, bounds }));
+      }
+    }
+    for (const id of reported.keys()) {
+      if (!seen.has(id)) {
+        reported.delete(id);
+      }
+    }
+  };
+
+  // Measure at most once a frame, and only after something happened that can move a placeholder
+  const schedule = () => {
+    if (!scheduled) {
+      scheduled = true;
+      requestAnimationFrame(report);
+    }
+  };
+
+  const resizes = new ResizeObserver(schedule);
+  const observePlaceholders = () => {
+    for (const placeholder of document.querySelectorAll("[data-dioxus-webview]")) {
+      resizes.observe(placeholder);
+    }
+  };
+  // Edits anywhere in the page can move a placeholder without resizing it
+  const mutations = new MutationObserver(() => {
+    observePlaceholders();
+    schedule();
+  });
+
+  // The placeholder of a new webview may not be in the page yet, and is picked up by the observers once it is
+  window.dioxus.trackWebViews = () => {
+    if (!tracking) {
+      tracking = true;
+      mutations.observe(document.documentElement, {
+        attributes: true,
+        characterData: true,
+        childList: true,
+        subtree: true,
+      });
+      resizes.observe(document.documentElement);
+      window.addEventListener("scroll", schedule, true);
+      window.addEventListener("resize", schedule);
+      document.addEventListener("visibilitychange", schedule);
+    }
+    observePlaceholders();
+    schedule();
+  };
+})();
@@ -0,0 +1,245 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use slab::Slab;
+use wry::application::dpi::{PhysicalPosition, PhysicalSize};
+use wry::application::event::{Event, WindowEvent};
+use wry::application::event_loop::EventLoopWindowTarget;
+use wry::application::window::{Window, WindowBuilder};
+use wry::webview::WebViewBuilder;
+
+use crate::desktop_context::{use_wry_event_handler, UserWindowEvent};
+use crate::use_window;
+
+/// The webviews embedded in a window with the [`WebView`] component
+#[derive(Clone, Default)]
+pub(crate) struct EmbeddedWebViews {
+    panes: Rc<RefCell<Slab<Rc<Pane>>>>,
+}
+
+struct Pane {
+    webview: wry::webview::WebView,
+    src: RefCell<String>,
+    // Where the placeholder is in the page, or `None` while it's hidden
+    bounds: Cell<Option<Bounds>>,
+}
+
+/// A rectangle in CSS pixels, relative to the top left corner of the page
+#[derive(Clone, Copy, Deserialize)]
+struct Bounds {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl EmbeddedWebViews {
+    /// Move a webview over its placeholder, after the page reported that the placeholder moved
+    pub(crate) fn layout(&self, parent: &Window, zoom: f64, params: Value) {
+        let id = match params["id"].as_u64() {
+            Some(id) => id as usize,
+            None => return,
+        };
+        let pane = match self.panes.borrow().get(id) {
+            Some(pane) => pane.clone(),
+            None => return,
+        };
+        pane.bounds
+            .set(serde_json::from_value(params["bounds"].clone()).unwrap_or(None));
+        pane.place(parent, zoom);
+    }
+}
+
+impl Pane {
+    fn place(&self, parent: &Window, zoom: f64) {
+        let window = self.webview.window();
+        let (bounds, origin) = match (self.bounds.get(), client_origin(parent)) {
+            // Minimized windows have no size
+            (Some(bounds), Ok(origin)) if parent.is_visible() && parent.inner_size().width > 0 => {
+                (bounds, origin)
+            }
+            _ => return window.set_visible(false),
+        };
+
+        let scale = parent.scale_factor() * zoom;
+        window.set_outer_position(PhysicalPosition::new(
+            origin.x + (bounds.x * scale).round() as i32,
+            origin.y + (bounds.y * scale).round() as i32,
+        ));
+        window.set_inner_size(PhysicalSize::new(
+            (bounds.width * scale).round() as u32,
+            (bounds.height * scale).round() as u32,
+        ));
+        window.set_visible(true);
+    }
+
+    fn navigate(&self, src: &str) {
+        if *self.src.borrow() != src {
+            *self.src.borrow_mut() = src.to_string();
+            let script = format!("window.location.href = {}", Value::from(src));
+            if let Err(err) = self.webview.evaluate_script(&script) {
+                log::warn!("Could not navigate the embedded webview to {src}: {err}");
+            }
+        }
+    }
+}
+
+/// Create the window of an embedded webview as a child of the window of the app
+///
+/// Child windows move, minimize and close with their parent and are only drawn above it, not above other apps.
+fn child_window(
+    parent: &Window,
+    event_loop: &EventLoopWindowTarget<UserWindowEvent>,
+) -> Result<Window, wry::Error> {
+    let builder = WindowBuilder::new()
+        .with_decorations(false)
+        .with_resizable(false)
+        .with_visible(false);
+
+    // Windows confines a child window to the client area of its parent
+    #[cfg(target_os = "windows")]
+    let builder = {
+        use windows::Win32::Foundation::HWND;
+        use wry::application::platform::windows::{WindowBuilderExtWindows, WindowExtWindows};
+        builder.with_parent_window(HWND(parent.hwnd() as _))
+    };
+
+    let window = builder.build(event_loop)?;
+
+    #[cfg(target_os = "macos")]
+    unsafe {
+        use objc::runtime::Object;
+        use objc::*;
+        use wry::application::platform::macos::WindowExtMacOS;
+        // NSWindowAbove
+        let ordered: isize = 1;
+        let parent = parent.ns_window() as *mut Object;
+        let _: () = msg_send![parent, addChildWindow: window.ns_window() ordered: ordered];
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use gtk::prelude::GtkWindowExt;
+        use wry::application::platform::unix::WindowExtUnix;
+        window
+            .gtk_window()
+            .set_transient_for(Some(parent.gtk_window()));
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    let _ = parent;
+
+    Ok(window)
+}
+
+/// Where the client area of the window of the app is, in the coordinates child windows are placed in
+fn client_origin(
+    parent: &Window,
+) -> Result<PhysicalPosition<i32>, wry::application::error::NotSupportedError> {
+    if cfg!(target_os = "windows") {
+        Ok(PhysicalPosition::new(0, 0))
+    } else {
+        parent.inner_position()
+    }
+}
+
+/// Embed external web content in the app, in a webview of its own
+///
+/// The component renders an empty `div` that takes part in the layout of the app like any other element, so size it
+/// with `class` or `style`. A second webview is placed over it and follows it as the layout changes and the window
+/// moves. The embedded page is isolated from the app: it has its own origin and can't use `window.dioxus`.
+///
+/// The embedded webview lives in a child window of the window of the app, so elements of the app can't be drawn over
+/// it.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     cx.render(rsx! {
+///         h1 { "Documentation" }
+///         WebView { src: "https://dioxuslabs.com", style: "width: 100%; height: 600px" }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn WebView(cx: Scope<WebViewProps>) -> Element {
+    let desktop = use_window(cx);
+
+    let pane = cx.use_hook(|| {
+        let webview = child_window(desktop.webview.window(), &desktop.event_loop)
+            .and_then(|window| WebViewBuilder::new(window)?.with_url(&cx.props.src))
+            .and_then(|builder| builder.build());
+
+        let webview = match webview {
+            Ok(webview) => webview,
+            Err(err) => {
+                log::error!("Could not embed a webview for {}: {err}", cx.props.src);
+                return None;
+            }
+        };
+
+        let pane = Rc::new(Pane {
+            webview,
+            src: RefCell::new(cx.props.src.clone()),
+            bounds: Cell::new(None),
+        });
+        let panes = desktop.embedded_webviews.panes.clone();
+        let id = panes.borrow_mut().insert(pane.clone());
+        cx.on_drop(move || {
+            panes.borrow_mut().try_remove(id);
+        });
+
+        _ = desktop
+            .webview
+            .evaluate_script("window.dioxus.trackWebViews()");
+
+        Some((id, pane))
+    });
+
+    // Keep the embedded webview over its placeholder as the window of the app moves
+    use_wry_event_handler(cx, {
+        let desktop = desktop.clone();
+        let pane = pane.as_ref().map(|(_, pane)| pane.clone());
+        move |event, _| {
+            let (pane, parent) = match (&pane, event) {
+                (
+                    Some(pane),
+                    Event::WindowEvent {
+                        window_id, event, ..
+                    },
+                ) if *window_id == desktop.id() => (pane, event),
+                _ => return,
+            };
+            if let WindowEvent::Moved(_) | WindowEvent::Resized(_) = parent {
+                pane.place(desktop.webview.window(), desktop.zoom_level());
+            }
+        }
+    });
+
+    let (id, pane) = pane.as_ref()?;
+    pane.navigate(&cx.props.src);
+
+    let class = cx.props.class.as_deref().unwrap_or_default();
+    let style = cx.props.style.as_deref().unwrap_or_default();
+    cx.render(rsx! {
+        div { "data-dioxus-webview": "{id}", class: "{class}", style: "{style}" }
+    })
+}
+
+/// The props of the [`WebView`] component
+#[derive(Props, PartialEq)]
+pub struct WebViewProps {
+    /// The url of the page to show
+    #[props(into)]
+    src: String,
+    /// The class of the placeholder element
+    #[props(into)]
+    class: Option<String>,
+    /// The style of the placeholder element, for example to size it
+    #[props(into)]
+    style: Option<String>,
+}
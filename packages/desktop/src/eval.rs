@@ -1,36 +1,103 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::use_window;
 use dioxus_core::ScopeState;
-use serde::de::Error;
+use futures_channel::oneshot;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use slab::Slab;
 use std::future::Future;
 use std::future::IntoFuture;
 use std::pin::Pin;
 
 /// A future that resolves to the result of a JavaScript evaluation.
+///
+/// If the script returns a promise, the result is the value the promise resolves to.
 pub struct EvalResult {
-    pub(crate) broadcast: tokio::sync::broadcast::Sender<serde_json::Value>,
+    receiver: oneshot::Receiver<Result<Value, EvalError>>,
 }
 
 impl EvalResult {
-    pub(crate) fn new(sender: tokio::sync::broadcast::Sender<serde_json::Value>) -> Self {
-        Self { broadcast: sender }
+    /// Wait for the result and deserialize it into `T`
+    ///
+    /// ```rust, ignore
+    /// let width: f64 = desktop.eval("return window.innerWidth").into_typed().await?;
+    /// ```
+    pub fn into_typed<T: DeserializeOwned>(
+        self,
+    ) -> impl Future<Output = Result<T, EvalError>> + 'static {
+        let result = self.into_future();
+        async move { serde_json::from_value(result.await?).map_err(EvalError::Deserialize) }
     }
 }
 
 impl IntoFuture for EvalResult {
-    type Output = Result<serde_json::Value, serde_json::Error>;
+    type Output = Result<Value, EvalError>;
 
-    type IntoFuture = Pin<Box<dyn Future<Output = Result<serde_json::Value, serde_json::Error>>>>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Value, EvalError>>>>;
 
     fn into_future(self) -> Self::IntoFuture {
         Box::pin(async move {
-            let mut reciever = self.broadcast.subscribe();
-            match reciever.recv().await {
-                Ok(result) => Ok(result),
-                Err(_) => Err(serde_json::Error::custom("No result returned")),
+            match self.receiver.await {
+                Ok(result) => result,
+                Err(_) => Err(EvalError::NoResult),
             }
-        }) as Pin<Box<dyn Future<Output = Result<serde_json::Value, serde_json::Error>>>>
+        }) as Pin<Box<dyn Future<Output = Result<Value, EvalError>>>>
+    }
+}
+
+/// Why a JavaScript evaluation didn't produce a value
+#[derive(Debug, thiserror::Error)]
+pub enum EvalError {
+    /// The script threw, or the promise it returned rejected, with this message
+    #[error("The script failed: {0}")]
+    Script(String),
+
+    /// The value the script returned doesn't deserialize into the type that was asked for
+    #[error("Could not deserialize the result: {0}")]
+    Deserialize(#[source] serde_json::Error),
+
+    /// The webview never sent a result back, because the script couldn't be evaluated or the window closed
+    #[error("No result returned")]
+    NoResult,
+}
+
+/// The evaluations of a window waiting for their result, so every result reaches the eval that asked for it
+#[derive(Clone, Default)]
+pub(crate) struct PendingEvals {
+    senders: Rc<RefCell<Slab<oneshot::Sender<Result<Value, EvalError>>>>>,
+}
+
+impl PendingEvals {
+    /// Wait for a result, returning the id the script has to send it back with
+    pub(crate) fn insert(&self) -> (usize, EvalResult) {
+        let (sender, receiver) = oneshot::channel();
+        let id = self.senders.borrow_mut().insert(sender);
+        (id, EvalResult { receiver })
+    }
+
+    /// Give up on a result, which fails the eval
+    pub(crate) fn cancel(&self, id: usize) {
+        self.senders.borrow_mut().try_remove(id);
+    }
+
+    /// Hand the result sent back by the webview to its eval. The script sends either the value as `ok`, or the error
+    /// it failed with as `err`.
+    pub(crate) fn resolve(&self, params: Value) {
+        let id = match params["id"].as_u64() {
+            Some(id) => id as usize,
+            None => return,
+        };
+        if let Some(sender) = self.senders.borrow_mut().try_remove(id) {
+            let result = match &params["err"] {
+                Value::Null => Ok(params["ok"].clone()),
+                err => Err(EvalError::Script(
+                    err.as_str().map_or_else(|| err.to_string(), str::to_string),
+                )),
+            };
+            let _ = sender.send(result);
+        }
     }
 }
 
@@ -43,3 +110,60 @@ pub fn use_eval(cx: &ScopeState) -> &Rc<dyn Fn(String) -> EvalResult> {
         Rc::new(move |script: String| desktop.eval(&script)) as Rc<dyn Fn(String) -> EvalResult>
     })
 }
+
+#[test]
+fn results_reach_their_eval() {
+    use futures_util::FutureExt;
+    use serde_json::json;
+
+    let evals = PendingEvals::default();
+    let (first, first_result) = evals.insert();
+    let (second, second_result) = evals.insert();
+    assert_ne!(first, second);
+
+    evals.resolve(json!({ "id": second, "ok": [1, 2] }));
+    evals.resolve(json!({ "id": first, "ok": "first" }));
+    assert_eq!(
+        first_result.into_future().now_or_never().unwrap().unwrap(),
+        json!("first")
+    );
+    let second: Vec<u32> = second_result.into_typed().now_or_never().unwrap().unwrap();
+    assert_eq!(second, [1, 2]);
+}
+
+#[test]
+fn failed_evals_resolve_to_errors() {
+    use futures_util::FutureExt;
+    use serde_json::json;
+
+    let evals = PendingEvals::default();
+
+    let (id, thrown) = evals.insert();
+    evals.resolve(json!({ "id": id, "err": "ReferenceError: x is not defined" }));
+    assert!(matches!(
+        thrown.into_future().now_or_never().unwrap(),
+        Err(EvalError::Script(message)) if message == "ReferenceError: x is not defined"
+    ));
+
+    let (id, canceled) = evals.insert();
+    evals.cancel(id);
+    assert!(matches!(
+        canceled.into_future().now_or_never().unwrap(),
+        Err(EvalError::NoResult)
+    ));
+
+    let (id, mistyped) = evals.insert();
+    evals.resolve(json!({ "id": id, "ok": "not a number" }));
+    assert!(matches!(
+        mistyped.into_typed::<f64>().now_or_never().unwrap(),
+        Err(EvalError::Deserialize(_))
+    ));
+
+    // a script that returns nothing resolves to null
+    let (id, empty) = evals.insert();
+    evals.resolve(json!({ "id": id }));
+    assert_eq!(
+        empty.into_future().now_or_never().unwrap().unwrap(),
+        Value::Null
+    );
+}
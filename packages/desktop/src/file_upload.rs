@@ -0,0 +1,185 @@
+use dioxus_core::{ElementId, VirtualDom};
+use dioxus_html::{FileEngine, FormData};
+use std::path::PathBuf;
+use std::{rc::Rc, sync::Arc};
+use wry::webview::FileDropEvent;
+
+/// The files wry reported being dragged over or dropped on a window
+///
+/// The webview can't see the paths of these files, so they are attached to its drag events.
+pub(crate) enum FileDropState {
+    /// Nothing is being dragged over the window
+    Idle,
+
+    /// Files are being dragged over the window
+    Hovering(Vec<PathBuf>),
+
+    /// Files were dropped on the window, waiting for the `drop` event of the webview
+    Dropped(Vec<PathBuf>),
+
+    /// The drag left the window or was cancelled, waiting for the `dragleave` event of the webview
+    Left(Vec<PathBuf>),
+}
+
+impl Default for FileDropState {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
+
+impl FileDropState {
+    pub fn update(&mut self, event: &FileDropEvent) {
+        *self = match (std::mem::take(self), event) {
+            (_, FileDropEvent::Hovered(paths)) => Self::Hovering(paths.clone()),
+            (_, FileDropEvent::Dropped(paths)) => Self::Dropped(paths.clone()),
+            (Self::Hovering(paths), FileDropEvent::Cancelled) => Self::Left(paths),
+            (_, FileDropEvent::Cancelled) => Self::Idle,
+            (state, _) => state,
+        };
+    }
+
+    /// The files to attach to a drag event of the webview
+    ///
+    /// The drop and the end of a drag take their files, so they are only attached once. The webview also sends
+    /// `dragleave` when the drag moves between elements, which gets the hovered files.
+    pub fn files_for(&mut self, event: &str) -> Vec<PathBuf> {
+        match (event, &*self) {
+            ("dragenter" | "dragover" | "dragleave", Self::Hovering(paths)) => paths.clone(),
+            ("drop", Self::Dropped(_)) | ("dragleave", Self::Left(_)) => {
+                match std::mem::take(self) {
+                    Self::Dropped(paths) | Self::Left(paths) => paths,
+                    _ => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A [`FileEngine`] that reads files from the native filesystem
+///
+/// The names it returns are the full paths of the files
+pub(crate) struct NativeFileEngine {
+    files: Vec<PathBuf>,
+}
+
+impl NativeFileEngine {
+    pub fn new(files: Vec<PathBuf>) -> Self {
+        Self { files }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl FileEngine for NativeFileEngine {
+    fn files(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .filter_map(|f| Some(f.to_str()?.to_string()))
+            .collect()
+    }
+
+    async fn read_file(&self, file: &str) -> Option<Vec<u8>> {
+        std::fs::read(file).ok()
+    }
+
+    async fn read_file_to_string(&self, file: &str) -> Option<String> {
+        std::fs::read_to_string(file).ok()
+    }
+}
+
+/// A file input that was clicked. The interpreter opens a native dialog instead of the webview's, so the app gets the
+/// paths of the files and reads them lazily.
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+#[derive(serde::Deserialize)]
+pub(crate) struct FileDialogRequest {
+    id: usize,
+    #[serde(default)]
+    accept: Option<String>,
+    #[serde(default)]
+    multiple: bool,
+    #[serde(default)]
+    directory: bool,
+}
+
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+impl FileDialogRequest {
+    /// Open the dialog on top of the window. The files picked are sent back to the window as
+    /// [`EventData::FilesPicked`](crate::desktop_context::EventData::FilesPicked).
+    pub fn open(
+        self,
+        dom: &VirtualDom,
+        window: &wry::application::window::Window,
+        proxy: crate::desktop_context::ProxyType,
+    ) {
+        use crate::desktop_context::{EventData, UserWindowEvent};
+
+        let mut dialog = crate::FileDialog::new(window);
+
+        // only file extensions can be filtered on, not mime types
+        let accept = self.accept.unwrap_or_default();
+        let extensions: Vec<_> = accept
+            .split(',')
+            .filter_map(|accepted| accepted.trim().strip_prefix('.'))
+            .collect();
+        if !extensions.is_empty() {
+            dialog = dialog.add_filter(&accept, &extensions);
+        }
+
+        let window_id = window.id();
+        let element = ElementId(self.id);
+        let (multiple, directory) = (self.multiple, self.directory);
+        dom.base_scope().spawn(async move {
+            let files = if directory {
+                dialog.pick_folder().await.map(|folder| files_in(&folder))
+            } else if multiple {
+                dialog.pick_files().await
+            } else {
+                dialog.pick_file().await.map(|file| vec![file])
+            };
+
+            if let Some(files) = files {
+                let _ = proxy.send_event(UserWindowEvent(
+                    EventData::FilesPicked { element, files },
+                    window_id,
+                ));
+            }
+        });
+    }
+}
+
+/// Fire the events of a file input whose dialog picked files, like the webview does
+pub(crate) fn dispatch_files_picked(dom: &mut VirtualDom, element: ElementId, files: Vec<PathBuf>) {
+    let value = files
+        .first()
+        .and_then(|file| file.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let files: Arc<dyn FileEngine> = Arc::new(NativeFileEngine::new(files));
+
+    for name in ["input", "change"] {
+        let data = FormData {
+            value: value.clone(),
+            values: Default::default(),
+            files: Some(files.clone()),
+        };
+        dom.handle_event(name, Rc::new(data), element, true);
+    }
+}
+
+// a folder input gives every file in the folder, like browsers do
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+fn files_in(folder: &std::path::Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut folders = vec![folder.to_path_buf()];
+    while let Some(folder) = folders.pop() {
+        for entry in std::fs::read_dir(folder).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                folders.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
@@ -0,0 +1,39 @@
+use dioxus_hooks::{EventForwarder, ListenerBackend, ListenerTarget};
+use std::rc::Rc;
+use wry::webview::WebView;
+
+/// Attaches global listeners inside the webview
+///
+/// The interpreter sends the events back as `global_event` IPC messages, which the event loop dispatches to the
+/// [`GlobalListeners`](dioxus_hooks::GlobalListeners) of the window.
+pub(crate) struct WebviewListeners {
+    webview: Rc<WebView>,
+}
+
+impl WebviewListeners {
+    pub fn new(webview: Rc<WebView>) -> Self {
+        Self { webview }
+    }
+
+    fn call(&self, method: &str, target: ListenerTarget, event: &str) {
+        let event = serde_json::to_string(event).unwrap();
+        let script = format!(
+            "window.interpreter.{method}({:?}, {event})",
+            target.as_str()
+        );
+
+        if let Err(err) = self.webview.evaluate_script(&script) {
+            log::warn!("Could not update global listeners: {err}");
+        }
+    }
+}
+
+impl ListenerBackend for WebviewListeners {
+    fn listen(&self, target: ListenerTarget, event: &str, _: EventForwarder) {
+        self.call("AddGlobalListener", target, event)
+    }
+
+    fn unlisten(&self, target: ListenerTarget, event: &str) {
+        self.call("RemoveGlobalListener", target, event)
+    }
+}
@@ -0,0 +1,23 @@
+// `window.dioxus.invoke` calls the Rust functions registered with `use_invoke_handler`
+window.dioxus = window.dioxus || {};
+(() => {
+  let nextId = 0;
+  const pending = new Map();
+
+  window.dioxus.invoke = (name, args) =>
+    new Promise((resolve, reject) => {
+      const id = nextId++;
+      pending.set(id, { resolve, reject });
+      window.ipc.postMessage(
+        serializeIpcMessage("invoke", { id, name, args: args === undefined ? null : args })
+      );
+    });
+
+  window.dioxus.settleInvoke = (id, ok, value) => {
+    const call = pending.get(id);
+    if (call) {
+      pending.delete(id);
+      ok ? call.resolve(value) : call.reject(new Error(value));
+    }
+  };
+})();
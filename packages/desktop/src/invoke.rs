@@ -0,0 +1,173 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use dioxus_core::ScopeState;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use wry::webview::WebView;
+
+use crate::desktop_context::use_latest_handler;
+use crate::use_window;
+
+type ErasedHandler = Rc<RefCell<dyn FnMut(Value) -> Result<Value, String>>>;
+
+/// The Rust functions a window exposes to its JavaScript through `window.dioxus.invoke`
+#[derive(Clone, Default)]
+pub(crate) struct InvokeHandlers {
+    handlers: Rc<RefCell<HashMap<String, ErasedHandler>>>,
+}
+
+impl InvokeHandlers {
+    fn insert(&self, name: &str, handler: ErasedHandler) {
+        let replaced = self.handlers.borrow_mut().insert(name.to_string(), handler);
+        if replaced.is_some() {
+            log::warn!(
+                "The invoke handler {name:?} was registered twice, the first one is replaced"
+            );
+        }
+    }
+
+    fn remove(&self, name: &str, handler: &ErasedHandler) {
+        let mut handlers = self.handlers.borrow_mut();
+        // Only remove the handler if it wasn't replaced in the meantime
+        if matches!(handlers.get(name), Some(current) if Rc::ptr_eq(current, handler)) {
+            handlers.remove(name);
+        }
+    }
+
+    /// Call the handler a script asked for, and settle the promise the script is waiting on
+    pub(crate) fn invoke(&self, webview: &WebView, params: Value) {
+        if let Some((name, script)) = self.call(params) {
+            if let Err(err) = webview.evaluate_script(&script) {
+                log::warn!("Could not send the result of {name:?} to the webview: {err}");
+            }
+        }
+    }
+
+    /// Call the handler a script asked for, returning its name and the script that settles the promise of the call
+    fn call(&self, mut params: Value) -> Option<(String, String)> {
+        let id = params["id"].as_u64()?;
+        let name = params["name"].as_str().unwrap_or_default().to_string();
+        let args = params["args"].take();
+
+        let handler = self.handlers.borrow().get(&name).cloned();
+        let result = match handler {
+            Some(handler) => (handler.borrow_mut())(args),
+            None => Err(format!("There is no invoke handler named {name:?}")),
+        };
+
+        let (ok, value) = match result {
+            Ok(value) => (true, value),
+            Err(err) => (false, Value::String(err)),
+        };
+        let script = format!("window.dioxus.settleInvoke({id}, {ok}, {value})");
+        Some((name, script))
+    }
+}
+
+/// Erase the types of a handler, which takes and returns JSON once erased
+fn erase<A, R, E>(mut handler: impl FnMut(A) -> Result<R, E> + 'static) -> ErasedHandler
+where
+    A: DeserializeOwned + 'static,
+    R: Serialize + 'static,
+    E: Display + 'static,
+{
+    Rc::new(RefCell::new(move |args: Value| {
+        let args =
+            serde_json::from_value(args).map_err(|err| format!("Invalid arguments: {err}"))?;
+        let result = handler(args).map_err(|err| err.to_string())?;
+        serde_json::to_value(result).map_err(|err| err.to_string())
+    }))
+}
+
+/// Expose a Rust function to the JavaScript of the window, which calls it with `window.dioxus.invoke(name, args)`
+///
+/// The arguments are deserialized from the value passed to `invoke`, and the promise `invoke` returns resolves to the
+/// serialized return value, or rejects with the error message. A call runs the closure of the component's latest
+/// render. The name is free again once the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// #[derive(Deserialize)]
+/// struct Greet {
+///     name: String,
+/// }
+///
+/// fn app(cx: Scope) -> Element {
+///     use_invoke_handler(cx, "greet", |args: Greet| {
+///         Ok::<_, String>(format!("Hello, {}!", args.name))
+///     });
+///
+///     cx.render(rsx! {
+///         script { "window.dioxus.invoke('greet', {{ name: 'Dioxus' }}).then(console.log)" }
+///     })
+/// }
+/// ```
+pub fn use_invoke_handler<A, R, E>(
+    cx: &ScopeState,
+    name: &str,
+    handler: impl FnMut(A) -> Result<R, E> + 'static,
+) where
+    A: DeserializeOwned + 'static,
+    R: Serialize + 'static,
+    E: Display + 'static,
+{
+    let desktop = use_window(cx);
+    let handler = use_latest_handler::<dyn FnMut(A) -> Result<R, E>>(cx, Box::new(handler));
+
+    cx.use_hook(|| {
+        let erased = erase(move |args: A| (handler.borrow_mut())(args));
+
+        let handlers = desktop.invoke_handlers.clone();
+        let name = name.to_string();
+        handlers.insert(&name, erased.clone());
+        cx.on_drop(move || handlers.remove(&name, &erased));
+    });
+}
+
+#[test]
+fn invoke_calls_the_handler_by_name() {
+    use serde_json::json;
+
+    let handlers = InvokeHandlers::default();
+    handlers.insert("add", erase(|(a, b): (i32, i32)| Ok::<_, String>(a + b)));
+    handlers.insert("fail", erase(|_: Value| Err::<Value, _>("it went wrong")));
+
+    let call = |params| handlers.call(params).map(|(_, script)| script);
+    assert_eq!(
+        call(json!({ "id": 1, "name": "add", "args": [1, 2] })).unwrap(),
+        "window.dioxus.settleInvoke(1, true, 3)"
+    );
+    assert_eq!(
+        call(json!({ "id": 2, "name": "fail", "args": null })).unwrap(),
+        r#"window.dioxus.settleInvoke(2, false, "it went wrong")"#
+    );
+    assert_eq!(
+        call(json!({ "id": 3, "name": "missing", "args": null })).unwrap(),
+        r#"window.dioxus.settleInvoke(3, false, "There is no invoke handler named \"missing\"")"#
+    );
+    assert!(call(json!({ "id": 4, "name": "add", "args": "one" }))
+        .unwrap()
+        .starts_with(r#"window.dioxus.settleInvoke(4, false, "Invalid arguments"#));
+    // calls without an id can't be settled
+    assert_eq!(call(json!({ "name": "add", "args": [1, 2] })), None);
+}
+
+#[test]
+fn only_the_registered_handler_is_removed() {
+    let handlers = InvokeHandlers::default();
+    let first = erase(|_: Value| Ok::<_, String>(1));
+    let second = erase(|_: Value| Ok::<_, String>(2));
+
+    handlers.insert("get", first.clone());
+    handlers.insert("get", second.clone());
+    // the handler of a component that unmounted after another one replaced it
+    handlers.remove("get", &first);
+    assert!(handlers.handlers.borrow().contains_key("get"));
+
+    handlers.remove("get", &second);
+    assert!(handlers.handlers.borrow().is_empty());
+}
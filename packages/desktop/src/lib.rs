@@ -3,29 +3,93 @@
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 #![deny(missing_docs)]
 
+mod app;
+mod app_proxy;
 mod cfg;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod clipboard;
+mod context_menu;
+mod deep_link;
 mod desktop_context;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod dialog;
+mod download;
+mod embedded;
 mod escape;
 mod eval;
 mod events;
+mod file_upload;
+mod global_listeners;
+mod invoke;
+mod media_queries;
+mod menu;
+#[cfg(any(target_os = "ios", target_os = "android"))]
+mod mobile;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod notification;
+mod print;
 mod protocol;
+mod proxy;
 mod shortcut;
+mod splash;
+mod testing;
+#[cfg(feature = "tray")]
+mod tray;
 mod waker;
 mod webview;
+mod window_effect;
+mod window_state;
 
+use app::AppInfo;
+pub use app::{AppDir, AppMetadata, UserDir};
+pub use app_proxy::{use_app_event_handler, AppExited, AppProxy};
 pub use cfg::Config;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use clipboard::ClipboardImage;
+pub use context_menu::{use_context_menu, ContextMenu};
+pub use deep_link::use_deep_link_handler;
 pub use desktop_context::{
-    use_window, use_wry_event_handler, DesktopContext, WryEventHandler, WryEventHandlerId,
+    use_window, use_wry_event_handler, DesktopContext, WindowHandle, WryEventHandler,
+    WryEventHandlerId,
 };
-use desktop_context::{EventData, UserWindowEvent, WebviewQueue, WindowEventHandlers};
+use desktop_context::{
+    EventData, UserWindowEvent, WebviewQueue, WindowEventHandlers, WindowLifecycle,
+};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use dialog::FileDialog;
 use dioxus_core::*;
-use dioxus_html::HtmlEvent;
-pub use eval::{use_eval, EvalResult};
+use dioxus_hooks::{FileStorage, GlobalListeners, ListenerTarget, MediaQueries, PersistentStorage};
+use dioxus_html::{GlobalEvent, HtmlEvent};
+pub use download::{use_download_event_handler, Download, DownloadAction, DownloadEvent};
+pub use embedded::{WebView, WebViewProps};
+pub use eval::{use_eval, EvalError, EvalResult};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+use file_upload::FileDialogRequest;
+use file_upload::{FileDropState, NativeFileEngine};
 use futures_util::{pin_mut, FutureExt};
+use global_listeners::WebviewListeners;
+pub use invoke::use_invoke_handler;
+use media_queries::WebviewMediaQueries;
+pub use menu::{use_menu_event_handler, AppMenu, MenuAction, MenuItems, NativeMenuItem};
+#[cfg(any(target_os = "ios", target_os = "android"))]
+use mobile::MobileViewport;
+#[cfg(any(target_os = "ios", target_os = "android"))]
+pub use mobile::{
+    use_app_lifecycle, use_safe_area_insets, use_soft_keyboard, AppLifecycle, SafeAreaInsets,
+    SoftKeyboard,
+};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use notification::{Notification, NotificationError};
+pub use print::{PageSize, PdfError, PrintOptions};
+pub use protocol::Asset;
 use shortcut::ShortcutRegistry;
 pub use shortcut::{use_global_shortcut, ShortcutHandle, ShortcutId, ShortcutRegistryError};
+use splash::DeferredShow;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::task::Waker;
 pub use tao::dpi::{LogicalSize, PhysicalSize};
 use tao::event_loop::{EventLoopProxy, EventLoopWindowTarget};
@@ -34,6 +98,10 @@ use tao::{
     event::{Event, StartCause, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
 };
+pub use testing::{launch_test, TestDriver};
+#[cfg(feature = "tray")]
+pub use tray::{use_tray_event_handler, TrayConfig, TrayEvent, TrayHandle, TrayMenu};
+pub use window_effect::{Vibrancy, WindowEffect};
 pub use wry;
 pub use wry::application as tao;
 use wry::application::window::WindowId;
@@ -106,7 +174,16 @@ pub fn launch_cfg(root: Component, config_builder: Config) {
 ///     })
 /// }
 /// ```
-pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config) {
+pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, mut cfg: Config) {
+    let deep_links = match cfg.deep_link_scheme.take() {
+        Some(scheme) => match deep_link::claim(&scheme, &cfg.data_directory()) {
+            Some(listener) => Some(listener),
+            // The instance that is already running took the links, so this one has nothing left to do
+            None => return,
+        },
+        None => None,
+    };
+
     let event_loop = EventLoop::<UserWindowEvent>::with_user_event();
 
     let proxy = event_loop.create_proxy();
@@ -155,6 +232,11 @@ pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config)
         shortcut_manager.clone(),
     ));
 
+    if let Some(deep_links) = deep_links {
+        let id = queue.borrow()[0].webview.window().id();
+        deep_links.spawn(proxy.clone(), id);
+    }
+
     event_loop.run(move |window_event, event_loop, control_flow| {
         *control_flow = ControlFlow::Wait;
 
@@ -213,6 +295,33 @@ pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config)
                     }
                 }
 
+                #[cfg(all(unix, not(target_os = "macos")))]
+                EventData::NotificationClosed { id, clicked } => {
+                    notification::closed(id, clicked);
+                }
+
+                EventData::DeepLink(urls) => {
+                    if let Some(view) = webviews.get(&event.1) {
+                        let window = view.webview.window();
+                        window.set_visible(true);
+                        window.set_minimized(false);
+                        window.set_focus();
+                    }
+                    deep_link::deliver(urls);
+                }
+
+                // The download event handlers get downloads through the wry event handlers
+                EventData::Download(_) => {}
+
+                EventData::AppEvent(app_event) => app_proxy::dispatch(app_event, event.1),
+
+                EventData::FilesPicked { element, files } => {
+                    if let Some(view) = webviews.get_mut(&event.1) {
+                        file_upload::dispatch_files_picked(&mut view.dom, element, files);
+                        send_edits(view.dom.render_immediate(), &view.webview);
+                    }
+                }
+
                 EventData::Poll => {
                     if let Some(view) = webviews.get_mut(&event.1) {
                         poll_vdom(view);
@@ -227,8 +336,66 @@ pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config)
 
                     let view = webviews.get_mut(&event.1).unwrap();
 
+                    // The webview can't see the paths of dragged files, so attach the ones wry reported
+                    let data = match evt.data {
+                        dioxus_html::EventData::Drag(mut data) => {
+                            let files = view.file_drop.borrow_mut().files_for(&evt.name);
+                            if !files.is_empty() {
+                                data.files = Some(Arc::new(NativeFileEngine::new(files)));
+                            }
+                            dioxus_html::EventData::Drag(data)
+                        }
+                        data => data,
+                    };
+
+                    // the webview already copied the selection, so the contents set by the handlers replace it
+                    let clipboard = match &data {
+                        dioxus_html::EventData::Clipboard(data) => Some(data.clone()),
+                        _ => None,
+                    };
+
                     view.dom
-                        .handle_event(&evt.name, evt.data.into_any(), evt.element, evt.bubbles);
+                        .handle_event(&evt.name, data.into_any(), evt.element, evt.bubbles);
+
+                    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                    if let Some(data) = clipboard {
+                        clipboard::write_event_data(&data);
+                    }
+                    #[cfg(any(target_os = "ios", target_os = "android"))]
+                    let _ = clipboard;
+
+                    send_edits(view.dom.render_immediate(), &view.webview);
+                }
+
+                EventData::Ipc(msg) if msg.method() == "global_event" => {
+                    let params = msg.params();
+                    let target = match params["target"].as_str() {
+                        Some("window") => ListenerTarget::Window,
+                        Some("document") => ListenerTarget::Document,
+                        _ => return,
+                    };
+                    let evt = match serde_json::from_value::<GlobalEvent>(params) {
+                        Ok(value) => value,
+                        Err(_) => return,
+                    };
+
+                    let view = webviews.get_mut(&event.1).unwrap();
+                    let name = evt.name.clone();
+                    view.global_listeners
+                        .dispatch(target, &name, evt.into_any());
+
+                    send_edits(view.dom.render_immediate(), &view.webview);
+                }
+
+                EventData::Ipc(msg) if msg.method() == "media_query" => {
+                    let params = msg.params();
+                    let (id, matches) = match (params["id"].as_u64(), params["matches"].as_bool()) {
+                        (Some(id), Some(matches)) => (id as usize, matches),
+                        _ => return,
+                    };
+
+                    let view = webviews.get_mut(&event.1).unwrap();
+                    view.media_queries.changed(id, matches);
 
                     send_edits(view.dom.render_immediate(), &view.webview);
                 }
@@ -236,21 +403,114 @@ pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config)
                 EventData::Ipc(msg) if msg.method() == "initialize" => {
                     let view = webviews.get_mut(&event.1).unwrap();
                     send_edits(view.dom.rebuild(), &view.webview);
+                    if view.deferred_show.is_some() {
+                        // Scripts run in order, so the webview answers once it applied the edits
+                        _ = view.webview.evaluate_script(
+                            r#"window.ipc.postMessage(serializeIpcMessage("first_render"))"#,
+                        );
+                    }
+                }
+
+                EventData::Ipc(msg) if msg.method() == "first_render" => {
+                    let view = webviews.get_mut(&event.1).unwrap();
+                    // Dropping the deferred show closes the splash screen
+                    if view.deferred_show.take().is_some() {
+                        let window = view.webview.window();
+                        window.set_visible(true);
+                        window.set_focus();
+                    }
                 }
 
-                // When the webview chirps back with the result of the eval, we send it to the active receiver
-                //
-                // This currently doesn't perform any targeting to the callsite, so if you eval multiple times at once,
-                // you might the wrong result. This should be fixed
+                // When the webview chirps back with the result of the eval, we send it to the eval that asked for it
                 EventData::Ipc(msg) if msg.method() == "eval_result" => {
                     webviews[&event.1]
                         .dom
                         .base_scope()
                         .consume_context::<DesktopContext>()
                         .unwrap()
-                        .eval
-                        .send(msg.params())
+                        .evals
+                        .resolve(msg.params());
+                }
+
+                EventData::Ipc(msg) if msg.method() == "invoke" => {
+                    let view = webviews.get_mut(&event.1).unwrap();
+                    let desktop = view
+                        .dom
+                        .base_scope()
+                        .consume_context::<DesktopContext>()
+                        .unwrap();
+                    desktop.invoke_handlers.invoke(&view.webview, msg.params());
+
+                    send_edits(view.dom.render_immediate(), &view.webview);
+                }
+
+                EventData::Ipc(msg) if msg.method() == "embedded_webview" => {
+                    let desktop = webviews[&event.1]
+                        .dom
+                        .base_scope()
+                        .consume_context::<DesktopContext>()
+                        .unwrap();
+                    desktop.embedded_webviews.layout(
+                        desktop.webview.window(),
+                        desktop.zoom_level(),
+                        msg.params(),
+                    );
+                }
+
+                // Components that use the viewport are scheduled to render, which the waker picks up
+                #[cfg(any(target_os = "ios", target_os = "android"))]
+                EventData::Ipc(msg) if msg.method() == "viewport" => {
+                    webviews[&event.1].viewport.update(msg.params());
+                }
+
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                EventData::Ipc(msg) if msg.method() == "file_dialog" => {
+                    let request = match serde_json::from_value::<FileDialogRequest>(msg.params()) {
+                        Ok(request) => request,
+                        Err(_) => return,
+                    };
+                    let view = &webviews[&event.1];
+                    request.open(&view.dom, view.webview.window(), proxy.clone());
+                }
+
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                EventData::Ipc(msg) if msg.method() == "notification" => {
+                    let view = &webviews[&event.1];
+                    notification::show_from_webview(msg.params(), &view.webview, &proxy, event.1);
+                }
+
+                EventData::Ipc(msg) if msg.method() == "drag_window" => {
+                    let window = webviews[&event.1].webview.window();
+                    if window.fullscreen().is_none() {
+                        let _ = window.drag_window();
+                    }
+                }
+
+                EventData::Ipc(msg) if msg.method() == "window_control" => {
+                    let window = webviews[&event.1].webview.window();
+                    match msg.params()["action"].as_str() {
+                        Some("minimize") => window.set_minimized(true),
+                        Some("maximize") => window.set_maximized(!window.is_maximized()),
+                        Some("close") => {
+                            _ = proxy.send_event(UserWindowEvent(EventData::CloseWindow, event.1));
+                        }
+                        action => log::warn!("Unknown window control {action:?}"),
+                    }
+                }
+
+                EventData::Ipc(msg) if msg.method() == "dev_shortcut" => {
+                    let desktop = webviews[&event.1]
+                        .dom
+                        .base_scope()
+                        .consume_context::<DesktopContext>()
                         .unwrap();
+                    match msg.params()["action"].as_str() {
+                        Some("toggle_devtools") => desktop.toggle_devtools(),
+                        Some("zoom_in") => desktop.zoom_in(),
+                        Some("zoom_out") => desktop.zoom_out(),
+                        Some("reset_zoom") => desktop.reset_zoom(),
+                        action => log::warn!("Unknown dev shortcut {action:?}"),
+                    }
                 }
 
                 EventData::Ipc(msg) if msg.method() == "browser_open" => {
@@ -267,6 +527,18 @@ pub fn launch_with_props<P: 'static>(root: Component<P>, props: P, cfg: Config)
                 _ => {}
             },
             Event::GlobalShortcutEvent(id) => shortcut_manager.call_handlers(id),
+            #[cfg(target_os = "macos")]
+            Event::Opened { urls } => deep_link::deliver(
+                urls.iter()
+                    .filter(|url| url.scheme() != "file")
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            Event::LoopDestroyed => {
+                // The event loop exits the process without dropping anything, so close the windows that are left
+                webviews.clear();
+                shortcut_manager.unregister_all();
+            }
             _ => {}
         }
     })
@@ -281,7 +553,55 @@ fn create_new_window(
     event_handlers: &WindowEventHandlers,
     shortcut_manager: ShortcutRegistry,
 ) -> WebviewHandler {
-    let webview = webview::build(&mut cfg, event_loop, proxy.clone());
+    let file_drop = Rc::new(RefCell::new(FileDropState::default()));
+
+    #[cfg(feature = "tray")]
+    if let Some(tray) = cfg.tray.take() {
+        tray::build(tray, event_loop);
+    }
+
+    let window_state = cfg.window_state_path();
+    if let Some(path) = &window_state {
+        cfg.window = window_state::restore(path, cfg.window.clone(), event_loop);
+    }
+
+    let deferred_show = cfg.show_on_first_render.then(|| {
+        cfg.window = cfg.window.clone().with_visible(false);
+        DeferredShow::new(cfg.splash.take(), event_loop)
+    });
+
+    let menu_actions = cfg.menu.take().map(|menu| {
+        let (bar, actions) = menu.build();
+        cfg.window = cfg.window.clone().with_menu(bar);
+        actions
+    });
+
+    let webview = webview::build(&mut cfg, event_loop, proxy.clone(), file_drop.clone());
+
+    if let Some(actions) = menu_actions {
+        dom.base_scope().provide_context(actions);
+    }
+
+    dom.base_scope()
+        .provide_context(PersistentStorage::new(FileStorage::new(
+            cfg.data_directory(),
+        )));
+
+    let global_listeners = GlobalListeners::new(WebviewListeners::new(webview.clone()));
+    dom.base_scope().provide_context(global_listeners.clone());
+
+    #[cfg(any(target_os = "ios", target_os = "android"))]
+    let viewport = Rc::new(MobileViewport::default());
+    #[cfg(any(target_os = "ios", target_os = "android"))]
+    dom.base_scope().provide_context(viewport.clone());
+
+    let media_queries = Rc::new(WebviewMediaQueries::new(webview.clone()));
+    dom.base_scope()
+        .provide_context(MediaQueries::new(media_queries.clone()));
+
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    dom.base_scope()
+        .provide_context(dioxus_hooks::Clipboard::new(clipboard::NativeClipboard));
 
     dom.base_scope().provide_context(DesktopContext::new(
         webview.clone(),
@@ -290,6 +610,11 @@ fn create_new_window(
         queue.clone(),
         event_handlers.clone(),
         shortcut_manager,
+        Rc::new(AppInfo {
+            metadata: cfg.app.clone(),
+            data_dir: cfg.data_directory(),
+        }),
+        dom.scheduler_handle(),
     ));
 
     let id = webview.window().id();
@@ -298,6 +623,14 @@ fn create_new_window(
     WebviewHandler {
         webview,
         dom,
+        file_drop,
+        global_listeners,
+        media_queries,
+        #[cfg(any(target_os = "ios", target_os = "android"))]
+        viewport,
+        lifecycle: Default::default(),
+        window_state,
+        deferred_show,
         waker: waker::tao_waker(proxy, id),
     }
 }
@@ -306,6 +639,29 @@ struct WebviewHandler {
     dom: VirtualDom,
     webview: Rc<wry::webview::WebView>,
     waker: Waker,
+    // The files dragged over or dropped on the window, waiting for the drag events from the webview
+    file_drop: Rc<RefCell<FileDropState>>,
+    // The listeners components attached to the window and document with `use_event_listener`
+    global_listeners: GlobalListeners,
+    media_queries: Rc<WebviewMediaQueries>,
+    // The safe area and soft keyboard, for `use_safe_area_insets` and `use_soft_keyboard`
+    #[cfg(any(target_os = "ios", target_os = "android"))]
+    viewport: Rc<MobileViewport>,
+    lifecycle: Rc<WindowLifecycle>,
+    // Where to remember the size and position of the window when it closes
+    window_state: Option<PathBuf>,
+    // Set while the window is hidden until the app rendered for the first time
+    deferred_show: Option<DeferredShow>,
+}
+
+// Every way a window can be closed ends with its handler being dropped
+impl Drop for WebviewHandler {
+    fn drop(&mut self) {
+        if let Some(path) = &self.window_state {
+            window_state::save(path, self.webview.window());
+        }
+        self.lifecycle.close();
+    }
 }
 
 /// Poll the virtualdom until it's pending
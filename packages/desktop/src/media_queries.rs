@@ -0,0 +1,54 @@
+use dioxus_hooks::MediaQueryBackend;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use wry::webview::WebView;
+
+/// Evaluates media queries with `matchMedia` inside the webview
+///
+/// The interpreter reports the result of each query as a `media_query` IPC message, once when it starts watching and
+/// again every time the result changes.
+pub(crate) struct WebviewMediaQueries {
+    webview: Rc<WebView>,
+    watching: RefCell<HashMap<usize, Rc<dyn Fn(bool)>>>,
+}
+
+impl WebviewMediaQueries {
+    pub fn new(webview: Rc<WebView>) -> Self {
+        Self {
+            webview,
+            watching: Default::default(),
+        }
+    }
+
+    /// Pass a result reported by the webview to the hook that is watching the query
+    pub fn changed(&self, id: usize, matches: bool) {
+        let on_change = self.watching.borrow().get(&id).cloned();
+        if let Some(on_change) = on_change {
+            on_change(matches);
+        }
+    }
+
+    fn eval(&self, script: &str) {
+        if let Err(err) = self.webview.evaluate_script(script) {
+            log::warn!("Could not update media queries: {err}");
+        }
+    }
+}
+
+impl MediaQueryBackend for WebviewMediaQueries {
+    fn watch(&self, id: usize, query: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool> {
+        self.watching.borrow_mut().insert(id, on_change);
+
+        let query = serde_json::to_string(query).unwrap();
+        self.eval(&format!(
+            "window.interpreter.WatchMediaQuery({id}, {query})"
+        ));
+
+        // The webview reports the result asynchronously
+        None
+    }
+
+    fn unwatch(&self, id: usize) {
+        self.watching.borrow_mut().remove(&id);
+        self.eval(&format!("window.interpreter.UnwatchMediaQuery({id})"));
+    }
+}
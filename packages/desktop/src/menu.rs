@@ -0,0 +1,242 @@
+use std::{any::Any, collections::HashMap, rc::Rc};
+
+use dioxus_core::ScopeState;
+use wry::application::{
+    accelerator::Accelerator,
+    event::Event,
+    menu::{MenuBar, MenuId, MenuItem, MenuItemAttributes, MenuType},
+};
+
+use crate::desktop_context::{use_latest_handler, use_wry_event_handler};
+use crate::shortcut::{IntoKeyCode, IntoModifersState};
+
+/// The native menu bar of a window, set with [`Config::with_menu`](crate::Config::with_menu)
+///
+/// Every item carries an action of your choosing, which is sent to the handlers registered with
+/// [`use_menu_event_handler`] when the item is clicked or its accelerator is pressed.
+///
+/// ```rust, ignore
+/// #[derive(Clone)]
+/// enum Action {
+///     Open,
+///     Save,
+/// }
+///
+/// let menu = AppMenu::new()
+///     .submenu(
+///         "File",
+///         MenuItems::new()
+///             .item(MenuAction::new("Open", Action::Open).with_accelerator(Modifiers::CONTROL, KeyCode::O))
+///             .item(MenuAction::new("Save", Action::Save).with_accelerator(Modifiers::CONTROL, KeyCode::S))
+///             .separator()
+///             .native(NativeMenuItem::Quit),
+///     )
+///     .submenu(
+///         "Edit",
+///         MenuItems::new()
+///             .native(NativeMenuItem::Copy)
+///             .native(NativeMenuItem::Paste),
+///     );
+/// ```
+#[derive(Default)]
+pub struct AppMenu {
+    submenus: Vec<(String, MenuItems)>,
+}
+
+impl AppMenu {
+    /// Create an empty menu bar
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a menu to the bar
+    pub fn submenu(mut self, title: impl Into<String>, items: MenuItems) -> Self {
+        self.submenus.push((title.into(), items));
+        self
+    }
+
+    pub(crate) fn build(self) -> (MenuBar, MenuActions) {
+        let mut actions = MenuActions::default();
+        let mut bar = MenuBar::new();
+        for (title, items) in self.submenus {
+            bar.add_submenu(&title, true, items.build(&mut actions));
+        }
+        (bar, actions)
+    }
+}
+
+/// The items of a menu in an [`AppMenu`]
+#[derive(Default)]
+pub struct MenuItems {
+    items: Vec<MenuEntry>,
+}
+
+enum MenuEntry {
+    Action(MenuAction),
+    Native(MenuItem),
+    Submenu(String, MenuItems),
+}
+
+impl MenuItems {
+    /// Create an empty menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item that sends an action to the app
+    pub fn item(mut self, action: MenuAction) -> Self {
+        self.items.push(MenuEntry::Action(action));
+        self
+    }
+
+    /// Add an item the platform implements itself, like copy, paste or quit
+    ///
+    /// Not every platform supports every item. Unsupported items are left out of the menu.
+    pub fn native(mut self, item: NativeMenuItem) -> Self {
+        self.items.push(MenuEntry::Native(item));
+        self
+    }
+
+    /// Add a line between two groups of items
+    pub fn separator(self) -> Self {
+        self.native(MenuItem::Separator)
+    }
+
+    /// Add a nested menu
+    pub fn submenu(mut self, title: impl Into<String>, items: MenuItems) -> Self {
+        self.items.push(MenuEntry::Submenu(title.into(), items));
+        self
+    }
+
+    fn build(self, actions: &mut MenuActions) -> MenuBar {
+        let mut menu = MenuBar::new();
+        for entry in self.items {
+            match entry {
+                MenuEntry::Action(action) => {
+                    let id = actions.insert(action.action);
+                    let mut attributes = MenuItemAttributes::new(&action.title)
+                        .with_id(id)
+                        .with_enabled(action.enabled);
+                    if let Some(accelerator) = &action.accelerator {
+                        attributes = attributes.with_accelerators(accelerator);
+                    }
+                    menu.add_item(attributes);
+                }
+                MenuEntry::Native(item) => {
+                    menu.add_native_item(item);
+                }
+                MenuEntry::Submenu(title, items) => {
+                    menu.add_submenu(&title, true, items.build(actions));
+                }
+            }
+        }
+        menu
+    }
+}
+
+/// The menu items the platform implements itself
+pub use wry::application::menu::MenuItem as NativeMenuItem;
+
+/// A menu item that sends an action to the app when it is clicked
+pub struct MenuAction {
+    title: String,
+    action: Rc<dyn Any>,
+    accelerator: Option<Accelerator>,
+    enabled: bool,
+}
+
+impl MenuAction {
+    /// Create an item that sends `action` to the handlers registered with [`use_menu_event_handler`] for its type
+    pub fn new(title: impl Into<String>, action: impl Any) -> Self {
+        Self {
+            title: title.into(),
+            action: Rc::new(action),
+            accelerator: None,
+            enabled: true,
+        }
+    }
+
+    /// Activate the item with a keyboard shortcut while the window is focused
+    pub fn with_accelerator(
+        mut self,
+        modifiers: impl IntoModifersState,
+        key: impl IntoKeyCode,
+    ) -> Self {
+        self.accelerator = Some(Accelerator::new(
+            Some(modifiers.into_modifiers_state()),
+            key.into_key_code(),
+        ));
+        self
+    }
+
+    /// Show the item greyed out, so it can't be clicked
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+}
+
+/// The action of every item in the menu bar of a window, by menu id
+#[derive(Clone, Default)]
+pub(crate) struct MenuActions {
+    actions: Rc<HashMap<MenuId, Rc<dyn Any>>>,
+}
+
+impl MenuActions {
+    fn insert(&mut self, action: Rc<dyn Any>) -> MenuId {
+        let actions = Rc::get_mut(&mut self.actions).expect("menu actions are only built once");
+        // Number the items ourselves, hashing their titles could give two items the same id
+        let id = MenuId(actions.len() as u16 + 1);
+        actions.insert(id, action);
+        id
+    }
+
+    fn get(&self, id: &MenuId) -> Option<&Rc<dyn Any>> {
+        self.actions.get(id)
+    }
+}
+
+/// Listen to the actions of the window's menu bar.
+///
+/// Only actions of type `T` are sent to the handler, so different components can handle different kinds of actions.
+/// Each action is passed to the closure of the component's latest render.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let saved = use_state(cx, || 0);
+///
+///     use_menu_event_handler(cx, {
+///         to_owned![saved];
+///         move |action: Action| match action {
+///             Action::Save => saved.modify(|count| count + 1),
+///             Action::Open => {}
+///         }
+///     });
+///
+///     cx.render(rsx!("Saved {saved} times"))
+/// }
+/// ```
+pub fn use_menu_event_handler<T: Clone + 'static>(
+    cx: &ScopeState,
+    handler: impl FnMut(T) + 'static,
+) {
+    let handler = use_latest_handler::<dyn FnMut(T)>(cx, Box::new(handler));
+    let actions = cx
+        .use_hook(|| cx.consume_context::<MenuActions>().unwrap_or_default())
+        .clone();
+    use_wry_event_handler(cx, move |event, _| {
+        if let Event::MenuEvent {
+            menu_id,
+            origin: MenuType::MenuBar,
+            ..
+        } = event
+        {
+            let action = actions.get(menu_id).and_then(|a| a.downcast_ref::<T>());
+            if let Some(action) = action {
+                (handler.borrow_mut())(action.clone());
+            }
+        }
+    });
+}
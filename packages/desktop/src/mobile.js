@@ -0,0 +1,38 @@
+// Report the safe area and the height of the soft keyboard, which phones change as they rotate and type
+(() => {
+  // `env()` values can only be read through the style of an element
+  const probe = document.createElement("div");
+  probe.style.cssText =
+    "position: fixed; visibility: hidden; pointer-events: none;" +
+    "padding: env(safe-area-inset-top) env(safe-area-inset-right) env(safe-area-inset-bottom) env(safe-area-inset-left)";
+  document.body.appendChild(probe);
+
+  let reported = null;
+  const report = () => {
+    const style = getComputedStyle(probe);
+    const visual = window.visualViewport;
+    // The keyboard shrinks the visual viewport, toolbars appearing and disappearing only change it a little
+    const covered = visual ? window.innerHeight - visual.height - visual.offsetTop : 0;
+    const viewport = {
+      safe_area: {
+        top: parseFloat(style.paddingTop) || 0,
+        right: parseFloat(style.paddingRight) || 0,
+        bottom: parseFloat(style.paddingBottom) || 0,
+        left: parseFloat(style.paddingLeft) || 0,
+      },
+      keyboard_height: covered > 100 ? covered : 0,
+    };
+    const key = JSON.stringify(viewport);
+    if (key !== reported) {
+      reported = key;
+      window.ipc.postMessage(serializeIpcMessage("viewport", viewport));
+    }
+  };
+
+  window.addEventListener("resize", report);
+  window.addEventListener("orientationchange", report);
+  if (window.visualViewport) {
+    window.visualViewport.addEventListener("resize", report);
+  }
+  report();
+})();
@@ -0,0 +1,177 @@
+//! What apps need to feel at home on phones: the safe area, the soft keyboard and the lifecycle of the app
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus_core::ScopeState;
+use serde::Deserialize;
+use serde_json::Value;
+use slab::Slab;
+use wry::application::event::Event;
+
+use crate::desktop_context::{use_latest_handler, use_wry_event_handler};
+
+/// The space taken by the notch, the rounded corners and the home indicator of the screen, in CSS pixels
+///
+/// Keep interactive content out of it by padding the app with these values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+pub struct SafeAreaInsets {
+    /// The space at the top of the window
+    pub top: f64,
+    /// The space at the right of the window
+    pub right: f64,
+    /// The space at the bottom of the window
+    pub bottom: f64,
+    /// The space at the left of the window
+    pub left: f64,
+}
+
+/// The on screen keyboard of phones and tablets
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SoftKeyboard {
+    /// The height of the window covered by the keyboard, in CSS pixels
+    pub height: f64,
+}
+
+impl SoftKeyboard {
+    /// `true` while the keyboard is shown
+    pub fn visible(&self) -> bool {
+        self.height > 0.0
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Deserialize)]
+struct ViewportState {
+    safe_area: SafeAreaInsets,
+    keyboard_height: f64,
+}
+
+/// The safe area and soft keyboard of a window, as reported by its webview
+#[derive(Default)]
+pub(crate) struct MobileViewport {
+    state: Cell<ViewportState>,
+    subscribers: RefCell<Slab<Arc<dyn Fn()>>>,
+}
+
+impl MobileViewport {
+    /// Store the state the webview reported, and render the components that use it if it changed
+    pub(crate) fn update(&self, params: Value) {
+        let state = match serde_json::from_value(params) {
+            Ok(state) => state,
+            Err(err) => return log::warn!("Invalid viewport state: {err}"),
+        };
+        if self.state.replace(state) != state {
+            for (_, schedule_update) in self.subscribers.borrow().iter() {
+                schedule_update();
+            }
+        }
+    }
+}
+
+fn use_viewport(cx: &ScopeState) -> ViewportState {
+    let viewport = cx.use_hook(|| {
+        let viewport = cx.consume_context::<Rc<MobileViewport>>()?;
+        let id = viewport
+            .subscribers
+            .borrow_mut()
+            .insert(cx.schedule_update());
+        cx.on_drop({
+            let viewport = viewport.clone();
+            move || {
+                viewport.subscribers.borrow_mut().try_remove(id);
+            }
+        });
+        Some(viewport)
+    });
+
+    viewport
+        .as_ref()
+        .map(|viewport| viewport.state.get())
+        .unwrap_or_default()
+}
+
+/// Get the safe area of the window, and render again when it changes, as the phone rotates for example
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let insets = use_safe_area_insets(cx);
+///
+///     cx.render(rsx! {
+///         div {
+///             style: "padding: {insets.top}px {insets.right}px {insets.bottom}px {insets.left}px",
+///             "Content"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_safe_area_insets(cx: &ScopeState) -> SafeAreaInsets {
+    use_viewport(cx).safe_area
+}
+
+/// Get the soft keyboard of the device, and render again when it shows, hides or changes size
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn chat(cx: Scope) -> Element {
+///     let keyboard = use_soft_keyboard(cx);
+///
+///     // Keep the message box above the keyboard
+///     cx.render(rsx! {
+///         input { style: "position: fixed; bottom: {keyboard.height}px" }
+///     })
+/// }
+/// ```
+pub fn use_soft_keyboard(cx: &ScopeState) -> SoftKeyboard {
+    SoftKeyboard {
+        height: use_viewport(cx).keyboard_height,
+    }
+}
+
+/// A change in the lifecycle of the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppLifecycle {
+    /// The app went to the background, and may be killed without further notice, so save what needs to be saved
+    Paused,
+    /// The app came back to the foreground
+    Resumed,
+}
+
+/// React to the app going to the background and coming back
+///
+/// The OS pauses apps in the background and may stop them there, so save anything the user would lose when the app
+/// is paused. The handler of the component's latest render is called, until the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let draft = use_state(cx, String::new);
+///
+///     use_app_lifecycle(cx, {
+///         to_owned![draft];
+///         move |lifecycle| {
+///             if lifecycle == AppLifecycle::Paused {
+///                 save_draft(&draft);
+///             }
+///         }
+///     });
+///
+///     cx.render(rsx!(textarea { value: "{draft}", oninput: move |e| draft.set(e.value.clone()) }))
+/// }
+/// ```
+pub fn use_app_lifecycle(cx: &ScopeState, handler: impl FnMut(AppLifecycle) + 'static) {
+    let handler = use_latest_handler::<dyn FnMut(AppLifecycle)>(cx, Box::new(handler));
+
+    use_wry_event_handler(cx, move |event, _| {
+        let lifecycle = match event {
+            Event::Suspended => AppLifecycle::Paused,
+            Event::Resumed => AppLifecycle::Resumed,
+            _ => return,
+        };
+        (handler.borrow_mut())(lifecycle);
+    });
+}
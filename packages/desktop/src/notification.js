@@ -0,0 +1,58 @@
+// Webviews don't show notifications from the web Notification API (or ask for permission in ways the app can't
+// control), so the constructor is replaced with one that shows a native notification
+(() => {
+  const shown = new Map();
+  let nextId = 0;
+
+  class NativeNotification extends EventTarget {
+    constructor(title, options = {}) {
+      super();
+      this.id = nextId++;
+      this.title = title;
+      this.body = options.body || "";
+      this.icon = options.icon || "";
+      this.onclick = null;
+      shown.set(this.id, this);
+      window.ipc.postMessage(
+        serializeIpcMessage("notification", {
+          id: this.id,
+          title: this.title,
+          body: this.body,
+          icon: this.icon,
+        })
+      );
+    }
+
+    static get permission() {
+      return "granted";
+    }
+
+    static requestPermission(callback) {
+      if (callback) {
+        callback("granted");
+      }
+      return Promise.resolve("granted");
+    }
+
+    close() {
+      shown.delete(this.id);
+    }
+  }
+
+  window.Notification = NativeNotification;
+  window.dioxus = window.dioxus || {};
+
+  // Called by the native side when a notification is clicked, which is only reported on Linux
+  window.dioxus.notificationClicked = (id) => {
+    const notification = shown.get(id);
+    if (notification === undefined) {
+      return;
+    }
+    shown.delete(id);
+    const event = new Event("click");
+    notification.dispatchEvent(event);
+    if (notification.onclick) {
+      notification.onclick(event);
+    }
+  };
+})();
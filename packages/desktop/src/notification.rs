@@ -0,0 +1,227 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use serde::Deserialize;
+use slab::Slab;
+use wry::{application::window::WindowId, webview::WebView};
+
+use crate::desktop_context::ProxyType;
+
+/// A native notification of the OS, shown with [`DesktopContext::notify`](crate::DesktopContext::notify)
+///
+/// Notifications are shown with `notify-rust`. Only the notification servers of Linux desktops report clicks back
+/// (through `wait_for_action`), so elsewhere a notification with an [`Notification::on_click`] callback isn't shown
+/// and [`NotificationError::ClicksUnsupported`] is returned instead. Check [`Notification::clicks_supported`] to
+/// leave the callback out on those platforms.
+///
+/// The web `Notification` API of the webview is replaced with one that shows these native notifications, so
+/// `new Notification(title, { body, icon })` from JavaScript works too.
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let window = use_window(cx);
+///
+///     cx.render(rsx! {
+///         button {
+///             onclick: move |_| {
+///                 let notification = Notification::new("Download finished")
+///                     .with_body("report.pdf was saved to your downloads")
+///                     .on_click({
+///                         to_owned![window];
+///                         move || window.set_focus()
+///                     });
+///                 if let Err(err) = window.notify(notification) {
+///                     log::error!("{err}");
+///                 }
+///             },
+///             "Notify"
+///         }
+///     })
+/// }
+/// ```
+pub struct Notification {
+    title: String,
+    body: Option<String>,
+    icon: Option<String>,
+    on_click: Option<Box<dyn FnOnce()>>,
+}
+
+impl Notification {
+    /// Create a notification with a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    /// Set the text shown under the title
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the icon, either the path to an image or the name of an icon of the desktop theme. Not supported on macOS.
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Run `callback` on the event loop if the user clicks the notification.
+    ///
+    /// Only supported on Linux, where notify-rust waits for the notification server to report the click. On other
+    /// platforms showing the notification fails with [`NotificationError::ClicksUnsupported`].
+    pub fn on_click(mut self, callback: impl FnOnce() + 'static) -> Self {
+        self.on_click = Some(Box::new(callback));
+        self
+    }
+
+    /// `true` if the OS reports clicks on notifications, so [`Notification::on_click`] callbacks can run
+    pub const fn clicks_supported() -> bool {
+        cfg!(all(unix, not(target_os = "macos")))
+    }
+}
+
+/// A notification couldn't be shown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationError {
+    /// The OS failed to show the notification
+    Failed(String),
+    /// The notification has an [`Notification::on_click`] callback, which the OS of this platform has no way to run
+    ClicksUnsupported,
+}
+
+impl fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(err) => write!(f, "could not show the notification: {err}"),
+            Self::ClicksUnsupported => {
+                f.write_str("clicks on notifications are only reported on Linux")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NotificationError {}
+
+// The click callbacks aren't Send, so they stay on the main thread while another thread waits for the click
+thread_local! {
+    static ON_CLICK: RefCell<Slab<Box<dyn FnOnce()>>> = RefCell::new(Slab::new());
+}
+
+pub(crate) fn show(
+    notification: Notification,
+    proxy: &ProxyType,
+    window: WindowId,
+) -> Result<(), NotificationError> {
+    let mut native = notify_rust::Notification::new();
+    native.summary(&notification.title);
+    if let Some(body) = &notification.body {
+        native.body(body);
+    }
+    if let Some(icon) = &notification.icon {
+        native.icon(icon);
+    }
+
+    match notification.on_click {
+        Some(on_click) => show_clickable(native, on_click, proxy.clone(), window),
+        None => native.show().map(drop).map_err(failed),
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show_clickable(
+    mut native: notify_rust::Notification,
+    on_click: Box<dyn FnOnce()>,
+    proxy: ProxyType,
+    window: WindowId,
+) -> Result<(), NotificationError> {
+    use crate::desktop_context::{EventData, UserWindowEvent};
+
+    // Notification servers only report clicks on notifications with a default action
+    native.action("default", "Open");
+    let handle = native.show().map_err(failed)?;
+
+    let id = ON_CLICK.with(|callbacks| callbacks.borrow_mut().insert(on_click));
+    std::thread::spawn(move || {
+        let mut clicked = false;
+        handle.wait_for_action(|action| clicked = action == "default");
+        let _ = proxy.send_event(UserWindowEvent(
+            EventData::NotificationClosed { id, clicked },
+            window,
+        ));
+    });
+
+    Ok(())
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn show_clickable(
+    _native: notify_rust::Notification,
+    _on_click: Box<dyn FnOnce()>,
+    _proxy: ProxyType,
+    _window: WindowId,
+) -> Result<(), NotificationError> {
+    Err(NotificationError::ClicksUnsupported)
+}
+
+/// A notification created with the web `Notification` constructor, which `notification.js` sends over IPC
+#[derive(Deserialize)]
+struct WebNotification {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    body: String,
+    #[serde(default)]
+    icon: String,
+}
+
+/// Show a notification created by the webview, and dispatch its `click` event if the notification is clicked
+pub(crate) fn show_from_webview(
+    params: serde_json::Value,
+    webview: &Rc<WebView>,
+    proxy: &ProxyType,
+    window: WindowId,
+) {
+    let request = match serde_json::from_value::<WebNotification>(params) {
+        Ok(request) => request,
+        Err(err) => {
+            log::warn!("Invalid notification from the webview: {err}");
+            return;
+        }
+    };
+
+    let mut notification = Notification::new(request.title);
+    if !request.body.is_empty() {
+        notification = notification.with_body(request.body);
+    }
+    if !request.icon.is_empty() {
+        notification = notification.with_icon(request.icon);
+    }
+
+    // Elsewhere the notification couldn't be shown with a click callback, and the page never hears about clicks
+    if Notification::clicks_supported() {
+        let webview = webview.clone();
+        let id = request.id;
+        notification = notification.on_click(move || {
+            let _ = webview.evaluate_script(&format!("window.dioxus.notificationClicked({id})"));
+        });
+    }
+
+    if let Err(err) = show(notification, proxy, window) {
+        log::error!("{err}");
+    }
+}
+
+/// Forget the click callback of a notification that was closed, and run it if the notification was clicked
+pub(crate) fn closed(id: usize, clicked: bool) {
+    let on_click = ON_CLICK.with(|callbacks| callbacks.borrow_mut().try_remove(id));
+    if let (Some(on_click), true) = (on_click, clicked) {
+        on_click();
+    }
+}
+
+fn failed(err: notify_rust::error::Error) -> NotificationError {
+    NotificationError::Failed(err.to_string())
+}
@@ -0,0 +1,272 @@
+use std::{fmt, path::Path};
+
+use serde_json::Value;
+use wry::webview::WebView;
+
+/// The id of the style element that applies the options while printing
+const STYLE_ID: &str = "dioxus-print-options";
+
+/// The page setup used by [`DesktopContext::print_with`](crate::DesktopContext::print_with) and
+/// [`DesktopContext::export_pdf`](crate::DesktopContext::export_pdf)
+///
+/// The options become CSS `@page` rules for the duration of the print, so they override the ones of the app.
+///
+/// ```rust, ignore
+/// window.print_with(
+///     PrintOptions::new()
+///         .with_page_size(PageSize::A4)
+///         .with_landscape(true)
+///         .with_margins_mm(15.0),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrintOptions {
+    page_size: Option<PageSize>,
+    landscape: bool,
+    margins_mm: Option<f64>,
+    backgrounds: bool,
+}
+
+/// The size of the printed pages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PageSize {
+    /// 210 × 297 mm
+    A4,
+    /// 297 × 420 mm
+    A3,
+    /// 148 × 210 mm
+    A5,
+    /// 8.5 × 11 in
+    Letter,
+    /// 8.5 × 14 in
+    Legal,
+    /// Any size, in millimeters, as it would be printed in portrait
+    Custom {
+        /// The width in millimeters
+        width_mm: f64,
+        /// The height in millimeters
+        height_mm: f64,
+    },
+}
+
+impl PrintOptions {
+    /// Print with the page setup of the app and the printer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the size of the pages
+    pub fn with_page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Print the pages in landscape instead of portrait
+    pub fn with_landscape(mut self, landscape: bool) -> Self {
+        self.landscape = landscape;
+        self
+    }
+
+    /// Set the margin around the content of every page, in millimeters
+    pub fn with_margins_mm(mut self, margins_mm: f64) -> Self {
+        self.margins_mm = Some(margins_mm);
+        self
+    }
+
+    /// Print background colors and images, which browsers leave out by default to save ink
+    pub fn with_backgrounds(mut self, backgrounds: bool) -> Self {
+        self.backgrounds = backgrounds;
+        self
+    }
+
+    fn css(&self) -> String {
+        let orientation = if self.landscape {
+            "landscape"
+        } else {
+            "portrait"
+        };
+        let size = match self.page_size {
+            None => orientation.to_string(),
+            Some(PageSize::A4) => format!("A4 {orientation}"),
+            Some(PageSize::A3) => format!("A3 {orientation}"),
+            Some(PageSize::A5) => format!("A5 {orientation}"),
+            Some(PageSize::Letter) => format!("letter {orientation}"),
+            Some(PageSize::Legal) => format!("legal {orientation}"),
+            Some(PageSize::Custom {
+                width_mm,
+                height_mm,
+            }) => match self.landscape {
+                true => format!("{height_mm}mm {width_mm}mm"),
+                false => format!("{width_mm}mm {height_mm}mm"),
+            },
+        };
+
+        let mut css = format!("@page {{ size: {size};");
+        if let Some(margins) = self.margins_mm {
+            css.push_str(&format!(" margin: {margins}mm;"));
+        }
+        css.push_str(" }");
+        if self.backgrounds {
+            css.push_str(
+                " * { -webkit-print-color-adjust: exact !important; print-color-adjust: exact !important; }",
+            );
+        }
+        css
+    }
+
+    /// A script that applies the options until printing is done, and opens the print dialog if `print` is set
+    ///
+    /// `afterprint` isn't fired when the print is started natively, so [`remove_style`] removes the style then.
+    pub(crate) fn script(&self, print: bool) -> String {
+        let css = Value::from(self.css());
+        let print = if print { "window.print();" } else { "" };
+        format!(
+            r#"
+            (() => {{
+                document.getElementById("{STYLE_ID}")?.remove();
+                const style = document.createElement("style");
+                style.id = "{STYLE_ID}";
+                style.textContent = {css};
+                document.head.appendChild(style);
+                window.addEventListener("afterprint", () => style.remove(), {{ once: true }});
+                {print}
+            }})();
+            "#
+        )
+    }
+}
+
+/// Remove the style applied by [`PrintOptions::script`] once a native print call returned
+pub(crate) fn remove_style(webview: &WebView) {
+    let _ = webview.evaluate_script(&format!(
+        r#"document.getElementById("{STYLE_ID}")?.remove();"#
+    ));
+}
+
+/// The webview failed to export a PDF
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfError(String);
+
+impl fmt::Display for PdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not export the PDF: {}", self.0)
+    }
+}
+
+impl std::error::Error for PdfError {}
+
+/// Start writing the page to a PDF at `path` without showing a print dialog
+///
+/// The PDF is written in the background once the webview finished laying out the pages.
+#[cfg(target_os = "linux")]
+pub(crate) fn export_pdf(webview: &WebView, path: &Path) -> Result<(), PdfError> {
+    use webkit2gtk::{PrintOperation, PrintOperationExt};
+    use wry::webview::WebviewExtUnix;
+
+    let path = dunce::canonicalize(path.parent().unwrap_or(path))
+        .map(|dir| dir.join(path.file_name().unwrap_or_default()))
+        .map_err(|err| PdfError(err.to_string()))?;
+    let uri = format!("file://{}", path.display());
+
+    let settings = gtk::PrintSettings::new();
+    settings.set_printer("Print to File");
+    settings.set("output-file-format", Some("pdf"));
+    settings.set("output-uri", Some(&uri));
+
+    let operation = PrintOperation::new(&webview.webview());
+    operation.set_print_settings(&settings);
+    operation.print();
+    Ok(())
+}
+
+/// Start writing the page to a PDF at `path` without showing a print dialog
+///
+/// The PDF is written in the background once the webview finished laying out the pages.
+#[cfg(target_os = "windows")]
+pub(crate) fn export_pdf(webview: &WebView, path: &Path) -> Result<(), PdfError> {
+    use webview2_com::{
+        Microsoft::Web::WebView2::Win32::ICoreWebView2_7, PrintToPdfCompletedHandler,
+    };
+    use windows::core::{Interface, HSTRING};
+    use wry::webview::WebviewExtWindows;
+
+    let failed = |err: windows::core::Error| PdfError(err.message().to_string());
+    let path = HSTRING::from(path.as_os_str());
+    let handler = PrintToPdfCompletedHandler::create(Box::new(|result, success| {
+        if result.is_err() || !success {
+            log::error!("Could not export the PDF");
+        }
+        Ok(())
+    }));
+
+    unsafe {
+        let webview = webview
+            .controller()
+            .CoreWebView2()
+            .and_then(|webview| webview.cast::<ICoreWebView2_7>())
+            .map_err(failed)?;
+        webview.PrintToPdf(&path, None, &handler).map_err(failed)
+    }
+}
+
+/// Start writing the page to a PDF at `path` without showing a print dialog
+///
+/// The print operation saves to the file instead of showing a panel.
+#[cfg(target_os = "macos")]
+pub(crate) fn export_pdf(webview: &WebView, path: &Path) -> Result<(), PdfError> {
+    use objc::runtime::{Object, BOOL, NO};
+    use objc::*;
+    use wry::webview::WebviewExtMacOS;
+
+    let path = path
+        .to_str()
+        .ok_or_else(|| PdfError("the path isn't valid UTF-8".to_string()))?;
+
+    unsafe {
+        let webview = webview.webview();
+        let supported: BOOL =
+            msg_send![webview, respondsToSelector: sel!(printOperationWithPrintInfo:)];
+        if supported == NO {
+            return Err(PdfError(
+                "WKWebView can't print before macOS 11".to_string(),
+            ));
+        }
+
+        // 4 is NSUTF8StringEncoding
+        let string: *mut Object = msg_send![class!(NSString), alloc];
+        let string: *mut Object =
+            msg_send![string, initWithBytes: path.as_ptr() length: path.len() encoding: 4];
+        let url: *mut Object = msg_send![class!(NSURL), fileURLWithPath: string];
+
+        let shared: *mut Object = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+        let info: *mut Object = msg_send![shared, copy];
+        let dictionary: *mut Object = msg_send![info, dictionary];
+        let disposition: *mut Object =
+            msg_send![class!(NSString), stringWithUTF8String: "NSPrintSaveJob\0".as_ptr()];
+        let disposition_key: *mut Object =
+            msg_send![class!(NSString), stringWithUTF8String: "NSPrintJobDisposition\0".as_ptr()];
+        let url_key: *mut Object =
+            msg_send![class!(NSString), stringWithUTF8String: "NSPrintJobSavingURL\0".as_ptr()];
+        let _: () = msg_send![dictionary, setObject: disposition forKey: disposition_key];
+        let _: () = msg_send![dictionary, setObject: url forKey: url_key];
+
+        let operation: *mut Object = msg_send![webview, printOperationWithPrintInfo: info];
+        let _: () = msg_send![operation, setShowsPrintPanel: NO];
+        let _: () = msg_send![operation, setShowsProgressPanel: NO];
+        let window: *mut Object = msg_send![webview, window];
+        let _: () = msg_send![
+            operation,
+            runOperationModalForWindow: window
+            delegate: std::ptr::null_mut::<Object>()
+            didRunSelector: std::ptr::null_mut::<Object>()
+            contextInfo: std::ptr::null_mut::<Object>()
+        ];
+    }
+    Ok(())
+}
+
+/// Mobile webviews can't print to files
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+pub(crate) fn export_pdf(_webview: &WebView, _path: &Path) -> Result<(), PdfError> {
+    Err(PdfError("not supported on this platform".to_string()))
+}
@@ -1,20 +1,38 @@
 use dioxus_interpreter_js::INTERPRETER_JS;
-use std::path::{Path, PathBuf};
+use std::borrow::Cow;
+use std::path::{Component, Path, PathBuf};
 use wry::{
     http::{status::StatusCode, Request, Response},
     Result,
 };
 
+const WINDOW_CHROME_JS: &str = include_str!("./window_chrome.js");
+const INVOKE_JS: &str = include_str!("./invoke.js");
+const EMBEDDED_JS: &str = include_str!("./embedded.js");
+const MOBILE_JS: &str = include_str!("./mobile.js");
+
 fn module_loader(root_name: &str) -> String {
+    // mobile has no native file dialog, so the webview picks files and sends their contents
+    let native_files = cfg!(not(any(target_os = "ios", target_os = "android")));
+    // only phones have a safe area and a soft keyboard to report
+    let mobile_js = if native_files { "" } else { MOBILE_JS };
     format!(
         r#"
 <script>
     {INTERPRETER_JS}
 
+    {WINDOW_CHROME_JS}
+
+    {INVOKE_JS}
+
+    {EMBEDDED_JS}
+
+    {mobile_js}
+
     let rootname = "{root_name}";
     let root = window.document.getElementById(rootname);
     if (root != null) {{
-        window.interpreter = new Interpreter(root);
+        window.interpreter = new Interpreter(root, {{ nativeFiles: {native_files} }});
         window.ipc.postMessage(serializeIpcMessage("initialize"));
     }}
 </script>
@@ -82,6 +100,72 @@ pub(super) fn desktop_handler(
         .map_err(From::from)
 }
 
+/// A file served to the webview by an asset handler, set with [`Config::with_asset_handler`](crate::Config::with_asset_handler)
+pub struct Asset {
+    data: Cow<'static, [u8]>,
+    mime: Cow<'static, str>,
+}
+
+impl Asset {
+    /// Serve `data` with the given mime type, like `"image/png"`
+    pub fn new(data: impl Into<Cow<'static, [u8]>>, mime: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            data: data.into(),
+            mime: mime.into(),
+        }
+    }
+
+    /// Read a file from the filesystem, guessing its mime type from its contents and extension
+    ///
+    /// Returns `None` if the file can't be read.
+    pub fn from_file(path: impl AsRef<Path>) -> Option<Self> {
+        let path = path.as_ref();
+        let mime = get_mime_from_path(path).ok()?;
+        let data = std::fs::read(path).ok()?;
+        Some(Self::new(data, mime))
+    }
+}
+
+/// Turn a handler from paths to assets into the handler of a custom protocol
+pub(super) fn asset_handler(
+    handler: impl Fn(&str) -> Option<Asset> + 'static,
+) -> impl Fn(&Request<Vec<u8>>) -> Result<Response<Vec<u8>>> + 'static {
+    move |request| {
+        let uri = request.uri();
+
+        // On Windows the webview requests `https://{scheme}.localhost/path`, elsewhere `{scheme}://localhost/path`.
+        // A url without `localhost` puts the first part of the path in the host, so add it back.
+        let path = match uri.host() {
+            Some(host) if host != "localhost" && !host.ends_with(".localhost") => {
+                format!("{host}{}", uri.path())
+            }
+            _ => uri.path().trim_start_matches('/').to_string(),
+        };
+
+        match handler(&path) {
+            Some(asset) => Response::builder()
+                .header("Content-Type", asset.mime.as_ref())
+                .body(asset.data.into_owned())
+                .map_err(From::from),
+            None => Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(String::from("Not Found").into_bytes())
+                .map_err(From::from),
+        }
+    }
+}
+
+/// Serve the files of a directory, refusing paths that would leave it
+pub(super) fn asset_dir(dir: PathBuf) -> impl Fn(&str) -> Option<Asset> + 'static {
+    move |path| {
+        let path = Path::new(path);
+        if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+            return None;
+        }
+        Asset::from_file(dir.join(path))
+    }
+}
+
 #[allow(unreachable_code)]
 fn get_asset_root() -> Option<PathBuf> {
     /*
@@ -0,0 +1,58 @@
+use wry::webview::{WebView, WebViewBuilder};
+
+/// Send the requests of a webview that is being built through a proxy server, like `http://proxy.example.com:8080`
+///
+/// WebView2 takes its proxy as a switch of the browser process, which is shared by every window of the app, so every
+/// window uses the proxy of the first one.
+pub(crate) fn configure<'a>(builder: WebViewBuilder<'a>, url: &str) -> WebViewBuilder<'a> {
+    #[cfg(target_os = "windows")]
+    {
+        use wry::webview::WebViewBuilderExtWindows;
+        // Passing switches replaces the ones wry passes by default, which keep WebView2 from opening its own UI for
+        // PDFs and downloads
+        builder.with_additional_browser_args(format!(
+            "--disable-features=msWebOOUI,msPdfOOUI,msSmartScreenProtection --proxy-server={url}"
+        ))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = url;
+        builder
+    }
+}
+
+/// Send the requests of a webview that was built through a proxy server
+///
+/// WebKitGTK keeps the proxy in the web context, which is shared by every window of the app.
+pub(crate) fn apply(webview: &WebView, url: &str) {
+    #[cfg(target_os = "linux")]
+    {
+        use webkit2gtk::{
+            NetworkProxyMode, NetworkProxySettings, WebContextExt, WebViewExt,
+            WebsiteDataManagerExt,
+        };
+        use wry::webview::WebviewExtUnix;
+
+        let manager = webview
+            .webview()
+            .context()
+            .and_then(|context| context.website_data_manager());
+        match manager {
+            Some(manager) => manager.set_network_proxy_settings(
+                NetworkProxyMode::Custom,
+                Some(&mut NetworkProxySettings::new(Some(url), &[])),
+            ),
+            None => log::warn!("The webview has no network settings, so {url} is ignored"),
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = webview;
+        log::warn!("Webviews use the proxy of the system on this platform, so {url} is ignored");
+    }
+
+    #[cfg(target_os = "windows")]
+    let _ = (webview, url);
+}
@@ -10,6 +10,7 @@ use wry::application::{
     keyboard::{KeyCode, ModifiersState},
 };
 
+use crate::desktop_context::use_latest_handler;
 use crate::{use_window, DesktopContext};
 
 #[derive(Clone)]
@@ -20,14 +21,16 @@ pub(crate) struct ShortcutRegistry {
 
 type ShortcutMap = Rc<RefCell<HashMap<AcceleratorId, Shortcut>>>;
 
+type Callback = Rc<RefCell<Box<dyn FnMut()>>>;
+
 struct Shortcut {
     shortcut: GlobalShortcut,
-    callbacks: Slab<Box<dyn FnMut()>>,
+    callbacks: Slab<Callback>,
 }
 
 impl Shortcut {
     fn insert(&mut self, callback: Box<dyn FnMut()>) -> usize {
-        self.callbacks.insert(callback)
+        self.callbacks.insert(Rc::new(RefCell::new(callback)))
     }
 
     fn remove(&mut self, id: usize) {
@@ -55,10 +58,13 @@ impl ShortcutRegistry {
     }
 
     pub(crate) fn call_handlers(&self, id: AcceleratorId) {
-        if let Some(Shortcut { callbacks, .. }) = self.shortcuts.borrow_mut().get_mut(&id) {
-            for (_, callback) in callbacks.iter_mut() {
-                (callback)();
-            }
+        // Collect the callbacks first so they can add or remove shortcuts while they run
+        let callbacks: Vec<Callback> = match self.shortcuts.borrow().get(&id) {
+            Some(shortcut) => shortcut.callbacks.iter().map(|(_, c)| c.clone()).collect(),
+            None => return,
+        };
+        for callback in callbacks {
+            (callback.borrow_mut())();
         }
     }
 
@@ -81,12 +87,11 @@ impl ShortcutRegistry {
             } else {
                 match self.manager.borrow_mut().register(accelerator) {
                     Ok(global_shortcut) => {
-                        let mut slab = Slab::new();
-                        let id = slab.insert(callback);
-                        let shortcut = Shortcut {
+                        let mut shortcut = Shortcut {
                             shortcut: global_shortcut,
-                            callbacks: slab,
+                            callbacks: Slab::new(),
                         };
+                        let id = shortcut.insert(callback);
                         shortcuts.insert(accelerator_id, shortcut);
                         ShortcutId {
                             id: accelerator_id,
@@ -113,6 +118,14 @@ impl ShortcutRegistry {
             }
         }
     }
+
+    /// Release every shortcut, so the OS doesn't keep them reserved after the app exits
+    pub(crate) fn unregister_all(&self) {
+        self.shortcuts.borrow_mut().clear();
+        if let Err(err) = self.manager.borrow_mut().unregister_all() {
+            log::warn!("Could not unregister the global shortcuts: {err}");
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -139,7 +152,32 @@ pub struct ShortcutHandle {
     pub shortcut_id: ShortcutId,
 }
 
-/// Get a closure that executes any JavaScript in the WebView context.
+/// Register a global shortcut, which calls the handler whenever the keys are pressed, even if the window isn't focused.
+///
+/// The keys are registered with the OS once, on the first render, while the closure that runs is the one of the
+/// component's latest render. The shortcut is unregistered when the component is unmounted, and every shortcut is
+/// unregistered when the app exits.
+///
+/// Linux: Only works on x11. See [this issue](https://github.com/tauri-apps/tao/issues/331) for more information.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let window = use_window(cx);
+///
+///     let shortcut = use_global_shortcut(cx, KeyCode::Space, ModifiersState::SUPER | ModifiersState::SHIFT, {
+///         to_owned![window];
+///         move || window.set_focus()
+///     });
+///
+///     if let Err(err) = shortcut {
+///         log::error!("Could not register the shortcut: {err:?}");
+///     }
+///
+///     cx.render(rsx!("Press Super+Shift+Space to bring the app to the front"))
+/// }
+/// ```
 pub fn use_global_shortcut(
     cx: &ScopeState,
     key: impl IntoKeyCode,
@@ -147,18 +185,19 @@ pub fn use_global_shortcut(
     handler: impl FnMut() + 'static,
 ) -> &Result<ShortcutHandle, ShortcutRegistryError> {
     let desktop = use_window(cx);
-    cx.use_hook(move || {
-        let desktop = desktop.clone();
+    let handler = use_latest_handler::<dyn FnMut()>(cx, Box::new(handler));
 
+    cx.use_hook(|| {
+        let desktop = desktop.clone();
         let id = desktop.create_shortcut(
             key.into_key_code(),
             modifiers.into_modifiers_state(),
-            handler,
+            move || (handler.borrow_mut())(),
         );
 
-        Ok(ShortcutHandle {
+        id.map(|shortcut_id| ShortcutHandle {
             desktop,
-            shortcut_id: id?,
+            shortcut_id,
         })
     })
 }
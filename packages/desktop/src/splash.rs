@@ -0,0 +1,56 @@
+use wry::application::dpi::{LogicalPosition, LogicalSize};
+use wry::application::event_loop::EventLoopWindowTarget;
+use wry::application::window::WindowBuilder;
+use wry::webview::{WebView, WebViewBuilder};
+
+/// A window that starts hidden and is shown once the app rendered, so the user never sees an empty page
+pub(crate) struct DeferredShow {
+    // Closed when it's dropped, as the main window is shown
+    _splash: Option<WebView>,
+}
+
+impl DeferredShow {
+    /// Open the splash screen, if there is one, to show while the app renders
+    pub(crate) fn new<T>(
+        splash: Option<(WindowBuilder, String)>,
+        event_loop: &EventLoopWindowTarget<T>,
+    ) -> Self {
+        let splash = splash.and_then(|(window, html)| {
+            let result = open_splash(window, html, event_loop);
+            if let Err(err) = &result {
+                log::warn!("Could not open the splash screen: {err}");
+            }
+            result.ok()
+        });
+
+        Self { _splash: splash }
+    }
+}
+
+fn open_splash<T>(
+    mut window: WindowBuilder,
+    html: String,
+    event_loop: &EventLoopWindowTarget<T>,
+) -> wry::Result<WebView> {
+    // Center the splash screen on the primary monitor unless it was placed somewhere else
+    if window.window.position.is_none() {
+        if let Some(monitor) = event_loop.primary_monitor() {
+            let scale = monitor.scale_factor();
+            let screen = monitor.size().to_logical::<f64>(scale);
+            let origin = monitor.position().to_logical::<f64>(scale);
+            let size = window
+                .window
+                .inner_size
+                .map(|size| size.to_logical::<f64>(scale))
+                .unwrap_or_else(|| LogicalSize::new(800.0, 600.0));
+            window = window.with_position(LogicalPosition::new(
+                origin.x + (screen.width - size.width) / 2.0,
+                origin.y + (screen.height - size.height) / 2.0,
+            ));
+        }
+    }
+
+    WebViewBuilder::new(window.build(event_loop)?)?
+        .with_html(html)?
+        .build()
+}
@@ -0,0 +1,190 @@
+//! Drive a desktop app from an integration test
+
+use std::cell::Cell;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use dioxus_core::{Component, Element, Scope, Template, TemplateNode, VNode};
+use futures_util::FutureExt;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{launch_with_props, use_window, Config, DesktopContext};
+
+type Test = Box<dyn FnOnce(TestDriver) -> Pin<Box<dyn Future<Output = ()>>>>;
+
+/// Run an app in a hidden window and drive it with `test`, then exit the process
+///
+/// The app renders into a real webview, so the test sees the same DOM as a user would. The test starts once the app
+/// rendered for the first time. When it finishes, the process exits with code 0, or with code 1 if the test panicked.
+///
+/// The event loop has to run on the main thread and never returns, so put every test in its own test target, with
+/// `harness = false` and a `main` function that calls `launch_test`. On Linux the webview still needs a display
+/// server, so run the tests with `xvfb-run` in CI.
+///
+/// ```rust, ignore
+/// // tests/counter.rs, with `[[test]] name = "counter" harness = false` in Cargo.toml
+/// fn main() {
+///     dioxus_desktop::launch_test(app, Config::new(), |driver| async move {
+///         driver.click("#increment").await;
+///         assert_eq!(driver.text("#count").await, "1");
+///     });
+/// }
+/// ```
+pub fn launch_test<F, Fut>(root: Component, cfg: Config, test: F)
+where
+    F: FnOnce(TestDriver) -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    let harness = TestHarness {
+        root,
+        test: Cell::new(Some(Box::new(move |driver| Box::pin(test(driver))))),
+    };
+
+    let mut cfg = cfg;
+    cfg.window = cfg.window.clone().with_visible(false);
+
+    launch_with_props(test_harness, harness, cfg)
+}
+
+struct TestHarness {
+    root: Component,
+    test: Cell<Option<Test>>,
+}
+
+fn test_harness(cx: Scope<TestHarness>) -> Element {
+    let desktop = use_window(cx);
+
+    cx.use_hook(|| {
+        if let Some(test) = cx.props.test.take() {
+            let driver = TestDriver {
+                desktop: desktop.clone(),
+            };
+            cx.spawn(async move {
+                let result = AssertUnwindSafe(test(driver)).catch_unwind().await;
+                std::process::exit(if result.is_ok() { 0 } else { 1 });
+            });
+        }
+    });
+
+    let node = cx.component(cx.props.root, (), "Root");
+
+    Some(VNode {
+        key: None,
+        parent: None,
+        template: Cell::new(HARNESS_TEMPLATE),
+        root_ids: Default::default(),
+        dynamic_nodes: cx.bump().alloc([node]),
+        dynamic_attrs: &[],
+    })
+}
+
+// A template with a single dynamic root that holds the app
+const HARNESS_TEMPLATE: Template<'static> = Template {
+    name: concat!(file!(), ":", line!(), ":", column!(), ":0"),
+    roots: &[TemplateNode::Dynamic { id: 0 }],
+    node_paths: &[&[0]],
+    attr_paths: &[],
+};
+
+/// Finds elements of the app by CSS selector and interacts with them like a user would
+///
+/// The interactions wait until the app handled the events they fired and the webview applied the edits of the render
+/// that followed. Changes the app makes later, like the result of a spawned task, need [`TestDriver::wait_for`]. The
+/// methods panic if no element matches the selector, which fails the test.
+pub struct TestDriver {
+    desktop: DesktopContext,
+}
+
+impl TestDriver {
+    /// The window the app runs in
+    pub fn window(&self) -> &DesktopContext {
+        &self.desktop
+    }
+
+    /// Evaluate JavaScript in the webview and deserialize the value it returns
+    pub async fn eval<T: DeserializeOwned>(&self, code: &str) -> T {
+        match self.desktop.eval(code).into_typed().await {
+            Ok(value) => value,
+            Err(err) => panic!("Could not evaluate {code:?}: {err}"),
+        }
+    }
+
+    /// `true` if an element matches the selector
+    pub async fn exists(&self, selector: &str) -> bool {
+        let selector = Value::from(selector);
+        self.eval(&format!(
+            "return document.querySelector({selector}) !== null"
+        ))
+        .await
+    }
+
+    /// Click the first element that matches the selector
+    pub async fn click(&self, selector: &str) {
+        self.with_element::<Value>(selector, "el.click(); return null")
+            .await;
+        self.settle().await;
+    }
+
+    /// Set the value of the first input that matches the selector, like typing into it
+    pub async fn set_value(&self, selector: &str, value: &str) {
+        let value = Value::from(value);
+        self.with_element::<Value>(
+            selector,
+            &format!(
+                "el.value = {value}; el.dispatchEvent(new Event('input', {{ bubbles: true }})); return null"
+            ),
+        )
+        .await;
+        self.settle().await;
+    }
+
+    /// The text of the first element that matches the selector
+    pub async fn text(&self, selector: &str) -> String {
+        self.with_element(selector, "return el.textContent").await
+    }
+
+    /// The HTML of the first element that matches the selector, including the element itself
+    pub async fn html(&self, selector: &str) -> String {
+        self.with_element(selector, "return el.outerHTML").await
+    }
+
+    /// Wait until an element matches the selector, for changes the app makes in the background
+    ///
+    /// Panics if no element matches after `timeout`.
+    pub async fn wait_for(&self, selector: &str, timeout: Duration) {
+        let start = Instant::now();
+        while !self.exists(selector).await {
+            if start.elapsed() > timeout {
+                panic!("No element matches {selector:?} after {timeout:?}");
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    /// Wait until the webview applied the edits of the events an interaction fired
+    ///
+    /// The webview sends events and eval results over the same channel, so the app handled the events of an interaction
+    /// and queued its edits before the interaction's eval resolved. Scripts run in the order they were queued, so the
+    /// edits are applied once a script evaluated after them answered.
+    async fn settle(&self) {
+        self.eval::<Value>("return null").await;
+    }
+
+    async fn with_element<T: DeserializeOwned>(&self, selector: &str, code: &str) -> T {
+        let quoted = Value::from(selector);
+        // Wrap the value in an array, so a null value can be told apart from a missing element
+        let result: Option<[T; 1]> = self
+            .eval(&format!(
+                "const el = document.querySelector({quoted}); if (el === null) return null; return [(() => {{ {code} }})()]"
+            ))
+            .await;
+
+        match result {
+            Some([value]) => value,
+            None => panic!("No element matches {selector:?}"),
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::desktop_context::{use_latest_handler, use_wry_event_handler, UserWindowEvent};
+use dioxus_core::ScopeState;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wry::application::event::{Event, TrayEvent as WryTrayEvent};
+use wry::application::event_loop::EventLoopWindowTarget;
+use wry::application::menu::{ContextMenu, MenuId, MenuItem, MenuItemAttributes, MenuType};
+use wry::application::system_tray::{SystemTray, SystemTrayBuilder};
+use wry::application::window::Icon;
+
+/// The icon, tooltip and menu of the system tray, set with [`Config::with_tray`](crate::Config::with_tray)
+pub struct TrayConfig {
+    icon: Icon,
+    tooltip: Option<String>,
+    menu: Option<TrayMenu>,
+}
+
+impl TrayConfig {
+    /// Show `icon` in the system tray
+    pub fn new(icon: Icon) -> Self {
+        Self {
+            icon,
+            tooltip: None,
+            menu: None,
+        }
+    }
+
+    /// Set the text shown when hovering the icon. Not supported on Linux.
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set the menu opened by clicking the icon
+    pub fn with_menu(mut self, menu: TrayMenu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+}
+
+/// A menu of the system tray
+///
+/// Every item has an id, which is sent back with [`TrayEvent::MenuItem`] when the item is clicked.
+///
+/// ```rust, ignore
+/// let menu = TrayMenu::new()
+///     .item("open", "Open")
+///     .separator()
+///     .item("quit", "Quit");
+/// ```
+#[derive(Default)]
+pub struct TrayMenu {
+    items: Vec<TrayMenuItem>,
+}
+
+enum TrayMenuItem {
+    Item {
+        id: String,
+        title: String,
+        enabled: bool,
+    },
+    Separator,
+    Submenu {
+        title: String,
+        menu: TrayMenu,
+    },
+}
+
+impl TrayMenu {
+    /// Create an empty menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item that can be clicked
+    pub fn item(mut self, id: impl Into<String>, title: impl Into<String>) -> Self {
+        self.items.push(TrayMenuItem::Item {
+            id: id.into(),
+            title: title.into(),
+            enabled: true,
+        });
+        self
+    }
+
+    /// Add an item that is shown greyed out and can't be clicked
+    pub fn disabled_item(mut self, id: impl Into<String>, title: impl Into<String>) -> Self {
+        self.items.push(TrayMenuItem::Item {
+            id: id.into(),
+            title: title.into(),
+            enabled: false,
+        });
+        self
+    }
+
+    /// Add a line between two groups of items
+    pub fn separator(mut self) -> Self {
+        self.items.push(TrayMenuItem::Separator);
+        self
+    }
+
+    /// Add a nested menu
+    pub fn submenu(mut self, title: impl Into<String>, menu: TrayMenu) -> Self {
+        self.items.push(TrayMenuItem::Submenu {
+            title: title.into(),
+            menu,
+        });
+        self
+    }
+
+    fn build(&self, ids: &mut HashMap<MenuId, String>) -> ContextMenu {
+        let mut menu = ContextMenu::new();
+        for item in &self.items {
+            match item {
+                TrayMenuItem::Item { id, title, enabled } => {
+                    let menu_id = MenuId::new(id);
+                    ids.insert(menu_id, id.clone());
+                    menu.add_item(
+                        MenuItemAttributes::new(title)
+                            .with_id(menu_id)
+                            .with_enabled(*enabled),
+                    );
+                }
+                TrayMenuItem::Separator => {
+                    menu.add_native_item(MenuItem::Separator);
+                }
+                TrayMenuItem::Submenu {
+                    title,
+                    menu: submenu,
+                } => {
+                    menu.add_submenu(title, true, submenu.build(ids));
+                }
+            }
+        }
+        menu
+    }
+}
+
+/// Something the user did with the system tray
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrayEvent {
+    /// The icon was clicked with the left mouse button. Not supported on Linux.
+    LeftClick,
+    /// The icon was clicked with the right mouse button. Not supported on Linux.
+    RightClick,
+    /// The icon was double clicked. Not supported on Linux.
+    DoubleClick,
+    /// The menu item with this id was clicked
+    MenuItem(String),
+}
+
+struct ActiveTray {
+    tray: SystemTray,
+    menu_ids: HashMap<MenuId, String>,
+}
+
+// The tray belongs to the event loop, which always runs on the main thread
+thread_local! {
+    static TRAY: RefCell<Option<ActiveTray>> = RefCell::new(None);
+}
+
+/// Show the tray of a config, replacing the current one
+pub(crate) fn build(cfg: TrayConfig, event_loop: &EventLoopWindowTarget<UserWindowEvent>) {
+    let mut menu_ids = HashMap::new();
+    let menu = cfg.menu.map(|menu| menu.build(&mut menu_ids));
+
+    let mut builder = SystemTrayBuilder::new(cfg.icon, menu);
+    if let Some(tooltip) = &cfg.tooltip {
+        builder = builder.with_tooltip(tooltip);
+    }
+
+    match builder.build(event_loop) {
+        Ok(tray) => TRAY.with(|t| *t.borrow_mut() = Some(ActiveTray { tray, menu_ids })),
+        Err(err) => log::error!("Could not create the system tray: {err}"),
+    }
+}
+
+/// The tray event a wry event stands for, if any
+fn tray_event(event: &Event<UserWindowEvent>) -> Option<TrayEvent> {
+    match event {
+        Event::TrayEvent { event, .. } => match event {
+            WryTrayEvent::LeftClick => Some(TrayEvent::LeftClick),
+            WryTrayEvent::RightClick => Some(TrayEvent::RightClick),
+            WryTrayEvent::DoubleClick => Some(TrayEvent::DoubleClick),
+            _ => None,
+        },
+        Event::MenuEvent {
+            menu_id,
+            origin: MenuType::ContextMenu,
+            ..
+        } => TRAY.with(|tray| {
+            tray.borrow()
+                .as_ref()
+                .and_then(|tray| tray.menu_ids.get(menu_id).cloned())
+                .map(TrayEvent::MenuItem)
+        }),
+        _ => None,
+    }
+}
+
+/// Update the system tray while the app is running
+///
+/// Get one with [`DesktopContext::tray`](crate::DesktopContext::tray). If the app doesn't have a tray, the updates are
+/// ignored.
+#[derive(Clone, Copy)]
+pub struct TrayHandle {
+    _private: (),
+}
+
+impl TrayHandle {
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// `true` if the app has a tray
+    pub fn is_active(&self) -> bool {
+        TRAY.with(|tray| tray.borrow().is_some())
+    }
+
+    /// Replace the icon
+    pub fn set_icon(&self, icon: Icon) {
+        with_tray(|tray| tray.tray.set_icon(icon));
+    }
+
+    /// Replace the text shown when hovering the icon. Not supported on Linux.
+    pub fn set_tooltip(&self, tooltip: &str) {
+        with_tray(|tray| tray.tray.set_tooltip(tooltip));
+    }
+
+    /// Replace the menu
+    pub fn set_menu(&self, menu: TrayMenu) {
+        with_tray(|tray| {
+            let mut menu_ids = HashMap::new();
+            let menu = menu.build(&mut menu_ids);
+            tray.tray.set_menu(&menu);
+            tray.menu_ids = menu_ids;
+        });
+    }
+
+    /// Show text next to the icon, like an unread count. Only supported on macOS.
+    pub fn set_title(&self, title: &str) {
+        #[cfg(target_os = "macos")]
+        {
+            use wry::application::platform::macos::SystemTrayExtMacOS;
+            with_tray(|tray| tray.tray.set_title(title));
+        }
+
+        #[cfg(not(target_os = "macos"))]
+        log::warn!("Tray titles are only supported on macOS, ignoring {title:?}");
+    }
+}
+
+fn with_tray(f: impl FnOnce(&mut ActiveTray)) {
+    TRAY.with(|tray| match tray.borrow_mut().as_mut() {
+        Some(tray) => f(tray),
+        None => log::warn!("The app doesn't have a system tray to update"),
+    })
+}
+
+/// Listen to clicks on the system tray and its menu.
+///
+/// The tray isn't tied to a window, so the handler gets every click, whichever window the component is in. It is
+/// called with the closure of the component's latest render, and stops listening when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let window = use_window(cx);
+///
+///     use_tray_event_handler(cx, {
+///         to_owned![window];
+///         move |event| match event {
+///             TrayEvent::MenuItem(id) if id == "quit" => window.close(),
+///             TrayEvent::MenuItem(id) if id == "open" => window.set_visible(true),
+///             _ => {}
+///         }
+///     });
+///
+///     cx.render(rsx!("Running in the tray"))
+/// }
+/// ```
+pub fn use_tray_event_handler(cx: &ScopeState, handler: impl FnMut(TrayEvent) + 'static) {
+    let handler = use_latest_handler::<dyn FnMut(TrayEvent)>(cx, Box::new(handler));
+
+    use_wry_event_handler(cx, move |event, _| {
+        if let Some(event) = tray_event(event) {
+            (handler.borrow_mut())(event);
+        }
+    });
+}
@@ -1,25 +1,35 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::desktop_context::EventData;
+use crate::download::{self, DownloadEvent};
+use crate::file_upload::FileDropState;
 use crate::protocol;
+use crate::window_effect;
 use crate::{desktop_context::UserWindowEvent, Config};
 use tao::event_loop::{EventLoopProxy, EventLoopWindowTarget};
 pub use wry;
 pub use wry::application as tao;
 use wry::application::window::Window;
-use wry::webview::{WebView, WebViewBuilder};
+use wry::webview::{FileDropEvent, WebView, WebViewBuilder};
 
 pub fn build(
     cfg: &mut Config,
     event_loop: &EventLoopWindowTarget<UserWindowEvent>,
     proxy: EventLoopProxy<UserWindowEvent>,
+    file_drop: Rc<RefCell<FileDropState>>,
 ) -> Rc<WebView> {
     let builder = cfg.window.clone();
     let window = builder.build(event_loop).unwrap();
+    if let Some(effect) = cfg.window_effect {
+        window_effect::apply(&window, effect);
+    }
     let file_handler = cfg.file_drop_handler.take();
     let custom_head = cfg.custom_head.clone();
     let index_file = cfg.custom_index.clone();
     let root_name = cfg.root_name.clone();
+    let mut download_handler = cfg.download_handler.take();
+    let custom_headers = cfg.custom_headers.clone();
 
     // We assume that if the icon is None in cfg, then the user just didnt set it
     if cfg.window.window.window_icon.is_none() {
@@ -33,6 +43,10 @@ pub fn build(
         ));
     }
 
+    let window_id = window.id();
+    let download_started_proxy = proxy.clone();
+    let download_completed_proxy = proxy.clone();
+
     let mut webview = WebViewBuilder::new(window)
         .unwrap()
         .with_transparent(cfg.window.window.transparent)
@@ -45,13 +59,41 @@ pub fn build(
             }
         })
         .with_custom_protocol(String::from("dioxus"), move |r| {
-            protocol::desktop_handler(r, custom_head.clone(), index_file.clone(), &root_name)
+            let mut response =
+                protocol::desktop_handler(r, custom_head.clone(), index_file.clone(), &root_name)?;
+            for (name, value) in &custom_headers {
+                response.headers_mut().append(name, value.clone());
+            }
+            Ok(response)
         })
         .with_file_drop_handler(move |window, evet| {
+            file_drop.borrow_mut().update(&evet);
+
             file_handler
                 .as_ref()
                 .map(|handler| handler(window, evet))
                 .unwrap_or_default()
+        })
+        .with_download_started_handler(move |url, path| {
+            let event = download::start(download_handler.as_mut(), url, path);
+            let allowed = !matches!(event, DownloadEvent::Cancelled { .. });
+            if let DownloadEvent::Started { url, path } = &event {
+                download::watch(
+                    url.clone(),
+                    path.clone(),
+                    download_started_proxy.clone(),
+                    window_id,
+                );
+            }
+            _ = download_started_proxy
+                .send_event(UserWindowEvent(EventData::Download(event), window_id));
+            allowed
+        })
+        .with_download_completed_handler(move |url, path, success| {
+            download::finished(&url);
+            let event = DownloadEvent::Finished { url, path, success };
+            _ = download_completed_proxy
+                .send_event(UserWindowEvent(EventData::Download(event), window_id));
         });
 
     for (name, handler) in cfg.protocols.drain(..) {
@@ -60,23 +102,41 @@ pub fn build(
 
     if cfg.disable_context_menu {
         // in release mode, we don't want to show the dev tool or reload menus
+        // window_chrome.js prevents the menu, except on elements that ask for it
         webview = webview.with_initialization_script(
-            r#"
-                        if (document.addEventListener) {
-                        document.addEventListener('contextmenu', function(e) {
-                            e.preventDefault();
-                        }, false);
-                        } else {
-                        document.attachEvent('oncontextmenu', function() {
-                            window.event.returnValue = false;
-                        });
-                        }
-                    "#,
+            "window.dioxus = window.dioxus || {}; window.dioxus.nativeContextMenu = false;",
         )
-    } else {
-        // in debug, we are okay with the reload menu showing and dev tool
-        webview = webview.with_devtools(true);
     }
 
-    Rc::new(webview.build().unwrap())
+    if let Some(user_agent) = &cfg.user_agent {
+        webview = webview.with_user_agent(user_agent);
+    }
+
+    // Transparent windows would show the white background of the webview otherwise
+    let transparent = cfg.window.window.transparent.then_some((0, 0, 0, 0));
+    if let Some(color) = cfg.background_color.or(transparent) {
+        webview = webview.with_background_color(color);
+    }
+
+    if cfg.dev_shortcuts {
+        webview = webview.with_initialization_script(include_str!("./dev_shortcuts.js"));
+    }
+
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    {
+        webview = webview.with_initialization_script(include_str!("./notification.js"));
+    }
+
+    if let Some(url) = &cfg.proxy {
+        webview = crate::proxy::configure(webview, url);
+    }
+
+    webview = webview.with_devtools(cfg.devtools);
+
+    let webview = webview.build().unwrap();
+    if let Some(url) = &cfg.proxy {
+        crate::proxy::apply(&webview, url);
+    }
+
+    Rc::new(webview)
 }
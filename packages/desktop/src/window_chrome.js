@@ -0,0 +1,45 @@
+window.dioxus = window.dioxus || {};
+
+// The native right click menu, which the config can hide everywhere and elements can show or hide for themselves
+document.addEventListener("contextmenu", (event) => {
+  const setting =
+    event.target instanceof Element ? event.target.closest("[data-native-context-menu]") : null;
+  const allowed = setting
+    ? setting.getAttribute("data-native-context-menu") !== "false"
+    : window.dioxus.nativeContextMenu !== false;
+  if (!allowed) {
+    event.preventDefault();
+  }
+});
+
+// Custom title bars: elements marked with `data-drag-region` move the window, and elements marked with
+// `data-window-control` act as the minimize, maximize and close buttons.
+document.addEventListener("mousedown", (event) => {
+  if (event.button !== 0 || !(event.target instanceof Element)) {
+    return;
+  }
+  // Let the user interact with the controls inside a drag region
+  if (event.target.closest("button, input, select, textarea, a, [data-window-control]")) {
+    return;
+  }
+  if (!event.target.closest("[data-drag-region]")) {
+    return;
+  }
+  event.preventDefault();
+  if (event.detail === 2) {
+    window.ipc.postMessage(serializeIpcMessage("window_control", { action: "maximize" }));
+  } else {
+    window.ipc.postMessage(serializeIpcMessage("drag_window"));
+  }
+});
+
+document.addEventListener("click", (event) => {
+  if (!(event.target instanceof Element)) {
+    return;
+  }
+  const control = event.target.closest("[data-window-control]");
+  if (control) {
+    const action = control.getAttribute("data-window-control");
+    window.ipc.postMessage(serializeIpcMessage("window_control", { action }));
+  }
+});
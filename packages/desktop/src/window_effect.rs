@@ -0,0 +1,80 @@
+use wry::application::window::Window;
+
+/// A translucent backdrop drawn by the OS behind the webview, set with
+/// [`Config::with_window_effect`](crate::Config::with_window_effect)
+///
+/// Each effect only exists on some platforms. On the others the window stays transparent, so give the app a
+/// background of its own for them, for example in a `@supports` or media query fallback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowEffect {
+    /// The blurred material of native macOS apps
+    Vibrancy(Vibrancy),
+    /// The blurred backdrop of Windows 10 and 11, optionally tinted with an RGBA color
+    Acrylic(Option<(u8, u8, u8, u8)>),
+    /// The backdrop of Windows 11 apps, tinted with the desktop wallpaper
+    Mica,
+}
+
+/// The materials of [`WindowEffect::Vibrancy`], named after the parts of macOS apps they are made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vibrancy {
+    /// The title bar of a window
+    Titlebar,
+    /// The sidebar of a window
+    Sidebar,
+    /// The background of a window
+    WindowBackground,
+    /// The background of the content of a window
+    ContentBackground,
+    /// The background behind a window's content
+    UnderWindowBackground,
+    /// A menu
+    Menu,
+    /// A popover
+    Popover,
+    /// A heads up display
+    HudWindow,
+    /// A sheet
+    Sheet,
+}
+
+/// Draw the effect behind the window, which has to be transparent
+pub(crate) fn apply(window: &Window, effect: WindowEffect) {
+    let result: Result<(), String> = match effect {
+        #[cfg(target_os = "macos")]
+        WindowEffect::Vibrancy(vibrancy) => {
+            use window_vibrancy::NSVisualEffectMaterial as Material;
+            let material = match vibrancy {
+                Vibrancy::Titlebar => Material::Titlebar,
+                Vibrancy::Sidebar => Material::Sidebar,
+                Vibrancy::WindowBackground => Material::WindowBackground,
+                Vibrancy::ContentBackground => Material::ContentBackground,
+                Vibrancy::UnderWindowBackground => Material::UnderWindowBackground,
+                Vibrancy::Menu => Material::Menu,
+                Vibrancy::Popover => Material::Popover,
+                Vibrancy::HudWindow => Material::HudWindow,
+                Vibrancy::Sheet => Material::Sheet,
+            };
+            window_vibrancy::apply_vibrancy(window, material, None, None)
+                .map_err(|err| err.to_string())
+        }
+
+        #[cfg(target_os = "windows")]
+        WindowEffect::Acrylic(color) => {
+            window_vibrancy::apply_acrylic(window, color).map_err(|err| err.to_string())
+        }
+
+        #[cfg(target_os = "windows")]
+        WindowEffect::Mica => window_vibrancy::apply_mica(window).map_err(|err| err.to_string()),
+
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = window;
+            Err("it isn't supported on this platform".to_string())
+        }
+    };
+
+    if let Err(err) = result {
+        log::warn!("Could not apply the window effect {effect:?}: {err}");
+    }
+}
@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use wry::application::dpi::{LogicalPosition, LogicalSize};
+use wry::application::event_loop::EventLoopWindowTarget;
+use wry::application::window::{Window, WindowBuilder};
+
+/// The size, position and maximized state of a window, remembered between launches
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+struct WindowState {
+    size: Option<(f64, f64)>,
+    position: Option<(f64, f64)>,
+    maximized: bool,
+}
+
+impl WindowState {
+    /// Update the state with where the window is now. `frame` is the logical size and position of the window, or
+    /// `None` while it's minimized.
+    fn record(&mut self, maximized: bool, frame: Option<((f64, f64), Option<(f64, f64)>)>) {
+        self.maximized = maximized;
+
+        // Keep the size and position from before the window was maximized, so unmaximizing it after a restore works
+        if let (false, Some((size, position))) = (maximized, frame) {
+            self.size = Some(size);
+            if position.is_some() {
+                self.position = position;
+            }
+        }
+    }
+}
+
+/// The state saved at `path`, or `None` if there is none or it can't be read
+fn load(path: &Path) -> Option<WindowState> {
+    let json = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn store(path: &Path, state: &WindowState) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string(state)?)
+}
+
+/// Open the window where it was when the app last closed
+pub(crate) fn restore<T>(
+    path: &Path,
+    mut window: WindowBuilder,
+    event_loop: &EventLoopWindowTarget<T>,
+) -> WindowBuilder {
+    let state = match load(path) {
+        Some(state) => state,
+        None => return window,
+    };
+
+    if let Some((width, height)) = state.size {
+        window = window.with_inner_size(LogicalSize::new(width, height));
+    }
+
+    // A monitor may have been unplugged since, so only restore positions that are still on screen
+    if let Some((x, y)) = state.position {
+        let on_screen = event_loop.available_monitors().any(|monitor| {
+            let scale = monitor.scale_factor();
+            let (x, y) = ((x * scale) as i32, (y * scale) as i32);
+            let origin = monitor.position();
+            let size = monitor.size();
+            x >= origin.x
+                && y >= origin.y
+                && x < origin.x + size.width as i32
+                && y < origin.y + size.height as i32
+        });
+        if on_screen {
+            window = window.with_position(LogicalPosition::new(x, y));
+        }
+    }
+
+    window.with_maximized(state.maximized)
+}
+
+/// Remember where the window is, so the next launch can restore it
+pub(crate) fn save(path: &Path, window: &Window) {
+    // Minimized windows have no size on some platforms, and a position off screen
+    let size = window.inner_size();
+    let frame = (size.width > 0 && size.height > 0).then(|| {
+        let scale = window.scale_factor();
+        let size = size.to_logical::<f64>(scale);
+        let position = window
+            .outer_position()
+            .ok()
+            .map(|position| position.to_logical::<f64>(scale))
+            .map(|position| (position.x, position.y));
+        ((size.width, size.height), position)
+    });
+
+    let mut state = load(path).unwrap_or_default();
+    state.record(window.is_maximized(), frame);
+
+    if let Err(err) = store(path, &state) {
+        log::warn!(
+            "Could not save the window state to {}: {err}",
+            path.display()
+        );
+    }
+}
+
+#[cfg(test)]
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("dioxus-window-state-{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn window_state_round_trips() {
+    let path = temp_path("round-trip/window.json");
+    let mut state = WindowState::default();
+    state.record(false, Some(((800.0, 600.0), Some((10.0, 20.0)))));
+
+    store(&path, &state).unwrap();
+    assert_eq!(load(&path), Some(state));
+
+    // A maximized window keeps the size and position it goes back to
+    let mut maximized = load(&path).unwrap();
+    maximized.record(true, Some(((1920.0, 1080.0), Some((0.0, 0.0)))));
+    store(&path, &maximized).unwrap();
+    assert_eq!(
+        load(&path),
+        Some(WindowState {
+            size: Some((800.0, 600.0)),
+            position: Some((10.0, 20.0)),
+            maximized: true,
+        })
+    );
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn missing_or_corrupt_window_state_is_ignored() {
+    let path = temp_path("corrupt/window.json");
+    assert_eq!(load(&path), None);
+
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "{\"size\": [800.0,").unwrap();
+    assert_eq!(load(&path), None);
+
+    // The next save replaces the corrupt file
+    let mut state = load(&path).unwrap_or_default();
+    state.record(false, Some(((640.0, 480.0), None)));
+    store(&path, &state).unwrap();
+    assert_eq!(load(&path).unwrap().size, Some((640.0, 480.0)));
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
@@ -0,0 +1,42 @@
+//! Drives a counter in a hidden window. The event loop owns the main thread, so this target runs without the test
+//! harness.
+
+use dioxus::prelude::*;
+use dioxus_desktop::{launch_test, Config};
+use dioxus_hooks::use_state;
+
+fn main() {
+    // The webview needs a display server on Linux, run the test with `xvfb-run` where there is none
+    #[cfg(target_os = "linux")]
+    if std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        println!("Skipping the counter test, there is no display");
+        return;
+    }
+
+    launch_test(app, Config::new(), |driver| async move {
+        assert_eq!(driver.text("#count").await, "0");
+
+        driver.click("#increment").await;
+        driver.click("#increment").await;
+        assert_eq!(driver.text("#count").await, "2");
+
+        driver.set_value("#step", "5").await;
+        driver.click("#increment").await;
+        assert_eq!(driver.text("#count").await, "7");
+    });
+}
+
+fn app(cx: Scope) -> Element {
+    let count = use_state(cx, || 0);
+    let step = use_state(cx, || 1);
+
+    cx.render(rsx! {
+        p { id: "count", "{count}" }
+        input {
+            id: "step",
+            value: "{step}",
+            oninput: move |evt| step.set(evt.value.parse().unwrap_or(1)),
+        }
+        button { id: "increment", onclick: move |_| count.set(**count + **step), "Increment" }
+    })
+}
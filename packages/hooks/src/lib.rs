@@ -21,6 +21,9 @@ macro_rules! to_owned {
     )*}
 }
 
+#[cfg(test)]
+mod testing;
+
 mod usecontext;
 pub use usecontext::*;
 
@@ -47,3 +50,42 @@ pub use usecallback::*;
 
 mod usememo;
 pub use usememo::*;
+
+mod usereducer;
+pub use usereducer::*;
+
+#[cfg(any(feature = "timer", test))]
+mod usetimer;
+#[cfg(any(feature = "timer", test))]
+pub use usetimer::*;
+
+#[cfg(any(feature = "persistent", test))]
+mod usepersistent;
+#[cfg(any(feature = "persistent", test))]
+pub use usepersistent::*;
+
+mod useeventlistener;
+pub use useeventlistener::*;
+
+mod useform;
+pub use useform::*;
+
+#[cfg(any(feature = "websocket", all(test, not(target_arch = "wasm32"))))]
+mod usewebsocket;
+#[cfg(any(feature = "websocket", all(test, not(target_arch = "wasm32"))))]
+pub use usewebsocket::*;
+
+mod usemediaquery;
+pub use usemediaquery::*;
+
+mod useelementsize;
+pub use useelementsize::*;
+
+mod useclipboard;
+pub use useclipboard::*;
+
+mod useprevious;
+pub use useprevious::*;
+
+mod usequery;
+pub use usequery::*;
@@ -0,0 +1,32 @@
+//! Drive a [`VirtualDom`] whose hooks run tasks, for the tests of the hooks
+
+use dioxus_core::VirtualDom;
+use std::time::Duration;
+
+/// Render whatever the tasks of the dom change, until they have been quiet for a moment
+pub(crate) async fn settle(dom: &mut VirtualDom) {
+    while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        let _ = dom.render_immediate();
+    }
+}
+
+/// Render whatever the tasks of the dom change until `done` returns `true`, or panic with `what` after five seconds
+///
+/// Tasks can make progress without marking anything dirty, so `done` is checked even while the dom has no work.
+pub(crate) async fn run_until(dom: &mut VirtualDom, what: &str, mut done: impl FnMut() -> bool) {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while !done() {
+        if tokio::time::Instant::now() > deadline {
+            panic!("{what} didn't happen within five seconds");
+        }
+        if tokio::time::timeout(Duration::from_millis(10), dom.wait_for_work())
+            .await
+            .is_ok()
+        {
+            let _ = dom.render_immediate();
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use dioxus_core::{ScopeId, ScopeState};
+use futures_channel::mpsc::{unbounded, UnboundedSender};
+use futures_util::StreamExt;
 use std::{
-    cell::{Ref, RefCell, RefMut},
-    collections::HashSet,
+    cell::{Cell, Ref, RefCell, RefMut},
+    collections::{HashMap, HashSet},
     rc::Rc,
     sync::Arc,
 };
@@ -13,6 +15,9 @@ pub(crate) struct ProvidedStateInner<T> {
     value: T,
     notify_any: Arc<dyn Fn(ScopeId)>,
     consumers: HashSet<ScopeId>,
+    // Selectors only learn that the value may have changed, and compare their slice once the write is done
+    selectors: HashMap<usize, UnboundedSender<()>>,
+    next_selector: usize,
 }
 
 impl<T> ProvidedStateInner<T> {
@@ -20,6 +25,9 @@ impl<T> ProvidedStateInner<T> {
         for consumer in self.consumers.iter() {
             (self.notify_any)(*consumer);
         }
+        for selector in self.selectors.values() {
+            let _ = selector.unbounded_send(());
+        }
     }
 }
 
@@ -164,6 +172,155 @@ impl<T: PartialEq> PartialEq for UseSharedState<T> {
     }
 }
 
+/// Subscribe to a slice of some shared state, only re-rendering the component when that slice changes.
+///
+/// [`use_shared_state`] re-renders every consumer whenever the state is written to. With a selector, the component
+/// still learns about every write, but it only re-renders if `selector` returns a value that is not equal to the last
+/// one. The selector is only read on the first render.
+///
+/// Returns `None` if no component above provides the state with [`use_shared_state_provider`].
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #
+/// struct AppState {
+///     user_name: String,
+///     notifications: Vec<String>,
+/// }
+///
+/// // Only re-renders when the user name changes, not when a notification comes in
+/// fn Greeting(cx: Scope) -> Element {
+///     let name = use_shared_state_selector(cx, |state: &AppState| state.user_name.clone())?;
+///     let name = name.read();
+///
+///     render! {
+///         "Hello, {name}!"
+///     }
+/// }
+/// ```
+pub fn use_shared_state_selector<T: 'static, S: PartialEq + 'static>(
+    cx: &ScopeState,
+    selector: impl Fn(&T) -> S + 'static,
+) -> Option<&UseSharedStateSelector<T, S>> {
+    let state: &Option<UseSharedStateSelectorOwner<T, S>> = &*cx.use_hook(move || {
+        let root = cx.consume_context::<ProvidedState<T>>()?;
+        let selector: Rc<dyn Fn(&T) -> S> = Rc::new(selector);
+        let selected = Rc::new(RefCell::new(selector(&root.borrow().value)));
+        let stale = Rc::new(Cell::new(false));
+
+        let (tx, mut rx) = unbounded();
+        let id = {
+            let mut root = root.borrow_mut();
+            let id = root.next_selector;
+            root.next_selector += 1;
+            root.selectors.insert(id, tx);
+            id
+        };
+
+        let selector = UseSharedStateSelector {
+            state: UseSharedState { inner: root },
+            selector,
+            selected,
+            stale,
+        };
+
+        let update = cx.schedule_update();
+        cx.push_future({
+            let selector = selector.clone();
+            async move {
+                while rx.next().await.is_some() {
+                    // Several writes may have happened since the last check, but one comparison covers all of them
+                    while let Ok(Some(())) = rx.try_next() {}
+
+                    // If the state is still borrowed, the comparison is deferred until the component re-renders
+                    selector.stale.set(true);
+                    if selector.refresh() || selector.stale.get() {
+                        update();
+                    }
+                }
+            }
+        });
+
+        Some(UseSharedStateSelectorOwner { selector, id })
+    });
+
+    let state = state.as_ref().map(|s| &s.selector);
+    if let Some(selector) = state {
+        selector.refresh();
+    }
+    state
+}
+
+/// Unsubscribes the selector when the component is unmounted
+struct UseSharedStateSelectorOwner<T, S> {
+    selector: UseSharedStateSelector<T, S>,
+    id: usize,
+}
+
+impl<T, S> Drop for UseSharedStateSelectorOwner<T, S> {
+    fn drop(&mut self) {
+        let mut root = self.selector.state.inner.borrow_mut();
+        root.selectors.remove(&self.id);
+    }
+}
+
+/// A slice of shared state selected with [`use_shared_state_selector`]
+pub struct UseSharedStateSelector<T, S> {
+    state: UseSharedState<T>,
+    selector: Rc<dyn Fn(&T) -> S>,
+    selected: Rc<RefCell<S>>,
+    // The state changed while it was borrowed, so the selected slice hasn't been compared yet
+    stale: Rc<Cell<bool>>,
+}
+
+impl<T, S: PartialEq> UseSharedStateSelector<T, S> {
+    /// Read the selected slice
+    pub fn read(&self) -> Ref<'_, S> {
+        self.refresh();
+        self.selected.borrow()
+    }
+
+    // Select the slice again if it is stale and the state isn't borrowed. Returns `true` if the slice changed.
+    fn refresh(&self) -> bool {
+        if !self.stale.get() {
+            return false;
+        }
+
+        let new = match self.state.inner.try_borrow() {
+            Ok(root) => (self.selector)(&root.value),
+            Err(_) => return false,
+        };
+        self.stale.set(false);
+
+        if *self.selected.borrow() == new {
+            return false;
+        }
+        *self.selected.borrow_mut() = new;
+        true
+    }
+}
+
+impl<T, S> UseSharedStateSelector<T, S> {
+    /// The whole shared state. Writing to it notifies every consumer, but reading from it doesn't subscribe this
+    /// component to anything beyond the selected slice.
+    pub fn state(&self) -> &UseSharedState<T> {
+        &self.state
+    }
+}
+
+impl<T, S> Clone for UseSharedStateSelector<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            selector: self.selector.clone(),
+            selected: self.selected.clone(),
+            stale: self.stale.clone(),
+        }
+    }
+}
+
 /// Provide some state for components down the hierarchy to consume without having to drill props. See [`use_shared_state`] to consume the state
 ///
 ///
@@ -210,8 +367,81 @@ pub fn use_shared_state_provider<T: 'static>(cx: &ScopeState, f: impl FnOnce() -
             value: f(),
             notify_any: cx.schedule_update_any(),
             consumers: HashSet::new(),
+            selectors: HashMap::new(),
+            next_selector: 0,
         }));
 
         cx.provide_context(state);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::{cell::Cell, time::Duration};
+
+    #[derive(Default)]
+    struct AppState {
+        count: usize,
+        other: usize,
+    }
+
+    #[tokio::test]
+    async fn selectors_only_rerender_when_their_slice_changes() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static STATE: RefCell<Option<UseSharedState<AppState>>> = RefCell::new(None);
+            static CHILD_RENDERS: Cell<usize> = Cell::new(0);
+            static SEEN: Cell<usize> = Cell::new(0);
+        }
+
+        fn app(cx: Scope) -> Element {
+            use_shared_state_provider(cx, AppState::default);
+            let state = use_shared_state::<AppState>(cx).unwrap();
+            STATE.with(|s| *s.borrow_mut() = Some(state.clone()));
+
+            cx.render(rsx!(Child {}))
+        }
+
+        #[allow(non_snake_case)]
+        fn Child(cx: Scope) -> Element {
+            let count = use_shared_state_selector(cx, |state: &AppState| state.count)?;
+            CHILD_RENDERS.with(|renders| renders.set(renders.get() + 1));
+            let count = *count.read();
+            SEEN.with(|seen| seen.set(count));
+
+            cx.render(rsx!("{count}"))
+        }
+
+        async fn settle(dom: &mut VirtualDom) {
+            let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+            let _ = dom.render_immediate();
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        let state = STATE.with(|s| s.borrow().clone().unwrap());
+        assert_eq!(CHILD_RENDERS.with(Cell::get), 1);
+
+        state.write().other += 1;
+        settle(&mut dom).await;
+        assert_eq!(CHILD_RENDERS.with(Cell::get), 1);
+
+        state.write().count += 1;
+        settle(&mut dom).await;
+        assert_eq!(CHILD_RENDERS.with(Cell::get), 2);
+        assert_eq!(SEEN.with(Cell::get), 1);
+
+        // A change made while the state is still borrowed is picked up once the borrow is released
+        {
+            let mut held = state.write();
+            held.count += 1;
+            let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        }
+        let _ = dom.render_immediate();
+        assert_eq!(CHILD_RENDERS.with(Cell::get), 3);
+        assert_eq!(SEEN.with(Cell::get), 2);
+    }
+}
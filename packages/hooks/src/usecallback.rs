@@ -1,47 +1,156 @@
 use dioxus_core::ScopeState;
-use std::future::Future;
+use std::{any::Any, cell::RefCell, rc::Rc};
 
+use crate::UseFutureDep;
+
+/// Turn an async closure into an event handler that spawns the future it returns.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// let login = use_callback!(cx, move |_| async move {
+///     // log in
+/// });
+///
+/// cx.render(rsx!(button { onclick: login, "Log in" }))
+/// ```
 #[macro_export]
 macro_rules! use_callback {
-    // ($cx:ident, || || $($rest:tt)*) => { use_callback( $cx, (), |_| $($rest)* ) };
-    // ($cx:ident, || || $($rest:tt)*) => { use_callback( $cx, (), |_| $($rest)* ) };
-    ($cx:ident, || $($rest:tt)*) => {
-        use_callback(
-            $cx,
-            move || $($rest)*
-        )
-    };
-    ($cx:ident, |$($args:tt),* | $($rest:tt)*) => {
-        use_callback(
-            $cx,
-            move || $($rest)*
-        )
-    };
-    ($cx:ident, $($rest:tt)*) => {
-        use_callback(
-            $cx,
-            move || $($rest)*
-        )
-    };
-}
-pub fn use_callback<T, R, F>(cx: &ScopeState, make: impl FnOnce() -> R) -> impl FnMut(T) + '_
+    ($cx:ident, || $($rest:tt)*) => {{
+        let mut handler = $($rest)*;
+        move |evt| $cx.spawn(handler(evt))
+    }};
+    ($cx:ident, $($rest:tt)*) => {{
+        let mut handler = $($rest)*;
+        move |evt| $cx.spawn(handler(evt))
+    }};
+}
+
+/// Memoize a callback, and only replace it when the dependencies change.
+///
+/// The callback passed on the first render is kept, along with every value it captured. It is replaced by the one
+/// passed on a later render only if one of the dependencies is no longer equal to its value from the previous render.
+/// Every other render hands out the same [`UseCallback`], which compares equal to itself, so passing it to a memoized
+/// child component doesn't make the child re-render.
+///
+/// - dependencies: a tuple of references to values that are PartialEq + Clone, or `()` to keep the first callback
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// #[inline_props]
+/// fn TodoList(cx: Scope, list_id: u32) -> Element {
+///     let on_delete = use_callback(cx, (list_id,), {
+///         let list_id = *list_id;
+///         move |item: u32| delete_item(list_id, item)
+///     });
+///
+///     cx.render(rsx! {
+///         // Only re-renders when the list changes
+///         TodoItems { on_delete: on_delete.clone() }
+///     })
+/// }
+/// ```
+pub fn use_callback<T, R, D>(
+    cx: &ScopeState,
+    dependencies: D,
+    callback: impl FnMut(T) -> R + 'static,
+) -> &UseCallback<T, R>
 where
-    R: FnMut(T) -> F + 'static,
-    F: Future<Output = ()> + 'static,
+    T: 'static,
+    R: 'static,
+    D: UseFutureDep,
 {
-    let mut hook = make();
+    let state = cx.use_hook(|| CallbackState {
+        handle: None,
+        dependencies: Vec::new(),
+    });
+
+    if dependencies.apply(&mut state.dependencies) || state.handle.is_none() {
+        state.handle = Some(UseCallback {
+            callback: Rc::new(RefCell::new(callback)),
+        });
+    }
+
+    state.handle.as_ref().unwrap()
+}
+
+struct CallbackState<T, R> {
+    handle: Option<UseCallback<T, R>>,
+    dependencies: Vec<Box<dyn Any>>,
+}
 
-    move |evt| cx.spawn(hook(evt))
+/// A callback memoized with [`use_callback`]
+///
+/// Two handles are equal if they hold the same callback, so a new handle is only unequal to the old one after the
+/// dependencies changed.
+pub struct UseCallback<T, R = ()> {
+    callback: Rc<RefCell<dyn FnMut(T) -> R>>,
 }
 
-fn _it_works(cx: &ScopeState) {
-    let _p = use_callback(cx, || {
-        |()| async {
-            //
+impl<T, R> UseCallback<T, R> {
+    /// Call the callback
+    ///
+    /// # Panics
+    ///
+    /// If the callback calls itself
+    pub fn call(&self, arg: T) -> R {
+        (self.callback.borrow_mut())(arg)
+    }
+}
+
+impl<T, R> Clone for UseCallback<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
         }
-    });
+    }
+}
+
+impl<T, R> PartialEq for UseCallback<T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn callback_only_changes_with_its_dependencies() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static MULTIPLIER: Cell<u32> = Cell::new(2);
+            static CALLBACKS: RefCell<Vec<UseCallback<u32, u32>>> = RefCell::new(Vec::new());
+        }
+
+        fn app(cx: Scope) -> Element {
+            let multiplier = MULTIPLIER.with(Cell::get);
+            let double = use_callback(cx, (&multiplier,), move |n| n * multiplier);
+            CALLBACKS.with(|c| c.borrow_mut().push(double.clone()));
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
+
+        MULTIPLIER.with(|m| m.set(3));
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
 
-    // let p = use_callback!(cx, || |evt| async {
-    //     //
-    // });
+        CALLBACKS.with(|c| {
+            let c = c.borrow();
+            assert!(c[0] == c[1]);
+            assert!(c[1] != c[2]);
+            assert_eq!(c[1].call(5), 10);
+            assert_eq!(c[2].call(5), 15);
+        });
+    }
 }
@@ -0,0 +1,176 @@
+use dioxus_core::ScopeState;
+use std::{fmt, future::Future, rc::Rc};
+
+/// Read and write text on the system clipboard.
+///
+/// The returned futures don't borrow the handle, so they can be spawned directly with `cx.spawn`. How the clipboard
+/// is accessed depends on the renderer:
+/// - web: `navigator.clipboard`, which browsers only allow in secure contexts and, for reading, after the user grants
+///   permission
+/// - desktop: the native clipboard
+/// - tui: OSC 52 escape sequences, which most terminals support for writing but not for reading, so reading returns
+///   the text the app copied last. With the `arboard` feature of dioxus-tui, the local clipboard is used as well.
+///
+/// If the renderer doesn't support the clipboard, every operation fails with [`ClipboardError::Unsupported`].
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let clipboard = use_clipboard(cx);
+///
+///     cx.render(rsx! {
+///         button {
+///             onclick: move |_| {
+///                 let write = clipboard.write_text("Hello from Dioxus!");
+///                 cx.spawn(async move {
+///                     if let Err(err) = write.await {
+///                         log::error!("Could not copy: {err}");
+///                     }
+///                 });
+///             },
+///             "Copy"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_clipboard(cx: &ScopeState) -> &UseClipboard {
+    cx.use_hook(|| UseClipboard {
+        clipboard: cx.consume_context::<Clipboard>(),
+    })
+}
+
+/// A handle to the clipboard created with [`use_clipboard`]
+#[derive(Clone)]
+pub struct UseClipboard {
+    clipboard: Option<Clipboard>,
+}
+
+impl UseClipboard {
+    /// Read the text currently on the clipboard
+    pub fn read_text(&self) -> impl Future<Output = Result<String, ClipboardError>> + 'static {
+        let backend = self.backend();
+        async move { backend?.read_text().await }
+    }
+
+    /// Replace the contents of the clipboard with `text`
+    pub fn write_text(
+        &self,
+        text: impl Into<String>,
+    ) -> impl Future<Output = Result<(), ClipboardError>> + 'static {
+        let backend = self.backend();
+        let text = text.into();
+        async move { backend?.write_text(text).await }
+    }
+
+    fn backend(&self) -> Result<Rc<dyn ClipboardBackend>, ClipboardError> {
+        self.clipboard
+            .as_ref()
+            .map(|clipboard| clipboard.backend.clone())
+            .ok_or(ClipboardError::Unsupported)
+    }
+}
+
+/// Why reading or writing the clipboard failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The renderer can't perform this operation on the clipboard
+    Unsupported,
+    /// The platform refused or failed the operation
+    Failed(String),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClipboardError::Unsupported => {
+                write!(f, "the clipboard is not supported by this renderer")
+            }
+            ClipboardError::Failed(reason) => write!(f, "clipboard operation failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// How a renderer accesses the clipboard for [`use_clipboard`]
+#[async_trait::async_trait(?Send)]
+pub trait ClipboardBackend {
+    /// Read the text currently on the clipboard
+    async fn read_text(&self) -> Result<String, ClipboardError>;
+
+    /// Replace the contents of the clipboard with `text`
+    async fn write_text(&self, text: String) -> Result<(), ClipboardError>;
+}
+
+/// The clipboard backend of the renderer, shared through the context API
+#[derive(Clone)]
+pub struct Clipboard {
+    backend: Rc<dyn ClipboardBackend>,
+}
+
+impl Clipboard {
+    /// Access the clipboard through the given backend
+    pub fn new(backend: impl ClipboardBackend + 'static) -> Self {
+        Self {
+            backend: Rc::new(backend),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct FakeClipboard {
+        text: RefCell<String>,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl ClipboardBackend for Rc<FakeClipboard> {
+        async fn read_text(&self) -> Result<String, ClipboardError> {
+            Ok(self.text.borrow().clone())
+        }
+
+        async fn write_text(&self, text: String) -> Result<(), ClipboardError> {
+            *self.text.borrow_mut() = text;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_back_what_was_written() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static HANDLE: RefCell<Option<UseClipboard>> = RefCell::new(None);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let clipboard = use_clipboard(cx);
+            HANDLE.with(|handle| *handle.borrow_mut() = Some(clipboard.clone()));
+
+            cx.render(rsx!(()))
+        }
+
+        let fake = Rc::new(FakeClipboard::default());
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope()
+            .provide_context(Clipboard::new(fake.clone()));
+        let _ = dom.rebuild();
+
+        let clipboard = HANDLE.with(|handle| handle.borrow().clone().unwrap());
+        clipboard.write_text("copied").await.unwrap();
+        assert_eq!(*fake.text.borrow(), "copied");
+        assert_eq!(clipboard.read_text().await.unwrap(), "copied");
+
+        let unsupported = UseClipboard { clipboard: None };
+        assert_eq!(
+            unsupported.read_text().await,
+            Err(ClipboardError::Unsupported)
+        );
+    }
+}
@@ -1,6 +1,12 @@
-use dioxus_core::{ScopeState, TaskId};
+use dioxus_core::{ScopeId, ScopeState, TaskId};
 pub use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
-use std::future::Future;
+use std::{
+    cell::{Cell, Ref, RefCell},
+    collections::{HashMap, VecDeque},
+    future::Future,
+    rc::Rc,
+    sync::Arc,
+};
 
 /// Maintain a handle over a future that can be paused, resumed, and canceled.
 ///
@@ -28,11 +34,12 @@ use std::future::Future;
 /// global data in a coroutine, and then access display-level values from the rest
 /// of our app through atoms.
 ///
-/// ## UseCallback instead
+/// ## use_callback! instead
 ///
 /// However, you must plan out your own concurrency and synchronization. If you
-/// don't care about actions in your app being synchronized, you can use [`use_callback`]
-/// hook to spawn multiple tasks and run them concurrently.
+/// don't care about actions in your app being synchronized, you can use the
+/// [`use_callback!`](crate::use_callback!) macro to spawn a task for every event
+/// and run them concurrently.
 ///
 /// ## Example
 ///
@@ -114,6 +121,257 @@ impl<T> PartialEq for Coroutine<T> {
     }
 }
 
+/// A coroutine that can also send replies back to the components that hold its handle.
+///
+/// This is [`use_coroutine`] with a second, typed channel running in the other direction. Every message sent to the
+/// coroutine is tagged with a [`RequestId`], and the coroutine files its replies under that id with the
+/// [`ReplySender`]. Components only read the replies to the requests they sent, so two components using the same
+/// coroutine at once never take each other's replies. Every component holding the handle - the one that created it
+/// and any child that calls [`use_duplex_coroutine_handle`] - re-renders when a reply arrives, so there's no need for
+/// a side-channel `UseState`.
+///
+/// The replies to each request are buffered according to the [`ReplyBuffer`] policy until a component takes them.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// enum Command {
+///     Fetch(u32),
+/// }
+///
+/// let loader = use_duplex_coroutine(
+///     cx,
+///     ReplyBuffer::Latest,
+///     |mut rx: UnboundedReceiver<Request<Command>>, replies: ReplySender<String>| async move {
+///         while let Some(Request { id, msg: Command::Fetch(post) }) = rx.next().await {
+///             replies.send(id, fetch_title(post).await);
+///         }
+///     },
+/// );
+/// let request = use_state(cx, || None);
+///
+/// cx.render(rsx! {
+///     button { onclick: move |_| request.set(Some(loader.send(Command::Fetch(1)))), "Load" }
+///     if let Some(title) = request.and_then(|id| loader.last_reply(id)) {
+///         rsx!("{title}")
+///     }
+/// })
+/// ```
+pub fn use_duplex_coroutine<M, R, G, F>(
+    cx: &ScopeState,
+    buffer: ReplyBuffer,
+    init: G,
+) -> &DuplexCoroutine<M, R>
+where
+    M: 'static,
+    R: 'static,
+    G: FnOnce(UnboundedReceiver<Request<M>>, ReplySender<R>) -> F,
+    F: Future<Output = ()> + 'static,
+{
+    let (handle, _subscription) = cx.use_hook(|| {
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        let replies = Rc::new(ReplyChannel {
+            buffer,
+            next_request: Cell::new(0),
+            queues: RefCell::new(HashMap::new()),
+            subscribers: RefCell::new(HashMap::new()),
+        });
+
+        let task = cx.push_future(init(
+            rx,
+            ReplySender {
+                channel: replies.clone(),
+            },
+        ));
+
+        let handle = cx.provide_context(DuplexCoroutine {
+            coroutine: Coroutine { tx, task },
+            replies,
+        });
+        let subscription = handle.subscribe(cx);
+
+        (handle, subscription)
+    });
+
+    handle
+}
+
+/// Get a handle to a duplex coroutine higher in the tree
+///
+/// The component re-renders whenever the coroutine sends a reply. See the docs for [`use_duplex_coroutine`] for more
+/// details.
+pub fn use_duplex_coroutine_handle<M: 'static, R: 'static>(
+    cx: &ScopeState,
+) -> Option<&DuplexCoroutine<M, R>> {
+    cx.use_hook(|| {
+        cx.consume_context::<DuplexCoroutine<M, R>>().map(|handle| {
+            let subscription = handle.subscribe(cx);
+            (handle, subscription)
+        })
+    })
+    .as_ref()
+    .map(|(handle, _)| handle)
+}
+
+/// How many replies to each request a [`DuplexCoroutine`] keeps until they are taken
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplyBuffer {
+    /// Only keep the most recent reply
+    Latest,
+
+    /// Keep up to this many replies, dropping the oldest one when the buffer is full
+    ///
+    /// Sending never waits for a reply to be taken, so a capacity of zero keeps the most recent reply like
+    /// [`ReplyBuffer::Latest`].
+    Bounded(usize),
+
+    /// Keep every reply until it is taken
+    Unbounded,
+}
+
+/// Identifies a message sent to a [`DuplexCoroutine`]. The coroutine sends the replies to the message under this id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestId(usize);
+
+/// A message received by a [`use_duplex_coroutine`], with the id to send its replies under
+#[derive(Debug)]
+pub struct Request<M> {
+    /// The id the replies to this message are filed under
+    pub id: RequestId,
+
+    /// The message a component sent
+    pub msg: M,
+}
+
+struct ReplyChannel<R> {
+    buffer: ReplyBuffer,
+    next_request: Cell<usize>,
+    queues: RefCell<HashMap<RequestId, VecDeque<R>>>,
+    subscribers: RefCell<HashMap<ScopeId, Arc<dyn Fn()>>>,
+}
+
+/// The coroutine's end of the reply channel of a [`use_duplex_coroutine`]
+pub struct ReplySender<R> {
+    channel: Rc<ReplyChannel<R>>,
+}
+
+impl<R> Clone for ReplySender<R> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<R> ReplySender<R> {
+    /// Send a reply to a request and re-render every component holding the coroutine's handle
+    pub fn send(&self, request: RequestId, reply: R) {
+        {
+            let mut queues = self.channel.queues.borrow_mut();
+            let queue = queues.entry(request).or_default();
+            match self.channel.buffer {
+                ReplyBuffer::Latest => queue.clear(),
+                ReplyBuffer::Bounded(capacity) => {
+                    while !queue.is_empty() && queue.len() >= capacity {
+                        queue.pop_front();
+                    }
+                }
+                ReplyBuffer::Unbounded => {}
+            }
+            queue.push_back(reply);
+        }
+
+        for update in self.channel.subscribers.borrow().values() {
+            update();
+        }
+    }
+}
+
+/// A handle to a coroutine created with [`use_duplex_coroutine`]
+pub struct DuplexCoroutine<M, R> {
+    coroutine: Coroutine<Request<M>>,
+    replies: Rc<ReplyChannel<R>>,
+}
+
+// for use in futures
+impl<M, R> Clone for DuplexCoroutine<M, R> {
+    fn clone(&self) -> Self {
+        Self {
+            coroutine: self.coroutine.clone(),
+            replies: self.replies.clone(),
+        }
+    }
+}
+
+impl<M, R> DuplexCoroutine<M, R> {
+    /// Get the ID of this coroutine
+    #[must_use]
+    pub fn task_id(&self) -> TaskId {
+        self.coroutine.task_id()
+    }
+
+    /// Send a message to the coroutine, returning the id its replies will be sent under
+    pub fn send(&self, msg: M) -> RequestId {
+        let id = RequestId(self.replies.next_request.get());
+        self.replies.next_request.set(id.0 + 1);
+        self.coroutine.send(Request { id, msg });
+        id
+    }
+
+    /// The buffered replies to a request, oldest first, without taking them
+    pub fn replies(&self, request: RequestId) -> Option<Ref<VecDeque<R>>> {
+        Ref::filter_map(self.replies.queues.borrow(), |queues| queues.get(&request)).ok()
+    }
+
+    /// The most recent buffered reply to a request, without taking it
+    pub fn last_reply(&self, request: RequestId) -> Option<Ref<R>> {
+        Ref::filter_map(self.replies.queues.borrow(), |queues| {
+            queues.get(&request)?.back()
+        })
+        .ok()
+    }
+
+    /// Take every buffered reply to a request, oldest first
+    pub fn take_replies(&self, request: RequestId) -> Vec<R> {
+        self.replies
+            .queues
+            .borrow_mut()
+            .remove(&request)
+            .map(Vec::from)
+            .unwrap_or_default()
+    }
+
+    fn subscribe(&self, cx: &ScopeState) -> ReplySubscription<R> {
+        self.replies
+            .subscribers
+            .borrow_mut()
+            .insert(cx.scope_id(), cx.schedule_update());
+
+        ReplySubscription {
+            channel: self.replies.clone(),
+            scope: cx.scope_id(),
+        }
+    }
+}
+
+impl<M, R> PartialEq for DuplexCoroutine<M, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coroutine == other.coroutine
+    }
+}
+
+// Stops re-rendering a component once the hook that subscribed it is dropped
+struct ReplySubscription<R> {
+    channel: Rc<ReplyChannel<R>>,
+    scope: ScopeId,
+}
+
+impl<R> Drop for ReplySubscription<R> {
+    fn drop(&mut self) {
+        self.channel.subscribers.borrow_mut().remove(&self.scope);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(unused)]
@@ -156,4 +414,49 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn replies_rerender_the_component() {
+        use dioxus::prelude::rsx;
+        use std::cell::Cell;
+
+        thread_local! {
+            static SEEN: Cell<Option<(usize, i32)>> = Cell::new(None);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let co = use_duplex_coroutine(
+                cx,
+                ReplyBuffer::Bounded(2),
+                |mut rx: UnboundedReceiver<Request<i32>>, replies: ReplySender<i32>| async move {
+                    while let Some(Request { id, msg }) = rx.next().await {
+                        for n in 1..=3 {
+                            replies.send(id, msg * n);
+                        }
+                    }
+                },
+            );
+            let first = *cx.use_hook(|| co.send(1));
+            let second = *cx.use_hook(|| co.send(10));
+
+            // Each request only sees its own replies
+            let last = co.last_reply(first).map(|reply| *reply);
+            SEEN.with(|seen| seen.set(last.map(|last| (co.replies(first).unwrap().len(), last))));
+            if last.is_some() {
+                assert_eq!(co.take_replies(second), [20, 30]);
+                assert!(co.replies(second).is_none());
+            }
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        assert_eq!(SEEN.with(Cell::get), None);
+
+        // Poll the coroutine, which replies and marks the component dirty
+        dom.process_events();
+        let _ = dom.render_immediate();
+        assert_eq!(SEEN.with(Cell::get), Some((2, 3)));
+    }
 }
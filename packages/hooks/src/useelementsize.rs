@@ -0,0 +1,111 @@
+use dioxus_core::ScopeState;
+use std::{cell::Cell, rc::Rc, sync::Arc};
+
+/// Track the size of an element, re-rendering the component whenever it changes.
+///
+/// Pass the size of the `onresize` event to [`UseElementSize::set`]. The event fires once the element has been laid
+/// out, and again every time its size changes:
+/// - web and desktop: a `ResizeObserver` watching the element's content box, in CSS pixels
+/// - tui: the element's layout, in terminal cells
+///
+/// Until the first event arrives the size is unknown, so [`UseElementSize::size`] returns `None` and the width and
+/// height are `0.0`.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let size = use_element_size(cx);
+///
+///     cx.render(rsx! {
+///         div {
+///             onresize: move |evt| size.set(evt.width, evt.height),
+///             "{size.width()} x {size.height()}"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_element_size(cx: &ScopeState) -> &UseElementSize {
+    cx.use_hook(|| UseElementSize {
+        size: Rc::new(Cell::new(None)),
+        update: cx.schedule_update(),
+    })
+}
+
+/// The size of an element tracked with [`use_element_size`]
+#[derive(Clone)]
+pub struct UseElementSize {
+    size: Rc<Cell<Option<(f64, f64)>>>,
+    update: Arc<dyn Fn()>,
+}
+
+impl UseElementSize {
+    /// Record the element's new size, re-rendering the component if it changed
+    pub fn set(&self, width: f64, height: f64) {
+        if self.size.replace(Some((width, height))) != Some((width, height)) {
+            (self.update)();
+        }
+    }
+
+    /// The width and height of the element, or `None` if it hasn't been laid out yet
+    pub fn size(&self) -> Option<(f64, f64)> {
+        self.size.get()
+    }
+
+    /// The width of the element, or `0.0` if it hasn't been laid out yet
+    pub fn width(&self) -> f64 {
+        self.size().map_or(0.0, |(width, _)| width)
+    }
+
+    /// The height of the element, or `0.0` if it hasn't been laid out yet
+    pub fn height(&self) -> f64 {
+        self.size().map_or(0.0, |(_, height)| height)
+    }
+}
+
+impl PartialEq for UseElementSize {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.size, &other.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn only_rerenders_when_the_size_changes() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static HANDLE: RefCell<Option<UseElementSize>> = RefCell::new(None);
+            static RENDERS: Cell<usize> = Cell::new(0);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let size = use_element_size(cx);
+            HANDLE.with(|handle| *handle.borrow_mut() = Some(size.clone()));
+            RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        let size = HANDLE.with(|handle| handle.borrow().clone().unwrap());
+        assert_eq!(size.size(), None);
+
+        size.set(320.0, 240.0);
+        dom.process_events();
+        let _ = dom.render_immediate();
+        assert_eq!(RENDERS.with(Cell::get), 2);
+        assert_eq!((size.width(), size.height()), (320.0, 240.0));
+
+        size.set(320.0, 240.0);
+        dom.process_events();
+        let _ = dom.render_immediate();
+        assert_eq!(RENDERS.with(Cell::get), 2);
+    }
+}
@@ -0,0 +1,311 @@
+use dioxus_core::ScopeState;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+/// Listen to an event on the window or the document, outside of the component's own elements.
+///
+/// `target` and `event` are fixed when the component first renders, but the handler may change between renders: an
+/// event always reaches the one passed most recently. The listener is removed when the component is unmounted.
+///
+/// `T` is the data the event carries, which is the same as for the element listener of the same name: `keydown`
+/// carries [`KeyboardData`](https://docs.rs/dioxus-html/latest/dioxus_html/struct.KeyboardData.html), `mousemove`
+/// carries `MouseData`, and so on. Events without any data, like `resize` and `visibilitychange`, carry `()`.
+///
+/// Renderers provide a [`GlobalListeners`] context that decides which events can be listened to:
+/// - web: any event the browser fires on the window or document
+/// - desktop: any event the webview fires on the window or document
+/// - tui: `keydown` on either target, and `resize` on the window
+///
+/// If the renderer doesn't support global listeners, a warning is logged and the handler is never called.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let last_key = use_state(cx, String::new);
+///
+///     use_event_listener(cx, ListenerTarget::Document, "keydown", {
+///         to_owned![last_key];
+///         move |evt: Rc<KeyboardData>| last_key.set(evt.key().to_string())
+///     });
+///
+///     cx.render(rsx!("Last key: {last_key}"))
+/// }
+/// ```
+pub fn use_event_listener<T: 'static>(
+    cx: &ScopeState,
+    target: ListenerTarget,
+    event: &'static str,
+    mut handler: impl FnMut(Rc<T>) + 'static,
+) {
+    let mut handler: Option<Box<dyn FnMut(Rc<dyn Any>)>> =
+        Some(Box::new(move |data: Rc<dyn Any>| {
+            match data.downcast::<T>() {
+                Ok(data) => handler(data),
+                Err(_) => log::warn!(
+                    "The {event:?} listener expected {} but the event carried different data",
+                    std::any::type_name::<T>()
+                ),
+            }
+        }));
+
+    let slot = cx.use_hook(|| {
+        let slot = Rc::new(RefCell::new(handler.take().unwrap()));
+
+        match cx.consume_context::<GlobalListeners>() {
+            Some(listeners) => {
+                let id = listeners.add(target, event, slot.clone());
+                cx.on_drop(move || listeners.remove(id));
+            }
+            None => log::warn!(
+                "Could not listen to {event:?} on the {}: this renderer doesn't support global listeners",
+                target.as_str()
+            ),
+        }
+
+        slot
+    });
+
+    if let Some(handler) = handler {
+        *slot.borrow_mut() = handler;
+    }
+}
+
+/// Something outside of the component tree that [`use_event_listener`] can listen to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ListenerTarget {
+    /// The browser window, or the terminal for tui
+    Window,
+    /// The document
+    Document,
+}
+
+impl ListenerTarget {
+    /// The name of the target in JavaScript, either `"window"` or `"document"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListenerTarget::Window => "window",
+            ListenerTarget::Document => "document",
+        }
+    }
+}
+
+type Handler = Rc<RefCell<Box<dyn FnMut(Rc<dyn Any>)>>>;
+
+struct Listener {
+    id: usize,
+    target: ListenerTarget,
+    event: &'static str,
+    handler: Handler,
+}
+
+struct ListenersInner {
+    backend: Box<dyn ListenerBackend>,
+    listeners: RefCell<Vec<Listener>>,
+    next_id: Cell<usize>,
+}
+
+/// The global listeners registered with [`use_event_listener`], shared through the context API
+///
+/// Renderers provide one on the root scope and call [`GlobalListeners::dispatch`] when an event fires on a target.
+#[derive(Clone)]
+pub struct GlobalListeners {
+    inner: Rc<ListenersInner>,
+}
+
+impl GlobalListeners {
+    /// Create the listeners for a renderer
+    pub fn new(backend: impl ListenerBackend + 'static) -> Self {
+        Self {
+            inner: Rc::new(ListenersInner {
+                backend: Box::new(backend),
+                listeners: Default::default(),
+                next_id: Cell::new(0),
+            }),
+        }
+    }
+
+    /// Call every handler listening to `event` on `target` with the event's data
+    pub fn dispatch(&self, target: ListenerTarget, event: &str, data: Rc<dyn Any>) {
+        dispatch(&self.inner, target, event, data)
+    }
+
+    /// `true` if any component is listening to `event` on `target`
+    pub fn is_listening(&self, target: ListenerTarget, event: &str) -> bool {
+        self.inner
+            .listeners
+            .borrow()
+            .iter()
+            .any(|l| l.target == target && l.event == event)
+    }
+
+    fn add(&self, target: ListenerTarget, event: &'static str, handler: Handler) -> usize {
+        let first = !self.is_listening(target, event);
+
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id + 1);
+        self.inner.listeners.borrow_mut().push(Listener {
+            id,
+            target,
+            event,
+            handler,
+        });
+
+        if first {
+            let forwarder = EventForwarder {
+                listeners: Rc::downgrade(&self.inner),
+                target,
+                event,
+            };
+            self.inner.backend.listen(target, event, forwarder);
+        }
+
+        id
+    }
+
+    fn remove(&self, id: usize) {
+        let removed = {
+            let mut listeners = self.inner.listeners.borrow_mut();
+            listeners
+                .iter()
+                .position(|l| l.id == id)
+                .map(|idx| listeners.remove(idx))
+        };
+
+        if let Some(removed) = removed {
+            if !self.is_listening(removed.target, removed.event) {
+                self.inner.backend.unlisten(removed.target, removed.event);
+            }
+        }
+    }
+}
+
+fn dispatch(inner: &ListenersInner, target: ListenerTarget, event: &str, data: Rc<dyn Any>) {
+    // Collect the handlers first so they can add or remove listeners while they run
+    let handlers: Vec<Handler> = inner
+        .listeners
+        .borrow()
+        .iter()
+        .filter(|l| l.target == target && l.event == event)
+        .map(|l| l.handler.clone())
+        .collect();
+
+    for handler in handlers {
+        match handler.try_borrow_mut() {
+            Ok(mut handler) => handler(data.clone()),
+            Err(_) => log::warn!("Skipping a {event:?} listener that is already running"),
+        }
+    }
+}
+
+/// How a renderer attaches listeners to the window and the document
+///
+/// [`GlobalListeners`] only calls `listen` when the first component starts listening to an event on a target, and
+/// `unlisten` when the last one stops, so renderers don't need to count listeners themselves.
+pub trait ListenerBackend {
+    /// Start sending `event` on `target` to the listeners. Renderers that dispatch events themselves can ignore the
+    /// forwarder.
+    fn listen(&self, target: ListenerTarget, event: &str, forwarder: EventForwarder);
+
+    /// Stop sending `event` on `target` to the listeners
+    fn unlisten(&self, target: ListenerTarget, event: &str);
+}
+
+/// Sends one kind of event to the [`GlobalListeners`] that asked for it
+///
+/// The forwarder doesn't keep the listeners alive, so it can be stored in a callback owned by the renderer.
+#[derive(Clone)]
+pub struct EventForwarder {
+    listeners: Weak<ListenersInner>,
+    target: ListenerTarget,
+    event: &'static str,
+}
+
+impl EventForwarder {
+    /// Call every handler listening to this event with the event's data
+    pub fn forward(&self, data: Rc<dyn Any>) {
+        if let Some(listeners) = self.listeners.upgrade() {
+            dispatch(&listeners, self.target, self.event, data)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+
+    #[derive(Default)]
+    struct RecordingBackend {
+        listening: RefCell<Vec<(ListenerTarget, String)>>,
+    }
+
+    impl ListenerBackend for Rc<RecordingBackend> {
+        fn listen(&self, target: ListenerTarget, event: &str, _: EventForwarder) {
+            self.listening
+                .borrow_mut()
+                .push((target, event.to_string()));
+        }
+
+        fn unlisten(&self, target: ListenerTarget, event: &str) {
+            self.listening
+                .borrow_mut()
+                .retain(|(t, e)| (*t, e.as_str()) != (target, event));
+        }
+    }
+
+    #[test]
+    fn listeners_are_removed_on_unmount() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static SHOW_CHILD: Cell<bool> = Cell::new(true);
+            static HEARD: Cell<usize> = Cell::new(0);
+        }
+
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx! {
+                if SHOW_CHILD.with(Cell::get) {
+                    rsx!(Child {})
+                }
+            })
+        }
+
+        #[allow(non_snake_case)]
+        fn Child(cx: Scope) -> Element {
+            use_event_listener(cx, ListenerTarget::Window, "resize", |_: Rc<()>| {
+                HEARD.with(|heard| heard.set(heard.get() + 1))
+            });
+
+            cx.render(rsx!(()))
+        }
+
+        let backend = Rc::new(RecordingBackend::default());
+        let listeners = GlobalListeners::new(backend.clone());
+
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope().provide_context(listeners.clone());
+        let _ = dom.rebuild();
+
+        assert_eq!(
+            *backend.listening.borrow(),
+            [(ListenerTarget::Window, "resize".to_string())]
+        );
+
+        listeners.dispatch(ListenerTarget::Window, "resize", Rc::new(()));
+        listeners.dispatch(ListenerTarget::Document, "resize", Rc::new(()));
+        assert_eq!(HEARD.with(Cell::get), 1);
+
+        // Unmount the child
+        SHOW_CHILD.with(|show| show.set(false));
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
+
+        assert!(backend.listening.borrow().is_empty());
+        listeners.dispatch(ListenerTarget::Window, "resize", Rc::new(()));
+        assert_eq!(HEARD.with(Cell::get), 1);
+    }
+}
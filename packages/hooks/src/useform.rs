@@ -0,0 +1,657 @@
+use dioxus_core::ScopeState;
+use futures_channel::mpsc::{unbounded, UnboundedSender};
+use futures_util::{
+    future::{join_all, select, Either},
+    stream::FuturesUnordered,
+    StreamExt,
+};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    rc::{Rc, Weak},
+    str::FromStr,
+    sync::Arc,
+};
+
+/// Track the state of a form: the value of each field, whether it has been changed or visited, and whether it is valid.
+///
+/// Fields are registered by name with [`UseForm::field`] on every render. Each field is typed, and inputs that produce
+/// strings can be bound with [`Field::set_from_str`], which reports values that don't parse as errors on the field.
+///
+/// Validators run every time a field changes. Synchronous validators run first, and async validators only run once
+/// they pass, all at the same time. [`UseForm::on_submit`] only calls its handler if every field is valid and no
+/// validation is in progress.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn Signup(cx: Scope) -> Element {
+///     let form = use_form(cx);
+///
+///     let email = form
+///         .field("email", String::new)
+///         .validate(|email| match email.contains('@') {
+///             true => Ok(()),
+///             false => Err("Enter a valid email".to_string()),
+///         })
+///         .validate_async(|email| async move { check_available(&email).await });
+///
+///     let age = form.field("age", || 18u32).validate(|age| match *age >= 13 {
+///         true => Ok(()),
+///         false => Err("You must be at least 13".to_string()),
+///     });
+///
+///     cx.render(rsx! {
+///         form {
+///             onsubmit: move |_| {
+///                 form.on_submit(|| save(email.value(), age.value()));
+///             },
+///             input {
+///                 value: "{email.value()}",
+///                 oninput: move |evt| email.set(evt.value.clone()),
+///                 onblur: move |_| email.touch(),
+///             }
+///             if email.is_touched() {
+///                 rsx!(email.error().map(|err| rsx!(p { "{err}" })))
+///             }
+///             input {
+///                 r#type: "number",
+///                 value: "{age.value()}",
+///                 oninput: move |evt| age.set_from_str(&evt.value),
+///             }
+///             button { disabled: form.is_validating(), "Sign up" }
+///         }
+///     })
+/// }
+/// ```
+pub fn use_form(cx: &ScopeState) -> &UseForm {
+    cx.use_hook(|| {
+        let (validations, mut rx) = unbounded::<Validation>();
+
+        // Async validations run side by side, so a slow check on one field doesn't hold up the others
+        cx.push_future(async move {
+            let mut running = FuturesUnordered::new();
+            loop {
+                let queued = match running.is_empty() {
+                    true => rx.next().await,
+                    false => match select(rx.next(), running.next()).await {
+                        Either::Left((queued, _)) => queued,
+                        Either::Right(_) => continue,
+                    },
+                };
+                match queued {
+                    Some(validation) => running.push(validation),
+                    None => return,
+                }
+            }
+        });
+
+        UseForm {
+            inner: Rc::new(FormInner {
+                fields: RefCell::new(Vec::new()),
+                update: cx.schedule_update(),
+                validations,
+            }),
+        }
+    })
+}
+
+type Validation = Pin<Box<dyn Future<Output = ()>>>;
+
+struct FormInner {
+    fields: RefCell<Vec<Rc<dyn ErasedField>>>,
+    update: Arc<dyn Fn()>,
+    validations: UnboundedSender<Validation>,
+}
+
+/// The state of a form created with [`use_form`]
+#[derive(Clone)]
+pub struct UseForm {
+    inner: Rc<FormInner>,
+}
+
+impl UseForm {
+    /// Register a field, or get the field that was registered under `name` on a previous render.
+    ///
+    /// `init` is only called the first time. Validators are registered again on every render, so they can capture the
+    /// latest values of the component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field with the same name was registered with a different type.
+    pub fn field<T: Clone + PartialEq + 'static>(
+        &self,
+        name: &'static str,
+        init: impl FnOnce() -> T,
+    ) -> Field<T> {
+        let existing = self
+            .inner
+            .fields
+            .borrow()
+            .iter()
+            .find(|field| field.name() == name)
+            .cloned();
+
+        let field = match existing {
+            Some(field) => field.as_any().downcast::<FieldInner<T>>().unwrap_or_else(|_| {
+                panic!(
+                    "The form field {name:?} was registered as a {}, but it already has a different type",
+                    std::any::type_name::<T>()
+                )
+            }),
+            None => {
+                let initial = init();
+                let field = Rc::new(FieldInner {
+                    name,
+                    value: RefCell::new(initial.clone()),
+                    initial,
+                    touched: Cell::new(false),
+                    validated: Cell::new(false),
+                    validating: Cell::new(false),
+                    generation: Cell::new(0),
+                    input_error: RefCell::new(None),
+                    error: RefCell::new(None),
+                    validators: RefCell::new(Vec::new()),
+                    async_validators: RefCell::new(Vec::new()),
+                    form: Rc::downgrade(&self.inner),
+                });
+                self.inner.fields.borrow_mut().push(field.clone());
+                field
+            }
+        };
+
+        field.validators.borrow_mut().clear();
+        field.async_validators.borrow_mut().clear();
+
+        Field { inner: field }
+    }
+
+    /// `true` if no field has an error and no validation is in progress.
+    ///
+    /// Fields that haven't been changed yet haven't been validated either, so they count as valid until they change or
+    /// the form is submitted.
+    pub fn is_valid(&self) -> bool {
+        self.inner
+            .fields
+            .borrow()
+            .iter()
+            .all(|field| field.error().is_none() && !field.is_validating())
+    }
+
+    /// `true` if any field is different from its initial value
+    pub fn is_dirty(&self) -> bool {
+        self.inner
+            .fields
+            .borrow()
+            .iter()
+            .any(|field| field.is_dirty())
+    }
+
+    /// `true` while any async validator is running
+    pub fn is_validating(&self) -> bool {
+        self.inner
+            .fields
+            .borrow()
+            .iter()
+            .any(|field| field.is_validating())
+    }
+
+    /// Validate the whole form, and call `handler` if it is valid.
+    ///
+    /// Every field is marked as touched so its errors can be shown. Fields that were never changed are validated now.
+    /// Returns what the handler returned, or `None` if the form isn't valid yet, including while async validators are
+    /// still running. If a field with async validators was never changed, submitting starts them, and the form can be
+    /// submitted again once they finish.
+    pub fn on_submit<R>(&self, handler: impl FnOnce() -> R) -> Option<R> {
+        let fields = self.inner.fields.borrow().clone();
+        for field in fields {
+            field.mark_touched();
+            field.ensure_validated();
+        }
+
+        let result = self.is_valid().then(handler);
+        (self.inner.update)();
+        result
+    }
+
+    /// Put every field back to its initial value, and clear all errors and touched flags
+    pub fn reset(&self) {
+        let fields = self.inner.fields.borrow().clone();
+        for field in &fields {
+            field.reset();
+        }
+        (self.inner.update)();
+    }
+}
+
+impl PartialEq for UseForm {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+type Validator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+type AsyncValidator<T> = Rc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+struct FieldInner<T> {
+    name: &'static str,
+    initial: T,
+    value: RefCell<T>,
+    touched: Cell<bool>,
+    validated: Cell<bool>,
+    validating: Cell<bool>,
+    // Bumped on every change so async validators can tell if their result is out of date
+    generation: Cell<usize>,
+    // The last input that could not be parsed, kept apart from the validators' errors
+    input_error: RefCell<Option<String>>,
+    error: RefCell<Option<String>>,
+    validators: RefCell<Vec<Validator<T>>>,
+    async_validators: RefCell<Vec<AsyncValidator<T>>>,
+    form: Weak<FormInner>,
+}
+
+impl<T: Clone + 'static> FieldInner<T> {
+    fn update(&self) {
+        if let Some(form) = self.form.upgrade() {
+            (form.update)();
+        }
+    }
+
+    fn validate(self: Rc<Self>) {
+        let generation = self.generation.get() + 1;
+        self.generation.set(generation);
+        self.validated.set(true);
+
+        let value = self.value.borrow().clone();
+        let error = self
+            .validators
+            .borrow()
+            .iter()
+            .find_map(|validator| validator(&value).err());
+
+        let async_validators = self.async_validators.borrow().clone();
+        let run_async = error.is_none() && !async_validators.is_empty();
+        *self.error.borrow_mut() = error;
+        self.validating.set(run_async);
+
+        if !run_async {
+            return;
+        }
+
+        let form = match self.form.upgrade() {
+            Some(form) => form,
+            None => return,
+        };
+
+        let field = self;
+        let validation = async move {
+            // The validators of the field all run at once, and the first one to fail, in the order they were added,
+            // sets the error
+            let checks = async_validators
+                .iter()
+                .map(|validator| validator(value.clone()));
+            let results = join_all(checks).await;
+
+            if field.generation.get() == generation {
+                *field.error.borrow_mut() = results.into_iter().find_map(Result::err);
+                field.validating.set(false);
+                field.update();
+            }
+        };
+
+        let _ = form.validations.unbounded_send(Box::pin(validation));
+    }
+}
+
+trait ErasedField {
+    fn name(&self) -> &'static str;
+    fn error(&self) -> Option<String>;
+    fn is_validating(&self) -> bool;
+    fn is_dirty(&self) -> bool;
+    fn mark_touched(&self);
+    fn ensure_validated(self: Rc<Self>);
+    fn reset(&self);
+    fn as_any(self: Rc<Self>) -> Rc<dyn Any>;
+}
+
+impl<T: Clone + PartialEq + 'static> ErasedField for FieldInner<T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn error(&self) -> Option<String> {
+        self.input_error
+            .borrow()
+            .clone()
+            .or_else(|| self.error.borrow().clone())
+    }
+
+    fn is_validating(&self) -> bool {
+        self.validating.get()
+    }
+
+    fn is_dirty(&self) -> bool {
+        *self.value.borrow() != self.initial
+    }
+
+    fn mark_touched(&self) {
+        self.touched.set(true);
+    }
+
+    fn ensure_validated(self: Rc<Self>) {
+        if !self.validated.get() {
+            self.validate();
+        }
+    }
+
+    fn reset(&self) {
+        *self.value.borrow_mut() = self.initial.clone();
+        self.generation.set(self.generation.get() + 1);
+        self.touched.set(false);
+        self.validated.set(false);
+        self.validating.set(false);
+        *self.input_error.borrow_mut() = None;
+        *self.error.borrow_mut() = None;
+    }
+
+    fn as_any(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+}
+
+/// A typed field of a [`UseForm`]
+pub struct Field<T> {
+    inner: Rc<FieldInner<T>>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Field<T> {
+    /// Add a validator that runs every time the field changes. The first error returned is shown on the field.
+    pub fn validate(self, validator: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        self.inner.validators.borrow_mut().push(Rc::new(validator));
+        self
+    }
+
+    /// Add a validator that runs in the background after every synchronous validator passes, like checking if a
+    /// username is taken. The async validators of a field run concurrently.
+    ///
+    /// If the field changes again before the validator finishes, its result is ignored.
+    pub fn validate_async<F: Future<Output = Result<(), String>> + 'static>(
+        self,
+        validator: impl Fn(T) -> F + 'static,
+    ) -> Self {
+        self.inner
+            .async_validators
+            .borrow_mut()
+            .push(Rc::new(move |value| Box::pin(validator(value))));
+        self
+    }
+
+    /// The name the field was registered with
+    pub fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    /// A copy of the current value
+    pub fn value(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+
+    /// Read the current value without cloning it
+    pub fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        f(&self.inner.value.borrow())
+    }
+
+    /// Change the value, validate it, and re-render the component
+    pub fn set(&self, value: T) {
+        *self.inner.value.borrow_mut() = value;
+        *self.inner.input_error.borrow_mut() = None;
+        self.inner.clone().validate();
+        self.inner.update();
+    }
+
+    /// Mark the field as visited, usually when it loses focus
+    pub fn touch(&self) {
+        if !self.inner.touched.replace(true) {
+            self.inner.update();
+        }
+    }
+
+    /// `true` once the field has been visited or the form has been submitted
+    pub fn is_touched(&self) -> bool {
+        self.inner.touched.get()
+    }
+
+    /// `true` if the value is different from the initial value
+    pub fn is_dirty(&self) -> bool {
+        ErasedField::is_dirty(&*self.inner)
+    }
+
+    /// `true` while the async validators of the field are running
+    pub fn is_validating(&self) -> bool {
+        self.inner.validating.get()
+    }
+
+    /// The error of the last input that could not be parsed, or else the first error from the validators
+    pub fn error(&self) -> Option<String> {
+        ErasedField::error(&*self.inner)
+    }
+}
+
+impl<T: Clone + PartialEq + FromStr + 'static> Field<T>
+where
+    T::Err: Display,
+{
+    /// Parse the text of an input into the field's type and set it.
+    ///
+    /// If the text doesn't parse, the value is left alone and the parse error is shown on the field until the next
+    /// valid input.
+    pub fn set_from_str(&self, input: &str) {
+        match input.parse() {
+            Ok(value) => self.set(value),
+            Err(err) => {
+                *self.inner.input_error.borrow_mut() = Some(err.to_string());
+                self.inner.update();
+            }
+        }
+    }
+}
+
+impl<T> PartialEq for Field<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{run_until, settle};
+    use dioxus_core::prelude::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn submit_only_fires_when_valid() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static SUBMITTED: RefCell<Vec<(String, u32)>> = RefCell::new(Vec::new());
+        }
+
+        fn app(cx: Scope) -> Element {
+            let form = use_form(cx);
+            let name = form.field("name", String::new).validate(|name| {
+                if name.is_empty() {
+                    Err("Required".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+            let age = form.field("age", || 0u32);
+
+            let submit = || {
+                form.on_submit(|| {
+                    SUBMITTED.with(|s| s.borrow_mut().push((name.value(), age.value())))
+                })
+            };
+
+            cx.use_hook(|| {
+                // The empty name was never changed, but it is still validated on submit
+                assert!(submit().is_none());
+                assert!(name.is_touched());
+                assert_eq!(name.error().as_deref(), Some("Required"));
+
+                name.set("Ferris".to_string());
+                age.set_from_str("old");
+                assert!(age.error().is_some());
+                assert!(submit().is_none());
+
+                age.set_from_str("7");
+                assert!(form.is_dirty());
+                assert!(submit().is_some());
+            });
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+
+        SUBMITTED.with(|s| assert_eq!(*s.borrow(), [("Ferris".to_string(), 7)]));
+    }
+
+    type Handles = Rc<RefCell<Option<(UseForm, Field<String>)>>>;
+
+    struct AsyncForm {
+        handles: Handles,
+        validators: Vec<AsyncValidator<String>>,
+    }
+
+    fn async_form(cx: Scope<AsyncForm>) -> Element {
+        use dioxus::prelude::rsx;
+
+        let form = use_form(cx);
+        let mut field = form.field("name", String::new);
+        for validator in &cx.props.validators {
+            let validator = validator.clone();
+            field = field.validate_async(move |value| validator(value));
+        }
+        *cx.props.handles.borrow_mut() = Some((form.clone(), field));
+
+        cx.render(rsx!(()))
+    }
+
+    /// Mount a form with a single field checked by `validators`
+    fn mount(validators: Vec<AsyncValidator<String>>) -> (VirtualDom, UseForm, Field<String>) {
+        let handles = Handles::default();
+        let mut dom = VirtualDom::new_with_props(
+            async_form,
+            AsyncForm {
+                handles: handles.clone(),
+                validators,
+            },
+        );
+        let _ = dom.rebuild();
+
+        let (form, field) = handles.borrow().clone().unwrap();
+        (dom, form, field)
+    }
+
+    fn validator<F: Future<Output = Result<(), String>> + 'static>(
+        validator: impl Fn(String) -> F + 'static,
+    ) -> AsyncValidator<String> {
+        Rc::new(move |value| Box::pin(validator(value)))
+    }
+
+    /// Wait until `open` is set
+    async fn gate(open: Rc<Cell<bool>>) {
+        while !open.get() {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn async_validators_of_a_field_run_concurrently() {
+        let open = Rc::new(Cell::new(false));
+        // The first validator only finishes once the second one has started
+        let (mut dom, _, name) = mount(vec![
+            validator({
+                let open = open.clone();
+                move |_| gate(open.clone()).map(Ok)
+            }),
+            validator(move |value| {
+                let open = open.clone();
+                async move {
+                    open.set(true);
+                    Err(format!("{value} is taken"))
+                }
+            }),
+        ]);
+
+        name.set("ferris".to_string());
+        assert!(name.is_validating());
+
+        run_until(&mut dom, "the validation", || !name.is_validating()).await;
+        assert_eq!(name.error().as_deref(), Some("ferris is taken"));
+    }
+
+    #[tokio::test]
+    async fn stale_async_results_are_discarded() {
+        let slow_finished = Rc::new(Cell::new(false));
+        let (mut dom, _, name) = mount(vec![validator({
+            let slow_finished = slow_finished.clone();
+            move |value| {
+                let slow_finished = slow_finished.clone();
+                async move {
+                    if value == "slow" {
+                        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                        slow_finished.set(true);
+                        return Err("slow is taken".to_string());
+                    }
+                    Ok(())
+                }
+            }
+        })]);
+
+        // The second value is checked while the first one is still being checked
+        name.set("slow".to_string());
+        name.set("fast".to_string());
+
+        run_until(&mut dom, "the validation", || !name.is_validating()).await;
+        assert_eq!(name.error(), None);
+
+        // The error for the old value arrives after the result for the new one, and is dropped
+        run_until(&mut dom, "the stale validation", || slow_finished.get()).await;
+        settle(&mut dom).await;
+        assert_eq!(name.error(), None);
+        assert!(!name.is_validating());
+    }
+
+    #[tokio::test]
+    async fn submitting_waits_for_async_validation() {
+        let open = Rc::new(Cell::new(false));
+        let (mut dom, form, name) = mount(vec![validator({
+            let open = open.clone();
+            move |_| gate(open.clone()).map(Ok)
+        })]);
+
+        name.set("ferris".to_string());
+        settle(&mut dom).await;
+        assert!(form.is_validating());
+        assert_eq!(form.on_submit(|| ()), None);
+
+        open.set(true);
+        run_until(&mut dom, "the validation", || !form.is_validating()).await;
+        assert_eq!(form.on_submit(|| "submitted"), Some("submitted"));
+    }
+}
@@ -18,9 +18,22 @@ use std::{
 ///
 /// Whenever the hooks dependencies change, the future will be re-evaluated.
 /// If a future is pending when the dependencies change, the previous future
-/// will be allowed to continue
+/// is cancelled so its result can never overwrite the result of the new one.
+/// The last completed value stays available while the new future runs.
 ///
 /// - dependencies: a tuple of references to values that are PartialEq + Clone
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// let user = use_future(cx, (id,), |(id,)| async move { fetch_user(id).await });
+///
+/// match user.state(cx) {
+///     UseFutureState::Pending => rsx!("Loading..."),
+///     UseFutureState::Reloading(user) => rsx!("Refreshing {user.name}..."),
+///     UseFutureState::Complete(user) => rsx!("{user.name}"),
+/// }
+/// ```
 pub fn use_future<T, F, D>(
     cx: &ScopeState,
     dependencies: D,
@@ -96,10 +109,10 @@ pub enum UseFutureState<'a, T> {
 }
 
 impl<T> UseFuture<T> {
-    /// Restart the future with new dependencies.
+    /// Restart the future with the same dependencies.
     ///
-    /// Will not cancel the previous future, but will ignore any values that it
-    /// generates.
+    /// The in-flight future, if any, is cancelled when the component re-renders
+    /// and a new one is started in its place.
     pub fn restart(&self) {
         self.needs_regen.set(true);
         (self.update)();
@@ -150,6 +163,16 @@ impl<T> UseFuture<T> {
         None
     }
 
+    /// `true` while the future is running, whether or not an older value is available.
+    pub fn is_loading(&self, cx: &ScopeState) -> bool {
+        self.task(cx).is_some()
+    }
+
+    /// `true` once the latest future has completed and its value is available.
+    pub fn is_finished(&self, cx: &ScopeState) -> bool {
+        self.task(cx).is_none() && self.value().is_some()
+    }
+
     /// Get the current state of the future.
     pub fn state(&self, cx: &ScopeState) -> UseFutureState<T> {
         match (&self.task(cx), &self.value()) {
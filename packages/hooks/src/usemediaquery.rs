@@ -0,0 +1,167 @@
+use dioxus_core::ScopeState;
+use std::{cell::Cell, rc::Rc};
+
+/// Check if a CSS media query matches, re-rendering the component whenever the result changes.
+///
+/// The query is only read on the first render. How it is evaluated depends on the renderer:
+/// - web and desktop: `matchMedia`, so any media query the browser understands works
+/// - tui: `width`, `height` and `orientation` features compared against the size of the terminal, where `px` means
+///   one cell
+///
+/// On desktop the result is only known once the webview has evaluated the query, so the first render sees `false`.
+/// If the renderer doesn't support media queries, a warning is logged and the query never matches.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let is_mobile = use_media_query(cx, "(max-width: 600px)");
+///
+///     cx.render(rsx! {
+///         if is_mobile {
+///             rsx!(MobileNav {})
+///         } else {
+///             rsx!(Sidebar {})
+///         }
+///     })
+/// }
+/// ```
+pub fn use_media_query(cx: &ScopeState, query: &str) -> bool {
+    let matches = cx.use_hook(|| {
+        let matches = Rc::new(Cell::new(false));
+
+        match cx.consume_context::<MediaQueries>() {
+            Some(queries) => {
+                let id = queries.next_id.get();
+                queries.next_id.set(id + 1);
+
+                let update = cx.schedule_update();
+                let on_change = Rc::new({
+                    let matches = matches.clone();
+                    move |new| {
+                        if matches.replace(new) != new {
+                            update();
+                        }
+                    }
+                });
+
+                if let Some(initial) = queries.backend.watch(id, query, on_change) {
+                    matches.set(initial);
+                }
+                cx.on_drop(move || queries.backend.unwatch(id));
+            }
+            None => log::warn!(
+                "Could not evaluate the media query {query:?}: this renderer doesn't support media queries"
+            ),
+        }
+
+        matches
+    });
+
+    matches.get()
+}
+
+/// How a renderer evaluates media queries for [`use_media_query`]
+pub trait MediaQueryBackend {
+    /// Start watching `query` under `id`, calling `on_change` with the new result every time it changes.
+    ///
+    /// Returns the current result if it is known right away.
+    fn watch(&self, id: usize, query: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool>;
+
+    /// Stop watching the query registered under `id`
+    fn unwatch(&self, id: usize);
+}
+
+impl<B: MediaQueryBackend> MediaQueryBackend for Rc<B> {
+    fn watch(&self, id: usize, query: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool> {
+        (**self).watch(id, query, on_change)
+    }
+
+    fn unwatch(&self, id: usize) {
+        (**self).unwatch(id)
+    }
+}
+
+/// The media query backend of the renderer, shared through the context API
+#[derive(Clone)]
+pub struct MediaQueries {
+    backend: Rc<dyn MediaQueryBackend>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl MediaQueries {
+    /// Evaluate media queries with the given backend
+    pub fn new(backend: impl MediaQueryBackend + 'static) -> Self {
+        Self {
+            backend: Rc::new(backend),
+            next_id: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::{cell::RefCell, collections::HashMap};
+
+    #[derive(Default)]
+    struct FakeScreen {
+        wide: Cell<bool>,
+        watching: RefCell<HashMap<usize, Rc<dyn Fn(bool)>>>,
+    }
+
+    impl FakeScreen {
+        fn resize(&self, wide: bool) {
+            self.wide.set(wide);
+            let watching: Vec<_> = self.watching.borrow().values().cloned().collect();
+            for on_change in watching {
+                on_change(wide);
+            }
+        }
+    }
+
+    impl MediaQueryBackend for FakeScreen {
+        fn watch(&self, id: usize, _: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool> {
+            self.watching.borrow_mut().insert(id, on_change);
+            Some(self.wide.get())
+        }
+
+        fn unwatch(&self, id: usize) {
+            self.watching.borrow_mut().remove(&id);
+        }
+    }
+
+    #[test]
+    fn rerenders_when_the_query_changes() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static WIDE: Cell<Option<bool>> = Cell::new(None);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let wide = use_media_query(cx, "(min-width: 800px)");
+            WIDE.with(|w| w.set(Some(wide)));
+
+            cx.render(rsx!(()))
+        }
+
+        let screen = Rc::new(FakeScreen::default());
+        screen.wide.set(true);
+
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope()
+            .provide_context(MediaQueries::new(screen.clone()));
+        let _ = dom.rebuild();
+        assert_eq!(WIDE.with(Cell::get), Some(true));
+
+        screen.resize(false);
+        dom.process_events();
+        let _ = dom.render_immediate();
+        assert_eq!(WIDE.with(Cell::get), Some(false));
+
+        drop(dom);
+        assert!(screen.watching.borrow().is_empty());
+    }
+}
@@ -1,22 +1,28 @@
 use dioxus_core::ScopeState;
+use std::future::{Future, IntoFuture};
 
-use crate::UseFutureDep;
+use crate::{use_future, UseFutureAwait, UseFutureDep};
 
-/// A hook that provides a callback that executes after the hooks have been applied
+/// Cache a value derived from the dependencies, and only recompute it when they change.
 ///
-/// Whenever the hooks dependencies change, the callback will be re-evaluated.
+/// The callback runs on the first render and again on any render where one of the dependencies is no longer equal to
+/// its value from the previous render. Every other render hands out a reference to the cached value, so expensive
+/// derived data isn't rebuilt on every render.
 ///
-/// - dependencies: a tuple of references to values that are PartialEq + Clone
+/// - dependencies: a tuple of references to values that are PartialEq + Clone, or `()` to compute the value once
 ///
 /// ## Examples
 ///
 /// ```rust, ignore
-///
 /// #[inline_props]
-/// fn app(cx: Scope, name: &str) -> Element {
-///     use_memo(cx, (name,), |(name,)| {
-///         expensive_computation(name);
-///     }))
+/// fn app(cx: Scope, items: Vec<Item>, filter: String) -> Element {
+///     let visible = use_memo(cx, (items, filter), |(items, filter)| {
+///         items.into_iter().filter(|item| item.name.contains(&filter)).collect::<Vec<_>>()
+///     });
+///
+///     cx.render(rsx! {
+///         visible.iter().map(|item| rsx!(li { "{item.name}" }))
+///     })
 /// }
 /// ```
 pub fn use_memo<T, D>(cx: &ScopeState, dependencies: D, callback: impl FnOnce(D::Out) -> T) -> &T
@@ -35,3 +41,122 @@ where
 
     value.as_ref().unwrap()
 }
+
+/// Await a value computed by an async function, and only recompute it when the dependencies change.
+///
+/// This is the async version of [`use_memo`], meant to be awaited in an async component. The hook doesn't render any
+/// loading state itself: on the first load the async component renders nothing until the value is ready. Afterwards
+/// the cached value is returned right away. When the dependencies change, the value is revalidated in the background: the
+/// previous value keeps being returned until the new one is ready, and the component re-renders with it.
+///
+/// - dependencies: a tuple of references to values that are PartialEq + Clone, or `()` to compute the value once
+///
+/// ## Examples
+///
+/// ```rust, ignore
+/// #[inline_props]
+/// async fn Profile(cx: Scope, id: u32) -> Element {
+///     let user = use_async_memo(cx, (id,), |(id,)| fetch_user(id)).await;
+///
+///     cx.render(rsx! { "{user.name}" })
+/// }
+/// ```
+pub fn use_async_memo<T, F, D>(
+    cx: &ScopeState,
+    dependencies: D,
+    future: impl FnOnce(D::Out) -> F,
+) -> UseFutureAwait<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+    D: UseFutureDep,
+{
+    use_future(cx, dependencies, future).into_future()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+
+    #[test]
+    fn recomputes_only_when_dependencies_change() {
+        use dioxus::prelude::rsx;
+        use std::cell::Cell;
+
+        thread_local! {
+            static INPUT: Cell<i32> = Cell::new(1);
+            static COMPUTED: Cell<usize> = Cell::new(0);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let input = INPUT.with(Cell::get);
+            let doubled = use_memo(cx, (&input,), |(input,)| {
+                COMPUTED.with(|computed| computed.set(computed.get() + 1));
+                input * 2
+            });
+            assert_eq!(*doubled, input * 2);
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        assert_eq!(COMPUTED.with(Cell::get), 1);
+
+        // Re-rendering with the same dependencies reuses the cached value
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
+        assert_eq!(COMPUTED.with(Cell::get), 1);
+
+        INPUT.with(|input| input.set(2));
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
+        assert_eq!(COMPUTED.with(Cell::get), 2);
+    }
+
+    #[tokio::test]
+    async fn async_memo_suspends_once_then_revalidates() {
+        use dioxus::prelude::rsx;
+        use std::{cell::Cell, time::Duration};
+
+        thread_local! {
+            static INPUT: Cell<i32> = Cell::new(1);
+            static SEEN: Cell<Option<i32>> = Cell::new(None);
+        }
+
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx!(Child {}))
+        }
+
+        #[allow(non_snake_case)]
+        async fn Child(cx: Scope<'_>) -> Element {
+            let input = INPUT.with(Cell::get);
+            let doubled = use_async_memo(cx, (&input,), |(input,)| async move {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                input * 2
+            })
+            .await;
+            SEEN.with(|seen| seen.set(Some(*doubled)));
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        assert_eq!(SEEN.with(Cell::get), None);
+
+        dom.wait_for_work().await;
+        assert_eq!(SEEN.with(Cell::get), Some(2));
+
+        // New dependencies keep handing out the old value until the new one resolves
+        INPUT.with(|input| input.set(2));
+        dom.mark_dirty(ScopeId(1));
+        let _ = dom.render_immediate();
+        assert_eq!(SEEN.with(Cell::get), Some(2));
+
+        dom.wait_for_work().await;
+        let _ = dom.render_immediate();
+        assert_eq!(SEEN.with(Cell::get), Some(4));
+    }
+}
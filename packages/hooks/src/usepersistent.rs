@@ -0,0 +1,340 @@
+use dioxus_core::ScopeState;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    cell::{Ref, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Store state between component renders, and between runs of the app.
+///
+/// The value is serialized with serde and written to a [`StorageBackend`] under `key` every time it is set. When the
+/// hook is first rendered, the stored value is read back; `init` is only called if there is no stored value or it can
+/// no longer be deserialized.
+///
+/// Where the value is stored depends on the renderer:
+/// - web: `localStorage`
+/// - desktop: a file per key in the app's data directory, written on a background thread
+/// - everywhere else: an in-memory store that lives as long as the [`VirtualDom`](dioxus_core::VirtualDom)
+///
+/// A different backend can be used by providing a [`PersistentStorage`] context above the components that use it.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let dark_mode = use_persistent(cx, "dark_mode", || false);
+///
+///     cx.render(rsx! {
+///         button {
+///             onclick: move |_| dark_mode.with_mut(|dark| *dark = !*dark),
+///             "Dark mode: {dark_mode.get()}"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_persistent<T: Serialize + DeserializeOwned + 'static>(
+    cx: &ScopeState,
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> &UsePersistent<T> {
+    cx.use_hook(|| {
+        let storage = cx
+            .consume_context::<PersistentStorage>()
+            .unwrap_or_else(|| cx.provide_root_context(PersistentStorage::default()));
+        let key = key.to_string();
+
+        let stored = storage.backend.get(&key).and_then(|stored| {
+            serde_json::from_str(&stored)
+                .map_err(|err| log::warn!("Could not restore persisted value {key:?}: {err}"))
+                .ok()
+        });
+
+        UsePersistent {
+            value: Rc::new(RefCell::new(stored.unwrap_or_else(init))),
+            key: Rc::new(key),
+            storage,
+            update: cx.schedule_update(),
+        }
+    })
+}
+
+/// A handle to a value created with [`use_persistent`]
+pub struct UsePersistent<T> {
+    value: Rc<RefCell<T>>,
+    key: Rc<String>,
+    storage: PersistentStorage,
+    update: Arc<dyn Fn()>,
+}
+
+impl<T> Clone for UsePersistent<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            key: self.key.clone(),
+            storage: self.storage.clone(),
+            update: self.update.clone(),
+        }
+    }
+}
+
+impl<T> UsePersistent<T> {
+    /// Read the current value
+    pub fn get(&self) -> Ref<T> {
+        self.value.borrow()
+    }
+}
+
+impl<T: Serialize> UsePersistent<T> {
+    /// Replace the value, store it, and re-render the component
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.persist();
+    }
+
+    /// Modify the value in place, store it, and re-render the component
+    pub fn with_mut(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value.borrow_mut());
+        self.persist();
+    }
+
+    fn persist(&self) {
+        match serde_json::to_string(&*self.value.borrow()) {
+            Ok(serialized) => self.storage.backend.set(&self.key, serialized),
+            Err(err) => log::error!("Could not persist value {:?}: {err}", self.key),
+        }
+        (self.update)();
+    }
+}
+
+impl<T> PartialEq for UsePersistent<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+/// Somewhere [`use_persistent`] can keep serialized values
+pub trait StorageBackend {
+    /// Read the value stored under `key`, if there is one
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store `value` under `key`, replacing any previous value
+    fn set(&self, key: &str, value: String);
+}
+
+impl<S: StorageBackend> StorageBackend for Rc<S> {
+    fn get(&self, key: &str) -> Option<String> {
+        (**self).get(key)
+    }
+
+    fn set(&self, key: &str, value: String) {
+        (**self).set(key, value)
+    }
+}
+
+/// The storage [`use_persistent`] writes to, shared through the context API
+///
+/// Renderers provide one on the root scope if they can store values across runs of the app. Providing one lower in
+/// the tree overrides it for that subtree.
+#[derive(Clone)]
+pub struct PersistentStorage {
+    backend: Rc<dyn StorageBackend>,
+}
+
+impl PersistentStorage {
+    /// Persist values in the given backend
+    pub fn new(backend: impl StorageBackend + 'static) -> Self {
+        Self {
+            backend: Rc::new(backend),
+        }
+    }
+}
+
+impl Default for PersistentStorage {
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self::new(LocalStorage)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        Self::new(MemoryStorage::default())
+    }
+}
+
+/// Keeps values in memory, so they only survive as long as the storage itself
+#[derive(Default)]
+pub struct MemoryStorage {
+    values: RefCell<HashMap<String, String>>,
+}
+
+impl StorageBackend for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: String) {
+        self.values.borrow_mut().insert(key.to_string(), value);
+    }
+}
+
+/// Keeps values in the browser's `localStorage`
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        web_sys::window()?
+            .local_storage()
+            .ok()??
+            .get_item(key)
+            .ok()?
+    }
+
+    fn set(&self, key: &str, value: String) {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+        match storage {
+            Some(storage) => {
+                if storage.set_item(key, &value).is_err() {
+                    log::error!("Could not write {key:?} to localStorage");
+                }
+            }
+            None => log::error!("localStorage is not available"),
+        }
+    }
+}
+
+/// Keeps every value in its own file in a directory
+///
+/// Files are written on a background thread so setting a value doesn't block rendering. Reads see values that are
+/// still waiting to be written.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    dir: std::path::PathBuf,
+    pending: std::sync::Arc<std::sync::Mutex<HashMap<std::path::PathBuf, String>>>,
+    writer: RefCell<Option<std::sync::mpsc::Sender<std::path::PathBuf>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    /// Store values in `dir`. The directory is created when the first value is written.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            pending: Default::default(),
+            writer: RefCell::new(None),
+        }
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        // Keys are user provided, so hex encode them to keep them from escaping the directory. Unlike replacing the
+        // characters that aren't allowed in file names, this never maps two keys to the same file, even on
+        // filesystems that ignore case.
+        let file_name: String = key.bytes().map(|byte| format!("{byte:02x}")).collect();
+        self.dir.join(format!("{file_name}.json"))
+    }
+
+    fn write_in_background(&self, path: std::path::PathBuf) {
+        let mut writer = self.writer.borrow_mut();
+        let writer = writer.get_or_insert_with(|| {
+            let (tx, rx) = std::sync::mpsc::channel::<std::path::PathBuf>();
+            let pending = self.pending.clone();
+            let dir = self.dir.clone();
+
+            std::thread::spawn(move || {
+                for path in rx {
+                    // Several writes to the same file are coalesced into the latest one
+                    let value = match pending.lock().unwrap().get(&path) {
+                        Some(value) => value.clone(),
+                        None => continue,
+                    };
+
+                    let result =
+                        std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, &value));
+                    if let Err(err) = result {
+                        log::error!("Could not write {}: {err}", path.display());
+                    }
+
+                    let mut pending = pending.lock().unwrap();
+                    if pending.get(&path) == Some(&value) {
+                        pending.remove(&path);
+                    }
+                }
+            });
+
+            tx
+        });
+
+        let _ = writer.send(path);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        let path = self.path(key);
+        if let Some(value) = self.pending.lock().unwrap().get(&path) {
+            return Some(value.clone());
+        }
+        std::fs::read_to_string(path).ok()
+    }
+
+    fn set(&self, key: &str, value: String) {
+        let path = self.path(key);
+        self.pending.lock().unwrap().insert(path.clone(), value);
+        self.write_in_background(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+
+    #[test]
+    fn values_are_restored_from_storage() {
+        use dioxus::prelude::rsx;
+
+        fn app(cx: Scope) -> Element {
+            let count = use_persistent(cx, "count", || 0);
+            cx.use_hook(|| count.with_mut(|count| *count += 1));
+
+            cx.render(rsx!(()))
+        }
+
+        let storage = Rc::new(MemoryStorage::default());
+        storage.set("count", "41".to_string());
+
+        let mut dom = VirtualDom::new(app);
+        dom.base_scope()
+            .provide_context(PersistentStorage::new(storage.clone()));
+        let _ = dom.rebuild();
+
+        assert_eq!(storage.get("count").as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn file_storage_keeps_similar_keys_apart() {
+        let dir = std::env::temp_dir().join(format!("dioxus-persistent-{}", std::process::id()));
+        let storage = FileStorage::new(&dir);
+        assert_ne!(storage.path("user:1"), storage.path("user_1"));
+        assert_ne!(storage.path("User"), storage.path("user"));
+
+        storage.set("user:1", "1".to_string());
+        storage.set("user_1", "2".to_string());
+        assert_eq!(storage.get("user:1").as_deref(), Some("1"));
+        assert_eq!(storage.get("user_1").as_deref(), Some("2"));
+
+        // Wait for the writer to catch up, then read the values back from disk
+        while !storage.pending.lock().unwrap().is_empty() {
+            std::thread::yield_now();
+        }
+        let storage = FileStorage::new(&dir);
+        assert_eq!(storage.get("user:1").as_deref(), Some("1"));
+        assert_eq!(storage.get("user_1").as_deref(), Some("2"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,65 @@
+use dioxus_core::ScopeState;
+
+/// Get the value `value` had on the previous render of the component.
+///
+/// Returns `None` on the first render. Every render counts, so if the component re-renders without `value` changing,
+/// the previous value is equal to the current one.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// #[inline_props]
+/// fn Counter(cx: Scope, count: i32) -> Element {
+///     let previous = use_previous(cx, *count);
+///
+///     let trend = match previous {
+///         Some(previous) if previous < count => "up",
+///         Some(previous) if previous > count => "down",
+///         _ => "steady",
+///     };
+///
+///     cx.render(rsx!("{count} ({trend})"))
+/// }
+/// ```
+pub fn use_previous<T: 'static>(cx: &ScopeState, value: T) -> Option<&T> {
+    let (previous, current) = cx.use_hook(|| (None, None));
+    *previous = current.replace(value);
+    previous.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn returns_the_value_from_the_last_render() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static RENDER: Cell<u32> = Cell::new(0);
+            static SEEN: RefCell<Vec<Option<u32>>> = RefCell::new(Vec::new());
+        }
+
+        fn app(cx: Scope) -> Element {
+            let render = RENDER.with(|r| {
+                r.set(r.get() + 1);
+                r.get()
+            });
+            let previous = use_previous(cx, render * 10).copied();
+            SEEN.with(|seen| seen.borrow_mut().push(previous));
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        for _ in 0..2 {
+            dom.mark_dirty(ScopeId(0));
+            let _ = dom.render_immediate();
+        }
+
+        SEEN.with(|seen| assert_eq!(*seen.borrow(), [None, Some(10), Some(20)]));
+    }
+}
@@ -0,0 +1,537 @@
+use dioxus_core::{ScopeId, ScopeState};
+use futures_channel::mpsc::{unbounded, UnboundedSender};
+use futures_util::StreamExt;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Fetch data and cache it by key, sharing it between every component that asks for the same key.
+///
+/// When a component starts using a key, the cached value (if any) is returned right away and the data is revalidated
+/// in the background: the fetcher runs again, and every component using the key re-renders with the new value once it
+/// is ready. Only one fetch per key runs at a time, so components that mount together share a single request.
+///
+/// A revalidation uses the fetcher of the component's latest render, so it reads fresh props and state. If the key
+/// changes, the component switches over to the new key and fetches it the same way.
+///
+/// The cache lives in a [`QueryClient`] on the root scope, which can also be used to update or invalidate queries from
+/// anywhere in the app with [`use_query_client`]. Data stays cached after the last component using it unmounts, so
+/// it's there right away when one comes back: free it with [`QueryClient::remove`] or [`QueryClient::clear`].
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// #[inline_props]
+/// fn Profile(cx: Scope, id: u32) -> Element {
+///     let user = use_query(cx, format!("user/{id}"), {
+///         let id = *id;
+///         move || fetch_user(id)
+///     });
+///
+///     cx.render(match user.data() {
+///         Some(user) => rsx!("{user.name}"),
+///         None => rsx!("Loading..."),
+///     })
+/// }
+/// ```
+pub fn use_query<T, F>(
+    cx: &ScopeState,
+    key: impl ToString,
+    fetcher: impl Fn() -> F + 'static,
+) -> &UseQuery<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
+    let owner = cx.use_hook(|| {
+        let client = use_query_client_inner(cx);
+        let id = client.inner.next_id.get();
+        client.inner.next_id.set(id + 1);
+
+        // Fetches run in the component's own task, so they are cancelled if it is unmounted
+        let (fetch_tx, mut fetch_rx) = unbounded::<String>();
+        cx.push_future({
+            let client = client.clone();
+            async move {
+                while let Some(key) = fetch_rx.next().await {
+                    client.fetch(&key).await;
+                }
+            }
+        });
+
+        QueryOwner {
+            query: UseQuery {
+                client,
+                key: Rc::new(RefCell::new(None)),
+                _marker: PhantomData,
+            },
+            subscriber: Subscriber {
+                scope: cx.scope_id(),
+                fetch: fetch_tx,
+            },
+            id,
+        }
+    });
+
+    let fetcher: Fetcher = Rc::new(move || {
+        let fut = fetcher();
+        Box::pin(async move { Rc::new(fut.await) as Rc<dyn Any> })
+    });
+    owner.subscribe(key.to_string(), fetcher);
+
+    &owner.query
+}
+
+/// Get the [`QueryClient`] that caches the data fetched with [`use_query`]
+pub fn use_query_client(cx: &ScopeState) -> &QueryClient {
+    cx.use_hook(|| use_query_client_inner(cx))
+}
+
+fn use_query_client_inner(cx: &ScopeState) -> QueryClient {
+    cx.consume_context::<QueryClient>().unwrap_or_else(|| {
+        cx.provide_root_context(QueryClient {
+            inner: Rc::new(ClientInner {
+                entries: Default::default(),
+                update_any: cx.schedule_update_any(),
+                next_id: Cell::new(0),
+            }),
+        })
+    })
+}
+
+type Fetcher = Rc<dyn Fn() -> Pin<Box<dyn Future<Output = Rc<dyn Any>>>>>;
+
+struct Subscriber {
+    scope: ScopeId,
+    // Asks the subscriber's task to run a fetch
+    fetch: UnboundedSender<String>,
+}
+
+#[derive(Default)]
+struct Entry {
+    data: Option<Rc<dyn Any>>,
+    fetcher: Option<Fetcher>,
+    fetching: bool,
+    // The subscriber that was asked to fetch the data, until its task starts the fetch
+    requested: Option<usize>,
+    stale: bool,
+    // Bumped whenever the data is replaced or invalidated, so fetches that started before are not applied
+    generation: usize,
+    subscribers: HashMap<usize, Subscriber>,
+}
+
+struct ClientInner {
+    entries: RefCell<HashMap<String, Entry>>,
+    update_any: Arc<dyn Fn(ScopeId)>,
+    next_id: Cell<usize>,
+}
+
+/// The cache of every query made with [`use_query`], shared through the context API
+#[derive(Clone)]
+pub struct QueryClient {
+    inner: Rc<ClientInner>,
+}
+
+impl QueryClient {
+    /// Read the cached data of a query
+    ///
+    /// Returns `None` if nothing was fetched for the key yet, or if it holds a different type.
+    pub fn data<T: 'static>(&self, key: &str) -> Option<Rc<T>> {
+        let data = self.inner.entries.borrow().get(key)?.data.clone()?;
+        match data.downcast::<T>() {
+            Ok(data) => Some(data),
+            Err(_) => {
+                log::warn!(
+                    "The query {key:?} holds data of a different type than {}",
+                    std::any::type_name::<T>()
+                );
+                None
+            }
+        }
+    }
+
+    /// `true` while the data of a query is being fetched
+    pub fn is_fetching(&self, key: &str) -> bool {
+        let entries = self.inner.entries.borrow();
+        entries.get(key).map_or(false, |entry| entry.fetching)
+    }
+
+    /// Replace the cached data of a query without fetching it, re-rendering every component that uses it
+    ///
+    /// A fetch that is already running for the key is discarded when it finishes.
+    pub fn set_data<T: 'static>(&self, key: &str, data: T) {
+        {
+            let mut entries = self.inner.entries.borrow_mut();
+            let entry = entries.entry(key.to_string()).or_default();
+            entry.data = Some(Rc::new(data));
+            entry.stale = false;
+            entry.generation += 1;
+        }
+        self.notify(key);
+    }
+
+    /// Mark a query as outdated and fetch it again
+    ///
+    /// The cached data keeps being served until the new data is ready. If no component uses the query, it is fetched
+    /// when the next one does.
+    pub fn invalidate(&self, key: &str) {
+        {
+            let mut entries = self.inner.entries.borrow_mut();
+            let entry = match entries.get_mut(key) {
+                Some(entry) => entry,
+                None => return,
+            };
+            entry.stale = true;
+            entry.generation += 1;
+        }
+        self.revalidate(key);
+    }
+
+    /// Mark every query as outdated and fetch the ones that are in use again
+    pub fn invalidate_all(&self) {
+        let keys: Vec<String> = self.inner.entries.borrow().keys().cloned().collect();
+        for key in keys {
+            self.invalidate(&key);
+        }
+    }
+
+    /// Drop the cached data of a query that no component uses
+    ///
+    /// Returns `false` and keeps the query if a component still uses it.
+    pub fn remove(&self, key: &str) -> bool {
+        let mut entries = self.inner.entries.borrow_mut();
+        match entries.get(key) {
+            Some(entry) if entry.subscribers.is_empty() => {
+                entries.remove(key);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop the cached data of every query that no component uses
+    pub fn clear(&self) {
+        self.inner
+            .entries
+            .borrow_mut()
+            .retain(|_, entry| !entry.subscribers.is_empty());
+    }
+
+    // Ask one of the subscribers to fetch the key, unless a fetch is already running or about to start
+    fn revalidate(&self, key: &str) {
+        let mut entries = self.inner.entries.borrow_mut();
+        if let Some(entry) = entries.get_mut(key) {
+            if entry.fetching || entry.requested.is_some() {
+                return;
+            }
+            for (id, subscriber) in &entry.subscribers {
+                if subscriber.fetch.unbounded_send(key.to_string()).is_ok() {
+                    entry.requested = Some(*id);
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn fetch(&self, key: &str) {
+        let (fetcher, generation) = {
+            let mut entries = self.inner.entries.borrow_mut();
+            let entry = match entries.get_mut(key) {
+                Some(entry) => entry,
+                None => return,
+            };
+            entry.requested = None;
+            if entry.fetching {
+                return;
+            }
+            let fetcher = match entry.fetcher.clone() {
+                Some(fetcher) => fetcher,
+                None => return,
+            };
+            entry.fetching = true;
+            (fetcher, entry.generation)
+        };
+        self.notify(key);
+
+        let mut guard = FetchGuard {
+            client: self.clone(),
+            key: key.to_string(),
+            finished: false,
+        };
+        let data = fetcher().await;
+        guard.finished = true;
+
+        let stale = {
+            let mut entries = self.inner.entries.borrow_mut();
+            // The query was removed while it was being fetched
+            let entry = match entries.get_mut(key) {
+                Some(entry) => entry,
+                None => return,
+            };
+            entry.fetching = false;
+            if entry.generation == generation {
+                entry.data = Some(data);
+                entry.stale = false;
+            }
+            entry.stale
+        };
+        self.notify(key);
+
+        // The query was invalidated while it was being fetched
+        if stale {
+            self.revalidate(key);
+        }
+    }
+
+    fn notify(&self, key: &str) {
+        let scopes: Vec<ScopeId> = match self.inner.entries.borrow().get(key) {
+            Some(entry) => entry.subscribers.values().map(|s| s.scope).collect(),
+            None => return,
+        };
+        for scope in scopes {
+            (self.inner.update_any)(scope);
+        }
+    }
+}
+
+// Hands the fetch over to another subscriber if the component running it is unmounted before it finishes
+struct FetchGuard {
+    client: QueryClient,
+    key: String,
+    finished: bool,
+}
+
+impl Drop for FetchGuard {
+    fn drop(&mut self) {
+        if !self.finished {
+            if let Some(entry) = self.client.inner.entries.borrow_mut().get_mut(&self.key) {
+                entry.fetching = false;
+            }
+            self.client.revalidate(&self.key);
+        }
+    }
+}
+
+/// Unsubscribes from the query when the component is unmounted
+struct QueryOwner<T> {
+    query: UseQuery<T>,
+    subscriber: Subscriber,
+    id: usize,
+}
+
+impl<T> QueryOwner<T> {
+    fn subscribe(&self, key: String, fetcher: Fetcher) {
+        let client = &self.query.client;
+        let changed = self.query.key.borrow().as_ref() != Some(&key);
+
+        if changed {
+            self.unsubscribe();
+        }
+
+        {
+            let mut entries = client.inner.entries.borrow_mut();
+            let entry = entries.entry(key.clone()).or_default();
+            entry.fetcher = Some(fetcher);
+            if changed {
+                entry.subscribers.insert(
+                    self.id,
+                    Subscriber {
+                        scope: self.subscriber.scope,
+                        fetch: self.subscriber.fetch.clone(),
+                    },
+                );
+            }
+        }
+
+        if changed {
+            // Serve whatever is cached, but fetch the latest data
+            client.revalidate(&key);
+            *self.query.key.borrow_mut() = Some(key);
+        }
+    }
+
+    fn unsubscribe(&self) {
+        let key = match self.query.key.borrow().clone() {
+            Some(key) => key,
+            None => return,
+        };
+
+        let handover = {
+            let mut entries = self.query.client.inner.entries.borrow_mut();
+            match entries.get_mut(&key) {
+                Some(entry) => {
+                    entry.subscribers.remove(&self.id);
+                    // The fetcher holds on to the values the component captured
+                    if entry.subscribers.is_empty() {
+                        entry.fetcher = None;
+                    }
+                    // This component was asked to fetch but won't get to it
+                    let handover = entry.requested == Some(self.id);
+                    if handover {
+                        entry.requested = None;
+                    }
+                    handover
+                }
+                None => false,
+            }
+        };
+
+        if handover {
+            self.query.client.revalidate(&key);
+        }
+    }
+}
+
+impl<T> Drop for QueryOwner<T> {
+    fn drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+/// A query made with [`use_query`]
+pub struct UseQuery<T> {
+    client: QueryClient,
+    key: Rc<RefCell<Option<String>>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: 'static> UseQuery<T> {
+    /// The cached data, or `None` if it hasn't been fetched yet
+    pub fn data(&self) -> Option<Rc<T>> {
+        self.client.data(&self.key())
+    }
+
+    /// `true` while the data is being fetched, including while stale data is being revalidated
+    pub fn is_fetching(&self) -> bool {
+        self.client.is_fetching(&self.key())
+    }
+
+    /// Replace the cached data without fetching it, re-rendering every component that uses the query
+    pub fn mutate(&self, data: T) {
+        self.client.set_data(&self.key(), data)
+    }
+
+    /// Fetch the data again, serving the cached data until the new data is ready
+    pub fn refetch(&self) {
+        self.client.invalidate(&self.key())
+    }
+
+    /// The key of the query
+    pub fn key(&self) -> String {
+        self.key.borrow().clone().unwrap_or_default()
+    }
+}
+
+impl<T> Clone for UseQuery<T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            key: self.key.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::settle;
+    use dioxus_core::prelude::*;
+
+    #[tokio::test]
+    async fn queries_are_shared_and_revalidated() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static FETCHES: Cell<u32> = Cell::new(0);
+            static QUERIES: RefCell<Vec<UseQuery<u32>>> = RefCell::new(Vec::new());
+        }
+
+        fn app(cx: Scope) -> Element {
+            cx.render(rsx!(Answer {} Answer {}))
+        }
+
+        #[allow(non_snake_case)]
+        fn Answer(cx: Scope) -> Element {
+            let answer = use_query(cx, "answer", || async {
+                FETCHES.with(|f| f.set(f.get() + 1));
+                FETCHES.with(Cell::get)
+            });
+            cx.use_hook(|| QUERIES.with(|q| q.borrow_mut().push(answer.clone())));
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        settle(&mut dom).await;
+
+        let queries = QUERIES.with(|q| q.borrow().clone());
+        assert_eq!(FETCHES.with(Cell::get), 1);
+        assert_eq!(queries[0].data().as_deref(), Some(&1));
+        assert_eq!(queries[1].data().as_deref(), Some(&1));
+
+        queries[0].mutate(42);
+        assert_eq!(queries[1].data().as_deref(), Some(&42));
+        assert_eq!(FETCHES.with(Cell::get), 1);
+
+        queries[1].refetch();
+        assert_eq!(queries[0].data().as_deref(), Some(&42));
+        settle(&mut dom).await;
+        assert_eq!(FETCHES.with(Cell::get), 2);
+        assert_eq!(queries[0].data().as_deref(), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn unused_queries_can_be_removed() {
+        use dioxus::prelude::rsx;
+
+        struct Shown(Rc<Cell<bool>>);
+
+        fn app(cx: Scope<Shown>) -> Element {
+            let shown = cx.props.0.get();
+
+            cx.render(rsx! {
+                if shown {
+                    rsx!(Greeting {})
+                }
+            })
+        }
+
+        #[allow(non_snake_case)]
+        fn Greeting(cx: Scope) -> Element {
+            use_query(cx, "greeting", || async { "hello" });
+            cx.render(rsx!(()))
+        }
+
+        let shown = Rc::new(Cell::new(true));
+        let mut dom = VirtualDom::new_with_props(app, Shown(shown.clone()));
+        let _ = dom.rebuild();
+        settle(&mut dom).await;
+
+        // The first query puts the client on the root scope
+        let client = dom.base_scope().consume_context::<QueryClient>().unwrap();
+        assert_eq!(client.data::<&str>("greeting").as_deref(), Some(&"hello"));
+        assert!(!client.remove("greeting"), "the query is still in use");
+
+        shown.set(false);
+        dom.mark_dirty(ScopeId(0));
+        let _ = dom.render_immediate();
+
+        // Unmounting keeps the data for the next component that needs it
+        assert_eq!(client.data::<&str>("greeting").as_deref(), Some(&"hello"));
+        assert!(client.remove("greeting"));
+        assert_eq!(client.data::<&str>("greeting"), None);
+
+        client.set_data("unused", 1);
+        client.clear();
+        assert_eq!(client.data::<i32>("unused"), None);
+    }
+}
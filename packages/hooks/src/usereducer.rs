@@ -0,0 +1,163 @@
+use dioxus_core::ScopeState;
+use std::{cell::RefCell, rc::Rc};
+
+/// Store state that changes through a set of actions.
+///
+/// `use_reducer` is an alternative to [`use_state`](crate::use_state) for state with transitions that depend on each
+/// other. Instead of updating several cells by hand in every handler, the component dispatches an action and the
+/// `reducer` computes the next state from the current one. Dispatching an action re-renders the component.
+///
+/// The reducer is a plain function, so all the transitions live in one place and can be tested without a component.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// enum Action {
+///     Increment,
+///     Decrement,
+///     Reset,
+/// }
+///
+/// fn reducer(count: &i32, action: Action) -> i32 {
+///     match action {
+///         Action::Increment => count + 1,
+///         Action::Decrement => count - 1,
+///         Action::Reset => 0,
+///     }
+/// }
+///
+/// fn app(cx: Scope) -> Element {
+///     let (count, dispatch) = use_reducer(cx, || 0, reducer);
+///
+///     cx.render(rsx! {
+///         h1 { "Count: {count}" }
+///         button { onclick: move |_| dispatch.dispatch(Action::Increment), "+" }
+///         button { onclick: move |_| dispatch.dispatch(Action::Decrement), "-" }
+///         button { onclick: move |_| dispatch.dispatch(Action::Reset), "Reset" }
+///     })
+/// }
+/// ```
+pub fn use_reducer<T: 'static, A: 'static>(
+    cx: &ScopeState,
+    initial_state_fn: impl FnOnce() -> T,
+    reducer: fn(&T, A) -> T,
+) -> (&T, &Dispatch<A>) {
+    let hook = cx.use_hook(move || {
+        let current_val = Rc::new(initial_state_fn());
+        let slot = Rc::new(RefCell::new(current_val.clone()));
+        let update = cx.schedule_update();
+
+        let dispatch = Dispatch {
+            inner: Rc::new({
+                let slot = slot.clone();
+                move |action| {
+                    {
+                        let mut slot = slot.borrow_mut();
+                        let new = reducer(&slot, action);
+
+                        // Reuse the allocation if the previous render doesn't hold onto the old state anymore
+                        match Rc::get_mut(&mut slot) {
+                            Some(val) => *val = new,
+                            None => *slot = Rc::new(new),
+                        }
+                    }
+                    update();
+                }
+            }),
+        };
+
+        UseReducer {
+            current_val,
+            slot,
+            dispatch,
+        }
+    });
+
+    hook.current_val = hook.slot.borrow().clone();
+
+    (&hook.current_val, &hook.dispatch)
+}
+
+struct UseReducer<T, A> {
+    current_val: Rc<T>,
+    slot: Rc<RefCell<Rc<T>>>,
+    dispatch: Dispatch<A>,
+}
+
+/// Sends actions to the reducer of a [`use_reducer`] hook
+///
+/// The handle can be cloned and moved into async tasks. Actions are applied in the order they are dispatched, and the
+/// component re-renders with the resulting state.
+pub struct Dispatch<A> {
+    inner: Rc<dyn Fn(A)>,
+}
+
+impl<A> Dispatch<A> {
+    /// Apply an action to the state and schedule a re-render
+    pub fn dispatch(&self, action: A) {
+        (self.inner)(action)
+    }
+}
+
+impl<A> Clone for Dispatch<A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A> PartialEq for Dispatch<A> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+
+    enum Action {
+        Add(i32),
+        Reset,
+    }
+
+    fn reducer(total: &i32, action: Action) -> i32 {
+        match action {
+            Action::Add(amount) => total + amount,
+            Action::Reset => 0,
+        }
+    }
+
+    #[test]
+    fn dispatch_applies_actions_in_order() {
+        use dioxus::prelude::rsx;
+        use std::cell::Cell;
+
+        thread_local! {
+            static SEEN: Cell<i32> = Cell::new(-1);
+        }
+
+        fn app(cx: Scope) -> Element {
+            let (total, dispatch) = use_reducer(cx, || 10, reducer);
+            SEEN.with(|seen| seen.set(*total));
+
+            cx.use_hook(|| {
+                dispatch.dispatch(Action::Reset);
+                dispatch.dispatch(Action::Add(2));
+                dispatch.dispatch(Action::Add(3));
+            });
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+        assert_eq!(SEEN.with(Cell::get), 10);
+
+        dom.process_events();
+        let _ = dom.render_immediate();
+        assert_eq!(SEEN.with(Cell::get), 5);
+    }
+}
@@ -0,0 +1,244 @@
+use dioxus_core::ScopeState;
+use futures_channel::mpsc::{unbounded, UnboundedSender};
+use futures_util::{
+    future::{select, Either},
+    pin_mut, StreamExt,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+/// Run a callback every `period`, starting one period after the component is first rendered.
+///
+/// The timer is a task owned by the component, so it is stopped when the component is unmounted. Each tick calls the
+/// callback passed on the most recent render, not the one the timer started with. Changing `period` takes effect after
+/// the next tick.
+///
+/// Timers use `setTimeout` on the web and the tokio timer on desktop, tui and liveview. They are behind the `timer`
+/// feature so apps that don't use them don't depend on tokio.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app(cx: Scope) -> Element {
+///     let seconds = use_state(cx, || 0);
+///     let timer = use_interval(cx, Duration::from_secs(1), {
+///         to_owned![seconds];
+///         move || seconds.modify(|s| s + 1)
+///     });
+///
+///     cx.render(rsx! {
+///         "{seconds}s"
+///         button { onclick: move |_| timer.pause(), "Pause" }
+///         button { onclick: move |_| timer.resume(), "Resume" }
+///     })
+/// }
+/// ```
+pub fn use_interval(
+    cx: &ScopeState,
+    period: Duration,
+    callback: impl FnMut() + 'static,
+) -> &UseTimer {
+    use_timer(cx, period, true, Box::new(callback))
+}
+
+/// Run a callback once, `delay` after the component is first rendered.
+///
+/// If the component is unmounted before the delay has passed, the callback never runs. Pausing the timeout and
+/// resuming it starts the full delay over again.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn Toast(cx: Scope) -> Element {
+///     let visible = use_state(cx, || true);
+///     use_timeout(cx, Duration::from_secs(3), {
+///         to_owned![visible];
+///         move || visible.set(false)
+///     });
+///
+///     cx.render(rsx! {
+///         if **visible {
+///             rsx!("Saved!")
+///         }
+///     })
+/// }
+/// ```
+pub fn use_timeout(
+    cx: &ScopeState,
+    delay: Duration,
+    callback: impl FnOnce() + 'static,
+) -> &UseTimer {
+    let mut callback = Some(callback);
+    use_timer(
+        cx,
+        delay,
+        false,
+        Box::new(move || {
+            if let Some(callback) = callback.take() {
+                callback()
+            }
+        }),
+    )
+}
+
+fn use_timer(
+    cx: &ScopeState,
+    delay: Duration,
+    repeat: bool,
+    callback: Box<dyn FnMut()>,
+) -> &UseTimer {
+    let mut callback = Some(callback);
+
+    let timer = cx.use_hook(|| {
+        let state = Rc::new(TimerState {
+            delay: Cell::new(delay),
+            paused: Cell::new(false),
+            finished: Cell::new(false),
+            callback: RefCell::new(callback.take().unwrap()),
+        });
+        let (commands, mut rx) = unbounded::<()>();
+
+        cx.push_future({
+            let state = state.clone();
+            async move {
+                loop {
+                    // Wait until the timer is resumed
+                    if state.paused.get() {
+                        match rx.next().await {
+                            Some(()) => continue,
+                            None => return,
+                        }
+                    }
+
+                    let tick = sleep(state.delay.get());
+                    pin_mut!(tick);
+
+                    match select(tick, rx.next()).await {
+                        Either::Left(_) => {
+                            (state.callback.borrow_mut())();
+
+                            if !repeat {
+                                state.finished.set(true);
+                                return;
+                            }
+                        }
+
+                        // Paused or resumed, so start counting again
+                        Either::Right((Some(()), _)) => {}
+                        Either::Right((None, _)) => return,
+                    }
+                }
+            }
+        });
+
+        UseTimer { state, commands }
+    });
+
+    if let Some(callback) = callback {
+        *timer.state.callback.borrow_mut() = callback;
+    }
+    timer.state.delay.set(delay);
+
+    timer
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+struct TimerState {
+    delay: Cell<Duration>,
+    paused: Cell<bool>,
+    finished: Cell<bool>,
+    callback: RefCell<Box<dyn FnMut()>>,
+}
+
+/// A handle to a timer created with [`use_interval`] or [`use_timeout`]
+#[derive(Clone)]
+pub struct UseTimer {
+    state: Rc<TimerState>,
+    commands: UnboundedSender<()>,
+}
+
+impl UseTimer {
+    /// Stop the timer until [`UseTimer::resume`] is called
+    pub fn pause(&self) {
+        if !self.state.paused.replace(true) {
+            let _ = self.commands.unbounded_send(());
+        }
+    }
+
+    /// Restart a paused timer. The current period starts over from the beginning.
+    pub fn resume(&self) {
+        if self.state.paused.replace(false) {
+            let _ = self.commands.unbounded_send(());
+        }
+    }
+
+    /// `true` if the timer has been paused
+    pub fn is_paused(&self) -> bool {
+        self.state.paused.get()
+    }
+
+    /// `true` once a [`use_timeout`] has run its callback. Intervals never finish.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.get()
+    }
+}
+
+impl PartialEq for UseTimer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::run_until;
+    use dioxus_core::prelude::*;
+
+    #[tokio::test]
+    async fn timers_fire_until_the_component_is_unmounted() {
+        use dioxus::prelude::rsx;
+
+        thread_local! {
+            static TICKS: Cell<usize> = Cell::new(0);
+            static TIMED_OUT: Cell<bool> = Cell::new(false);
+        }
+
+        fn app(cx: Scope) -> Element {
+            use_interval(cx, Duration::from_millis(5), || {
+                TICKS.with(|ticks| ticks.set(ticks.get() + 1))
+            });
+            use_timeout(cx, Duration::from_millis(5), || {
+                TIMED_OUT.with(|timed_out| timed_out.set(true))
+            });
+
+            cx.render(rsx!(()))
+        }
+
+        let mut dom = VirtualDom::new(app);
+        let _ = dom.rebuild();
+
+        run_until(&mut dom, "two ticks and the timeout", || {
+            TIMED_OUT.with(Cell::get) && TICKS.with(Cell::get) >= 2
+        })
+        .await;
+
+        // Dropping the dom drops the timer tasks
+        drop(dom);
+        let ticks = TICKS.with(Cell::get);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(TICKS.with(Cell::get), ticks);
+    }
+}
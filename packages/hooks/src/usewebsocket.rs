@@ -0,0 +1,492 @@
+use crate::usetimer::sleep;
+use dioxus_core::ScopeState;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::{
+    future::{select, Either},
+    pin_mut, StreamExt,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+
+/// How long to wait before the first reconnect. The delay doubles after every failed attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Connect to a websocket server for as long as the component is mounted.
+///
+/// Every message the server sends is deserialized from JSON into `T` and passed to `on_message`, whether it arrives as
+/// a text or a binary frame. Messages that can't be deserialized are logged and skipped. The socket stays open across
+/// renders, so `url` is only read on the first one, while messages go to the `on_message` of the latest render.
+///
+/// If the connection fails or drops, the hook reconnects, waiting 500ms before the first attempt and doubling the
+/// delay up to 30s after every failure. Messages sent with [`UseWebSocket::send`] while the socket isn't open, or that
+/// failed to send, are queued and sent in order once it reconnects. The socket is closed when the component is
+/// unmounted, telling the server with a close frame.
+///
+/// Sockets use `WebSocket` on the web and tungstenite everywhere else. This hook requires the `websocket` feature.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct ChatMessage {
+///     author: String,
+///     text: String,
+/// }
+///
+/// fn Chat(cx: Scope) -> Element {
+///     let messages = use_ref(cx, Vec::new);
+///     let socket = use_websocket(cx, "wss://chat.example.com", {
+///         to_owned![messages];
+///         move |message: ChatMessage| messages.write().push(message)
+///     });
+///
+///     cx.render(rsx! {
+///         for message in messages.read().iter() {
+///             p { "{message.author}: {message.text}" }
+///         }
+///         button {
+///             disabled: !socket.is_open(),
+///             onclick: move |_| socket.send(&ChatMessage { author: "me".into(), text: "hi!".into() }),
+///             "Say hi"
+///         }
+///     })
+/// }
+/// ```
+pub fn use_websocket<T: DeserializeOwned + 'static>(
+    cx: &ScopeState,
+    url: impl ToString,
+    on_message: impl FnMut(T) + 'static,
+) -> &UseWebSocket {
+    let mut on_message: Option<Box<dyn FnMut(T)>> = Some(Box::new(on_message));
+
+    let hook = cx.use_hook(|| {
+        let handler = Rc::new(RefCell::new(on_message.take().unwrap()));
+        let status = Rc::new(Cell::new(WebSocketStatus::Connecting));
+        let (outgoing, rx) = unbounded();
+
+        cx.push_future(run(
+            url.to_string(),
+            rx,
+            status.clone(),
+            cx.schedule_update(),
+            {
+                let handler = handler.clone();
+                move |text: String| match serde_json::from_str(&text) {
+                    Ok(message) => (handler.borrow_mut())(message),
+                    Err(err) => log::warn!("Could not deserialize websocket message: {err}"),
+                }
+            },
+        ));
+
+        UseWebSocketHook {
+            socket: UseWebSocket { status, outgoing },
+            handler,
+        }
+    });
+
+    if let Some(on_message) = on_message {
+        *hook.handler.borrow_mut() = on_message;
+    }
+
+    &hook.socket
+}
+
+struct UseWebSocketHook<T> {
+    socket: UseWebSocket,
+    handler: Rc<RefCell<Box<dyn FnMut(T)>>>,
+}
+
+/// The state of the connection of a [`use_websocket`] hook
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WebSocketStatus {
+    /// Connecting for the first time
+    Connecting,
+    /// Connected to the server
+    Open,
+    /// The connection failed or dropped, and the hook is waiting to try again
+    Reconnecting {
+        /// How many attempts in a row have failed
+        attempt: u32,
+    },
+}
+
+/// A handle to a websocket created with [`use_websocket`]
+#[derive(Clone)]
+pub struct UseWebSocket {
+    status: Rc<Cell<WebSocketStatus>>,
+    outgoing: UnboundedSender<String>,
+}
+
+impl UseWebSocket {
+    /// Serialize a message to JSON and send it, or queue it until the socket is open
+    pub fn send<M: Serialize>(&self, message: &M) {
+        match serde_json::to_string(message) {
+            Ok(text) => {
+                let _ = self.outgoing.unbounded_send(text);
+            }
+            Err(err) => log::error!("Could not serialize websocket message: {err}"),
+        }
+    }
+
+    /// The current state of the connection
+    pub fn status(&self) -> WebSocketStatus {
+        self.status.get()
+    }
+
+    /// `true` if the socket is connected
+    pub fn is_open(&self) -> bool {
+        self.status.get() == WebSocketStatus::Open
+    }
+}
+
+impl PartialEq for UseWebSocket {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.status, &other.status)
+    }
+}
+
+async fn run(
+    url: String,
+    mut outgoing: UnboundedReceiver<String>,
+    status: Rc<Cell<WebSocketStatus>>,
+    update: Arc<dyn Fn()>,
+    mut on_message: impl FnMut(String),
+) {
+    let set_status = |new| {
+        if status.replace(new) != new {
+            update();
+        }
+    };
+
+    let mut attempt = 0;
+    let mut backoff = INITIAL_BACKOFF;
+
+    // Messages waiting for an open socket, oldest first
+    let mut queue = VecDeque::new();
+
+    loop {
+        if let Some(mut connection) = Connection::open(&url).await {
+            set_status(WebSocketStatus::Open);
+            attempt = 0;
+            backoff = INITIAL_BACKOFF;
+
+            while connection.send_queued(&mut queue).await {
+                enum Next {
+                    Received(Option<String>),
+                    Send(Option<String>),
+                }
+
+                let next = {
+                    let received = connection.recv();
+                    pin_mut!(received);
+                    match select(received, outgoing.next()).await {
+                        Either::Left((message, _)) => Next::Received(message),
+                        Either::Right((message, _)) => Next::Send(message),
+                    }
+                };
+
+                match next {
+                    Next::Received(Some(text)) => on_message(text),
+                    Next::Send(Some(text)) => queue.push_back(text),
+                    Next::Received(None) => break,
+                    // Every handle was dropped, so nothing can be sent anymore
+                    Next::Send(None) => return,
+                }
+            }
+        }
+
+        attempt += 1;
+        set_status(WebSocketStatus::Reconnecting { attempt });
+        sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+impl Connection {
+    /// Send the queued messages in order. A message stays at the front of the queue until it is sent, so it is
+    /// retried after reconnecting if sending it fails. Returns `false` if the connection failed.
+    async fn send_queued(&mut self, queue: &mut VecDeque<String>) -> bool {
+        while let Some(text) = queue.front() {
+            if !self.send(text).await {
+                return false;
+            }
+            queue.pop_front();
+        }
+        true
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Connection {
+    socket: tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Connection {
+    async fn open(url: &str) -> Option<Self> {
+        match tokio_tungstenite::connect_async(url).await {
+            Ok((socket, _)) => Some(Self { socket }),
+            Err(err) => {
+                log::warn!("Could not connect to {url}: {err}");
+                None
+            }
+        }
+    }
+
+    /// The next text message, or `None` once the connection is closed
+    async fn recv(&mut self) -> Option<String> {
+        use tokio_tungstenite::tungstenite::Message;
+
+        loop {
+            match self.socket.next().await? {
+                Ok(Message::Text(text)) => return Some(text),
+                Ok(Message::Binary(bytes)) => match String::from_utf8(bytes) {
+                    Ok(text) => return Some(text),
+                    Err(_) => log::warn!("Ignoring a binary websocket message that isn't UTF-8"),
+                },
+                Ok(Message::Close(_)) => return None,
+                // Pings are answered by tungstenite
+                Ok(_) => {}
+                Err(err) => {
+                    log::warn!("Websocket error: {err}");
+                    return None;
+                }
+            }
+        }
+    }
+
+    async fn send(&mut self, text: &str) -> bool {
+        use futures_util::SinkExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        self.socket
+            .send(Message::Text(text.to_string()))
+            .await
+            .is_ok()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Connection {
+    fn drop(&mut self) {
+        use futures_util::{task::noop_waker_ref, Sink};
+        use std::{pin::Pin, task::Context, task::Poll};
+        use tokio_tungstenite::tungstenite::Message;
+
+        // Dropping can't wait for the socket, so the close frame is only sent if the socket can take it right away,
+        // which it can unless the server stopped reading
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let mut socket = Pin::new(&mut self.socket);
+        if let Poll::Ready(Ok(())) = socket.as_mut().poll_ready(&mut cx) {
+            if socket.as_mut().start_send(Message::Close(None)).is_ok() {
+                let _ = socket.poll_flush(&mut cx);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+enum SocketEvent {
+    Open,
+    Message(String),
+    Closed,
+}
+
+#[cfg(target_arch = "wasm32")]
+struct Connection {
+    socket: web_sys::WebSocket,
+    events: UnboundedReceiver<SocketEvent>,
+    _callbacks: Vec<wasm_bindgen::closure::Closure<dyn FnMut(web_sys::Event)>>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Connection {
+    async fn open(url: &str) -> Option<Self> {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let socket = match web_sys::WebSocket::new(url) {
+            Ok(socket) => socket,
+            Err(err) => {
+                log::warn!("Could not connect to {url}: {err:?}");
+                return None;
+            }
+        };
+        // Binary messages arrive as Blobs otherwise, which can only be read asynchronously
+        socket.set_binary_type(web_sys::BinaryType::Arraybuffer);
+
+        let (tx, events) = unbounded();
+        let callback = |f: fn(web_sys::Event) -> Option<SocketEvent>| {
+            let tx = tx.clone();
+            Closure::wrap(Box::new(move |event: web_sys::Event| {
+                if let Some(event) = f(event) {
+                    let _ = tx.unbounded_send(event);
+                }
+            }) as Box<dyn FnMut(web_sys::Event)>)
+        };
+
+        let onopen = callback(|_| Some(SocketEvent::Open));
+        let onmessage = callback(|event| {
+            let data = event.unchecked_into::<web_sys::MessageEvent>().data();
+            if let Some(text) = data.as_string() {
+                return Some(SocketEvent::Message(text));
+            }
+            let bytes = js_sys::Uint8Array::new(&data).to_vec();
+            match String::from_utf8(bytes) {
+                Ok(text) => Some(SocketEvent::Message(text)),
+                Err(_) => {
+                    log::warn!("Ignoring a binary websocket message that isn't UTF-8");
+                    None
+                }
+            }
+        });
+        let onclose = callback(|_| Some(SocketEvent::Closed));
+
+        socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(onclose.as_ref().unchecked_ref()));
+
+        let mut connection = Self {
+            socket,
+            events,
+            _callbacks: vec![onopen, onmessage, onclose],
+        };
+
+        match connection.events.next().await {
+            Some(SocketEvent::Open) => Some(connection),
+            _ => {
+                log::warn!("Could not connect to {url}");
+                None
+            }
+        }
+    }
+
+    /// The next text message, or `None` once the connection is closed
+    async fn recv(&mut self) -> Option<String> {
+        loop {
+            match self.events.next().await? {
+                SocketEvent::Message(text) => return Some(text),
+                SocketEvent::Closed => return None,
+                SocketEvent::Open => {}
+            }
+        }
+    }
+
+    async fn send(&mut self, text: &str) -> bool {
+        self.socket.send_with_str(text).is_ok()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        self.socket.set_onerror(None);
+        let _ = self.socket.close();
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::testing::run_until;
+    use dioxus::prelude::rsx;
+    use dioxus_core::prelude::*;
+    use futures_util::SinkExt;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// A server that echoes the text messages of a single connection, and reports every message it gets
+    async fn echo_server() -> (String, UnboundedReceiver<Message>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (tx, rx) = unbounded();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(message)) = socket.next().await {
+                if message.is_text() {
+                    let _ = socket.send(message.clone()).await;
+                }
+                let _ = tx.unbounded_send(message);
+            }
+        });
+
+        (url, rx)
+    }
+
+    struct Client {
+        url: String,
+        received: Rc<RefCell<Vec<String>>>,
+    }
+
+    fn client(cx: Scope<Client>) -> Element {
+        let socket = use_websocket(cx, &cx.props.url, {
+            let received = cx.props.received.clone();
+            move |message: String| received.borrow_mut().push(message)
+        });
+        // Sent before the socket is open, so it waits in the queue
+        cx.use_hook(|| socket.send(&"hello"));
+
+        cx.render(rsx!(()))
+    }
+
+    #[tokio::test]
+    async fn queued_messages_are_sent_once_connected() {
+        let (url, _) = echo_server().await;
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let mut dom = VirtualDom::new_with_props(
+            client,
+            Client {
+                url,
+                received: received.clone(),
+            },
+        );
+        let _ = dom.rebuild();
+
+        run_until(&mut dom, "the echo", || !received.borrow().is_empty()).await;
+        assert_eq!(*received.borrow(), ["hello"]);
+    }
+
+    #[tokio::test]
+    async fn unmounting_closes_the_socket() {
+        let (url, mut server) = echo_server().await;
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let mut dom = VirtualDom::new_with_props(
+            client,
+            Client {
+                url,
+                received: received.clone(),
+            },
+        );
+        let _ = dom.rebuild();
+        run_until(&mut dom, "the echo", || !received.borrow().is_empty()).await;
+
+        drop(dom);
+
+        let close = async {
+            loop {
+                match server.next().await {
+                    Some(Message::Close(_)) => return true,
+                    Some(_) => {}
+                    None => return false,
+                }
+            }
+        };
+        let closed = tokio::time::timeout(Duration::from_secs(5), close).await;
+        assert_eq!(closed, Ok(true), "the server never got a close frame");
+    }
+}
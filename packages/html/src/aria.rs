@@ -0,0 +1,389 @@
+//! Typed values for the `role` and `aria-*` attributes
+//!
+//! Every attribute still accepts a string, but these types can be used instead to catch typos at compile time and to
+//! find the allowed values through autocomplete:
+//!
+//! ```rust, ignore
+//! use dioxus_html::aria::{AriaLive, Role};
+//!
+//! rsx! {
+//!     div { role: Role::Status, aria_live: AriaLive::Polite, aria_busy: true, "Saving..." }
+//! }
+//! ```
+//!
+//! Boolean attributes like `aria_hidden`, `aria_expanded` and `aria_busy` take a `bool` directly.
+
+use dioxus_core::{exports::bumpalo::Bump, prelude::IntoAttributeValue, AttributeValue};
+use std::fmt::{Display, Formatter};
+
+macro_rules! attribute_value {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// The value of the attribute, as it is written in HTML
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$variant => $value,
+                    )*
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl<'a> IntoAttributeValue<'a> for $name {
+            fn into_value(self, _: &'a Bump) -> AttributeValue<'a> {
+                AttributeValue::Text(self.as_str())
+            }
+        }
+    };
+}
+
+attribute_value! {
+    /// How updates to a live region are announced
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live>
+    AriaLive {
+        /// Updates are not announced
+        Off => "off",
+        /// Updates are announced when the user is idle
+        Polite => "polite",
+        /// Updates are announced immediately
+        Assertive => "assertive",
+    }
+}
+
+attribute_value! {
+    /// Which item in a set of related elements is the current one
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current>
+    AriaCurrent {
+        /// The element is not current
+        False => "false",
+        /// The element is the current item in its set
+        True => "true",
+        /// The element is the current page
+        Page => "page",
+        /// The element is the current step in a process
+        Step => "step",
+        /// The element is the current location, like in a flow chart
+        Location => "location",
+        /// The element is the current date
+        Date => "date",
+        /// The element is the current time
+        Time => "time",
+    }
+}
+
+attribute_value! {
+    /// How an input suggests completions
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete>
+    AriaAutocomplete {
+        /// No suggestions are shown
+        None => "none",
+        /// The completion is shown inline after the caret
+        Inline => "inline",
+        /// Completions are shown in a popup list
+        List => "list",
+        /// Completions are shown both inline and in a list
+        Both => "both",
+    }
+}
+
+attribute_value! {
+    /// The kind of popup an element opens
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup>
+    AriaHaspopup {
+        /// The element has no popup
+        False => "false",
+        /// The element opens a menu
+        True => "true",
+        /// The element opens a menu
+        Menu => "menu",
+        /// The element opens a listbox
+        Listbox => "listbox",
+        /// The element opens a tree
+        Tree => "tree",
+        /// The element opens a grid
+        Grid => "grid",
+        /// The element opens a dialog
+        Dialog => "dialog",
+    }
+}
+
+attribute_value! {
+    /// Whether the entered value is valid
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid>
+    AriaInvalid {
+        /// The value is valid
+        False => "false",
+        /// The value is invalid
+        True => "true",
+        /// The value contains a grammatical error
+        Grammar => "grammar",
+        /// The value contains a spelling error
+        Spelling => "spelling",
+    }
+}
+
+attribute_value! {
+    /// The orientation of a scrollbar, slider, separator, or list
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation>
+    AriaOrientation {
+        /// The element is horizontal
+        Horizontal => "horizontal",
+        /// The element is vertical
+        Vertical => "vertical",
+    }
+}
+
+attribute_value! {
+    /// How the items in a table or grid column are sorted
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort>
+    AriaSort {
+        /// The items are not sorted
+        None => "none",
+        /// The items are sorted in ascending order
+        Ascending => "ascending",
+        /// The items are sorted in descending order
+        Descending => "descending",
+        /// The items are sorted by some other algorithm
+        Other => "other",
+    }
+}
+
+attribute_value! {
+    /// Which changes to a live region are announced
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant>
+    AriaRelevant {
+        /// Nodes added to the region
+        Additions => "additions",
+        /// Nodes removed from the region
+        Removals => "removals",
+        /// Text added to the region
+        Text => "text",
+        /// Every kind of change
+        All => "all",
+        /// Nodes and text added to the region
+        AdditionsText => "additions text",
+    }
+}
+
+attribute_value! {
+    /// The state of a checkbox or toggle button that can be partially checked, used for `aria-checked` and `aria-pressed`
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked>
+    AriaTristate {
+        /// The element is not checked or pressed
+        False => "false",
+        /// The element is checked or pressed
+        True => "true",
+        /// The element is partially checked or pressed
+        Mixed => "mixed",
+    }
+}
+
+attribute_value! {
+    /// The semantic meaning of an element, set with the `role` attribute
+    ///
+    /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles>
+    Role {
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/alert_role>
+        Alert => "alert",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/alertdialog_role>
+        Alertdialog => "alertdialog",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/application_role>
+        Application => "application",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/article_role>
+        Article => "article",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/banner_role>
+        Banner => "banner",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/blockquote_role>
+        Blockquote => "blockquote",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/button_role>
+        Button => "button",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/caption_role>
+        Caption => "caption",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/cell_role>
+        Cell => "cell",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/checkbox_role>
+        Checkbox => "checkbox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/code_role>
+        Code => "code",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/columnheader_role>
+        Columnheader => "columnheader",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/combobox_role>
+        Combobox => "combobox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/comment_role>
+        Comment => "comment",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/complementary_role>
+        Complementary => "complementary",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/contentinfo_role>
+        Contentinfo => "contentinfo",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/definition_role>
+        Definition => "definition",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/deletion_role>
+        Deletion => "deletion",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/dialog_role>
+        Dialog => "dialog",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/directory_role>
+        Directory => "directory",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/document_role>
+        Document => "document",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/emphasis_role>
+        Emphasis => "emphasis",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/feed_role>
+        Feed => "feed",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/figure_role>
+        Figure => "figure",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/form_role>
+        Form => "form",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/generic_role>
+        Generic => "generic",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/grid_role>
+        Grid => "grid",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/gridcell_role>
+        Gridcell => "gridcell",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/group_role>
+        Group => "group",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/heading_role>
+        Heading => "heading",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/img_role>
+        Img => "img",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/insertion_role>
+        Insertion => "insertion",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/link_role>
+        Link => "link",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/list_role>
+        List => "list",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/listbox_role>
+        Listbox => "listbox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/listitem_role>
+        Listitem => "listitem",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/log_role>
+        Log => "log",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/main_role>
+        Main => "main",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/mark_role>
+        Mark => "mark",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/marquee_role>
+        Marquee => "marquee",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/math_role>
+        Math => "math",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/menu_role>
+        Menu => "menu",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/menubar_role>
+        Menubar => "menubar",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/menuitem_role>
+        Menuitem => "menuitem",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/menuitemcheckbox_role>
+        Menuitemcheckbox => "menuitemcheckbox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/menuitemradio_role>
+        Menuitemradio => "menuitemradio",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/meter_role>
+        Meter => "meter",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/navigation_role>
+        Navigation => "navigation",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/none_role>
+        None => "none",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/note_role>
+        Note => "note",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/option_role>
+        Option => "option",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/paragraph_role>
+        Paragraph => "paragraph",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/presentation_role>
+        Presentation => "presentation",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/progressbar_role>
+        Progressbar => "progressbar",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/radio_role>
+        Radio => "radio",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/radiogroup_role>
+        Radiogroup => "radiogroup",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/region_role>
+        Region => "region",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/row_role>
+        Row => "row",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/rowgroup_role>
+        Rowgroup => "rowgroup",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/rowheader_role>
+        Rowheader => "rowheader",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/scrollbar_role>
+        Scrollbar => "scrollbar",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/search_role>
+        Search => "search",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/searchbox_role>
+        Searchbox => "searchbox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/separator_role>
+        Separator => "separator",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/slider_role>
+        Slider => "slider",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/spinbutton_role>
+        Spinbutton => "spinbutton",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/status_role>
+        Status => "status",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/strong_role>
+        Strong => "strong",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/subscript_role>
+        Subscript => "subscript",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/suggestion_role>
+        Suggestion => "suggestion",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/superscript_role>
+        Superscript => "superscript",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/switch_role>
+        Switch => "switch",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/tab_role>
+        Tab => "tab",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/table_role>
+        Table => "table",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/tablist_role>
+        Tablist => "tablist",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/tabpanel_role>
+        Tabpanel => "tabpanel",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/term_role>
+        Term => "term",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/textbox_role>
+        Textbox => "textbox",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/time_role>
+        Time => "time",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/timer_role>
+        Timer => "timer",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/toolbar_role>
+        Toolbar => "toolbar",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/tooltip_role>
+        Tooltip => "tooltip",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/tree_role>
+        Tree => "tree",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/treegrid_role>
+        Treegrid => "treegrid",
+        /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/treeitem_role>
+        Treeitem => "treeitem",
+    }
+}
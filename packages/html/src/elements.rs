@@ -1,7 +1,7 @@
 #![allow(non_upper_case_globals)]
 #[cfg(feature = "hot-reload-context")]
-use crate::{map_global_attributes, map_svg_attributes};
-use crate::{GlobalAttributes, SvgAttributes};
+use crate::{map_global_attributes, map_mathml_attributes, map_svg_attributes};
+use crate::{GlobalAttributes, MathMlAttributes, SvgAttributes};
 #[cfg(feature = "hot-reload-context")]
 use dioxus_rsx::HotReloadingContext;
 
@@ -116,15 +116,79 @@ macro_rules! impl_element {
                 $fil:ident: $vil:ident $extra:tt,
             )*
         }
+    ) => {
+        impl_element!(
+            $(#[$attr])*
+            $name $namespace [stringify!($name)] {
+                $(
+                    $(#[$attr_method])*
+                    $fil: $vil $extra,
+                )*
+            }
+        );
+    };
+
+    // Elements whose tag isn't a valid rust identifier, like `use`
+    (
+        $(#[$attr:meta])*
+        $name:ident "http://www.w3.org/1998/Math/MathML" [$tag:expr] {
+            $(
+                $(#[$attr_method:meta])*
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
+    ) => {
+        impl_element!(
+            @namespaced MathMlAttributes
+            $(#[$attr])*
+            $name "http://www.w3.org/1998/Math/MathML" [$tag] {
+                $(
+                    $(#[$attr_method])*
+                    $fil: $vil $extra,
+                )*
+            }
+        );
+    };
+
+    (
+        $(#[$attr:meta])*
+        $name:ident $namespace:tt [$tag:expr] {
+            $(
+                $(#[$attr_method:meta])*
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
+    ) => {
+        impl_element!(
+            @namespaced SvgAttributes
+            $(#[$attr])*
+            $name $namespace [$tag] {
+                $(
+                    $(#[$attr_method])*
+                    $fil: $vil $extra,
+                )*
+            }
+        );
+    };
+
+    (
+        @namespaced $attributes:ident
+        $(#[$attr:meta])*
+        $name:ident $namespace:tt [$tag:expr] {
+            $(
+                $(#[$attr_method:meta])*
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
     ) => {
         #[allow(non_camel_case_types)]
         $(#[$attr])*
         pub struct $name;
 
-        impl SvgAttributes for $name {}
+        impl $attributes for $name {}
 
         impl $name {
-            pub const TAG_NAME: &'static str = stringify!($name);
+            pub const TAG_NAME: &'static str = $tag;
             pub const NAME_SPACE: Option<&'static str> = Some($namespace);
 
             $(
@@ -159,7 +223,19 @@ macro_rules! impl_element_match {
         }
     ) => {
         if $el == stringify!($name) {
-            return Some((stringify!($name), Some(stringify!($namespace))));
+            return Some((stringify!($name), Some($namespace)));
+        }
+    };
+
+    (
+        $el:ident $name:ident $namespace:tt [$tag:literal] {
+            $(
+                $fil:ident: $vil:ident $extra:tt,
+            )*
+        }
+    ) => {
+        if $el == stringify!($name) {
+            return Some(($tag, Some($namespace)));
         }
     };
 }
@@ -183,7 +259,7 @@ macro_rules! impl_element_match_attributes {
     };
 
     (
-        $el:ident $attr:ident $name:ident $namespace:tt {
+        $el:ident $attr:ident $name:ident $namespace:tt $([$tag:literal])? {
             $(
                 $fil:ident: $vil:ident $extra:tt,
             )*
@@ -203,7 +279,7 @@ macro_rules! builder_constructors {
     (
         $(
             $(#[$attr:meta])*
-            $name:ident $namespace:tt {
+            $name:ident $namespace:tt $([$tag:literal])? {
                 $(
                     $(#[$attr_method:meta])*
                     $fil:ident: $vil:ident $extra:tt,
@@ -219,20 +295,22 @@ macro_rules! builder_constructors {
             fn map_attribute(element: &str, attribute: &str) -> Option<(&'static str, Option<&'static str>)> {
                 $(
                     impl_element_match_attributes!(
-                        element attribute $name $namespace {
+                        element attribute $name $namespace $([$tag])? {
                             $(
                                 $fil: $vil $extra,
                             )*
                         }
                     );
                 )*
-                map_global_attributes(attribute).or_else(|| map_svg_attributes(attribute))
+                map_global_attributes(attribute)
+                    .or_else(|| map_svg_attributes(attribute))
+                    .or_else(|| map_mathml_attributes(attribute))
             }
 
             fn map_element(element: &str) -> Option<(&'static str, Option<&'static str>)> {
                 $(
                     impl_element_match!(
-                        element $name $namespace {
+                        element $name $namespace $([$tag])? {
                             $(
                                 $fil: $vil $extra,
                             )*
@@ -246,7 +324,7 @@ macro_rules! builder_constructors {
         $(
             impl_element!(
                 $(#[$attr])*
-                $name $namespace {
+                $name $namespace $([$tag])? {
                     $(
                         $(#[$attr_method])*
                         $fil: $vil $extra,
@@ -1064,7 +1142,7 @@ builder_constructors! {
         autocomplete: String DEFAULT,
         autofocus: Bool DEFAULT,
         capture: String DEFAULT,
-        checked: Bool DEFAULT,
+        checked: Bool volatile,
         disabled: Bool DEFAULT,
         form: Id DEFAULT,
         formaction: Uri DEFAULT,
@@ -1471,6 +1549,11 @@ builder_constructors! {
     // /// element.
     // image "http://www.w3.org/2000/svg" {};
 
+    /// Build a
+    /// [`<image>`](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/image)
+    /// element.
+    image "http://www.w3.org/2000/svg" {};
+
     /// Build a
     /// [`<line>`](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/line)
     /// element.
@@ -1591,10 +1674,172 @@ builder_constructors! {
     /// element.
     view "http://www.w3.org/2000/svg" {};
 
-    // /// Build a
-    // /// [`<use>`](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use)
-    // /// element.
-    // use "http://www.w3.org/2000/svg" {};
+    /// Build a
+    /// [`<use>`](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use)
+    /// element.
+    r#use "http://www.w3.org/2000/svg" ["use"] {};
+
+
+    // MathML components
+    /// Build a
+    /// [`<math>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math)
+    /// element.
+    math "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<annotation>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/semantics)
+    /// element.
+    annotation "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<annotation-xml>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/semantics)
+    /// element.
+    annotation_xml "http://www.w3.org/1998/Math/MathML" ["annotation-xml"] {};
+
+    /// Build a
+    /// [`<maction>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/maction)
+    /// element.
+    maction "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<menclose>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/menclose)
+    /// element.
+    menclose "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<merror>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/merror)
+    /// element.
+    merror "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mfrac>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mfrac)
+    /// element.
+    mfrac "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mi>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mi)
+    /// element.
+    mi "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mmultiscripts>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mmultiscripts)
+    /// element.
+    mmultiscripts "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mn>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mn)
+    /// element.
+    mn "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mo>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo)
+    /// element.
+    mo "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mover>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mover)
+    /// element.
+    mover "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mpadded>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mpadded)
+    /// element.
+    mpadded "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mphantom>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mphantom)
+    /// element.
+    mphantom "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mprescripts>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mmultiscripts)
+    /// element.
+    mprescripts "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mroot>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mroot)
+    /// element.
+    mroot "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mrow>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mrow)
+    /// element.
+    mrow "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<ms>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/ms)
+    /// element.
+    ms "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mspace>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mspace)
+    /// element.
+    mspace "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<msqrt>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/msqrt)
+    /// element.
+    msqrt "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mstyle>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mstyle)
+    /// element.
+    mstyle "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<msub>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/msub)
+    /// element.
+    msub "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<msubsup>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/msubsup)
+    /// element.
+    msubsup "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<msup>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/msup)
+    /// element.
+    msup "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mtable>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable)
+    /// element.
+    mtable "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mtd>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtd)
+    /// element.
+    mtd "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mtext>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtext)
+    /// element.
+    mtext "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<mtr>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtr)
+    /// element.
+    mtr "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<munder>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/munder)
+    /// element.
+    munder "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<munderover>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/munderover)
+    /// element.
+    munderover "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<none>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mmultiscripts)
+    /// element.
+    none "http://www.w3.org/1998/Math/MathML" {};
+
+    /// Build a
+    /// [`<semantics>`](https://developer.mozilla.org/en-US/docs/Web/MathML/Element/semantics)
+    /// element.
+    semantics "http://www.w3.org/1998/Math/MathML" {};
 
 
 }
@@ -10,8 +10,14 @@ macro_rules! impl_event {
             $( #[$attr] )*
             #[inline]
             pub fn $name<'a, E: crate::EventReturn<T>, T>(_cx: &'a ::dioxus_core::ScopeState, mut _f: impl FnMut(::dioxus_core::Event<$data>) -> E + 'a) -> ::dioxus_core::Attribute<'a> {
+                // the renderer is told to prevent the default action through the name of the listener
+                let name = if <E as crate::EventReturn<T>>::prevents_default() {
+                    concat!(stringify!($name), "_prevent_default")
+                } else {
+                    stringify!($name)
+                };
                 ::dioxus_core::Attribute {
-                    name: stringify!($name),
+                    name,
                     value: _cx.listener(move |e: ::dioxus_core::Event<$data>| {
                         _f(e).spawn(_cx);
                     }),
@@ -33,13 +39,16 @@ mod form;
 mod image;
 mod keyboard;
 mod media;
+mod mounted;
 mod mouse;
 mod pointer;
+mod resize;
 mod scroll;
 mod selection;
 mod toggle;
 mod touch;
 mod transition;
+mod visible;
 mod wheel;
 
 pub use animation::*;
@@ -51,13 +60,16 @@ pub use form::*;
 pub use image::*;
 pub use keyboard::*;
 pub use media::*;
+pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use resize::*;
 pub use scroll::*;
 pub use selection::*;
 pub use toggle::*;
 pub use touch::*;
 pub use transition::*;
+pub use visible::*;
 pub use wheel::*;
 
 pub fn event_bubbles(evt: &str) -> bool {
@@ -144,27 +156,15 @@ pub fn event_bubbles(evt: &str) -> bool {
         "animationiteration" => true,
         "transitionend" => true,
         "toggle" => true,
+        "resize" => false,
+        "mounted" => false,
+        "visible" => false,
         _ => true,
     }
 }
 
-use std::future::Future;
+pub use dioxus_core::EventReturn;
 
 #[doc(hidden)]
-pub trait EventReturn<P>: Sized {
-    fn spawn(self, _cx: &dioxus_core::ScopeState) {}
-}
-
-impl EventReturn<()> for () {}
-#[doc(hidden)]
-pub struct AsyncMarker;
-
-impl<T> EventReturn<AsyncMarker> for T
-where
-    T: Future<Output = ()> + 'static,
-{
-    #[inline]
-    fn spawn(self, cx: &dioxus_core::ScopeState) {
-        cx.spawn(self);
-    }
-}
+#[deprecated(since = "0.3.1", note = "use dioxus_core::AsyncMarker instead")]
+pub type AsyncMarker = dioxus_core::AsyncMarker;
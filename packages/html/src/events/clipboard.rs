@@ -1,21 +1,101 @@
 use dioxus_core::Event;
+use std::{cell::RefCell, rc::Rc};
 
 pub type ClipboardEvent = Event<ClipboardData>;
+
+/// A synthetic event that wraps a web-style [`ClipboardEvent`](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardEvent)
+///
+/// The contents are captured when the event fires, so handlers can read them right away. Browsers generally expose
+/// the clipboard during `paste`, while `copy` and `cut` carry the data already placed on the clipboard (usually
+/// nothing).
+///
+/// Handlers of `copy` and `cut` can replace what goes on the clipboard with [`ClipboardData::set_data`], and handlers
+/// of `paste` can replace what gets pasted by preventing the default and inserting the contents themselves:
+///
+/// ```rust, ignore
+/// rsx! {
+///     div {
+///         oncopy: move |evt: ClipboardEvent| evt.set_data("text/plain", "copied from dioxus"),
+///         onpaste: move |evt: ClipboardEvent| {
+///             evt.prevent_default();
+///             let text = evt.get_data("text/plain").unwrap_or_default();
+///             insert_sanitized(&text);
+///         },
+///     }
+/// }
+/// ```
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct ClipboardData {
-    // DOMDataTransfer clipboardData
+    /// The `text/plain` contents of the clipboard
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub text: Option<String>,
+
+    /// The `text/html` contents of the clipboard
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub html: Option<String>,
+
+    /// The names of the files on the clipboard
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub files: Vec<String>,
+
+    // the contents set by the handlers, shared by every clone of the event so the renderer can read them back
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    written: Rc<RefCell<Vec<(String, String)>>>,
+}
+
+impl ClipboardData {
+    /// Get the clipboard contents for the given MIME type, if they were made available or set by a handler
+    pub fn get_data(&self, format: &str) -> Option<String> {
+        let format = normalize_format(format);
+        if let Some((_, data)) = self.written.borrow().iter().find(|(f, _)| f == format) {
+            return Some(data.clone());
+        }
+        match format {
+            "text/plain" => self.text.clone(),
+            "text/html" => self.html.clone(),
+            _ => None,
+        }
+    }
+
+    /// Put `data` on the clipboard as `format`, like `text/plain` or `text/html`, instead of the selection.
+    ///
+    /// Only `copy` and `cut` write to the clipboard, and renderers that support it write the contents set by the
+    /// handlers once they have run instead of the selection. The web renderer writes every format, desktop writes
+    /// `text/plain` and `text/html`, and the contents are ignored elsewhere.
+    pub fn set_data(&self, format: &str, data: impl Into<String>) {
+        let format = normalize_format(format);
+        let data = data.into();
+        let mut written = self.written.borrow_mut();
+        match written.iter_mut().find(|(f, _)| f == format) {
+            Some((_, old)) => *old = data,
+            None => written.push((format.to_string(), data)),
+        }
+    }
+
+    /// The contents the handlers set with [`ClipboardData::set_data`], as `(format, data)` pairs
+    pub fn written_data(&self) -> Vec<(String, String)> {
+        self.written.borrow().clone()
+    }
+}
+
+// the clipboard API accepts `text` as an alias of `text/plain`
+fn normalize_format(format: &str) -> &str {
+    match format {
+        "text" => "text/plain",
+        format => format,
+    }
 }
 
 impl_event![
     ClipboardData;
 
-    /// oncopy
+    /// Fired when the user copies the current selection
     oncopy
 
-    /// oncut
+    /// Fired when the user cuts the current selection
     oncut
 
-    /// onpaste
+    /// Fired when the user pastes into the element
     onpaste
 ];
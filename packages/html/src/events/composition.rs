@@ -1,21 +1,27 @@
 use dioxus_core::Event;
 
 pub type CompositionEvent = Event<CompositionData>;
+
+/// A synthetic event that wraps a web-style [`CompositionEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CompositionEvent)
+///
+/// Composition events are fired while the user enters text through an input method editor (IME), like when typing
+/// CJK text. Intermediate text is reported with `compositionupdate` and the committed text with `compositionend`.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CompositionData {
+    /// The text being composed. Empty for `compositionstart` unless the IME is replacing a selection.
     pub data: String,
 }
 
 impl_event! [
     CompositionData;
 
-    /// oncompositionstart
+    /// Fired when an IME composition session starts
     oncompositionstart
 
-    /// oncompositionend
+    /// Fired when the composition is committed or cancelled, with the final text
     oncompositionend
 
-    /// oncompositionupdate
+    /// Fired when the text being composed changes
     oncompositionupdate
 ];
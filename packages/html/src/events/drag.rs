@@ -1,6 +1,8 @@
+use std::{collections::HashMap, fmt::Debug};
+
 use dioxus_core::Event;
 
-use crate::MouseData;
+use crate::{FileEngine, MouseData};
 
 pub type DragEvent = Event<DragData>;
 
@@ -8,11 +10,90 @@ pub type DragEvent = Event<DragData>;
 /// placing a pointer device (such as a mouse) on the touch surface and then dragging the pointer to a new location
 /// (such as another DOM element). Applications are free to interpret a drag and drop interaction in an
 /// application-specific way.
+///
+/// The contents of the drag are exposed like a [`DataTransfer`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer).
+/// Browsers only allow reading the data in `dragstart` and `drop` handlers, so it will be empty for other drag events.
+///
+/// To control the cursor shown while dragging, set `effect_allowed` on the dragged element and `drop_effect` on the
+/// drop target:
+///
+/// ```rust, ignore
+/// rsx! {
+///     div { draggable: "true", effect_allowed: "copy" }
+///     div { drop_effect: "copy", ondragover: prevent_default(|_| {}), ondrop: move |evt| { /* ... */ } }
+/// }
+/// ```
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct DragData {
     /// Inherit mouse data
     pub mouse: MouseData,
+
+    /// The string data being dragged, keyed by format (like `text/plain` or `text/uri-list`)
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub data: HashMap<String, String>,
+
+    /// The kinds of operations the drag source allows: "none", "copy", "link", "move", "copyLink", "copyMove",
+    /// "linkMove", "all", or "uninitialized"
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub effect_allowed: String,
+
+    /// The operation the drop target accepts: "none", "copy", "link", or "move"
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub drop_effect: String,
+
+    /// The files being dropped, if any
+    ///
+    /// On desktop, the names are the full paths of the files, and the files are also available while they are
+    /// dragged over the window, in `dragenter` and `dragover` handlers.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            default,
+            skip_serializing,
+            deserialize_with = "crate::events::deserialize_file_engine"
+        )
+    )]
+    pub files: Option<std::sync::Arc<dyn FileEngine>>,
+}
+
+impl DragData {
+    /// Create a new DragData from the mouse data of the drag, with nothing being transferred
+    pub fn new(mouse: MouseData) -> Self {
+        Self {
+            mouse,
+            data: HashMap::new(),
+            effect_allowed: String::new(),
+            drop_effect: String::new(),
+            files: None,
+        }
+    }
+
+    /// Get the data being dragged for the given format, if any
+    pub fn get_data(&self, format: &str) -> Option<&str> {
+        self.data.get(format).map(String::as_str)
+    }
+}
+
+impl PartialEq for DragData {
+    fn eq(&self, other: &Self) -> bool {
+        self.mouse == other.mouse
+            && self.data == other.data
+            && self.effect_allowed == other.effect_allowed
+            && self.drop_effect == other.drop_effect
+    }
+}
+
+impl Debug for DragData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragData")
+            .field("mouse", &self.mouse)
+            .field("data", &self.data)
+            .field("effect_allowed", &self.effect_allowed)
+            .field("drop_effect", &self.drop_effect)
+            .field("files", &self.files.as_ref().map(|files| files.files()))
+            .finish()
+    }
 }
 
 impl_event! {
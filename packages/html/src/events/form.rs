@@ -12,7 +12,15 @@ pub struct FormData {
 
     pub values: HashMap<String, String>,
 
-    #[cfg_attr(feature = "serialize", serde(skip))]
+    /// The files selected in an `<input type="file">`, if any
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            default,
+            skip_serializing,
+            deserialize_with = "deserialize_file_engine"
+        )
+    )]
     pub files: Option<std::sync::Arc<dyn FileEngine>>,
 }
 
@@ -27,6 +35,7 @@ impl Debug for FormData {
         f.debug_struct("FormEvent")
             .field("value", &self.value)
             .field("values", &self.values)
+            .field("files", &self.files.as_ref().map(|files| files.files()))
             .finish()
     }
 }
@@ -43,6 +52,55 @@ pub trait FileEngine {
     async fn read_file_to_string(&self, file: &str) -> Option<String>;
 }
 
+/// A [`FileEngine`] over file contents that were sent along with the event
+///
+/// Renderers that run away from the user's files (like liveview) can't read them themselves, so the interpreter reads
+/// them and sends the contents with the event. Desktop reads the files from their paths instead.
+#[cfg(feature = "serialize")]
+pub struct SerializedFileEngine {
+    files: HashMap<String, Vec<u8>>,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedFileEngine {
+    /// Create a file engine from the contents of each file, keyed by file name
+    pub fn new(files: HashMap<String, Vec<u8>>) -> Self {
+        Self { files }
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[async_trait::async_trait(?Send)]
+impl FileEngine for SerializedFileEngine {
+    fn files(&self) -> Vec<String> {
+        self.files.keys().cloned().collect()
+    }
+
+    async fn read_file(&self, file: &str) -> Option<Vec<u8>> {
+        self.files.get(file).cloned()
+    }
+
+    async fn read_file_to_string(&self, file: &str) -> Option<String> {
+        String::from_utf8(self.files.get(file)?.clone()).ok()
+    }
+}
+
+#[cfg(feature = "serialize")]
+pub(crate) fn deserialize_file_engine<'de, D>(
+    deserializer: D,
+) -> Result<Option<std::sync::Arc<dyn FileEngine>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    let files: Option<HashMap<String, Vec<u8>>> = Option::deserialize(deserializer)?;
+
+    Ok(files
+        .filter(|files| !files.is_empty())
+        .map(|files| std::sync::Arc::new(SerializedFileEngine::new(files)) as _))
+}
+
 impl_event! {
     FormData;
 
@@ -1,7 +1,6 @@
 use crate::input_data::{decode_key_location, encode_key_location};
 use dioxus_core::Event;
 use keyboard_types::{Code, Key, Location, Modifiers};
-use std::convert::TryInto;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 
@@ -9,50 +8,35 @@ pub type KeyboardEvent = Event<KeyboardData>;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, PartialEq, Eq)]
 pub struct KeyboardData {
-    #[deprecated(
-        since = "0.3.0",
-        note = "This may not work in all environments. Use key() instead."
-    )]
-    pub char_code: u32,
-
-    /// Identify which "key" was entered.
-    #[deprecated(since = "0.3.0", note = "use key() instead")]
-    pub key: String,
-
-    /// Get the key code as an enum Variant.
-    #[deprecated(
-        since = "0.3.0",
-        note = "This may not work in all environments. Use code() instead."
-    )]
-    pub key_code: KeyCode,
+    /// the logical key, as reported by the `key` property of the DOM event
+    key: String,
 
     /// the physical key on the keyboard
     code: Code,
 
-    /// Indicate if the `alt` modifier key was pressed during this keyboard event
-    #[deprecated(since = "0.3.0", note = "use modifiers() instead")]
-    pub alt_key: bool,
+    /// the DOM encoding of the key location
+    #[cfg_attr(feature = "serialize", serde(default))]
+    location: usize,
 
-    /// Indicate if the `ctrl` modifier key was pressed during this keyboard event
-    #[deprecated(since = "0.3.0", note = "use modifiers() instead")]
-    pub ctrl_key: bool,
+    /// Indicate if the key is being held down such that it is automatically repeating
+    #[cfg_attr(feature = "serialize", serde(default))]
+    repeat: bool,
 
-    /// Indicate if the `meta` modifier key was pressed during this keyboard event
-    #[deprecated(since = "0.3.0", note = "use modifiers() instead")]
-    pub meta_key: bool,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    alt_key: bool,
 
-    /// Indicate if the `shift` modifier key was pressed during this keyboard event
-    #[deprecated(since = "0.3.0", note = "use modifiers() instead")]
-    pub shift_key: bool,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    ctrl_key: bool,
 
-    #[deprecated(since = "0.3.0", note = "use location() instead")]
-    pub location: usize,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    meta_key: bool,
 
-    #[deprecated(since = "0.3.0", note = "use is_auto_repeating() instead")]
-    pub repeat: bool,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    shift_key: bool,
 
-    #[deprecated(since = "0.3.0", note = "use code() or key() instead")]
-    pub which: usize,
+    /// Indicate if the event was fired while an IME composition session was in progress
+    #[cfg_attr(feature = "serialize", serde(default))]
+    is_composing: bool,
 }
 
 impl_event! {
@@ -76,32 +60,27 @@ impl KeyboardData {
         is_auto_repeating: bool,
         modifiers: Modifiers,
     ) -> Self {
-        #[allow(deprecated)]
         KeyboardData {
-            char_code: key.legacy_charcode(),
             key: key.to_string(),
-            key_code: KeyCode::from_raw_code(
-                key.legacy_keycode()
-                    .try_into()
-                    .expect("could not convert keycode to u8"),
-            ),
             code,
+            location: encode_key_location(location),
+            repeat: is_auto_repeating,
             alt_key: modifiers.contains(Modifiers::ALT),
             ctrl_key: modifiers.contains(Modifiers::CONTROL),
             meta_key: modifiers.contains(Modifiers::META),
             shift_key: modifiers.contains(Modifiers::SHIFT),
-            location: encode_key_location(location),
-            repeat: is_auto_repeating,
-            which: key
-                .legacy_charcode()
-                .try_into()
-                .expect("could not convert charcode to usize"),
+            is_composing: false,
         }
     }
 
+    /// Mark this event as fired during an IME composition session
+    pub fn with_composing(mut self, is_composing: bool) -> Self {
+        self.is_composing = is_composing;
+        self
+    }
+
     /// The value of the key pressed by the user, taking into consideration the state of modifier keys such as Shift as well as the keyboard locale and layout.
     pub fn key(&self) -> Key {
-        #[allow(deprecated)]
         FromStr::from_str(&self.key).expect("could not parse")
     }
 
@@ -114,20 +93,17 @@ impl KeyboardData {
     pub fn modifiers(&self) -> Modifiers {
         let mut modifiers = Modifiers::empty();
 
-        #[allow(deprecated)]
-        {
-            if self.alt_key {
-                modifiers.insert(Modifiers::ALT);
-            }
-            if self.ctrl_key {
-                modifiers.insert(Modifiers::CONTROL);
-            }
-            if self.meta_key {
-                modifiers.insert(Modifiers::META);
-            }
-            if self.shift_key {
-                modifiers.insert(Modifiers::SHIFT);
-            }
+        if self.alt_key {
+            modifiers.insert(Modifiers::ALT);
+        }
+        if self.ctrl_key {
+            modifiers.insert(Modifiers::CONTROL);
+        }
+        if self.meta_key {
+            modifiers.insert(Modifiers::META);
+        }
+        if self.shift_key {
+            modifiers.insert(Modifiers::SHIFT);
         }
 
         modifiers
@@ -135,15 +111,20 @@ impl KeyboardData {
 
     /// The location of the key on the keyboard or other input device.
     pub fn location(&self) -> Location {
-        #[allow(deprecated)]
         decode_key_location(self.location)
     }
 
     /// `true` iff the key is being held down such that it is automatically repeating.
     pub fn is_auto_repeating(&self) -> bool {
-        #[allow(deprecated)]
         self.repeat
     }
+
+    /// `true` iff the event was fired between `compositionstart` and `compositionend`.
+    ///
+    /// Text editors should ignore key presses while composing, since the IME owns them until the composition ends.
+    pub fn is_composing(&self) -> bool {
+        self.is_composing
+    }
 }
 
 impl Debug for KeyboardData {
@@ -154,6 +135,7 @@ impl Debug for KeyboardData {
             .field("modifiers", &self.modifiers())
             .field("location", &self.location())
             .field("is_auto_repeating", &self.is_auto_repeating())
+            .field("is_composing", &self.is_composing())
             .finish()
     }
 }
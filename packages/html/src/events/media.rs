@@ -1,9 +1,41 @@
 use dioxus_core::Event;
 
 pub type MediaEvent = Event<MediaData>;
+
+/// The playback state of an `audio` or `video` element when one of its media events fired
+///
+/// Events that don't come from a media element (like `error` on an image) carry the default values.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MediaData {}
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MediaData {
+    /// The playback position, in seconds
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub current_time: f64,
+
+    /// The length of the media in seconds, or `None` if it isn't known yet or the media is a live stream
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub duration: Option<f64>,
+
+    /// True if playback is paused
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub paused: bool,
+
+    /// True if playback reached the end of the media
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub ended: bool,
+
+    /// True if the audio is muted
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub muted: bool,
+
+    /// The audio volume, from 0.0 (silent) to 1.0 (loudest)
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub volume: f64,
+
+    /// The rate the media is played at, where 1.0 is normal speed
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub playback_rate: f64,
+}
 
 impl_event! [
     MediaData;
@@ -0,0 +1,55 @@
+use dioxus_core::Event;
+use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
+
+pub type MountedEvent = Event<MountedData>;
+
+/// The element a listener is attached to, in the DOM of the renderer
+///
+/// Use it to call the APIs of the platform that need the element itself, like measuring it, focusing it or drawing
+/// on a canvas. The web renderer gives a `web_sys::Element`.
+#[derive(Clone)]
+pub struct MountedData {
+    element: Rc<dyn Any>,
+}
+
+impl MountedData {
+    /// Wrap the element of the renderer
+    pub fn new(element: impl Any) -> Self {
+        Self {
+            element: Rc::new(element),
+        }
+    }
+
+    /// The element of the renderer
+    pub fn get_raw_element(&self) -> &dyn Any {
+        &*self.element
+    }
+
+    /// The element of the renderer, if it is a `T`
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.element.downcast_ref()
+    }
+}
+
+impl fmt::Debug for MountedData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MountedData").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for MountedData {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.element, &other.element)
+    }
+}
+
+impl_event! {
+    MountedData;
+
+    /// onmounted
+    ///
+    /// Fires once the element is in the DOM of the renderer. Only the web renderer sends it for now.
+    onmounted
+}
@@ -1,8 +1,13 @@
 use dioxus_core::Event;
 
 pub type PointerEvent = Event<PointerData>;
+
+/// A synthetic event that wraps a web-style [`PointerEvent`](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent)
+///
+/// Pointer events are fired for mice, pens, and touch contacts alike. Use `pointer_type` to tell them apart and the
+/// pressure, tilt, and twist fields to read stylus data.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct PointerData {
     // Mouse only
     pub alt_key: bool,
@@ -17,15 +22,37 @@ pub struct PointerData {
     pub screen_x: i32,
     pub screen_y: i32,
     pub shift_key: bool,
+
+    /// A unique identifier for the pointer causing the event, stable for as long as the pointer is in contact
     pub pointer_id: i32,
+
+    /// The width (magnitude on the X axis), in CSS pixels, of the contact geometry of the pointer
     pub width: i32,
+
+    /// The height (magnitude on the Y axis), in CSS pixels, of the contact geometry of the pointer
     pub height: i32,
+
+    /// The normalized pressure of the pointer input in the range 0 to 1
+    ///
+    /// Hardware without pressure support reports 0.5 while buttons are held and 0 otherwise.
     pub pressure: f32,
+
+    /// The normalized tangential pressure (barrel pressure) of the pointer input in the range -1 to 1
     pub tangential_pressure: f32,
+
+    /// The plane angle in degrees (-90 to 90) between the Y-Z plane and the plane containing the pen's axis and the Y axis
     pub tilt_x: i32,
+
+    /// The plane angle in degrees (-90 to 90) between the X-Z plane and the plane containing the pen's axis and the X axis
     pub tilt_y: i32,
+
+    /// The clockwise rotation in degrees (0 to 359) of the pen around its own major axis
     pub twist: i32,
+
+    /// The device type that caused the event: "mouse", "pen", or "touch"
     pub pointer_type: String,
+
+    /// True if this pointer is the primary pointer of its type
     pub is_primary: bool,
     // pub get_modifier_state: bool,
 }
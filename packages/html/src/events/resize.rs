@@ -0,0 +1,22 @@
+use dioxus_core::Event;
+
+pub type ResizeEvent = Event<ResizeData>;
+
+/// The size of an element's content box
+///
+/// Web renderers measure it in CSS pixels and the tui renderer in terminal cells.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResizeData {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl_event! {
+    ResizeData;
+
+    /// onresize
+    ///
+    /// Fires once the element has been laid out, and again every time its size changes.
+    onresize
+}
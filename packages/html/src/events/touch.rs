@@ -1,17 +1,103 @@
+use crate::geometry::{ClientPoint, PagePoint, ScreenPoint};
 use dioxus_core::Event;
+use keyboard_types::Modifiers;
 
 pub type TouchEvent = Event<TouchData>;
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct TouchData {
     pub alt_key: bool,
     pub ctrl_key: bool,
     pub meta_key: bool,
     pub shift_key: bool,
-    // get_modifier_state: bool,
-    // changedTouches: DOMTouchList,
-    // targetTouches: DOMTouchList,
-    // touches: DOMTouchList,
+
+    /// Every touch point currently on the surface, regardless of the target or whether it changed.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub touches: Vec<TouchPoint>,
+
+    /// The touch points that changed in this event: the new points for `touchstart`, the moved points for
+    /// `touchmove`, and the lifted points for `touchend` and `touchcancel`.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub changed_touches: Vec<TouchPoint>,
+
+    /// The touch points that started on the same element as this event's target and are still on the surface.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub target_touches: Vec<TouchPoint>,
+}
+
+impl TouchData {
+    /// The set of modifier keys which were pressed when the event occurred
+    pub fn modifiers(&self) -> Modifiers {
+        let mut modifiers = Modifiers::empty();
+
+        if self.alt_key {
+            modifiers.insert(Modifiers::ALT);
+        }
+        if self.ctrl_key {
+            modifiers.insert(Modifiers::CONTROL);
+        }
+        if self.meta_key {
+            modifiers.insert(Modifiers::META);
+        }
+        if self.shift_key {
+            modifiers.insert(Modifiers::SHIFT);
+        }
+
+        modifiers
+    }
+
+    /// Find a touch point that is still on the surface by its [`TouchPoint::identifier`]
+    pub fn touch(&self, identifier: i32) -> Option<&TouchPoint> {
+        self.touches.iter().find(|t| t.identifier == identifier)
+    }
+}
+
+/// A single contact point on a touch surface, modeled after the web [`Touch`](https://developer.mozilla.org/en-US/docs/Web/API/Touch)
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TouchPoint {
+    /// A unique identifier for this touch point. It stays the same for every event fired while the point is on the surface.
+    pub identifier: i32,
+
+    pub client_x: f64,
+    pub client_y: f64,
+    pub page_x: f64,
+    pub page_y: f64,
+    pub screen_x: f64,
+    pub screen_y: f64,
+
+    /// The X radius of the ellipse that most closely circumscribes the area of contact with the screen.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub radius_x: f64,
+
+    /// The Y radius of the ellipse that most closely circumscribes the area of contact with the screen.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub radius_y: f64,
+
+    /// The angle (in degrees) that the ellipse described by `radius_x` and `radius_y` must be rotated, clockwise, to most accurately cover the area of contact.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub rotation_angle: f64,
+
+    /// The amount of pressure being applied, from 0.0 (no pressure) to 1.0 (maximum pressure).
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub force: f64,
+}
+
+impl TouchPoint {
+    /// The touch coordinates, relative to the viewport
+    pub fn client_coordinates(&self) -> ClientPoint {
+        ClientPoint::new(self.client_x, self.client_y)
+    }
+
+    /// The touch coordinates, relative to the whole document, including any part scrolled out of view
+    pub fn page_coordinates(&self) -> PagePoint {
+        PagePoint::new(self.page_x, self.page_y)
+    }
+
+    /// The touch coordinates, relative to the screen
+    pub fn screen_coordinates(&self) -> ScreenPoint {
+        ScreenPoint::new(self.screen_x, self.screen_y)
+    }
 }
 
 impl_event! {
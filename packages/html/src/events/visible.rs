@@ -0,0 +1,24 @@
+use dioxus_core::Event;
+
+pub type VisibleEvent = Event<VisibleData>;
+
+/// How much of an element is in the viewport
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VisibleData {
+    /// Whether any part of the element is in the viewport
+    pub is_intersecting: bool,
+    /// The part of the element in the viewport, from 0 to 1
+    pub intersection_ratio: f64,
+}
+
+impl_event! {
+    VisibleData;
+
+    /// onvisible
+    ///
+    /// Fires once the element has been laid out, and again every time it enters or leaves the viewport, or half of
+    /// it or all of it becomes visible. Use it to load images when they're about to be seen, or the next page of an
+    /// infinite list when its end scrolls into view.
+    onvisible
+}
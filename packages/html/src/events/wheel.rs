@@ -1,10 +1,16 @@
 use dioxus_core::Event;
 use euclid::UnknownUnit;
+use keyboard_types::Modifiers;
 use std::fmt::{Debug, Formatter};
 
-use crate::geometry::{LinesVector, PagesVector, PixelsVector, WheelDelta};
+use crate::geometry::{LinesVector, PagesVector, PixelsVector, WheelDelta, WheelDeltaMode};
 
 pub type WheelEvent = Event<WheelData>;
+
+/// A synthetic event that wraps a web-style [`WheelEvent`](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent)
+///
+/// Trackpad pinch gestures are reported as wheel events with the control key held, so check
+/// `modifiers().contains(Modifiers::CONTROL)` to tell zooming apart from scrolling.
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, PartialEq, Default)]
 pub struct WheelData {
@@ -16,6 +22,22 @@ pub struct WheelData {
     pub delta_y: f64,
     #[deprecated(since = "0.3.0", note = "use delta() instead")]
     pub delta_z: f64,
+
+    /// True if the alt key was down when the wheel was moved
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub alt_key: bool,
+
+    /// True if the control key was down when the wheel was moved
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub ctrl_key: bool,
+
+    /// True if the meta key was down when the wheel was moved
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub meta_key: bool,
+
+    /// True if the shift key was down when the wheel was moved
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub shift_key: bool,
 }
 
 impl_event![
@@ -40,6 +62,7 @@ impl WheelData {
             delta_x: vector.x,
             delta_y: vector.y,
             delta_z: vector.z,
+            ..Default::default()
         }
     }
 
@@ -51,9 +74,19 @@ impl WheelData {
             delta_x,
             delta_y,
             delta_z,
+            ..Default::default()
         }
     }
 
+    /// Set the modifier keys that were held when the wheel was moved
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.alt_key = modifiers.contains(Modifiers::ALT);
+        self.ctrl_key = modifiers.contains(Modifiers::CONTROL);
+        self.meta_key = modifiers.contains(Modifiers::META);
+        self.shift_key = modifiers.contains(Modifiers::SHIFT);
+        self
+    }
+
     /// The amount of wheel movement
     #[allow(deprecated)]
     pub fn delta(&self) -> WheelDelta {
@@ -67,12 +100,45 @@ impl WheelData {
             _ => panic!("Invalid delta mode, {:?}", self.delta_mode),
         }
     }
+
+    /// The unit the wheel movement is expressed in
+    pub fn delta_mode(&self) -> WheelDeltaMode {
+        self.delta().mode()
+    }
+
+    /// The amount of wheel movement converted to pixels, regardless of the unit the device reported it in
+    ///
+    /// See [`WheelDelta::to_pixels`] for how lines and pages are converted.
+    pub fn pixel_delta(&self) -> PixelsVector {
+        self.delta().to_pixels()
+    }
+
+    /// The set of modifier keys which were pressed when the event occurred
+    pub fn modifiers(&self) -> Modifiers {
+        let mut modifiers = Modifiers::empty();
+
+        if self.alt_key {
+            modifiers.insert(Modifiers::ALT);
+        }
+        if self.ctrl_key {
+            modifiers.insert(Modifiers::CONTROL);
+        }
+        if self.meta_key {
+            modifiers.insert(Modifiers::META);
+        }
+        if self.shift_key {
+            modifiers.insert(Modifiers::SHIFT);
+        }
+
+        modifiers
+    }
 }
 
 impl Debug for WheelData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("WheelData")
             .field("delta", &self.delta())
+            .field("modifiers", &self.modifiers())
             .finish()
     }
 }
@@ -58,7 +58,25 @@ pub enum WheelDelta {
     Pages(PagesVector),
 }
 
+/// The unit a [`WheelDelta`] is expressed in, like the web's
+/// [`deltaMode`](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaMode)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WheelDeltaMode {
+    /// The delta is in pixels
+    Pixel,
+    /// The delta is in lines
+    Line,
+    /// The delta is in pages
+    Page,
+}
+
 impl WheelDelta {
+    /// The number of pixels one line of movement is treated as by [`WheelDelta::to_pixels`]
+    pub const LINE_HEIGHT: f64 = 16.0;
+
+    /// The number of pixels one page of movement is treated as by [`WheelDelta::to_pixels`]
+    pub const PAGE_HEIGHT: f64 = 800.0;
+
     /// Convenience function for constructing a WheelDelta with pixel units
     pub fn pixels(x: f64, y: f64, z: f64) -> Self {
         WheelDelta::Pixels(PixelsVector::new(x, y, z))
@@ -93,6 +111,28 @@ impl WheelDelta {
             WheelDelta::Pages(v) => v.cast_unit(),
         }
     }
+
+    /// The unit this delta is expressed in
+    pub fn mode(&self) -> WheelDeltaMode {
+        match self {
+            WheelDelta::Pixels(_) => WheelDeltaMode::Pixel,
+            WheelDelta::Lines(_) => WheelDeltaMode::Line,
+            WheelDelta::Pages(_) => WheelDeltaMode::Page,
+        }
+    }
+
+    /// The amount scrolled, converted to pixels
+    ///
+    /// Use this when you want to treat every device the same way, like in a zoom handler. Lines and pages are
+    /// converted with [`WheelDelta::LINE_HEIGHT`] and [`WheelDelta::PAGE_HEIGHT`], which only approximate how far the
+    /// browser would scroll.
+    pub fn to_pixels(&self) -> PixelsVector {
+        match self {
+            WheelDelta::Pixels(v) => *v,
+            WheelDelta::Lines(v) => (*v * Self::LINE_HEIGHT).cast_unit(),
+            WheelDelta::Pages(v) => (*v * Self::PAGE_HEIGHT).cast_unit(),
+        }
+    }
 }
 
 /// Coordinates of a point in the app's interface
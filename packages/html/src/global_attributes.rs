@@ -82,10 +82,36 @@ trait_methods! {
 
     /// Prevent the default action for this element.
     ///
+    /// The value is the name of the listener, like `"onclick"`. Wrapping the handler in
+    /// [`prevent_default`](dioxus_core::prevent_default) does the same and is checked by the compiler.
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
 
+    /// Set the operation shown to the user while dragging over this element, like "copy" or "move".
+    ///
+    /// The renderer applies this to the drag's `dropEffect` in `dragenter` and `dragover` events on the element.
+    /// For more information, see the MDN docs:
+    /// <https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/dropEffect>
+    drop_effect: "dioxus-drop-effect";
+
+    /// Set the operations allowed when dragging this element, like "copy" or "copyMove".
+    ///
+    /// The renderer applies this to the drag's `effectAllowed` in the `dragstart` event on the element.
+    /// For more information, see the MDN docs:
+    /// <https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/effectAllowed>
+    effect_allowed: "dioxus-effect-allowed";
+
+    /// Reuse the elements removed from this element's children for the children created later, instead of creating
+    /// new ones.
+    ///
+    /// This makes clearing and refilling a large keyed list, like a paginated or filtered table, cheaper. Only set it
+    /// to `true` on lists whose items keep no state in the DOM besides what the app renders: a recycled element keeps
+    /// the text typed in its inputs, its scroll position and anything changed outside of Dioxus. Only the web
+    /// renderer recycles elements.
+    recycle_children: "dioxus-recycle";
+
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/accesskey>
     accesskey: "accesskey";
@@ -184,6 +210,8 @@ trait_methods! {
     part;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/role>
+    ///
+    /// Takes a [`Role`](crate::aria::Role) or a string.
     role;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/slot>
@@ -193,6 +221,8 @@ trait_methods! {
     spellcheck;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/style>
+    ///
+    /// Takes a [`Style`](crate::style::Style) or a string.
     style;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex>
@@ -266,6 +296,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/animation-timing-function>
     animation_timing_function: "animation-timing-function", "style";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio>
+    aspect_ratio: "aspect-ratio", "style";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/CSS/azimuth>
     azimuth: "azimuth", "style";
 
@@ -1436,18 +1469,26 @@ trait_methods! {
     // area attribute
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-current>
+    ///
+    /// Takes an [`AriaCurrent`](crate::aria::AriaCurrent) or a string.
     aria_current: "aria-current";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-details>
     aria_details: "aria-details";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-disabled>
+    ///
+    /// Takes a `bool`.
     aria_disabled: "aria-disabled";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden>
+    ///
+    /// Takes a `bool`.
     aria_hidden: "aria-hidden";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-invalid>
+    ///
+    /// Takes an [`AriaInvalid`](crate::aria::AriaInvalid) or a string.
     aria_invalid: "aria-invalid";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-keyshortcuts>
@@ -1462,48 +1503,74 @@ trait_methods! {
 // Widget Attributes
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-autocomplete>
+    ///
+    /// Takes an [`AriaAutocomplete`](crate::aria::AriaAutocomplete) or a string.
     aria_autocomplete: "aria-autocomplete";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-checked>
+    ///
+    /// Takes an [`AriaTristate`](crate::aria::AriaTristate) or a string.
     aria_checked: "aria-checked";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-expanded>
+    ///
+    /// Takes a `bool`.
     aria_expanded: "aria-expanded";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-haspopup>
+    ///
+    /// Takes an [`AriaHaspopup`](crate::aria::AriaHaspopup) or a string.
     aria_haspopup: "aria-haspopup";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-level>
     aria_level: "aria-level";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-modal>
+    ///
+    /// Takes a `bool`.
     aria_modal: "aria-modal";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiline>
+    ///
+    /// Takes a `bool`.
     aria_multiline: "aria-multiline";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-multiselectable>
+    ///
+    /// Takes a `bool`.
     aria_multiselectable: "aria-multiselectable";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-orientation>
+    ///
+    /// Takes an [`AriaOrientation`](crate::aria::AriaOrientation) or a string.
     aria_orientation: "aria-orientation";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-placeholder>
     aria_placeholder: "aria-placeholder";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-pressed>
+    ///
+    /// Takes an [`AriaTristate`](crate::aria::AriaTristate) or a string.
     aria_pressed: "aria-pressed";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-readonly>
+    ///
+    /// Takes a `bool`.
     aria_readonly: "aria-readonly";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-required>
+    ///
+    /// Takes a `bool`.
     aria_required: "aria-required";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-selected>
+    ///
+    /// Takes a `bool`.
     aria_selected: "aria-selected";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-sort>
+    ///
+    /// Takes an [`AriaSort`](crate::aria::AriaSort) or a string.
     aria_sort: "aria-sort";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-valuemax>
@@ -1521,15 +1588,23 @@ trait_methods! {
 // Live Region Attributes
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-atomic>
+    ///
+    /// Takes a `bool`.
     aria_atomic: "aria-atomic";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-busy>
+    ///
+    /// Takes a `bool`.
     aria_busy: "aria-busy";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-live>
+    ///
+    /// Takes an [`AriaLive`](crate::aria::AriaLive) or a string.
     aria_live: "aria-live";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-relevant>
+    ///
+    /// Takes an [`AriaRelevant`](crate::aria::AriaRelevant) or a string.
     aria_relevant: "aria-relevant";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-dropeffect>
@@ -1593,6 +1668,9 @@ trait_methods! {
 
     /// Prevent the default action for this element.
     ///
+    /// The value is the name of the listener, like `"onclick"`. Wrapping the handler in
+    /// [`prevent_default`](dioxus_core::prevent_default) does the same and is checked by the compiler.
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
@@ -1711,6 +1789,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/decelerate>
     decelerate: "decelerate";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/decoding>
+    decoding: "decoding";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/descent>
     descent: "descent";
 
@@ -1766,9 +1847,17 @@ trait_methods! {
     filter: "filter";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterRes>
+    filter_res: "filterRes";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits>
+    filter_units: "filterUnits";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterRes>
+    #[deprecated(since = "0.3.1", note = "use filter_res instead")]
     filterRes: "filterRes";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits>
+    #[deprecated(since = "0.3.1", note = "use filter_units instead")]
     filterUnits: "filterUnits";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color>
@@ -1864,8 +1953,12 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/image-rendering>
     image_rendering: "image-rendering";
 
-    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/_in>
-    _in: "_in";
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in>
+    r#in: "in";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in>
+    #[deprecated(since = "0.3.1", note = "use r#in instead")]
+    _in: "in";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in2>
     in2: "in2";
@@ -1948,6 +2041,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskContentUnits>
     mask_content_units: "maskContentUnits";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mask-type>
+    mask_type: "mask-type";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskUnits>
     mask_units: "maskUnits";
 
@@ -2113,6 +2209,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/shape-rendering>
     shape_rendering: "shape-rendering";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/side>
+    side: "side";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/slope>
     slope: "slope";
 
@@ -2224,15 +2323,15 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/to>
     to: "to";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type>
+    r#type: "type";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform>
     transform: "transform";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform-origin>
     transform_origin: "transform-origin";
 
-    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/_type>
-    r#type: "_type";
-
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u1>
     u1: "u1";
 
@@ -2323,9 +2422,42 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xmlns>
     xmlns: "xmlns";
 
+    /// The namespace declaration for `xlink:` attributes, for documents that use [`SvgAttributes::xlink_href`] and friends
+    xmlns_xlink: "xmlns:xlink", "http://www.w3.org/2000/xmlns/";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector>
     x_channel_selector: "xChannelSelector";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:actuate>
+    xlink_actuate: "xlink:actuate", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:arcrole>
+    xlink_arcrole: "xlink:arcrole", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:href>
+    xlink_href: "xlink:href", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:role>
+    xlink_role: "xlink:role", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:show>
+    xlink_show: "xlink:show", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:title>
+    xlink_title: "xlink:title", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:type>
+    xlink_type: "xlink:type", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:base>
+    xml_base: "xml:base", "http://www.w3.org/XML/1998/namespace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang>
+    xml_lang: "xml:lang", "http://www.w3.org/XML/1998/namespace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space>
+    xml_space: "xml:space", "http://www.w3.org/XML/1998/namespace";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y>
     y: "y";
 
@@ -2345,3 +2477,156 @@ trait_methods! {
     zoom_and_pan: "zoomAndPan";
 
 }
+
+trait_methods! {
+    @base
+    MathMlAttributes;
+    map_mathml_attributes;
+
+    /// Prevent the default action for this element.
+    ///
+    /// The value is the name of the listener, like `"onclick"`. Wrapping the handler in
+    /// [`prevent_default`](dioxus_core::prevent_default) does the same and is checked by the compiler.
+    ///
+    /// For more information, see the MDN docs:
+    /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
+    prevent_default: "dioxus-prevent-default";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    accent: "accent";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/munder>
+    accentunder: "accentunder";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/maction>
+    actiontype: "actiontype";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    align: "align";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math>
+    alttext: "alttext";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes>
+    class: "class";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    columnalign: "columnalign";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    columnlines: "columnlines";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    columnspacing: "columnspacing";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtd>
+    columnspan: "columnspan";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mpadded>
+    depth: "depth";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/dir>
+    dir: "dir";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/math>
+    display: "display";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/displaystyle>
+    displaystyle: "displaystyle";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/semantics>
+    encoding: "encoding";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    fence: "fence";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    frame: "frame";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mpadded>
+    height: "height";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/href>
+    href: "href";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes>
+    id: "id";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    largeop: "largeop";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mfrac>
+    linethickness: "linethickness";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    lspace: "lspace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathbackground>
+    mathbackground: "mathbackground";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathcolor>
+    mathcolor: "mathcolor";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathsize>
+    mathsize: "mathsize";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/mathvariant>
+    mathvariant: "mathvariant";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    maxsize: "maxsize";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    minsize: "minsize";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    movablelimits: "movablelimits";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/menclose>
+    notation: "notation";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/nonce>
+    nonce: "nonce";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    rowalign: "rowalign";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    rowlines: "rowlines";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtable>
+    rowspacing: "rowspacing";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mtd>
+    rowspan: "rowspan";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    rspace: "rspace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/scriptlevel>
+    scriptlevel: "scriptlevel";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/maction>
+    selection: "selection";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    separator: "separator";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    stretchy: "stretchy";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/style>
+    style: "style";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mo>
+    symmetric: "symmetric";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Global_attributes/tabindex>
+    tabindex: "tabindex";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mpadded>
+    voffset: "voffset";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/MathML/Element/mpadded>
+    width: "width";
+}
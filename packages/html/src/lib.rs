@@ -13,6 +13,7 @@
 //!
 //! Currently, we don't validate for structures, but do validate attributes.
 
+pub mod aria;
 mod elements;
 #[cfg(feature = "hot-reload-context")]
 pub use elements::HtmlCtx;
@@ -21,6 +22,7 @@ pub mod geometry;
 mod global_attributes;
 pub mod input_data;
 mod render_template;
+pub mod style;
 #[cfg(feature = "wasm-bind")]
 mod web_sys_bind;
 
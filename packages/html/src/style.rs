@@ -0,0 +1,277 @@
+//! A typed builder for the `style` attribute
+//!
+//! Styles can always be written as a string, but building them programmatically with `format!` is easy to get wrong. A
+//! [`Style`] collects typed declarations and renders them in the same `name: value;` form as a handwritten style:
+//!
+//! ```rust, ignore
+//! use dioxus_html::style::{Color, Length, Style};
+//!
+//! let style = Style::new()
+//!     .width(Length::Percent(50.0))
+//!     .padding(Length::Px(4.0))
+//!     .color(Color::Rgb(255, 0, 0))
+//!     .property("transition", "opacity 0.2s");
+//!
+//! rsx! {
+//!     div { style: style, "Hello" }
+//! }
+//! ```
+//!
+//! The rendered value is a plain string, so it works with every renderer that understands the `style` attribute.
+
+use dioxus_core::{exports::bumpalo::Bump, prelude::IntoAttributeValue, AttributeValue};
+use std::fmt::{Display, Formatter};
+
+/// A CSS length or percentage
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Length {
+    /// Let the browser pick the length
+    Auto,
+    /// Pixels
+    Px(f64),
+    /// Relative to the font size of the element
+    Em(f64),
+    /// Relative to the font size of the root element
+    Rem(f64),
+    /// Relative to the parent element
+    Percent(f64),
+    /// Relative to 1% of the width of the viewport
+    Vw(f64),
+    /// Relative to 1% of the height of the viewport
+    Vh(f64),
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Auto => f.write_str("auto"),
+            Length::Px(v) => write!(f, "{v}px"),
+            Length::Em(v) => write!(f, "{v}em"),
+            Length::Rem(v) => write!(f, "{v}rem"),
+            Length::Percent(v) => write!(f, "{v}%"),
+            Length::Vw(v) => write!(f, "{v}vw"),
+            Length::Vh(v) => write!(f, "{v}vh"),
+        }
+    }
+}
+
+/// A CSS color
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Color {
+    /// Red, green and blue channels
+    Rgb(u8, u8, u8),
+    /// Red, green and blue channels with an alpha between 0.0 and 1.0
+    Rgba(u8, u8, u8, f32),
+    /// Hue in degrees, saturation and lightness in percent
+    Hsl(f32, f32, f32),
+    /// A named color like `"rebeccapurple"`
+    Named(&'static str),
+    /// The value of the `color` property
+    CurrentColor,
+    /// A fully transparent color
+    Transparent,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Rgb(r, g, b) => write!(f, "rgb({r}, {g}, {b})"),
+            Color::Rgba(r, g, b, a) => write!(f, "rgba({r}, {g}, {b}, {a})"),
+            Color::Hsl(h, s, l) => write!(f, "hsl({h}, {s}%, {l}%)"),
+            Color::Named(name) => f.write_str(name),
+            Color::CurrentColor => f.write_str("currentcolor"),
+            Color::Transparent => f.write_str("transparent"),
+        }
+    }
+}
+
+/// A list of CSS declarations that can be passed to the `style` attribute
+///
+/// Declarations are rendered in the order they were added. Setting the same property twice keeps both declarations,
+/// just like a handwritten style, so the last one wins.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Style {
+    declarations: Vec<(&'static str, String)>,
+}
+
+macro_rules! style_properties {
+    (
+        $(
+            $(#[$attr:meta])*
+            $name:ident: $css:literal => $ty:ty;
+        )*
+    ) => {
+        $(
+            $(#[$attr])*
+            pub fn $name(self, value: $ty) -> Self {
+                self.property($css, value)
+            }
+        )*
+    };
+}
+
+impl Style {
+    /// Create an empty style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a declaration for any CSS property
+    ///
+    /// This is the escape hatch for properties that don't have a typed method.
+    pub fn property(mut self, name: &'static str, value: impl Display) -> Self {
+        self.declarations.push((name, value.to_string()));
+        self
+    }
+
+    /// `true` if no declarations have been added
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+
+    /// The declarations in this style, in the order they were added
+    pub fn declarations(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.declarations
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+    }
+
+    style_properties! {
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/width>
+        width: "width" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/height>
+        height: "height" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/min-width>
+        min_width: "min-width" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/min-height>
+        min_height: "min-height" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/max-width>
+        max_width: "max-width" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/max-height>
+        max_height: "max-height" => Length;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/margin>
+        margin: "margin" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/margin-top>
+        margin_top: "margin-top" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/margin-right>
+        margin_right: "margin-right" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/margin-bottom>
+        margin_bottom: "margin-bottom" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/margin-left>
+        margin_left: "margin-left" => Length;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/padding>
+        padding: "padding" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/padding-top>
+        padding_top: "padding-top" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/padding-right>
+        padding_right: "padding-right" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/padding-bottom>
+        padding_bottom: "padding-bottom" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/padding-left>
+        padding_left: "padding-left" => Length;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/top>
+        top: "top" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/right>
+        right: "right" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/bottom>
+        bottom: "bottom" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/left>
+        left: "left" => Length;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/gap>
+        gap: "gap" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-size>
+        font_size: "font-size" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-radius>
+        border_radius: "border-radius" => Length;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-width>
+        border_width: "border-width" => Length;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/color>
+        color: "color" => Color;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/background-color>
+        background_color: "background-color" => Color;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-color>
+        border_color: "border-color" => Color;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/opacity>
+        opacity: "opacity" => f64;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/flex-grow>
+        flex_grow: "flex-grow" => f64;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/flex-shrink>
+        flex_shrink: "flex-shrink" => f64;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/z-index>
+        z_index: "z-index" => i64;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-weight>
+        font_weight: "font-weight" => u16;
+
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/display>
+        display: "display" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/position>
+        position: "position" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/flex-direction>
+        flex_direction: "flex-direction" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/align-items>
+        align_items: "align-items" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content>
+        justify_content: "justify-content" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/overflow>
+        overflow: "overflow" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-family>
+        font_family: "font-family" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/text-align>
+        text_align: "text-align" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/border-style>
+        border_style: "border-style" => &str;
+        /// <https://developer.mozilla.org/en-US/docs/Web/CSS/cursor>
+        cursor: "cursor" => &str;
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, (name, value)) in self.declarations().enumerate() {
+            if idx > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{name}: {value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoAttributeValue<'a> for &Style {
+    fn into_value(self, bump: &'a Bump) -> AttributeValue<'a> {
+        use dioxus_core::exports::bumpalo::collections::String;
+        use std::fmt::Write;
+
+        let mut buf = String::new_in(bump);
+        write!(buf, "{self}").unwrap();
+        AttributeValue::Text(buf.into_bump_str())
+    }
+}
+
+impl<'a> IntoAttributeValue<'a> for Style {
+    fn into_value(self, bump: &'a Bump) -> AttributeValue<'a> {
+        (&self).into_value(bump)
+    }
+}
+
+#[test]
+fn style_renders_declarations_in_order() {
+    let style = Style::new()
+        .width(Length::Percent(50.0))
+        .padding(Length::Px(4.5))
+        .color(Color::Rgb(255, 0, 0))
+        .display("flex")
+        .property("transition", "opacity 0.2s");
+
+    assert_eq!(
+        style.to_string(),
+        "width: 50%; padding: 4.5px; color: rgb(255, 0, 0); display: flex; transition: opacity 0.2s;"
+    );
+    assert_eq!(Style::new().to_string(), "");
+}
@@ -82,8 +82,8 @@ fn fun_name(
 
         // Pointer
         "pointerlockchange" | "pointerlockerror" | "pointerdown" | "pointermove" | "pointerup"
-        | "pointerover" | "pointerout" | "pointerenter" | "pointerleave" | "gotpointercapture"
-        | "lostpointercapture" => Pointer(de(data)?),
+        | "pointercancel" | "pointerover" | "pointerout" | "pointerenter" | "pointerleave"
+        | "gotpointercapture" | "lostpointercapture" => Pointer(de(data)?),
 
         // Selection
         "selectstart" | "selectionchange" | "select" => Selection(de(data)?),
@@ -113,6 +113,12 @@ fn fun_name(
         // Toggle
         "toggle" => Toggle(de(data)?),
 
+        // Resize
+        "resize" => Resize(de(data)?),
+
+        // Visible
+        "visible" => Visible(de(data)?),
+
         // ImageData => "load" | "error";
         // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "hashchange" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "popstate" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
         other => {
@@ -132,6 +138,45 @@ impl HtmlEvent {
     }
 }
 
+/// An event that fired on the window or the document instead of an element
+///
+/// Events that aren't attached to elements often don't carry any data, like `resize`, so the data is optional.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct GlobalEvent {
+    pub name: String,
+    pub data: Option<EventData>,
+}
+
+impl<'de> Deserialize<'de> for GlobalEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize, Debug, Clone)]
+        struct Inner {
+            name: String,
+            data: serde_value::Value,
+        }
+
+        let Inner { name, data } = Inner::deserialize(deserializer)?;
+
+        Ok(GlobalEvent {
+            data: fun_name(&name, data).ok(),
+            name,
+        })
+    }
+}
+
+impl GlobalEvent {
+    /// The event's data, or `()` if the event doesn't carry any
+    pub fn into_any(self) -> Rc<dyn Any> {
+        match self.data {
+            Some(data) => data.into_any(),
+            None => Rc::new(()),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum EventData {
@@ -151,6 +196,8 @@ pub enum EventData {
     Animation(AnimationData),
     Transition(TransitionData),
     Toggle(ToggleData),
+    Resize(ResizeData),
+    Visible(VisibleData),
 }
 
 impl EventData {
@@ -172,6 +219,8 @@ impl EventData {
             EventData::Animation(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Transition(data) => Rc::new(data) as Rc<dyn Any>,
             EventData::Toggle(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Resize(data) => Rc::new(data) as Rc<dyn Any>,
+            EventData::Visible(data) => Rc::new(data) as Rc<dyn Any>,
         }
     }
 }
@@ -215,3 +264,268 @@ fn test_back_and_forth() {
 
     assert_eq!(data, p);
 }
+
+#[test]
+fn pointer_data_round_trips() {
+    let data = HtmlEvent {
+        element: ElementId(1),
+        data: EventData::Pointer(PointerData {
+            pointer_id: 2,
+            pressure: 0.25,
+            tilt_x: 30,
+            tilt_y: -15,
+            twist: 90,
+            pointer_type: "pen".to_string(),
+            is_primary: true,
+            ..Default::default()
+        }),
+        name: "pointercancel".to_string(),
+        bubbles: true,
+    };
+
+    let json = serde_json::to_string(&data).unwrap();
+    let p: HtmlEvent = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(data, p);
+}
+
+#[test]
+fn drag_data_round_trips() {
+    let mut drag = DragData::new(MouseData::default());
+    drag.data
+        .insert("text/plain".to_string(), "hello".to_string());
+    drag.effect_allowed = "copyMove".to_string();
+    drag.drop_effect = "copy".to_string();
+
+    let data = HtmlEvent {
+        element: ElementId(1),
+        data: EventData::Drag(drag),
+        name: "drop".to_string(),
+        bubbles: true,
+    };
+
+    let json = serde_json::to_string(&data).unwrap();
+    let p: HtmlEvent = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(data, p);
+}
+
+#[test]
+fn wheel_data_without_modifiers_deserializes() {
+    use crate::geometry::{WheelDelta, WheelDeltaMode};
+
+    let o = r#"
+{
+  "element": 0,
+  "name": "wheel",
+  "bubbles": true,
+  "data": {
+    "delta_mode": 1,
+    "delta_x": 0.0,
+    "delta_y": 3.0,
+    "delta_z": 0.0
+  }
+}"#;
+
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    let wheel = match p.data {
+        EventData::Wheel(wheel) => wheel,
+        data => panic!("expected wheel data, got {data:?}"),
+    };
+
+    assert!(wheel.modifiers().is_empty());
+    assert_eq!(wheel.delta_mode(), WheelDeltaMode::Line);
+    assert_eq!(wheel.pixel_delta().y, 3.0 * WheelDelta::LINE_HEIGHT);
+}
+
+#[test]
+fn media_data_round_trips() {
+    let data = HtmlEvent {
+        element: ElementId(1),
+        data: EventData::Media(MediaData {
+            current_time: 12.5,
+            duration: Some(60.0),
+            paused: false,
+            ended: false,
+            muted: true,
+            volume: 0.5,
+            playback_rate: 1.0,
+        }),
+        name: "timeupdate".to_string(),
+        bubbles: false,
+    };
+
+    let json = serde_json::to_string(&data).unwrap();
+    let p: HtmlEvent = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(data, p);
+
+    // Events that don't come from a media element send no playback state
+    let o = r#"{ "element": 0, "name": "error", "bubbles": false, "data": {} }"#;
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    assert_eq!(p.data, EventData::Media(MediaData::default()));
+}
+
+#[test]
+fn form_data_reads_sent_files() {
+    let o = r#"
+{
+  "element": 0,
+  "name": "change",
+  "bubbles": true,
+  "data": {
+    "value": "C:\\fakepath\\hello.txt",
+    "values": {},
+    "files": { "hello.txt": [104, 105] }
+  }
+}"#;
+
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    let form = match p.data {
+        EventData::Form(form) => form,
+        data => panic!("expected form data, got {data:?}"),
+    };
+
+    let files = form.files.expect("files should be attached to the event");
+    assert_eq!(files.files(), vec!["hello.txt".to_string()]);
+}
+
+#[test]
+fn keyboard_data_deserializes_code_location_and_modifiers() {
+    use keyboard_types::{Code, Key, Location, Modifiers};
+
+    let o = r#"
+{
+  "element": 0,
+  "name": "keydown",
+  "bubbles": true,
+  "data": {
+    "key": "A",
+    "code": "KeyA",
+    "location": 0,
+    "repeat": true,
+    "alt_key": false,
+    "ctrl_key": true,
+    "meta_key": false,
+    "shift_key": true,
+    "is_composing": false
+  }
+}"#;
+
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    let keyboard = match p.data {
+        EventData::Keyboard(keyboard) => keyboard,
+        data => panic!("expected keyboard data, got {data:?}"),
+    };
+
+    assert_eq!(keyboard.key(), Key::Character("A".to_string()));
+    assert_eq!(keyboard.code(), Code::KeyA);
+    assert_eq!(keyboard.location(), Location::Standard);
+    assert!(keyboard.is_auto_repeating());
+    assert_eq!(keyboard.modifiers(), Modifiers::CONTROL | Modifiers::SHIFT);
+
+    let rebuilt = KeyboardData::new(
+        keyboard.key(),
+        keyboard.code(),
+        keyboard.location(),
+        keyboard.is_auto_repeating(),
+        keyboard.modifiers(),
+    );
+    assert_eq!(rebuilt, keyboard);
+}
+
+#[test]
+fn touch_data_deserializes_touch_lists() {
+    let o = r#"
+{
+  "element": 0,
+  "name": "touchmove",
+  "bubbles": true,
+  "data": {
+    "alt_key": false,
+    "ctrl_key": false,
+    "meta_key": false,
+    "shift_key": false,
+    "touches": [
+      {
+        "identifier": 3,
+        "client_x": 10.0,
+        "client_y": 20.0,
+        "page_x": 10.0,
+        "page_y": 220.0,
+        "screen_x": 110.0,
+        "screen_y": 320.0,
+        "radius_x": 4.5,
+        "radius_y": 6.0,
+        "rotation_angle": 15.0,
+        "force": 0.75
+      }
+    ],
+    "changed_touches": [],
+    "target_touches": []
+  }
+}"#;
+
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    let touch = match p.data {
+        EventData::Touch(touch) => touch,
+        data => panic!("expected touch data, got {data:?}"),
+    };
+
+    assert!(touch.changed_touches.is_empty());
+    let point = touch.touch(3).expect("touch 3 should be on the surface");
+    assert_eq!(point.page_coordinates().y, 220.0);
+    assert_eq!(point.screen_coordinates().x, 110.0);
+    assert_eq!(point.force, 0.75);
+
+    let p: HtmlEvent = serde_json::from_str(
+        r#"{"element": 0, "name": "touchend", "bubbles": true, "data": {"alt_key": false, "ctrl_key": false, "meta_key": false, "shift_key": true}}"#,
+    )
+    .unwrap();
+    match p.data {
+        EventData::Touch(touch) => {
+            assert!(touch.touches.is_empty());
+            assert_eq!(touch.modifiers(), keyboard_types::Modifiers::SHIFT);
+        }
+        data => panic!("expected touch data, got {data:?}"),
+    }
+}
+
+#[test]
+fn element_resize_events_carry_their_size() {
+    let o = r#"{"element":3,"name":"resize","bubbles":false,"data":{"width":120.5,"height":80.0}}"#;
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    assert_eq!(
+        p.data,
+        EventData::Resize(ResizeData {
+            width: 120.5,
+            height: 80.0
+        })
+    );
+}
+
+#[test]
+fn visible_events_carry_the_intersection() {
+    let o = r#"{"element":2,"name":"visible","bubbles":false,"data":{"is_intersecting":true,"intersection_ratio":0.5}}"#;
+    let p: HtmlEvent = serde_json::from_str(o).unwrap();
+    assert!(!p.bubbles());
+    assert_eq!(
+        p.data,
+        EventData::Visible(VisibleData {
+            is_intersecting: true,
+            intersection_ratio: 0.5
+        })
+    );
+}
+
+#[test]
+fn global_events_without_data_carry_unit() {
+    let o = r#"{"name":"resize","data":{}}"#;
+    let p: GlobalEvent = serde_json::from_str(o).unwrap();
+    assert_eq!(p.data, None);
+    assert!(p.into_any().downcast::<()>().is_ok());
+
+    let o = r#"{"name":"keydown","data":{"key":"a","code":"KeyA"}}"#;
+    let p: GlobalEvent = serde_json::from_str(o).unwrap();
+    assert!(p.into_any().downcast::<KeyboardData>().is_ok());
+}
@@ -1,6 +1,6 @@
 use crate::events::{
-    AnimationData, CompositionData, KeyboardData, MouseData, PointerData, TouchData,
-    TransitionData, WheelData,
+    AnimationData, ClipboardData, CompositionData, KeyboardData, MediaData, MouseData, PointerData,
+    TouchData, TouchPoint, TransitionData, WheelData,
 };
 use crate::geometry::{ClientPoint, Coordinates, ElementPoint, PagePoint, ScreenPoint};
 use crate::input_data::{decode_key_location, decode_mouse_button_set, MouseButton};
@@ -10,8 +10,9 @@ use std::convert::TryInto;
 use std::str::FromStr;
 use wasm_bindgen::JsCast;
 use web_sys::{
-    AnimationEvent, CompositionEvent, Event, KeyboardEvent, MouseEvent, PointerEvent, TouchEvent,
-    TransitionEvent, WheelEvent,
+    AnimationEvent, ClipboardEvent, CompositionEvent, DragEvent, Event, HtmlMediaElement,
+    KeyboardEvent, MouseEvent, PointerEvent, Touch, TouchEvent, TouchList, TransitionEvent,
+    WheelEvent,
 };
 
 macro_rules! uncheck_convert {
@@ -38,10 +39,11 @@ macro_rules! uncheck_convert {
 }
 
 uncheck_convert![
+    ClipboardEvent   => ClipboardData,
     CompositionEvent => CompositionData,
     KeyboardEvent    => KeyboardData,
     MouseEvent       => MouseData,
-    MouseEvent       => DragData,
+    DragEvent        => DragData,
     TouchEvent       => TouchData,
     PointerEvent     => PointerData,
     WheelEvent       => WheelData,
@@ -49,6 +51,38 @@ uncheck_convert![
     TransitionEvent  => TransitionData,
 ];
 
+impl From<&ClipboardEvent> for ClipboardData {
+    fn from(e: &ClipboardEvent) -> Self {
+        let transfer = match e.clipboard_data() {
+            Some(transfer) => transfer,
+            None => return Self::default(),
+        };
+
+        let data = |format| {
+            transfer
+                .get_data(format)
+                .ok()
+                .filter(|data| !data.is_empty())
+        };
+
+        let mut files = Vec::new();
+        if let Some(list) = transfer.files() {
+            for idx in 0..list.length() {
+                if let Some(file) = list.get(idx) {
+                    files.push(file.name());
+                }
+            }
+        }
+
+        Self {
+            text: data("text/plain"),
+            html: data("text/html"),
+            files,
+            ..Default::default()
+        }
+    }
+}
+
 impl From<&CompositionEvent> for CompositionData {
     fn from(e: &CompositionEvent) -> Self {
         Self {
@@ -85,6 +119,7 @@ impl From<&KeyboardEvent> for KeyboardData {
             e.repeat(),
             modifiers,
         )
+        .with_composing(e.is_composing())
     }
 }
 
@@ -119,11 +154,32 @@ impl From<&MouseEvent> for MouseData {
     }
 }
 
-impl From<&MouseEvent> for DragData {
-    fn from(value: &MouseEvent) -> Self {
-        Self {
-            mouse: MouseData::from(value),
+impl From<&DragEvent> for DragData {
+    fn from(e: &DragEvent) -> Self {
+        let mouse: &MouseEvent = e;
+        let mut data = DragData::new(MouseData::from(mouse));
+
+        if let Some(transfer) = e.data_transfer() {
+            // Files are exposed through a FileEngine by the renderer, so only collect the string data here
+            for format in transfer
+                .types()
+                .iter()
+                .filter_map(|format| format.as_string())
+            {
+                if format == "Files" {
+                    continue;
+                }
+
+                if let Ok(value) = transfer.get_data(&format) {
+                    data.data.insert(format, value);
+                }
+            }
+
+            data.effect_allowed = transfer.effect_allowed();
+            data.drop_effect = transfer.drop_effect();
         }
+
+        data
     }
 }
 
@@ -134,6 +190,34 @@ impl From<&TouchEvent> for TouchData {
             ctrl_key: e.ctrl_key(),
             meta_key: e.meta_key(),
             shift_key: e.shift_key(),
+            touches: touch_points(&e.touches()),
+            changed_touches: touch_points(&e.changed_touches()),
+            target_touches: touch_points(&e.target_touches()),
+        }
+    }
+}
+
+fn touch_points(list: &TouchList) -> Vec<TouchPoint> {
+    (0..list.length())
+        .filter_map(|i| list.get(i))
+        .map(|t| TouchPoint::from(&t))
+        .collect()
+}
+
+impl From<&Touch> for TouchPoint {
+    fn from(t: &Touch) -> Self {
+        Self {
+            identifier: t.identifier(),
+            client_x: t.client_x().into(),
+            client_y: t.client_y().into(),
+            page_x: t.page_x().into(),
+            page_y: t.page_y().into(),
+            screen_x: t.screen_x().into(),
+            screen_y: t.screen_y().into(),
+            radius_x: t.radius_x().into(),
+            radius_y: t.radius_y().into(),
+            rotation_angle: t.rotation_angle().into(),
+            force: t.force().into(),
         }
     }
 }
@@ -170,7 +254,43 @@ impl From<&PointerEvent> for PointerData {
 
 impl From<&WheelEvent> for WheelData {
     fn from(e: &WheelEvent) -> Self {
+        let mut modifiers = Modifiers::empty();
+
+        if e.alt_key() {
+            modifiers.insert(Modifiers::ALT);
+        }
+        if e.ctrl_key() {
+            modifiers.insert(Modifiers::CONTROL);
+        }
+        if e.meta_key() {
+            modifiers.insert(Modifiers::META);
+        }
+        if e.shift_key() {
+            modifiers.insert(Modifiers::SHIFT);
+        }
+
         WheelData::from_web_attributes(e.delta_mode(), e.delta_x(), e.delta_y(), e.delta_z())
+            .with_modifiers(modifiers)
+    }
+}
+
+impl From<&HtmlMediaElement> for MediaData {
+    fn from(e: &HtmlMediaElement) -> Self {
+        let duration = e.duration();
+
+        Self {
+            current_time: e.current_time(),
+            duration: if duration.is_finite() {
+                Some(duration)
+            } else {
+                None
+            },
+            paused: e.paused(),
+            ended: e.ended(),
+            muted: e.muted(),
+            volume: e.volume(),
+            playback_rate: e.playback_rate(),
+        }
     }
 }
 
@@ -1,67 +1,172 @@
 class ListenerMap {
   constructor(root) {
-    // bubbling events can listen at the root element
+    // every type of event listens once at the root element, counting the elements that listen for it
     this.global = {};
-    // non bubbling events listen at the element the listener was created at
+    // the non bubbling events each element listens for, by id
     this.local = {};
     this.root = root;
+    // elements don't fire resize and visible events on their own, so observe the ones listening for them
+    this.resizeObserver = null;
+    this.intersectionObserver = null;
   }
 
   create(event_name, element, handler, bubbles) {
-    if (bubbles) {
-      if (this.global[event_name] === undefined) {
-        this.global[event_name] = {};
-        this.global[event_name].active = 1;
-        this.global[event_name].callback = handler;
-        this.root.addEventListener(event_name, handler);
-      } else {
-        this.global[event_name].active++;
-      }
+    if (this.global[event_name] === undefined) {
+      // non bubbling events never reach the root, but they pass it while capturing
+      const callback = (event) => {
+        if (bubbles || this.listensAt(event)) {
+          if (this.preventsDefault(event)) {
+            event.preventDefault();
+          }
+          handler(event);
+        }
+      };
+      this.global[event_name] = { active: 1, callback, capture: !bubbles };
+      this.root.addEventListener(event_name, callback, !bubbles);
+    } else {
+      this.global[event_name].active++;
     }
-    else {
+    if (!bubbles) {
       const id = element.getAttribute("data-dioxus-id");
       if (!this.local[id]) {
         this.local[id] = {};
       }
-      this.local[id][event_name] = handler;
-      element.addEventListener(event_name, handler);
+      this.local[id][event_name] = true;
+      if (event_name === "resize") {
+        this.observeResize(element);
+      }
+      if (event_name === "visible") {
+        this.observeVisibility(element);
+      }
     }
   }
 
   remove(element, event_name, bubbles) {
-    if (bubbles) {
-      this.global[event_name].active--;
-      if (this.global[event_name].active === 0) {
-        this.root.removeEventListener(event_name, this.global[event_name].callback);
+    const listener = this.global[event_name];
+    if (listener !== undefined) {
+      listener.active--;
+      if (listener.active === 0) {
+        this.root.removeEventListener(event_name, listener.callback, listener.capture);
         delete this.global[event_name];
       }
     }
-    else {
+    if (!bubbles) {
       const id = element.getAttribute("data-dioxus-id");
-      delete this.local[id][event_name];
-      if (this.local[id].length === 0) {
-        delete this.local[id];
+      if (this.local[id]) {
+        delete this.local[id][event_name];
+        if (Object.keys(this.local[id]).length === 0) {
+          delete this.local[id];
+        }
+      }
+      if (event_name === "resize" && this.resizeObserver) {
+        this.resizeObserver.unobserve(element);
+      }
+      if (event_name === "visible" && this.intersectionObserver) {
+        this.intersectionObserver.unobserve(element);
       }
-      element.removeEventListener(event_name, handler);
     }
   }
 
-  removeAllNonBubbling(element) {
+  // a non bubbling event only goes to the element it fired at
+  listensAt(event) {
+    const target = event.composedPath()[0];
+    if (!(target instanceof Element)) {
+      return false;
+    }
+    const local = this.local[target.getAttribute("data-dioxus-id")];
+    return local !== undefined && local[event.type] === true;
+  }
+
+  // the default action is prevented if any element the event reaches has a listener that always prevents it,
+  // marked with an attribute named after the listener
+  preventsDefault(event) {
+    const path = event.bubbles ? event.composedPath() : event.composedPath().slice(0, 1);
+    const names = [`on${event.type}_prevent_default`, `on${event.type}_capture_prevent_default`];
+    return path.some((target) => target instanceof Element && names.some((name) => target.hasAttribute(name)));
+  }
+
+  // forget the listeners of an element that is no longer in the dom
+  removeElement(element) {
     const id = element.getAttribute("data-dioxus-id");
     delete this.local[id];
+    if (this.resizeObserver) {
+      this.resizeObserver.unobserve(element);
+    }
+    if (this.intersectionObserver) {
+      this.intersectionObserver.unobserve(element);
+    }
+  }
+
+  observeResize(element) {
+    if (!this.resizeObserver) {
+      this.resizeObserver = new ResizeObserver((entries) => {
+        for (const entry of entries) {
+          const detail = {
+            width: entry.contentRect.width,
+            height: entry.contentRect.height,
+          };
+          entry.target.dispatchEvent(new CustomEvent("resize", { detail }));
+        }
+      });
+    }
+    this.resizeObserver.observe(element);
+  }
+
+  observeVisibility(element) {
+    if (!this.intersectionObserver) {
+      this.intersectionObserver = new IntersectionObserver(
+        (entries) => {
+          for (const entry of entries) {
+            const detail = {
+              is_intersecting: entry.isIntersecting,
+              intersection_ratio: entry.intersectionRatio,
+            };
+            entry.target.dispatchEvent(new CustomEvent("visible", { detail }));
+          }
+        },
+        { threshold: [0, 0.5, 1] }
+      );
+    }
+    this.intersectionObserver.observe(element);
   }
 }
 
 class Interpreter {
-  constructor(root) {
+  // `nativeFiles` is set by renderers that read the user's files themselves, like desktop. File inputs open a
+  // dialog through the renderer instead of the webview, and the contents of files are never sent.
+  constructor(root, options = {}) {
     this.root = root;
+    this.nativeFiles = options.nativeFiles === true;
+    // events are sent one after another, so the ones waiting for files to be read don't fall behind later events
+    this.sending = Promise.resolve();
+    if (this.nativeFiles) {
+      root.addEventListener("click", (event) => this.openFileDialog(event));
+    }
     this.listeners = new ListenerMap(root);
     this.nodes = [root];
     this.stack = [root];
     this.handlers = {};
     this.templates = {};
+    this.globalListeners = {};
+    this.mediaQueries = {};
     this.lastNodeWasText = false;
   }
+  // opens the native dialog for file inputs with listeners, which reports the paths of the files picked
+  openFileDialog(event) {
+    const input = event.target instanceof Element ? event.target.closest(`input[type="file"]`) : null;
+    if (input === null || !input.hasAttribute(`data-dioxus-id`)) {
+      return;
+    }
+    event.preventDefault();
+    window.ipc.postMessage(
+      serializeIpcMessage("file_dialog", {
+        id: parseInt(input.getAttribute(`data-dioxus-id`)),
+        accept: input.getAttribute("accept"),
+        multiple: input.multiple,
+        directory: input.webkitdirectory === true,
+      })
+    );
+  }
   top() {
     return this.stack[this.stack.length - 1];
   }
@@ -93,7 +198,7 @@ class Interpreter {
     let root = this.nodes[root_id];
     let els = this.stack.splice(this.stack.length - m);
     if (is_element_node(root.nodeType)) {
-      this.listeners.removeAllNonBubbling(root);
+      this.listeners.removeElement(root);
     }
     root.replaceWith(...els);
   }
@@ -111,7 +216,7 @@ class Interpreter {
     let node = this.nodes[root];
     if (node !== undefined) {
       if (is_element_node(node)) {
-        this.listeners.removeAllNonBubbling(node);
+        this.listeners.removeElement(node);
       }
       node.remove();
     }
@@ -129,13 +234,68 @@ class Interpreter {
   }
   NewEventListener(event_name, root, bubbles, handler) {
     const element = this.nodes[root];
+    element.listening = (element.listening || 0) + 1;
     element.setAttribute("data-dioxus-id", `${root}`);
     this.listeners.create(event_name, element, handler, bubbles);
   }
   RemoveEventListener(root, event_name, bubbles) {
     const element = this.nodes[root];
-    element.removeAttribute(`data-dioxus-id`);
     this.listeners.remove(element, event_name, bubbles);
+    element.listening -= 1;
+    if (element.listening === 0) {
+      element.removeAttribute(`data-dioxus-id`);
+    }
+  }
+  AddGlobalListener(target, event_name) {
+    const key = `${target}:${event_name}`;
+    if (this.globalListeners[key] !== undefined) {
+      return;
+    }
+    const handler = (event) => {
+      window.ipc.postMessage(
+        serializeIpcMessage("global_event", {
+          target,
+          name: event_name,
+          data: serialize_event(event),
+        })
+      );
+    };
+    this.globalListeners[key] = handler;
+    (target === "document" ? document : window).addEventListener(
+      event_name,
+      handler
+    );
+  }
+  RemoveGlobalListener(target, event_name) {
+    const key = `${target}:${event_name}`;
+    const handler = this.globalListeners[key];
+    if (handler === undefined) {
+      return;
+    }
+    (target === "document" ? document : window).removeEventListener(
+      event_name,
+      handler
+    );
+    delete this.globalListeners[key];
+  }
+  WatchMediaQuery(id, query) {
+    const list = window.matchMedia(query);
+    const report = () => {
+      window.ipc.postMessage(
+        serializeIpcMessage("media_query", { id, matches: list.matches })
+      );
+    };
+    list.addEventListener("change", report);
+    this.mediaQueries[id] = { list, report };
+    report();
+  }
+  UnwatchMediaQuery(id) {
+    const watched = this.mediaQueries[id];
+    if (watched === undefined) {
+      return;
+    }
+    watched.list.removeEventListener("change", watched.report);
+    delete this.mediaQueries[id];
   }
   SetText(root, text) {
     this.nodes[root].textContent = text;
@@ -167,10 +327,10 @@ class Interpreter {
           }
           break;
         case "checked":
-          node.checked = value === "true";
+          node.checked = value === true || value === "true";
           break;
         case "selected":
-          node.selected = value === "true";
+          node.selected = value === true || value === "true";
           break;
         case "dangerous_inner_html":
           node.innerHTML = value;
@@ -212,6 +372,11 @@ class Interpreter {
     for (let edit of edits.edits) {
       this.handleEdit(edit);
     }
+
+    // the ids are not used anymore, so the nodes can be garbage collected
+    for (let id of edits.freed_ids ?? []) {
+      delete this.nodes[id];
+    }
   }
 
   SaveTemplate(template) {
@@ -287,7 +452,11 @@ class Interpreter {
     node.replaceWith(...els);
   }
   LoadTemplate(name, index, id) {
-    let node = this.templates[name][index].cloneNode(true);
+    const template = this.templates[name];
+    if (template === undefined) {
+      throw new Error(`The template ${name} was loaded before it was saved`);
+    }
+    let node = template[index].cloneNode(true);
     this.nodes[id] = node;
     this.stack.push(node);
   }
@@ -339,7 +508,7 @@ class Interpreter {
         this.RemoveAttribute(edit.id, edit.name, edit.ns);
         break;
       case "RemoveEventListener":
-        this.RemoveEventListener(edit.id, edit.name);
+        this.RemoveEventListener(edit.id, edit.name, event_bubbles(edit.name));
         break;
       case "NewEventListener":
 
@@ -360,7 +529,7 @@ class Interpreter {
               let a_element = target.closest("a");
               if (a_element != null) {
                 event.preventDefault();
-                if (shouldPreventDefault !== `onclick` && a_element.getAttribute(`dioxus-prevent-default`) !== `onclick`) {
+                if (shouldPreventDefault !== `onclick` && a_element.getAttribute(`dioxus-prevent-default`) !== `onclick` && !this.listeners.preventsDefault(event)) {
                   const href = a_element.getAttribute("href");
                   if (href !== "" && href !== null && href !== undefined) {
                     window.ipc.postMessage(
@@ -375,16 +544,12 @@ class Interpreter {
                 event.preventDefault();
               }
             }
-            // walk the tree to find the real element
-            while (realId == null) {
-              // we've reached the root we don't want to send an event
-              if (target.parentElement === null) {
-                return;
-              }
-
-              target = target.parentElement;
-              realId = target.getAttribute(`data-dioxus-id`);
+            // the first element in the path of the event with an id is the one listening
+            target = listening_element(event);
+            if (target === null) {
+              return;
             }
+            realId = target.getAttribute(`data-dioxus-id`);
 
             shouldPreventDefault = target.getAttribute(
               `dioxus-prevent-default`
@@ -396,6 +561,20 @@ class Interpreter {
               event.preventDefault();
             }
 
+            if (event.dataTransfer != null) {
+              let effectAllowed = target.getAttribute(`dioxus-effect-allowed`);
+              let dropEffect = target.getAttribute(`dioxus-drop-effect`);
+              if (event.type === "dragstart" && effectAllowed != null) {
+                event.dataTransfer.effectAllowed = effectAllowed;
+              }
+              if (
+                (event.type === "dragenter" || event.type === "dragover") &&
+                dropEffect != null
+              ) {
+                event.dataTransfer.dropEffect = dropEffect;
+              }
+            }
+
             if (event.type === "submit") {
               event.preventDefault();
             }
@@ -427,14 +606,35 @@ class Interpreter {
             if (realId === null) {
               return;
             }
-            window.ipc.postMessage(
-              serializeIpcMessage("user_event", {
-                name: edit.name,
-                element: parseInt(realId),
-                data: contents,
-                bubbles,
-              })
-            );
+
+            // renderers that can't read the user's files get their contents along with the event
+            let files = null;
+            if (this.nativeFiles) {
+              // desktop attaches the files it picked or the window reported itself
+            } else if (
+              event.target.tagName === "INPUT" &&
+              event.target.type === "file" &&
+              (event.type === "change" || event.type === "input")
+            ) {
+              files = event.target.files;
+            } else if (event.type === "drop" && event.dataTransfer != null) {
+              files = event.dataTransfer.files;
+            }
+            const read = files != null && files.length > 0 ? read_files(files) : null;
+
+            this.sending = this.sending.then(async () => {
+              if (read !== null) {
+                contents.files = await read;
+              }
+              window.ipc.postMessage(
+                serializeIpcMessage("user_event", {
+                  name: edit.name,
+                  element: parseInt(realId),
+                  data: contents,
+                  bubbles,
+                })
+              );
+            });
           }
         };
         this.NewEventListener(edit.name, edit.id, bubbles, handler);
@@ -443,6 +643,15 @@ class Interpreter {
   }
 }
 
+async function read_files(files) {
+  let read = {};
+  for (let file of files) {
+    const buffer = await file.arrayBuffer();
+    read[file.name] = Array.from(new Uint8Array(buffer));
+  }
+  return read;
+}
+
 function get_mouse_data(event) {
   const {
     altKey,
@@ -478,49 +687,78 @@ function get_mouse_data(event) {
   };
 }
 
+function serialize_touch_list(list) {
+  let touches = [];
+  for (let i = 0; i < list.length; i++) {
+    const touch = list[i];
+    touches.push({
+      identifier: touch.identifier,
+      client_x: touch.clientX,
+      client_y: touch.clientY,
+      page_x: touch.pageX,
+      page_y: touch.pageY,
+      screen_x: touch.screenX,
+      screen_y: touch.screenY,
+      radius_x: touch.radiusX ?? 0,
+      radius_y: touch.radiusY ?? 0,
+      rotation_angle: touch.rotationAngle ?? 0,
+      force: touch.force ?? 0,
+    });
+  }
+  return touches;
+}
+
 function serialize_event(event) {
   switch (event.type) {
     case "copy":
     case "cut":
-    case "past": {
-      return {};
+    case "paste": {
+      let transfer = event.clipboardData;
+      if (!transfer) {
+        return {};
+      }
+      let files = [];
+      for (let i = 0; i < transfer.files.length; i++) {
+        files.push(transfer.files[i].name);
+      }
+      return {
+        text: transfer.getData("text/plain") || null,
+        html: transfer.getData("text/html") || null,
+        files: files,
+      };
     }
     case "compositionend":
     case "compositionstart":
     case "compositionupdate": {
       let { data } = event;
       return {
-        data,
+        data: data ?? "",
       };
     }
     case "keydown":
     case "keypress":
     case "keyup": {
       let {
-        charCode,
         key,
+        code,
+        location,
+        repeat,
         altKey,
         ctrlKey,
         metaKey,
-        keyCode,
         shiftKey,
-        location,
-        repeat,
-        which,
-        code,
+        isComposing,
       } = event;
       return {
-        char_code: charCode,
-        key: key,
+        key,
+        code,
+        location,
+        repeat,
         alt_key: altKey,
         ctrl_key: ctrlKey,
         meta_key: metaKey,
-        key_code: keyCode,
         shift_key: shiftKey,
-        location: location,
-        repeat: repeat,
-        which: which,
-        code,
+        is_composing: isComposing,
       };
     }
     case "focus":
@@ -564,7 +802,22 @@ function serialize_event(event) {
     case "dragover":
     case "dragstart":
     case "drop": {
-      return { mouse: get_mouse_data(event) };
+      let data = {};
+      let transfer = event.dataTransfer;
+      if (transfer != null) {
+        for (let format of transfer.types) {
+          // files are read by the renderer, not sent over as strings
+          if (format !== "Files") {
+            data[format] = transfer.getData(format);
+          }
+        }
+      }
+      return {
+        mouse: get_mouse_data(event),
+        data: data,
+        effect_allowed: transfer?.effectAllowed ?? "",
+        drop_effect: transfer?.dropEffect ?? "",
+      };
     }
     case "click":
     case "contextmenu":
@@ -647,25 +900,30 @@ function serialize_event(event) {
     case "touchstart": {
       const { altKey, ctrlKey, metaKey, shiftKey } = event;
       return {
-        // changed_touches: event.changedTouches,
-        // target_touches: event.targetTouches,
-        // touches: event.touches,
         alt_key: altKey,
         ctrl_key: ctrlKey,
         meta_key: metaKey,
         shift_key: shiftKey,
+        touches: serialize_touch_list(event.touches),
+        changed_touches: serialize_touch_list(event.changedTouches),
+        target_touches: serialize_touch_list(event.targetTouches),
       };
     }
     case "scroll": {
       return {};
     }
     case "wheel": {
-      const { deltaX, deltaY, deltaZ, deltaMode } = event;
+      const { deltaX, deltaY, deltaZ, deltaMode, altKey, ctrlKey, metaKey, shiftKey } =
+        event;
       return {
         delta_x: deltaX,
         delta_y: deltaY,
         delta_z: deltaZ,
         delta_mode: deltaMode,
+        alt_key: altKey,
+        ctrl_key: ctrlKey,
+        meta_key: metaKey,
+        shift_key: shiftKey,
       };
     }
     case "animationstart":
@@ -709,11 +967,36 @@ function serialize_event(event) {
     case "timeupdate":
     case "volumechange":
     case "waiting": {
-      return {};
+      const target = event.target;
+      if (!(target instanceof HTMLMediaElement)) {
+        return {};
+      }
+      return {
+        current_time: target.currentTime,
+        // NaN and Infinity can't be sent as json
+        duration: isFinite(target.duration) ? target.duration : null,
+        paused: target.paused,
+        ended: target.ended,
+        muted: target.muted,
+        volume: target.volume,
+        playback_rate: target.playbackRate,
+      };
     }
     case "toggle": {
       return {};
     }
+    case "resize": {
+      if (event.detail) {
+        return { width: event.detail.width, height: event.detail.height };
+      }
+      return {};
+    }
+    case "visible": {
+      return {
+        is_intersecting: event.detail.is_intersecting,
+        intersection_ratio: event.detail.intersection_ratio,
+      };
+    }
     default: {
       return {};
     }
@@ -751,6 +1034,15 @@ const bool_attrs = {
   truespeed: true,
 };
 
+function listening_element(event) {
+  for (const node of event.composedPath()) {
+    if (node instanceof Element && node.hasAttribute(`data-dioxus-id`)) {
+      return node;
+    }
+  }
+  return null;
+}
+
 function is_element_node(node) {
   return node.nodeType == 1;
 }
@@ -921,6 +1213,10 @@ function event_bubbles(event) {
       return true;
     case "toggle":
       return true;
+    case "resize":
+      return false;
+    case "visible":
+      return false;
   }
 
   return true;
@@ -7,54 +7,132 @@ mod js {
     const JS: &str = r#"
     class ListenerMap {
         constructor(root) {
-            // bubbling events can listen at the root element
+            // every type of event listens once at the root element, counting the elements that listen for it
             this.global = {};
-            // non bubbling events listen at the element the listener was created at
+            // the non bubbling events each element listens for, by id
             this.local = {};
             this.root = null;
             this.handler = null;
+            // elements don't fire resize and visible events on their own, so observe the ones listening for them
+            this.resizeObserver = null;
+            this.intersectionObserver = null;
         }
 
         create(event_name, element, bubbles) {
-            if (bubbles) {
-                if (this.global[event_name] === undefined) {
-                    this.global[event_name] = {};
-                    this.global[event_name].active = 1;
-                    this.root.addEventListener(event_name, this.handler);
-                } else {
-                    this.global[event_name].active++;
-                }
+            if (this.global[event_name] === undefined) {
+                // non bubbling events never reach the root, but they pass it while capturing
+                const callback = (event) => {
+                    if (bubbles || this.listensAt(event)) {
+                        if (this.preventsDefault(event)) {
+                            event.preventDefault();
+                        }
+                        this.handler(event);
+                    }
+                };
+                this.global[event_name] = { active: 1, callback, capture: !bubbles };
+                this.root.addEventListener(event_name, callback, !bubbles);
+            } else {
+                this.global[event_name].active++;
             }
-            else {
+            if (!bubbles) {
                 const id = element.getAttribute("data-dioxus-id");
                 if (!this.local[id]) {
                     this.local[id] = {};
                 }
-                element.addEventListener(event_name, this.handler);
+                this.local[id][event_name] = true;
+                if (event_name === "resize") {
+                    this.observeResize(element);
+                }
+                if (event_name === "visible") {
+                    this.observeVisibility(element);
+                }
             }
         }
 
         remove(element, event_name, bubbles) {
-            if (bubbles) {
-                this.global[event_name].active--;
-                if (this.global[event_name].active === 0) {
-                    this.root.removeEventListener(event_name, this.global[event_name].callback);
+            const listener = this.global[event_name];
+            if (listener !== undefined) {
+                listener.active--;
+                if (listener.active === 0) {
+                    this.root.removeEventListener(event_name, listener.callback, listener.capture);
                     delete this.global[event_name];
                 }
             }
-            else {
+            if (!bubbles) {
                 const id = element.getAttribute("data-dioxus-id");
-                delete this.local[id][event_name];
-                if (this.local[id].length === 0) {
-                    delete this.local[id];
+                if (this.local[id]) {
+                    delete this.local[id][event_name];
+                    if (Object.keys(this.local[id]).length === 0) {
+                        delete this.local[id];
+                    }
+                }
+                if (event_name === "resize" && this.resizeObserver) {
+                    this.resizeObserver.unobserve(element);
                 }
-                element.removeEventListener(event_name, this.handler);
+                if (event_name === "visible" && this.intersectionObserver) {
+                    this.intersectionObserver.unobserve(element);
+                }
+            }
+        }
+
+        // a non bubbling event only goes to the element it fired at
+        listensAt(event) {
+            const target = event.composedPath()[0];
+            if (!(target instanceof Element)) {
+                return false;
             }
+            const local = this.local[target.getAttribute("data-dioxus-id")];
+            return local !== undefined && local[event.type] === true;
         }
 
-        removeAllNonBubbling(element) {
+        // the default action is prevented if any element the event reaches has a listener that always prevents it,
+        // marked with an attribute named after the listener
+        preventsDefault(event) {
+            const path = event.bubbles ? event.composedPath() : event.composedPath().slice(0, 1);
+            const names = ["on" + event.type + "_prevent_default", "on" + event.type + "_capture_prevent_default"];
+            return path.some((target) => target instanceof Element && names.some((name) => target.hasAttribute(name)));
+        }
+
+        // forget the listeners of an element that is no longer in the dom
+        removeElement(element) {
             const id = element.getAttribute("data-dioxus-id");
             delete this.local[id];
+            if (this.resizeObserver) {
+                this.resizeObserver.unobserve(element);
+            }
+            if (this.intersectionObserver) {
+                this.intersectionObserver.unobserve(element);
+            }
+        }
+
+        observeResize(element) {
+            if (!this.resizeObserver) {
+                this.resizeObserver = new ResizeObserver((entries) => {
+                    for (const entry of entries) {
+                        const detail = {
+                            width: entry.contentRect.width,
+                            height: entry.contentRect.height,
+                        };
+                        entry.target.dispatchEvent(new CustomEvent("resize", { detail }));
+                    }
+                });
+            }
+            this.resizeObserver.observe(element);
+        }
+
+        observeVisibility(element) {
+            if (!this.intersectionObserver) {
+                this.intersectionObserver = new IntersectionObserver((entries) => {
+                    for (const entry of entries) {
+                        const detail = {
+                            is_intersecting: entry.isIntersecting,
+                            intersection_ratio: entry.intersectionRatio,
+                        };
+                        entry.target.dispatchEvent(new CustomEvent("visible", { detail }));
+                    }
+                }, { threshold: [0, 0.5, 1] });
+            }
+            this.intersectionObserver.observe(element);
         }
     }
     function SetAttributeInner(node, field, value, ns) {
@@ -109,19 +187,63 @@ mod js {
     let nodes = [];
     let stack = [];
     const templates = {};
-    let node, els, end, ptr_end, k;
-    export function save_template(nodes, tmpl_id) {
+    // the template roots without dynamic nodes, which can be reused once removed
+    const recyclable = {};
+    // removed template roots waiting to be reused, by template and root index
+    const recycled = {};
+    // the most removed nodes kept for each template root
+    const RECYCLE_LIMIT = 1000;
+    let node, els, end, ptr_end, k, pool;
+    export function save_template(nodes, tmpl_id, reusable) {
         templates[tmpl_id] = nodes;
+        recyclable[tmpl_id] = reusable;
+        recycled[tmpl_id] = nodes.map(() => []);
     }
     export function set_node(id, node) {
         nodes[id] = node;
     }
+    export function get_node(id) {
+        return nodes[id];
+    }
     export function initilize(root, handler) {
         listeners.handler = handler;
         nodes = [root];
         stack = [root];
         listeners.root = root;
     }
+    function LoadTemplate(tmpl_id, index) {
+        node = recycled[tmpl_id][index].pop();
+        if (node === undefined) {
+            node = templates[tmpl_id][index].cloneNode(true);
+            node.template = tmpl_id;
+            node.templateIndex = index;
+        }
+        return node;
+    }
+    // keep a removed node to load its template root again, if its parent asked for it with `dioxus-recycle`.
+    // the dynamic attributes and text are written again when it is loaded, so only the listeners need to be cleared
+    function Recycle(node) {
+        if (!(node instanceof Element) || node.template === undefined || node.parentElement === null || node.parentElement.getAttribute("dioxus-recycle") !== "true") {
+            return;
+        }
+        if (recyclable[node.template][node.templateIndex] !== 1) {
+            return;
+        }
+        pool = recycled[node.template][node.templateIndex];
+        if (pool.length >= RECYCLE_LIMIT) {
+            return;
+        }
+        for (const el of node.querySelectorAll("[data-dioxus-id]")) {
+            if (el.listening) {
+                listeners.removeElement(el);
+                el.listening = 0;
+            }
+            el.removeAttribute("data-dioxus-id");
+        }
+        node.listening = 0;
+        node.removeAttribute("data-dioxus-id");
+        pool.push(node);
+    }
     function AppendChildren(id, many){
         root = nodes[id];
         els = stack.splice(stack.length-many);
@@ -161,11 +283,14 @@ mod js {
 
     extern "C" {
         #[wasm_bindgen]
-        pub fn save_template(nodes: Vec<Node>, tmpl_id: u32);
+        pub fn save_template(nodes: Vec<Node>, tmpl_id: u32, recyclable: Vec<u8>);
 
         #[wasm_bindgen]
         pub fn set_node(id: u32, node: Node);
 
+        #[wasm_bindgen]
+        pub fn get_node(id: u32) -> Node;
+
         #[wasm_bindgen]
         pub fn initilize(root: Node, handler: &Function);
     }
@@ -183,7 +308,7 @@ mod js {
         "{stack.pop();}"
     }
     fn replace_with(id: u32, n: u32) {
-        "{root = nodes[$id$]; els = stack.splice(stack.length-$n$); if (root.listening) { listeners.removeAllNonBubbling(root); } root.replaceWith(...els);}"
+        "{root = nodes[$id$]; els = stack.splice(stack.length-$n$); if (root.listening) { listeners.removeElement(root); } Recycle(root); root.replaceWith(...els);}"
     }
     fn insert_after(id: u32, n: u32) {
         "{nodes[$id$].after(...stack.splice(stack.length-$n$));}"
@@ -192,7 +317,10 @@ mod js {
         "{nodes[$id$].before(...stack.splice(stack.length-$n$));}"
     }
     fn remove(id: u32) {
-        "{node = nodes[$id$]; if (node !== undefined) { if (node.listening) { listeners.removeAllNonBubbling(node); } node.remove(); }}"
+        "{node = nodes[$id$]; if (node !== undefined) { if (node.listening) { listeners.removeElement(node); } Recycle(node); node.remove(); }}"
+    }
+    fn free_id(id: u32) {
+        "{delete nodes[$id$];}"
     }
     fn create_raw_text(text: &str) {
         "{stack.push(document.createTextNode($text$));}"
@@ -207,7 +335,7 @@ mod js {
         r#"node = nodes[id]; if(node.listening){node.listening += 1;}else{node.listening = 1;} node.setAttribute('data-dioxus-id', `\${id}`); listeners.create($event_name$, node, $bubbles$);"#
     }
     fn remove_event_listener(event_name: &str<u8, evt>, id: u32, bubbles: u8) {
-        "{node = nodes[$id$]; node.listening -= 1; node.removeAttribute('data-dioxus-id'); listeners.remove(node, $event_name$, $bubbles$);}"
+        "{node = nodes[$id$]; listeners.remove(node, $event_name$, $bubbles$); node.listening -= 1; if (node.listening === 0) { node.removeAttribute('data-dioxus-id'); }}"
     }
     fn set_text(id: u32, text: &str) {
         "{nodes[$id$].textContent = $text$;}"
@@ -254,6 +382,6 @@ mod js {
         "{els = stack.splice(stack.length - $n$); node = LoadChild($ptr$, $len$); node.replaceWith(...els);}"
     }
     fn load_template(tmpl_id: u32, index: u32, id: u32) {
-        "{node = templates[$tmpl_id$][$index$].cloneNode(true); nodes[$id$] = node; stack.push(node);}"
+        "{node = LoadTemplate($tmpl_id$, $index$); nodes[$id$] = node; stack.push(node);}"
     }
 }
@@ -1,6 +1,6 @@
 /*
-- [ ] pub display: Display, ----> taffy doesnt support all display types
-- [x] pub position_type: PositionType,  --> taffy doesnt support everything
+- [x] pub display: Display, ----> taffy doesnt support all display types
+- [x] pub position: Position,  --> taffy doesnt support everything
 - [x] pub direction: Direction,
 
 - [x] pub flex_direction: FlexDirection,
@@ -15,18 +15,26 @@
 - [x] pub align_self: AlignSelf,
 - [x] pub align_content: AlignContent,
 
-- [x] pub margin: Rect<Dimension>,
-- [x] pub padding: Rect<Dimension>,
+- [x] pub margin: Rect<LengthPercentageAuto>,
+- [x] pub padding: Rect<LengthPercentage>,
 
 - [x] pub justify_content: JustifyContent,
-- [x] pub position: Rect<Dimension>,
-- [x] pub border: Rect<Dimension>,
+- [x] pub inset: Rect<LengthPercentageAuto>,
+- [x] pub border: Rect<LengthPercentage>,
 
-- [ ] pub size: Size<Dimension>, ----> seems to only be relevant for input?
-- [ ] pub min_size: Size<Dimension>,
-- [ ] pub max_size: Size<Dimension>,
+- [x] pub size: Size<Dimension>,
+- [x] pub min_size: Size<Dimension>,
+- [x] pub max_size: Size<Dimension>,
 
-- [ ] pub aspect_ratio: Number, ----> parsing is done, but taffy doesnt support it
+- [x] pub aspect_ratio: Option<f32>,
+
+- [x] pub grid_template_rows: GridTrackVec<TrackSizingFunction>, ----> repeat() only with a number of repetitions
+- [x] pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+- [x] pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
+- [x] pub grid_auto_columns: GridTrackVec<NonRepeatedTrackSizingFunction>,
+- [x] pub grid_auto_flow: GridAutoFlow,
+- [x] pub grid_row: Line<GridPlacement>, ----> no named lines
+- [x] pub grid_column: Line<GridPlacement>,
 */
 
 use lightningcss::properties::border::LineStyle;
@@ -42,8 +50,13 @@ use lightningcss::{
     },
 };
 use taffy::{
+    geometry::Line,
     prelude::*,
-    style::{FlexDirection, PositionType},
+    style::{
+        GridAutoFlow, GridPlacement, MaxTrackSizingFunction, MinTrackSizingFunction,
+        NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    },
+    style_helpers::{line, span},
 };
 
 #[derive(Default)]
@@ -91,33 +104,31 @@ pub fn apply_layout_attributes_cfg(
                 display::Display::Keyword(display::DisplayKeyword::None) => {
                     style.display = Display::None
                 }
-                display::Display::Pair(pair) => {
-                    if let display::DisplayInside::Flex(_) = pair.inside {
-                        style.display = Display::Flex
-                    }
-                }
+                display::Display::Pair(pair) => match pair.inside {
+                    display::DisplayInside::Flex(_) => style.display = Display::Flex,
+                    display::DisplayInside::Grid => style.display = Display::Grid,
+                    _ => (),
+                },
                 _ => (),
             },
             Property::Position(position) => {
-                style.position_type = match position {
-                    position::Position::Relative => PositionType::Relative,
-                    position::Position::Absolute => PositionType::Absolute,
+                style.position = match position {
+                    position::Position::Relative => Position::Relative,
+                    position::Position::Absolute => Position::Absolute,
                     _ => return,
                 }
             }
-            Property::Top(top) => style.position.top = convert_length_percentage_or_auto(top),
+            Property::Top(top) => style.inset.top = convert_length_percentage_or_auto(top),
             Property::Bottom(bottom) => {
-                style.position.bottom = convert_length_percentage_or_auto(bottom)
-            }
-            Property::Left(left) => style.position.left = convert_length_percentage_or_auto(left),
-            Property::Right(right) => {
-                style.position.right = convert_length_percentage_or_auto(right)
+                style.inset.bottom = convert_length_percentage_or_auto(bottom)
             }
+            Property::Left(left) => style.inset.left = convert_length_percentage_or_auto(left),
+            Property::Right(right) => style.inset.right = convert_length_percentage_or_auto(right),
             Property::Inset(inset) => {
-                style.position.top = convert_length_percentage_or_auto(inset.top);
-                style.position.bottom = convert_length_percentage_or_auto(inset.bottom);
-                style.position.left = convert_length_percentage_or_auto(inset.left);
-                style.position.right = convert_length_percentage_or_auto(inset.right);
+                style.inset.top = convert_length_percentage_or_auto(inset.top);
+                style.inset.bottom = convert_length_percentage_or_auto(inset.bottom);
+                style.inset.left = convert_length_percentage_or_auto(inset.left);
+                style.inset.right = convert_length_percentage_or_auto(inset.right);
             }
             Property::BorderTopWidth(width) => {
                 style.border.top = convert_border_side_width(width, &config.border_widths);
@@ -224,16 +235,16 @@ pub fn apply_layout_attributes_cfg(
                 style.flex_shrink = shrink;
             }
             Property::FlexBasis(basis, _) => {
-                style.flex_basis = convert_length_percentage_or_auto(basis);
+                style.flex_basis = convert_dimension(basis);
             }
             Property::Flex(flex, _) => {
                 style.flex_grow = flex.grow;
                 style.flex_shrink = flex.shrink;
-                style.flex_basis = convert_length_percentage_or_auto(flex.basis);
+                style.flex_basis = convert_dimension(flex.basis);
             }
             Property::AlignContent(align, _) => {
                 use AlignContent::*;
-                style.align_content = match align {
+                style.align_content = Some(match align {
                     align::AlignContent::ContentDistribution(distribution) => match distribution {
                         align::ContentDistribution::SpaceBetween => SpaceBetween,
                         align::ContentDistribution::SpaceAround => SpaceAround,
@@ -244,17 +255,17 @@ pub fn apply_layout_attributes_cfg(
                         value: position, ..
                     } => match position {
                         align::ContentPosition::Center => Center,
-                        align::ContentPosition::Start | align::ContentPosition::FlexStart => {
-                            FlexStart
-                        }
-                        align::ContentPosition::End | align::ContentPosition::FlexEnd => FlexEnd,
+                        align::ContentPosition::Start => Start,
+                        align::ContentPosition::FlexStart => FlexStart,
+                        align::ContentPosition::End => End,
+                        align::ContentPosition::FlexEnd => FlexEnd,
                     },
                     _ => return,
-                };
+                });
             }
             Property::JustifyContent(justify, _) => {
-                use JustifyContent::*;
-                style.justify_content = match justify {
+                use AlignContent::*;
+                style.justify_content = Some(match justify {
                     align::JustifyContent::ContentDistribution(distribution) => {
                         match distribution {
                             align::ContentDistribution::SpaceBetween => SpaceBetween,
@@ -267,62 +278,60 @@ pub fn apply_layout_attributes_cfg(
                         value: position, ..
                     } => match position {
                         align::ContentPosition::Center => Center,
-                        // start ignores -reverse flex-direction but there is no way to specify that in Taffy
-                        align::ContentPosition::Start | align::ContentPosition::FlexStart => {
-                            FlexStart
-                        }
-                        // end ignores -reverse flex-direction but there is no way to specify that in Taffy
-                        align::ContentPosition::End | align::ContentPosition::FlexEnd => FlexEnd,
+                        align::ContentPosition::Start => Start,
+                        align::ContentPosition::FlexStart => FlexStart,
+                        align::ContentPosition::End => End,
+                        align::ContentPosition::FlexEnd => FlexEnd,
                     },
                     _ => return,
-                };
+                });
             }
             Property::AlignSelf(align, _) => {
-                use AlignSelf::*;
+                use AlignItems::*;
                 style.align_self = match align {
-                    align::AlignSelf::Auto => Auto,
-                    align::AlignSelf::Stretch => Stretch,
-                    align::AlignSelf::BaselinePosition(_) => Baseline,
+                    align::AlignSelf::Auto => None,
+                    align::AlignSelf::Stretch => Some(Stretch),
+                    align::AlignSelf::BaselinePosition(_) => Some(Baseline),
                     align::AlignSelf::SelfPosition {
                         value: position, ..
-                    } => match position {
+                    } => Some(match position {
                         align::SelfPosition::Center => Center,
-                        align::SelfPosition::Start
-                        | align::SelfPosition::SelfStart
-                        | align::SelfPosition::FlexStart => FlexStart,
-                        align::SelfPosition::End
-                        | align::SelfPosition::SelfEnd
-                        | align::SelfPosition::FlexEnd => FlexEnd,
-                    },
+                        align::SelfPosition::Start | align::SelfPosition::SelfStart => Start,
+                        align::SelfPosition::FlexStart => FlexStart,
+                        align::SelfPosition::End | align::SelfPosition::SelfEnd => End,
+                        align::SelfPosition::FlexEnd => FlexEnd,
+                    }),
                     _ => return,
                 };
             }
             Property::AlignItems(align, _) => {
                 use AlignItems::*;
-                style.align_items = match align {
+                style.align_items = Some(match align {
                     align::AlignItems::BaselinePosition(_) => Baseline,
                     align::AlignItems::Stretch => Stretch,
                     align::AlignItems::SelfPosition {
                         value: position, ..
                     } => match position {
                         align::SelfPosition::Center => Center,
+                        align::SelfPosition::Start | align::SelfPosition::SelfStart => Start,
                         align::SelfPosition::FlexStart => FlexStart,
+                        align::SelfPosition::End | align::SelfPosition::SelfEnd => End,
                         align::SelfPosition::FlexEnd => FlexEnd,
-                        _ => return,
                     },
                     _ => return,
-                };
+                });
             }
+            // the gap between rows is the height of the gap in taffy, and the gap between columns is the width
             Property::RowGap(row_gap) => {
-                style.gap.width = convert_gap_value(row_gap);
+                style.gap.height = convert_gap_value(row_gap);
             }
             Property::ColumnGap(column_gap) => {
-                style.gap.height = convert_gap_value(column_gap);
+                style.gap.width = convert_gap_value(column_gap);
             }
             Property::Gap(gap) => {
                 style.gap = Size {
-                    width: convert_gap_value(gap.row),
-                    height: convert_gap_value(gap.column),
+                    width: convert_gap_value(gap.column),
+                    height: convert_gap_value(gap.row),
                 };
             }
             Property::MarginTop(margin) => {
@@ -346,23 +355,23 @@ pub fn apply_layout_attributes_cfg(
                 };
             }
             Property::PaddingTop(padding) => {
-                style.padding.top = convert_length_percentage_or_auto(padding);
+                style.padding.top = convert_padding(padding);
             }
             Property::PaddingBottom(padding) => {
-                style.padding.bottom = convert_length_percentage_or_auto(padding);
+                style.padding.bottom = convert_padding(padding);
             }
             Property::PaddingLeft(padding) => {
-                style.padding.left = convert_length_percentage_or_auto(padding);
+                style.padding.left = convert_padding(padding);
             }
             Property::PaddingRight(padding) => {
-                style.padding.right = convert_length_percentage_or_auto(padding);
+                style.padding.right = convert_padding(padding);
             }
             Property::Padding(padding) => {
                 style.padding = Rect {
-                    top: convert_length_percentage_or_auto(padding.top),
-                    bottom: convert_length_percentage_or_auto(padding.bottom),
-                    left: convert_length_percentage_or_auto(padding.left),
-                    right: convert_length_percentage_or_auto(padding.right),
+                    top: convert_padding(padding.top),
+                    bottom: convert_padding(padding.bottom),
+                    left: convert_padding(padding.left),
+                    right: convert_padding(padding.right),
                 };
             }
             Property::Width(width) => {
@@ -371,39 +380,278 @@ pub fn apply_layout_attributes_cfg(
             Property::Height(height) => {
                 style.size.height = convert_size(height);
             }
+            Property::MinWidth(width) => {
+                style.min_size.width = convert_size(width);
+            }
+            Property::MinHeight(height) => {
+                style.min_size.height = convert_size(height);
+            }
+            Property::MaxWidth(width) => {
+                style.max_size.width = convert_max_size(width);
+            }
+            Property::MaxHeight(height) => {
+                style.max_size.height = convert_max_size(height);
+            }
             _ => (),
         }
         // currently not implemented in lightningcss
         if name == "aspect-ratio" {
-            if let Ok(ratio) = Ratio::parse_string(value) {
+            if value.trim() == "auto" {
+                style.aspect_ratio = None;
+            } else if let Ok(ratio) = Ratio::parse_string(value) {
                 style.aspect_ratio = Some(ratio.0 / ratio.1);
             }
         }
     }
+    apply_grid_attribute(name, value, style);
+}
+
+/// Grid properties are parsed here, because lightningcss keeps line names and areas we have no use for
+fn apply_grid_attribute(name: &str, value: &str, style: &mut Style) {
+    let value = value.trim();
+    match name {
+        "grid-template-columns" => {
+            if let Some(tracks) = parse_grid_template(value) {
+                style.grid_template_columns = tracks;
+            }
+        }
+        "grid-template-rows" => {
+            if let Some(tracks) = parse_grid_template(value) {
+                style.grid_template_rows = tracks;
+            }
+        }
+        "grid-auto-columns" => {
+            if let Some(tracks) = parse_grid_auto_tracks(value) {
+                style.grid_auto_columns = tracks;
+            }
+        }
+        "grid-auto-rows" => {
+            if let Some(tracks) = parse_grid_auto_tracks(value) {
+                style.grid_auto_rows = tracks;
+            }
+        }
+        "grid-auto-flow" => {
+            let dense = value.split_whitespace().any(|word| word == "dense");
+            let column = value.split_whitespace().any(|word| word == "column");
+            style.grid_auto_flow = match (column, dense) {
+                (false, false) => GridAutoFlow::Row,
+                (false, true) => GridAutoFlow::RowDense,
+                (true, false) => GridAutoFlow::Column,
+                (true, true) => GridAutoFlow::ColumnDense,
+            };
+        }
+        "grid-column" => {
+            if let Some(placement) = parse_grid_lines(value) {
+                style.grid_column = placement;
+            }
+        }
+        "grid-row" => {
+            if let Some(placement) = parse_grid_lines(value) {
+                style.grid_row = placement;
+            }
+        }
+        "grid-column-start" => {
+            if let Some(placement) = parse_grid_placement(value) {
+                style.grid_column.start = placement;
+            }
+        }
+        "grid-column-end" => {
+            if let Some(placement) = parse_grid_placement(value) {
+                style.grid_column.end = placement;
+            }
+        }
+        "grid-row-start" => {
+            if let Some(placement) = parse_grid_placement(value) {
+                style.grid_row.start = placement;
+            }
+        }
+        "grid-row-end" => {
+            if let Some(placement) = parse_grid_placement(value) {
+                style.grid_row.end = placement;
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Split a value on whitespace and commas outside of parentheses
+fn split_grid_value(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if depth == 0 && (c.is_whitespace() || c == ',') => {
+                if start < i {
+                    parts.push(&value[start..i]);
+                }
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    if start < value.len() {
+        parts.push(&value[start..]);
+    }
+    parts
+}
+
+/// Parse a `grid-template-columns` or `grid-template-rows` track list
+fn parse_grid_template(value: &str) -> Option<Vec<TrackSizingFunction>> {
+    if value == "none" {
+        return Some(Vec::new());
+    }
+    let mut tracks = Vec::new();
+    for part in split_grid_value(value) {
+        if let Some(args) = function_arguments(part, "repeat") {
+            let (count, repeated) = args.split_once(',')?;
+            let count: u16 = count.trim().parse().ok()?;
+            let repeated = parse_grid_auto_tracks(repeated)?;
+            for _ in 0..count {
+                tracks.extend(repeated.iter().copied().map(TrackSizingFunction::Single));
+            }
+        } else {
+            tracks.push(TrackSizingFunction::Single(parse_track_size(part)?));
+        }
+    }
+    Some(tracks)
+}
+
+/// Parse a list of track sizes without repetitions, like `grid-auto-rows`
+fn parse_grid_auto_tracks(value: &str) -> Option<Vec<NonRepeatedTrackSizingFunction>> {
+    split_grid_value(value)
+        .into_iter()
+        .map(parse_track_size)
+        .collect()
+}
+
+fn parse_track_size(value: &str) -> Option<NonRepeatedTrackSizingFunction> {
+    if let Some(args) = function_arguments(value, "minmax") {
+        let (min, max) = args.split_once(',')?;
+        Some(NonRepeatedTrackSizingFunction {
+            min: parse_min_track_size(min.trim())?,
+            max: parse_max_track_size(max.trim())?,
+        })
+    } else if let Some(limit) = function_arguments(value, "fit-content") {
+        Some(NonRepeatedTrackSizingFunction {
+            min: MinTrackSizingFunction::Auto,
+            max: MaxTrackSizingFunction::FitContent(parse_length_percentage(limit.trim())?),
+        })
+    } else {
+        let max = parse_max_track_size(value)?;
+        let min = match max {
+            // flexible tracks can't be smaller than their content
+            MaxTrackSizingFunction::Fraction(_) => MinTrackSizingFunction::Auto,
+            _ => parse_min_track_size(value)?,
+        };
+        Some(NonRepeatedTrackSizingFunction { min, max })
+    }
+}
+
+fn parse_min_track_size(value: &str) -> Option<MinTrackSizingFunction> {
+    Some(match value {
+        "auto" => MinTrackSizingFunction::Auto,
+        "min-content" => MinTrackSizingFunction::MinContent,
+        "max-content" => MinTrackSizingFunction::MaxContent,
+        _ => MinTrackSizingFunction::Fixed(parse_length_percentage(value)?),
+    })
+}
+
+fn parse_max_track_size(value: &str) -> Option<MaxTrackSizingFunction> {
+    Some(match value {
+        "auto" => MaxTrackSizingFunction::Auto,
+        "min-content" => MaxTrackSizingFunction::MinContent,
+        "max-content" => MaxTrackSizingFunction::MaxContent,
+        _ => match value.strip_suffix("fr") {
+            Some(fraction) => MaxTrackSizingFunction::Fraction(fraction.parse().ok()?),
+            None => MaxTrackSizingFunction::Fixed(parse_length_percentage(value)?),
+        },
+    })
+}
+
+fn parse_length_percentage(value: &str) -> Option<LengthPercentage> {
+    match parse_value(value)? {
+        Dimension::Points(points) => Some(LengthPercentage::Points(points)),
+        Dimension::Percent(percent) => Some(LengthPercentage::Percent(percent)),
+        _ => None,
+    }
+}
+
+/// The arguments of a css function call like `repeat(3, 1fr)`
+fn function_arguments<'a>(value: &'a str, function: &str) -> Option<&'a str> {
+    value
+        .strip_prefix(function)?
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Parse the start and end of a `grid-column` or `grid-row` like `1 / span 2`
+fn parse_grid_lines(value: &str) -> Option<Line<GridPlacement>> {
+    let (start, end) = match value.split_once('/') {
+        Some((start, end)) => (parse_grid_placement(start)?, parse_grid_placement(end)?),
+        None => (parse_grid_placement(value)?, GridPlacement::Auto),
+    };
+    Some(Line { start, end })
+}
+
+fn parse_grid_placement(value: &str) -> Option<GridPlacement> {
+    let value = value.trim();
+    if value == "auto" {
+        Some(GridPlacement::Auto)
+    } else if let Some(count) = value.strip_prefix("span") {
+        Some(span(count.trim().parse().ok()?))
+    } else {
+        Some(line(value.parse().ok()?))
+    }
 }
 
-fn convert_length_value(length_value: LengthValue) -> Dimension {
+fn convert_length_value(length_value: LengthValue) -> f32 {
     match length_value {
-        LengthValue::Px(value) => Dimension::Points(value),
+        LengthValue::Px(value) => value,
         _ => todo!(),
     }
 }
 
 fn convert_dimension_percentage(
     dimension_percentage: DimensionPercentage<LengthValue>,
-) -> Dimension {
+) -> LengthPercentage {
     match dimension_percentage {
-        DimensionPercentage::Dimension(value) => convert_length_value(value),
-        DimensionPercentage::Percentage(percentage) => Dimension::Percent(percentage.0),
+        DimensionPercentage::Dimension(value) => {
+            LengthPercentage::Points(convert_length_value(value))
+        }
+        DimensionPercentage::Percentage(percentage) => LengthPercentage::Percent(percentage.0),
         _ => todo!(),
     }
 }
 
 fn convert_length_percentage_or_auto(
     length_percentage_or_auto: LengthPercentageOrAuto,
-) -> Dimension {
+) -> LengthPercentageAuto {
+    match length_percentage_or_auto {
+        LengthPercentageOrAuto::Auto => LengthPercentageAuto::Auto,
+        LengthPercentageOrAuto::LengthPercentage(percentage) => {
+            match convert_dimension_percentage(percentage) {
+                LengthPercentage::Points(points) => LengthPercentageAuto::Points(points),
+                LengthPercentage::Percent(percent) => LengthPercentageAuto::Percent(percent),
+            }
+        }
+    }
+}
+
+fn convert_dimension(length_percentage_or_auto: LengthPercentageOrAuto) -> Dimension {
+    match convert_length_percentage_or_auto(length_percentage_or_auto) {
+        LengthPercentageAuto::Points(points) => Dimension::Points(points),
+        LengthPercentageAuto::Percent(percent) => Dimension::Percent(percent),
+        LengthPercentageAuto::Auto => Dimension::Auto,
+    }
+}
+
+fn convert_padding(length_percentage_or_auto: LengthPercentageOrAuto) -> LengthPercentage {
     match length_percentage_or_auto {
-        LengthPercentageOrAuto::Auto => Dimension::Auto,
+        // padding can't be auto
+        LengthPercentageOrAuto::Auto => LengthPercentage::Points(0.0),
         LengthPercentageOrAuto::LengthPercentage(percentage) => {
             convert_dimension_percentage(percentage)
         }
@@ -413,31 +661,45 @@ fn convert_length_percentage_or_auto(
 fn convert_border_side_width(
     border_side_width: BorderSideWidth,
     border_width_config: &BorderWidths,
-) -> Dimension {
-    match border_side_width {
+) -> LengthPercentage {
+    LengthPercentage::Points(match border_side_width {
         BorderSideWidth::Length(Length::Value(value)) => convert_length_value(value),
-        BorderSideWidth::Thick => Dimension::Points(border_width_config.thick),
-        BorderSideWidth::Medium => Dimension::Points(border_width_config.medium),
-        BorderSideWidth::Thin => Dimension::Points(border_width_config.thin),
+        BorderSideWidth::Thick => border_width_config.thick,
+        BorderSideWidth::Medium => border_width_config.medium,
+        BorderSideWidth::Thin => border_width_config.thin,
         _ => todo!(),
-    }
+    })
 }
 
-fn convert_gap_value(gap_value: GapValue) -> Dimension {
+fn convert_gap_value(gap_value: GapValue) -> LengthPercentage {
     match gap_value {
         GapValue::LengthPercentage(dim) => convert_dimension_percentage(dim),
-        GapValue::Normal => Dimension::Auto,
+        GapValue::Normal => LengthPercentage::Points(0.0),
     }
 }
 
 fn convert_size(size: size::Size) -> Dimension {
     match size {
         size::Size::Auto => Dimension::Auto,
-        size::Size::LengthPercentage(length) => convert_dimension_percentage(length),
+        size::Size::LengthPercentage(length) => match convert_dimension_percentage(length) {
+            LengthPercentage::Points(points) => Dimension::Points(points),
+            LengthPercentage::Percent(percent) => Dimension::Percent(percent),
+        },
         _ => todo!(),
     }
 }
 
+fn convert_max_size(max_size: size::MaxSize) -> Dimension {
+    match max_size {
+        size::MaxSize::LengthPercentage(length) => match convert_dimension_percentage(length) {
+            LengthPercentage::Points(points) => Dimension::Points(points),
+            LengthPercentage::Percent(percent) => Dimension::Percent(percent),
+        },
+        // none and the content based sizes don't limit the size
+        _ => Dimension::Auto,
+    }
+}
+
 /// parse relative or absolute value
 pub fn parse_value(value: &str) -> Option<Dimension> {
     if value.ends_with("px") {
@@ -456,3 +718,70 @@ pub fn parse_value(value: &str) -> Option<Dimension> {
         None
     }
 }
+
+#[test]
+fn grid_tracks_and_lines() {
+    let mut style = Style::default();
+    apply_layout_attributes("display", "grid", &mut style);
+    apply_layout_attributes(
+        "grid-template-columns",
+        "10px repeat(2, 1fr) minmax(20%, auto)",
+        &mut style,
+    );
+    apply_layout_attributes("grid-row", "2 / span 3", &mut style);
+    apply_layout_attributes("grid-auto-flow", "column dense", &mut style);
+    apply_layout_attributes("gap", "1px 2px", &mut style);
+
+    let fr = NonRepeatedTrackSizingFunction {
+        min: MinTrackSizingFunction::Auto,
+        max: MaxTrackSizingFunction::Fraction(1.0),
+    };
+    assert_eq!(style.display, Display::Grid);
+    assert_eq!(
+        style.grid_template_columns,
+        vec![
+            TrackSizingFunction::Single(NonRepeatedTrackSizingFunction {
+                min: MinTrackSizingFunction::Fixed(LengthPercentage::Points(10.0)),
+                max: MaxTrackSizingFunction::Fixed(LengthPercentage::Points(10.0)),
+            }),
+            TrackSizingFunction::Single(fr),
+            TrackSizingFunction::Single(fr),
+            TrackSizingFunction::Single(NonRepeatedTrackSizingFunction {
+                min: MinTrackSizingFunction::Fixed(LengthPercentage::Percent(0.2)),
+                max: MaxTrackSizingFunction::Auto,
+            }),
+        ]
+    );
+    assert_eq!(
+        style.grid_row,
+        Line {
+            start: line(2),
+            end: span(3)
+        }
+    );
+    assert_eq!(style.grid_auto_flow, GridAutoFlow::ColumnDense);
+    assert_eq!(
+        style.gap,
+        Size {
+            width: LengthPercentage::Points(2.0),
+            height: LengthPercentage::Points(1.0),
+        }
+    );
+}
+
+#[test]
+fn min_max_size_and_aspect_ratio() {
+    let mut style = Style::default();
+    apply_layout_attributes("min-width", "10px", &mut style);
+    apply_layout_attributes("max-width", "50%", &mut style);
+    apply_layout_attributes("max-height", "none", &mut style);
+    apply_layout_attributes("aspect-ratio", "16 / 9", &mut style);
+
+    assert_eq!(style.min_size.width, Dimension::Points(10.0));
+    assert_eq!(style.max_size.width, Dimension::Percent(0.5));
+    assert_eq!(style.max_size.height, Dimension::Auto);
+    assert_eq!(style.aspect_ratio, Some(16.0 / 9.0));
+
+    apply_layout_attributes("aspect-ratio", "auto", &mut style);
+    assert_eq!(style.aspect_ratio, None);
+}
@@ -72,6 +72,12 @@ impl<S: State<V>, V: FromAnyValue> RealDom<S, V> {
         self.node_id_mapping.get(element_id.0).unwrap().unwrap()
     }
 
+    /// The number of elements that have a node in the dom. Ids freed by the VirtualDom are forgotten, so this shrinks
+    /// as elements are removed.
+    pub fn mapped_elements(&self) -> usize {
+        self.node_id_mapping.iter().flatten().count()
+    }
+
     fn set_element_id(&mut self, node_id: NodeId, element_id: ElementId) {
         let node = self.tree.get_mut(node_id).unwrap();
         let node_id = node.node_data.node_id;
@@ -334,9 +340,31 @@ impl<S: State<V>, V: FromAnyValue> RealDom<S, V> {
             }
         }
 
+        // the VirtualDom no longer uses these ids, so their nodes can't be found by them anymore
+        for id in mutations.freed_ids {
+            if let Some(mapping) = self.node_id_mapping.get_mut(id.0) {
+                *mapping = None;
+            }
+        }
+        while let Some(None) = self.node_id_mapping.last() {
+            self.node_id_mapping.pop();
+        }
+
+        let dirty_nodes =
+            self.dirty_nodes(nodes_updated.iter().map(|(&n, mask)| (n, mask.clone())));
+
+        (dirty_nodes, nodes_updated)
+    }
+
+    /// Find the passes to run for nodes as if the parts of them in the masks changed. Pass the result to
+    /// [RealDom::update_state] to update state that depends on something outside of the dom, like a file that loaded.
+    pub fn dirty_nodes(
+        &self,
+        nodes: impl IntoIterator<Item = (RealNodeId, NodeMask)>,
+    ) -> DirtyNodeStates {
         let mut dirty_nodes = DirtyNodeStates::default();
-        for (&n, mask) in &nodes_updated {
-            // remove any nodes that were created and then removed in the same mutations from the dirty nodes list
+        for (n, mask) in nodes {
+            // skip nodes that were removed, like the ones created and then removed in the same mutations
             if let Some(height) = self.tree.height(n) {
                 for (m, p) in S::MASKS.iter().zip(S::PASSES.iter()) {
                     if mask.overlaps(m) {
@@ -345,8 +373,7 @@ impl<S: State<V>, V: FromAnyValue> RealDom<S, V> {
                 }
             }
         }
-
-        (dirty_nodes, nodes_updated)
+        dirty_nodes
     }
 
     /// Update the state of the dom, after appling some mutations. This will keep the nodes in the dom up to date with their VNode counterparts.
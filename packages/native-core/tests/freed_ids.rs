@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use dioxus_native_core::{
+    node_ref::{AttributeMask, NodeView},
+    real_dom::RealDom,
+    state::{ParentDepState, State},
+    NodeMask,
+};
+use dioxus_native_core_macro::{sorted_str_slice, State};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BlablaState {}
+
+impl ParentDepState for BlablaState {
+    type Ctx = ();
+    type DepState = (Self,);
+
+    const NODE_MASK: NodeMask =
+        NodeMask::new_with_attrs(AttributeMask::Static(&sorted_str_slice!(["blabla",])));
+
+    fn reduce(&mut self, _node: NodeView, _parent: Option<(&Self,)>, _ctx: &Self::Ctx) -> bool {
+        false
+    }
+}
+
+#[derive(Clone, State, Default, Debug)]
+pub struct NodeState {
+    #[parent_dep_state(blabla)]
+    blabla: BlablaState,
+}
+
+#[test]
+fn freed_ids_are_forgotten() {
+    fn app(cx: Scope) -> Element {
+        let count = if cx.generation() % 2 == 0 { 10 } else { 0 };
+        render! {
+            div {
+                (0..count).map(|i| rsx! { p { key: "{i}", onclick: |_| {}, "{i}" } })
+            }
+        }
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    let mut rdom: RealDom<NodeState> = RealDom::new();
+
+    let _ = rdom.apply_mutations(vdom.rebuild());
+    let mapped = rdom.mapped_elements();
+    assert!(mapped > 10);
+
+    vdom.mark_dirty(ScopeId(0));
+    let _ = rdom.apply_mutations(vdom.render_immediate());
+    assert!(rdom.mapped_elements() + 9 <= mapped);
+
+    // the ids are handed out again, and the dom finds the new nodes by them
+    vdom.mark_dirty(ScopeId(0));
+    let _ = rdom.apply_mutations(vdom.render_immediate());
+    assert_eq!(rdom.mapped_elements(), mapped);
+}
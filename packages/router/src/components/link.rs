@@ -53,6 +53,12 @@ pub struct LinkProps<'a> {
     #[props(default = false)]
     pub new_tab: bool,
 
+    /// Keep the scroll position of the page when the link is clicked, instead of scrolling to the top.
+    ///
+    /// This is useful for links that only change part of the page, like tabs.
+    #[props(default = false)]
+    pub preserve_scroll: bool,
+
     /// Pass children into the `<a>` element
     pub children: Element<'a>,
 
@@ -88,6 +94,7 @@ pub fn Link<'a>(cx: Scope<'a, LinkProps<'a>>) -> Element {
         autodetect,
         external,
         new_tab,
+        preserve_scroll,
         children,
         active_class,
         ..
@@ -128,12 +135,10 @@ pub fn Link<'a>(cx: Scope<'a, LinkProps<'a>>) -> Element {
                 if !outerlink {
                     if let Some(service) = svc {
                         log::trace!("Pushing route to {}", to);
-                        service.push_route(to, cx.props.title.map(|f| f.to_string()), None);
-
-                        #[cfg(feature = "web")]
-                        {
-                            web_sys::window().unwrap().scroll_to_with_x_and_y(0.0, 0.0);
+                        if *preserve_scroll {
+                            service.preserve_scroll();
                         }
+                        service.push_route(to, cx.props.title.map(|f| f.to_string()), None);
                     } else {
                         log::error!(
                             "Attempted to create a Link to {} outside of a Router context", cx.props
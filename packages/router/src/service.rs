@@ -62,6 +62,8 @@ pub struct RouterService {
     pub(crate) router_id: ScopeId,
 
     pub(crate) cfg: RouterCfg,
+
+    pub(crate) preserve_scroll: Cell<bool>,
 }
 
 /// A route is a combination of window title, saved state, and a URL.
@@ -108,6 +110,7 @@ impl RouterService {
             ordering: Default::default(),
             slots: Default::default(),
             onchange_listeners: Default::default(),
+            preserve_scroll: Cell::new(false),
             history,
         });
 
@@ -135,7 +138,7 @@ impl RouterService {
             serialized_state,
         });
 
-        self.history.push(&new_route);
+        self.history.push(&new_route, !self.preserve_scroll.take());
         self.stack.borrow_mut().push(new_route);
 
         self.regen_routes();
@@ -154,6 +157,7 @@ impl RouterService {
             serialized_state,
         });
 
+        self.preserve_scroll.set(false);
         self.history.replace(&new_route);
         *self.stack.borrow_mut().last_mut().unwrap() = new_route;
 
@@ -171,6 +175,38 @@ impl RouterService {
         self.regen_routes();
     }
 
+    /// Keep the scroll position of the page on the next navigation.
+    ///
+    /// By default, the page scrolls to the top when a new route is pushed, and back to where it was
+    /// when the user goes back or forward through the history of the browser. This opts the next
+    /// push, back or forward navigation out of it, which is useful for routes like tabs that only
+    /// change part of the page.
+    ///
+    /// Only the web router scrolls the page.
+    pub fn preserve_scroll(&self) {
+        self.preserve_scroll.set(true);
+    }
+
+    /// Show the route the platform navigated to on its own, like with the back and forward
+    /// buttons of browsers.
+    #[cfg(feature = "web")]
+    pub(crate) fn history_navigated(&self, route: ParsedRoute, back: bool) {
+        let route = Arc::new(route);
+        {
+            let mut stack = self.stack.borrow_mut();
+            if !back {
+                stack.push(route);
+            } else {
+                if stack.len() > 1 {
+                    stack.pop();
+                }
+                *stack.last_mut().unwrap() = route;
+            }
+        }
+
+        self.regen_routes();
+    }
+
     /// Regenerate any routes that need to be regenerated, discarding the currently found route
     ///
     /// You probably don't need this method
@@ -313,7 +349,7 @@ fn route_matches_path(cur: &Url, attempt: &str, base_url: Option<&String>) -> bo
 }
 
 pub(crate) trait RouterProvider {
-    fn push(&self, route: &ParsedRoute);
+    fn push(&self, route: &ParsedRoute, scroll_to_top: bool);
     fn replace(&self, route: &ParsedRoute);
     fn native_location(&self) -> Box<dyn Any>;
     fn init_location(&self) -> ParsedRoute;
@@ -332,7 +368,7 @@ mod hash {
     pub struct HashRouter {}
 
     impl RouterProvider for HashRouter {
-        fn push(&self, _route: &ParsedRoute) {}
+        fn push(&self, _route: &ParsedRoute, _scroll_to_top: bool) {}
 
         fn native_location(&self) -> Box<dyn Any> {
             Box::new(())
@@ -358,8 +394,15 @@ mod web {
     use crate::ParsedRoute;
 
     use gloo_events::EventListener;
-    use std::{any::Any, cell::Cell};
-    use web_sys::History;
+    use js_sys::{Object, Reflect};
+    use std::{
+        any::Any,
+        cell::{Cell, RefCell},
+        collections::HashMap,
+        rc::Rc,
+    };
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use web_sys::{History, ScrollRestoration};
 
     pub struct WebRouter {
         // keep it around so it drops when the router is dropped
@@ -367,21 +410,109 @@ mod web {
 
         window: web_sys::Window,
         history: History,
+        scroll: Rc<ScrollPositions>,
+    }
+
+    /// Where the page was scrolled to when the user left each entry of the history
+    ///
+    /// The browser restores the scroll position before the app renders the route it went back to,
+    /// so the router does it itself once the route is rendered.
+    #[derive(Default)]
+    struct ScrollPositions {
+        // the index of the current entry, stored in the state of each entry
+        index: Cell<u32>,
+        positions: RefCell<HashMap<u32, (f64, f64)>>,
+    }
+
+    impl ScrollPositions {
+        fn save(&self, window: &web_sys::Window) {
+            let position = (
+                window.scroll_x().unwrap_or_default(),
+                window.scroll_y().unwrap_or_default(),
+            );
+            self.positions
+                .borrow_mut()
+                .insert(self.index.get(), position);
+        }
+
+        fn restore(&self, window: &web_sys::Window) {
+            let (x, y) = self
+                .positions
+                .borrow()
+                .get(&self.index.get())
+                .copied()
+                .unwrap_or_default();
+
+            // wait for the route to render, or the page might not be long enough to scroll
+            let scroll = Closure::once_into_js({
+                let window = window.clone();
+                move || window.scroll_to_with_x_and_y(x, y)
+            });
+            let _ = window.request_animation_frame(scroll.unchecked_ref());
+        }
+    }
+
+    fn entry_state(index: u32, serialized_state: Option<&str>) -> JsValue {
+        let state = Object::new();
+        let _ = Reflect::set(&state, &"index".into(), &index.into());
+        let _ = Reflect::set(
+            &state,
+            &"state".into(),
+            &JsValue::from_str(serialized_state.unwrap_or("")),
+        );
+        state.into()
+    }
+
+    fn entry_index(state: &JsValue) -> Option<u32> {
+        Reflect::get(state, &"index".into())
+            .ok()?
+            .as_f64()
+            .map(|index| index as u32)
+    }
+
+    fn current_location(window: &web_sys::Window) -> ParsedRoute {
+        let state = window
+            .history()
+            .and_then(|history| history.state())
+            .unwrap_or(JsValue::NULL);
+
+        ParsedRoute {
+            url: url::Url::parse(&window.location().href().unwrap()).unwrap(),
+            title: window.document().unwrap().title().into(),
+            serialized_state: Reflect::get(&state, &"state".into())
+                .ok()
+                .and_then(|state| state.as_string())
+                .filter(|state| !state.is_empty()),
+        }
     }
 
     impl RouterProvider for WebRouter {
-        fn push(&self, route: &ParsedRoute) {
+        fn push(&self, route: &ParsedRoute, scroll_to_top: bool) {
             let ParsedRoute {
                 url,
                 title,
                 serialized_state,
             } = route;
 
+            self.scroll.save(&self.window);
+            let index = self.scroll.index.get() + 1;
+            self.scroll.index.set(index);
+
+            // the entries after this one are gone, and so are their positions
+            self.scroll
+                .positions
+                .borrow_mut()
+                .retain(|entry, _| *entry < index);
+
             let _ = self.history.push_state_with_url(
-                &wasm_bindgen::JsValue::from_str(serialized_state.as_deref().unwrap_or("")),
+                &entry_state(index, serialized_state.as_deref()),
                 title.as_deref().unwrap_or(""),
                 Some(url.as_str()),
             );
+
+            if scroll_to_top {
+                self.window.scroll_to_with_x_and_y(0.0, 0.0);
+            }
         }
 
         fn replace(&self, route: &ParsedRoute) {
@@ -392,7 +523,7 @@ mod web {
             } = route;
 
             let _ = self.history.replace_state_with_url(
-                &wasm_bindgen::JsValue::from_str(serialized_state.as_deref().unwrap_or("")),
+                &entry_state(self.scroll.index.get(), serialized_state.as_deref()),
                 title.as_deref().unwrap_or(""),
                 Some(url.as_str()),
             );
@@ -403,26 +534,42 @@ mod web {
         }
 
         fn init_location(&self) -> ParsedRoute {
-            ParsedRoute {
-                url: url::Url::parse(&web_sys::window().unwrap().location().href().unwrap())
-                    .unwrap(),
-                title: web_sys::window()
-                    .unwrap()
-                    .document()
-                    .unwrap()
-                    .title()
-                    .into(),
-                serialized_state: None,
-            }
+            current_location(&self.window)
         }
 
         fn attach_listeners(&self, svc: std::rc::Weak<crate::RouterService>) {
+            let window = self.window.clone();
+            let history = self.history.clone();
+            let scroll = self.scroll.clone();
+
             self._listener.set(Some(EventListener::new(
                 &web_sys::window().unwrap(),
                 "popstate",
                 move |_| {
-                    if let Some(svc) = svc.upgrade() {
-                        svc.pop_route();
+                    let svc = match svc.upgrade() {
+                        Some(svc) => svc,
+                        None => return,
+                    };
+
+                    let state = history.state().unwrap_or(JsValue::NULL);
+                    let index = match entry_index(&state) {
+                        Some(index) => index,
+                        // links to anchors of the page make entries of their own
+                        None => {
+                            let index = scroll.index.get() + 1;
+                            let _ = history.replace_state(&entry_state(index, None), "");
+                            index
+                        }
+                    };
+                    let back = index < scroll.index.get();
+
+                    scroll.save(&window);
+                    scroll.index.set(index);
+
+                    svc.history_navigated(current_location(&window), back);
+
+                    if !svc.preserve_scroll.take() {
+                        scroll.restore(&window);
                     }
                 },
             )));
@@ -430,9 +577,27 @@ mod web {
     }
 
     pub(crate) fn new() -> WebRouter {
+        let window = web_sys::window().unwrap();
+        let history = window.history().unwrap();
+
+        // the router restores the scroll position once the route it went back to is rendered
+        let _ = history.set_scroll_restoration(ScrollRestoration::Manual);
+
+        // number the entries of the history, keeping the index if the page was reloaded
+        let state = history.state().unwrap_or(JsValue::NULL);
+        let scroll = Rc::new(ScrollPositions::default());
+        match entry_index(&state) {
+            Some(index) => scroll.index.set(index),
+            None => {
+                let serialized_state = state.as_string();
+                let _ = history.replace_state(&entry_state(0, serialized_state.as_deref()), "");
+            }
+        }
+
         WebRouter {
-            history: web_sys::window().unwrap().history().unwrap(),
-            window: web_sys::window().unwrap(),
+            history,
+            window,
+            scroll,
             _listener: Cell::new(None),
         }
     }
@@ -232,6 +232,15 @@ impl ElementAttr {
     }
 }
 
+/// The `data-*` attribute an attribute ident refers to, if it starts with `data_`.
+///
+/// `data_user_id: "1"` becomes `data-user-id="1"`, so custom data attributes don't need to be declared on the element.
+pub(crate) fn data_attribute_name(name: &Ident) -> Option<String> {
+    let name = name.to_string();
+    let rest = name.strip_prefix("data_").filter(|rest| !rest.is_empty())?;
+    Some(format!("data-{}", rest.replace('_', "-")))
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 pub struct ElementAttrNamed {
     pub el_name: Ident,
@@ -243,26 +252,42 @@ impl ToTokens for ElementAttrNamed {
         let ElementAttrNamed { el_name, attr } = self;
 
         tokens.append_all(match attr {
-            ElementAttr::AttrText { name, value } => {
-                quote! {
+            ElementAttr::AttrText { name, value } => match data_attribute_name(name) {
+                Some(data_name) => quote! {
+                    __cx.attr(
+                        #data_name,
+                        #value,
+                        None,
+                        false
+                    )
+                },
+                None => quote! {
                     __cx.attr(
                         dioxus_elements::#el_name::#name.0,
                         #value,
                         dioxus_elements::#el_name::#name.1,
                         dioxus_elements::#el_name::#name.2
                     )
-                }
-            }
-            ElementAttr::AttrExpression { name, value } => {
-                quote! {
+                },
+            },
+            ElementAttr::AttrExpression { name, value } => match data_attribute_name(name) {
+                Some(data_name) => quote! {
+                    __cx.attr(
+                        #data_name,
+                        #value,
+                        None,
+                        false
+                    )
+                },
+                None => quote! {
                     __cx.attr(
                         dioxus_elements::#el_name::#name.0,
                         #value,
                         dioxus_elements::#el_name::#name.1,
                         dioxus_elements::#el_name::#name.2
                     )
-                }
-            }
+                },
+            },
             ElementAttr::CustomAttrText { name, value } => {
                 quote! {
                     __cx.attr(
@@ -284,8 +309,30 @@ impl ToTokens for ElementAttrNamed {
                 }
             }
             ElementAttr::EventTokens { name, tokens } => {
-                quote! {
-                    dioxus_elements::events::#name(__cx, #tokens)
+                let name_str = name.to_string();
+
+                // Capture listeners reuse the regular listener, but are registered under the `_capture` name
+                match name_str.strip_suffix("_capture") {
+                    Some(event) => {
+                        let event = Ident::new(event, name.span());
+                        let prevent_name = format!("{name_str}_prevent_default");
+                        quote! {
+                            {
+                                let __attr = dioxus_elements::events::#event(__cx, #tokens);
+                                ::dioxus::core::Attribute {
+                                    name: if __attr.name.ends_with("_prevent_default") {
+                                        #prevent_name
+                                    } else {
+                                        #name_str
+                                    },
+                                    ..__attr
+                                }
+                            }
+                        }
+                    }
+                    None => quote! {
+                        dioxus_elements::events::#name(__cx, #tokens)
+                    },
                 }
             }
         });
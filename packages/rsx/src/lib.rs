@@ -335,9 +335,13 @@ impl<'a> DynamicContext<'a> {
                         ElementAttr::AttrText { name, value } if value.is_static() => {
                             let value = value.source.as_ref().unwrap();
                             let attribute_name_rust = name.to_string();
-                            let (name, namespace) =
-                                Ctx::map_attribute(&element_name_rust, &attribute_name_rust)
-                                    .unwrap_or((intern(attribute_name_rust.as_str()), None));
+                            let (name, namespace) = match data_attribute_name(name) {
+                                Some(data_name) => (intern(data_name.as_str()), None),
+                                None => {
+                                    Ctx::map_attribute(&element_name_rust, &attribute_name_rust)
+                                        .unwrap_or((intern(attribute_name_rust.as_str()), None))
+                                }
+                            };
                             static_attrs.push(TemplateAttribute::Static {
                                 name,
                                 namespace,
@@ -429,6 +433,15 @@ impl<'a> DynamicContext<'a> {
                 let static_attrs = el.attributes.iter().map(|attr| match &attr.attr {
                     ElementAttr::AttrText { name, value } if value.is_static() => {
                         let value = value.to_static().unwrap();
+                        if let Some(data_name) = data_attribute_name(name) {
+                            return quote! {
+                                ::dioxus::core::TemplateAttribute::Static {
+                                    name: #data_name,
+                                    namespace: None,
+                                    value: #value,
+                                }
+                            };
+                        }
                         quote! {
                             ::dioxus::core::TemplateAttribute::Static {
                                 name: dioxus_elements::#el_name::#name.0,
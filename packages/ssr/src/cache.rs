@@ -15,6 +15,8 @@ pub struct StringChain {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Segment {
     Attr(usize),
+    /// The id of the element that owns this dynamic attribute, written when pre-rendering so hydration can check it
+    HydrationId(usize),
     Node(usize),
     PreRendered(String),
 }
@@ -31,13 +33,25 @@ impl std::fmt::Write for StringChain {
 }
 
 impl StringCache {
-    pub fn from_template(template: &VNode) -> Result<Self, std::fmt::Error> {
+    pub fn from_template(template: &VNode, pre_render: bool) -> Result<Self, std::fmt::Error> {
         let mut chain = StringChain::default();
 
         let mut cur_path = vec![];
 
         for (root_idx, root) in template.template.get().roots.iter().enumerate() {
-            Self::recurse(root, &mut cur_path, root_idx, &mut chain)?;
+            // Adjacent text roots get merged into a single text node by the browser, even across templates when a
+            // fragment or component starts with text, so we mark both ends of every static text root. This lets
+            // hydration find each root of a multi-root template.
+            let text_root = pre_render && matches!(root, TemplateNode::Text { .. });
+            if text_root {
+                write!(chain, "<!--#-->")?;
+            }
+
+            Self::recurse(root, &mut cur_path, root_idx, &mut chain, pre_render)?;
+
+            if text_root {
+                write!(chain, "<!--#-->")?;
+            }
         }
 
         Ok(Self {
@@ -51,6 +65,7 @@ impl StringCache {
         cur_path: &mut Vec<usize>,
         root_idx: usize,
         chain: &mut StringChain,
+        pre_render: bool,
     ) -> Result<(), std::fmt::Error> {
         match root {
             TemplateNode::Element {
@@ -61,6 +76,18 @@ impl StringCache {
             } => {
                 cur_path.push(root_idx);
                 write!(chain, "<{tag}")?;
+
+                // Elements with dynamic attributes are the ones hydration needs ids for
+                if pre_render {
+                    let first_dynamic = attrs.iter().find_map(|attr| match attr {
+                        TemplateAttribute::Dynamic { id } => Some(*id),
+                        TemplateAttribute::Static { .. } => None,
+                    });
+                    if let Some(id) = first_dynamic {
+                        chain.segments.push(Segment::HydrationId(id));
+                    }
+                }
+
                 for attr in *attrs {
                     match attr {
                         TemplateAttribute::Static { name, value, .. } => {
@@ -76,7 +103,7 @@ impl StringCache {
                 } else {
                     write!(chain, ">")?;
                     for child in *children {
-                        Self::recurse(child, cur_path, root_idx, chain)?;
+                        Self::recurse(child, cur_path, root_idx, chain, pre_render)?;
                     }
                     write!(chain, "</{tag}>")?;
                 }
@@ -64,10 +64,11 @@ impl Renderer {
         dom: &VirtualDom,
         template: &VNode,
     ) -> std::fmt::Result {
+        let pre_render = self.pre_render;
         let entry = self
             .template_cache
             .entry(template.template.get().name)
-            .or_insert_with(|| Rc::new(StringCache::from_template(template).unwrap()))
+            .or_insert_with(|| Rc::new(StringCache::from_template(template, pre_render).unwrap()))
             .clone();
 
         for segment in entry.segments.iter() {
@@ -80,6 +81,10 @@ impl Renderer {
                         _ => {}
                     };
                 }
+                Segment::HydrationId(idx) => {
+                    let id = template.dynamic_attrs[*idx].mounted_element.get();
+                    write!(buf, " data-node-hydration=\"{}\"", id.0)?;
+                }
                 Segment::Node(idx) => match &template.dynamic_nodes[*idx] {
                     DynamicNode::Component(node) => {
                         if self.skip_components {
@@ -121,8 +126,9 @@ impl Renderer {
                     }
 
                     DynamicNode::Placeholder(_el) => {
+                        // renderers create hidden pre elements for placeholders, so we do the same to match
                         if self.pre_render {
-                            write!(buf, "<pre></pre>")?;
+                            write!(buf, "<pre hidden></pre>")?;
                         }
                     }
                 },
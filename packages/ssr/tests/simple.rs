@@ -74,3 +74,139 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn multi_root_pre_render() {
+    fn app(cx: Scope) -> Element {
+        render! {
+            "first"
+            "second"
+            div {}
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // Without pre-rendering, adjacent text roots are written as-is
+    assert_eq!(dioxus_ssr::render(&dom), "firstsecond<div></div>");
+
+    // When pre-rendering, every text root is marked so hydration can find it again
+    assert_eq!(
+        dioxus_ssr::pre_render(&dom),
+        "<!--#-->first<!--#--><!--#-->second<!--#--><div></div>"
+    );
+}
+
+#[test]
+fn fragment_text_roots_pre_render() {
+    fn app(cx: Scope) -> Element {
+        render! {
+            div {
+                "before"
+                (0..2).map(|i| rsx!( "item {i}" ))
+                (0..2).map(|_| rsx!( "static" ))
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    // Text at the start of a fragment would otherwise be merged with the text before it
+    assert_eq!(
+        dioxus_ssr::pre_render(&dom),
+        "<div>before<!--#-->item 0<!--#--><!--#-->item 1<!--#--><!--#-->static<!--#--><!--#-->static<!--#--></div>"
+    );
+}
+
+#[test]
+fn adjacent_dynamic_text_pre_render() {
+    fn app(cx: Scope) -> Element {
+        let (a, b) = ("a", "b");
+        render! {
+            div {
+                p { class: "{a}", "{a}" "{b}" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    let out = dioxus_ssr::pre_render(&dom);
+
+    // Elements with dynamic attributes carry the id hydration expects them to have
+    assert!(out.starts_with("<div><p data-node-hydration=\""));
+    assert!(out.ends_with(" class=\"a\"><!--#-->a<!--#--><!--#-->b<!--#--></p></div>"));
+}
+
+#[test]
+fn svg() {
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            svg {
+                filter { id: "blur", filter_units: "userSpaceOnUse",
+                    feGaussianBlur { r#in: "SourceGraphic", std_deviation: "5" }
+                }
+                r#use { xlink_href: "#shape", filter: "url(#blur)" }
+            }
+        }),
+        r##"<svg><filter id="blur" filterUnits="userSpaceOnUse"><feGaussianBlur in="SourceGraphic" stdDeviation="5"></feGaussianBlur></filter><use xlink:href="#shape" filter="url(#blur)"></use></svg>"##
+    );
+}
+
+#[test]
+fn mathml() {
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            math { display: "block",
+                mfrac {
+                    msup { mi { "x" } mn { "2" } }
+                    mn { "2" }
+                }
+            }
+        }),
+        r#"<math display="block"><mfrac><msup><mi>x</mi><mn>2</mn></msup><mn>2</mn></mfrac></math>"#
+    );
+}
+
+#[test]
+fn aria() {
+    use dioxus_elements::aria::{AriaLive, Role};
+
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            div { role: Role::Status, aria_live: AriaLive::Polite, "Saving..." }
+        }),
+        r#"<div role="status" aria-live="polite">Saving...</div>"#
+    );
+}
+
+#[test]
+fn data_attributes() {
+    let id = 42;
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            div { data_kind: "video", data_video_id: "{id}", data_visible: true }
+        }),
+        r#"<div data-kind="video" data-video-id="42" data-visible=true></div>"#
+    );
+}
+
+#[test]
+fn typed_style() {
+    use dioxus_elements::style::{Color, Length, Style};
+
+    let style = Style::new()
+        .width(Length::Percent(50.0))
+        .background_color(Color::Named("rebeccapurple"))
+        .opacity(0.5);
+
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            div { style: style, "styled" }
+        }),
+        r#"<div style="width: 50%; background-color: rebeccapurple; opacity: 0.5;">styled</div>"#
+    );
+}
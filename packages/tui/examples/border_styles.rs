@@ -0,0 +1,44 @@
+use dioxus::prelude::*;
+
+fn main() {
+    dioxus_tui::launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    let styles = ["solid", "double", "rounded", "thick", "dashed", "dotted"];
+
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            flex_direction: "column",
+
+            div {
+                width: "100%",
+                height: "5px",
+                flex_direction: "row",
+                styles.iter().map(|style| rsx! {
+                    div {
+                        key: "{style}",
+                        width: "14px",
+                        height: "5px",
+                        border_style: "{style}",
+                        justify_content: "center",
+                        align_items: "center",
+                        "{style}"
+                    }
+                })
+            }
+            div {
+                width: "40px",
+                height: "5px",
+                border_top: "1px thick #FF0000",
+                border_bottom: "1px double #00FF00",
+                border_left: "1px dashed",
+                justify_content: "center",
+                align_items: "center",
+                "per-side borders"
+            }
+        }
+    })
+}
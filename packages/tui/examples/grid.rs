@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+
+fn main() {
+    dioxus_tui::launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            display: "grid",
+            grid_template_columns: "20px repeat(2, 1fr)",
+            grid_template_rows: "3px 1fr 1fr",
+            gap: "1px",
+
+            div {
+                grid_column: "1 / span 3",
+                border_style: "rounded",
+                justify_content: "center",
+                align_items: "center",
+                "dashboard"
+            }
+            div {
+                grid_row: "2 / span 2",
+                border_style: "solid",
+                "menu"
+            }
+            div {
+                border_style: "solid",
+                background_color: "#0000AA",
+                "cpu"
+            }
+            div {
+                border_style: "solid",
+                background_color: "#00AA00",
+                "memory"
+            }
+            div {
+                grid_column: "2 / 4",
+                border_style: "solid",
+                background_color: "#AA0000",
+                "logs"
+            }
+        }
+    })
+}
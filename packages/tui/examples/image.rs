@@ -0,0 +1,32 @@
+use dioxus::prelude::*;
+
+fn main() {
+    dioxus_tui::launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            flex_direction: "column",
+            align_items: "center",
+
+            "An image sized by its pixels, then one 40 cells wide"
+            img {
+                src: "examples/example.png",
+                alt: "dioxus tui example",
+            }
+            img {
+                src: "examples/example.png",
+                width: "40px",
+            }
+            img {
+                src: "examples/missing.png",
+                alt: "this image doesn't exist, so a placeholder is drawn",
+                width: "40px",
+                height: "3px",
+            }
+        }
+    })
+}
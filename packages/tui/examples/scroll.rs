@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+
+fn main() {
+    dioxus_tui::launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            flex_direction: "row",
+
+            div {
+                width: "50%",
+                height: "100%",
+                flex_direction: "column",
+                overflow: "scroll",
+                border_width: "1px",
+
+                (0..100).map(|i| rsx! {
+                    div {
+                        key: "{i}",
+                        height: "1px",
+                        "line {i}"
+                    }
+                })
+            }
+            div {
+                width: "50%",
+                height: "100%",
+                flex_direction: "column",
+                overflow: "auto",
+                border_width: "1px",
+
+                (0..10).map(|i| rsx! {
+                    div {
+                        key: "{i}",
+                        height: "5px",
+                        flex_direction: "column",
+                        border_width: "1px",
+                        "box {i}"
+                    }
+                })
+            }
+        }
+    })
+}
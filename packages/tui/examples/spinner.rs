@@ -0,0 +1,37 @@
+use dioxus::prelude::*;
+use dioxus_tui::use_animation_frame;
+
+fn main() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_max_fps(30));
+}
+
+fn app(cx: Scope) -> Element {
+    let loading = use_state(cx, || true);
+
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            flex_direction: "column",
+            onclick: move |_| loading.modify(|loading| !loading),
+
+            Spinner { active: *loading.get() }
+            "click to pause or resume the spinner"
+        }
+    })
+}
+
+#[inline_props]
+fn Spinner(cx: Scope, active: bool) -> Element {
+    const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    let elapsed = use_animation_frame(cx, *active);
+    let frame = FRAMES[elapsed.as_millis() as usize / 80 % FRAMES.len()];
+    let label = if *active { "loading" } else { "paused" };
+
+    cx.render(rsx! {
+        div {
+            height: "1px",
+            "{frame} {label}"
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+use dioxus_tui::prelude::*;
+
+fn main() {
+    dioxus_tui::launch(app);
+}
+
+const COLUMNS: &[Column] = &[
+    Column::new("Name", ColumnWidth::Auto),
+    Column::new("Size", ColumnWidth::Fixed(8)),
+    Column::new("Description", ColumnWidth::Percent(50.0)),
+];
+
+fn app(cx: Scope) -> Element {
+    let rows = cx.use_hook(|| {
+        (0..20)
+            .map(|i| {
+                vec![
+                    format!("file_{i}.rs"),
+                    format!("{} KB", i * 3),
+                    "a file with a description too long for its column".to_string(),
+                ]
+            })
+            .collect::<Vec<_>>()
+    });
+
+    cx.render(rsx! {
+        div {
+            width: "100%",
+            height: "100%",
+            flex_direction: "column",
+
+            Table {
+                columns: COLUMNS,
+                rows: rows,
+            }
+        }
+    })
+}
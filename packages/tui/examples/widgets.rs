@@ -88,6 +88,11 @@ fn app(cx: Scope) -> Element {
                 width: "50%",
                 height: "10%",
             }
+            Select{
+                options: &[("red", "Red"), ("green", "Green")],
+                onchange: |data: FormData| bg_green.set(data.value == "green"),
+                width: "50%",
+            }
         }
     })
 }
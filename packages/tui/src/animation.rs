@@ -0,0 +1,89 @@
+use dioxus_core::ScopeState;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::TuiContext;
+
+type FrameCallback = Box<dyn FnOnce(Instant)>;
+
+/// The callbacks waiting for the next frame. The event loop only wakes up for frames while some are waiting.
+#[derive(Clone, Default)]
+pub(crate) struct AnimationFrames {
+    callbacks: Rc<RefCell<Vec<FrameCallback>>>,
+}
+
+impl AnimationFrames {
+    pub(crate) fn request(&self, callback: impl FnOnce(Instant) + 'static) {
+        self.callbacks.borrow_mut().push(Box::new(callback));
+    }
+
+    pub(crate) fn pending(&self) -> bool {
+        !self.callbacks.borrow().is_empty()
+    }
+
+    /// Call the callbacks requested before this frame. The ones they request wait for the next frame.
+    pub(crate) fn run(&self, now: Instant) {
+        let callbacks = std::mem::take(&mut *self.callbacks.borrow_mut());
+        for callback in callbacks {
+            callback(now);
+        }
+    }
+}
+
+struct AnimationState {
+    start: Cell<Option<Instant>>,
+    now: Cell<Instant>,
+    requested: Cell<bool>,
+}
+
+/// Render the component again every frame while `active` is true, and get how long it has been active.
+///
+/// Frames are limited to the max fps of the [`crate::Config`], and nothing is rendered for the animation while it is
+/// inactive.
+///
+/// ```rust, ignore
+/// fn Spinner(cx: Scope) -> Element {
+///     let elapsed = use_animation_frame(cx, true);
+///     let frame = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"][elapsed.as_millis() as usize / 80 % 10];
+///
+///     cx.render(rsx! { "{frame}" })
+/// }
+/// ```
+pub fn use_animation_frame(cx: &ScopeState, active: bool) -> Duration {
+    let (state, tui_ctx) = cx.use_hook(|| {
+        let state = Rc::new(AnimationState {
+            start: Cell::new(None),
+            now: Cell::new(Instant::now()),
+            requested: Cell::new(false),
+        });
+        (state, cx.consume_context::<TuiContext>())
+    });
+
+    if !active {
+        state.start.set(None);
+        return Duration::ZERO;
+    }
+
+    let start = match state.start.get() {
+        Some(start) => start,
+        None => {
+            let now = Instant::now();
+            state.start.set(Some(now));
+            state.now.set(now);
+            now
+        }
+    };
+    if let Some(tui_ctx) = tui_ctx {
+        if !state.requested.replace(true) {
+            let state = state.clone();
+            let update = cx.schedule_update();
+            tui_ctx.request_animation_frame(move |now| {
+                state.requested.set(false);
+                state.now.set(now);
+                update();
+            });
+        }
+    }
+    state.now.get().saturating_duration_since(start)
+}
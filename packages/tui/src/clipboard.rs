@@ -0,0 +1,65 @@
+use base64::Engine;
+use dioxus::hooks::{ClipboardBackend, ClipboardError};
+use std::cell::RefCell;
+use std::io::Write;
+
+/// Writes to the clipboard of the terminal emulator with OSC 52 escape sequences
+///
+/// Terminals that support OSC 52 usually refuse to answer clipboard queries, so reading returns the text the app
+/// copied last. With the `arboard` feature, the clipboard of the machine the app runs on is also written and read,
+/// which is the same as the one of the terminal unless the app runs over ssh.
+pub(crate) struct TerminalClipboard {
+    headless: bool,
+    copied: RefCell<Option<String>>,
+    // on Linux, the text copied by the app is only available while the clipboard that wrote it is alive
+    #[cfg(feature = "arboard")]
+    system: RefCell<Option<arboard::Clipboard>>,
+}
+
+impl TerminalClipboard {
+    pub fn new(headless: bool) -> Self {
+        Self {
+            headless,
+            copied: RefCell::new(None),
+            #[cfg(feature = "arboard")]
+            system: RefCell::new(if headless {
+                None
+            } else {
+                arboard::Clipboard::new().ok()
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ClipboardBackend for TerminalClipboard {
+    async fn read_text(&self) -> Result<String, ClipboardError> {
+        #[cfg(feature = "arboard")]
+        if let Some(Ok(text)) = self.system.borrow_mut().as_mut().map(|c| c.get_text()) {
+            return Ok(text);
+        }
+
+        self.copied
+            .borrow()
+            .clone()
+            .ok_or(ClipboardError::Unsupported)
+    }
+
+    async fn write_text(&self, text: String) -> Result<(), ClipboardError> {
+        if self.headless {
+            return Err(ClipboardError::Unsupported);
+        }
+
+        #[cfg(feature = "arboard")]
+        if let Some(clipboard) = self.system.borrow_mut().as_mut() {
+            let _ = clipboard.set_text(text.clone());
+        }
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&text);
+        self.copied.replace(Some(text));
+        let mut stdout = std::io::stdout();
+        write!(stdout, "\x1b]52;c;{encoded}\x07")
+            .and_then(|_| stdout.flush())
+            .map_err(|err| ClipboardError::Failed(err.to_string()))
+    }
+}
@@ -1,12 +1,20 @@
+use crate::ImageProtocol;
+
 #[derive(Clone, Copy)]
 #[non_exhaustive]
 pub struct Config {
-    pub(crate) rendering_mode: RenderingMode,
+    /// The colors the terminal can show. Without it, they are detected from the environment when the app launches.
+    pub(crate) rendering_mode: Option<RenderingMode>,
     /// Controls if the terminal quit when the user presses `ctrl+c`?
     /// To handle quiting on your own, use the [crate::TuiContext] root context.
+    /// The focused element can prevent the default of the keydown to keep running, like text inputs do to copy.
     pub(crate) ctrl_c_quit: bool,
     /// Controls if the terminal should dislay anything, usefull for testing.
     pub(crate) headless: bool,
+    /// How `img` elements are drawn. Without it, the protocol is detected from the environment when the app launches.
+    pub(crate) image_protocol: Option<ImageProtocol>,
+    /// The most frames per second animations render at
+    pub(crate) max_fps: u16,
 }
 
 impl Config {
@@ -14,9 +22,10 @@ impl Config {
         Self::default()
     }
 
+    /// Render with a set of colors instead of the one detected from the terminal
     pub fn with_rendering_mode(self, rendering_mode: RenderingMode) -> Self {
         Self {
-            rendering_mode,
+            rendering_mode: Some(rendering_mode),
             ..self
         }
     }
@@ -34,19 +43,59 @@ impl Config {
             ..self
         }
     }
+
+    /// Limit animations to `max_fps` frames per second, 60 by default
+    pub fn with_max_fps(self, max_fps: u16) -> Self {
+        Self { max_fps, ..self }
+    }
+
+    /// Draw images with a graphics protocol instead of the one detected from the terminal
+    pub fn with_image_protocol(self, image_protocol: ImageProtocol) -> Self {
+        Self {
+            image_protocol: Some(image_protocol),
+            ..self
+        }
+    }
+
+    pub(crate) fn image_protocol(&self) -> ImageProtocol {
+        self.image_protocol.unwrap_or(ImageProtocol::None)
+    }
+
+    pub(crate) fn rendering_mode(&self) -> RenderingMode {
+        self.rendering_mode.unwrap_or_default()
+    }
+
+    /// Detect the colors and graphics the terminal supports, unless they were set with [`Config::with_rendering_mode`]
+    /// and [`Config::with_image_protocol`]
+    pub(crate) fn detect_terminal(self) -> Self {
+        let detect_images = || {
+            if self.headless {
+                ImageProtocol::None
+            } else {
+                ImageProtocol::detect()
+            }
+        };
+        Self {
+            rendering_mode: Some(self.rendering_mode.unwrap_or_else(RenderingMode::detect)),
+            image_protocol: Some(self.image_protocol.unwrap_or_else(detect_images)),
+            ..self
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            rendering_mode: Default::default(),
+            rendering_mode: None,
             ctrl_c_quit: true,
             headless: false,
+            image_protocol: None,
+            max_fps: 60,
         }
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RenderingMode {
     /// only 16 colors by accessed by name, no alpha support
     BaseColors,
@@ -61,3 +110,87 @@ impl Default for RenderingMode {
         RenderingMode::Rgb
     }
 }
+
+impl RenderingMode {
+    /// Guess the colors the terminal supports from the `COLORTERM` environment variable, the Windows console and the
+    /// `TERM` environment variable, in that order
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").ok();
+        let term = std::env::var("TERM").ok();
+        Self::from_env(colorterm.as_deref(), windows_console(), term.as_deref())
+    }
+
+    fn from_env(
+        colorterm: Option<&str>,
+        windows_console: Option<bool>,
+        term: Option<&str>,
+    ) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return RenderingMode::Rgb;
+        }
+        // consoles with virtual terminal sequences support 24 bit colors without setting any variables
+        if let Some(virtual_terminal) = windows_console {
+            return if virtual_terminal {
+                RenderingMode::Rgb
+            } else {
+                RenderingMode::BaseColors
+            };
+        }
+        match term {
+            Some(term) if term.contains("truecolor") || term.contains("direct") => {
+                RenderingMode::Rgb
+            }
+            Some(term) if term.contains("256") => RenderingMode::Ansi,
+            Some(_) => RenderingMode::BaseColors,
+            // nothing is known about the terminal, so keep the default
+            None => RenderingMode::default(),
+        }
+    }
+}
+
+/// If the Windows console supports virtual terminal sequences, or `None` outside of Windows
+#[cfg(windows)]
+fn windows_console() -> Option<bool> {
+    Some(crossterm::ansi_support::supports_ansi())
+}
+
+#[cfg(not(windows))]
+fn windows_console() -> Option<bool> {
+    None
+}
+
+#[test]
+fn rendering_mode_from_env() {
+    assert_eq!(
+        RenderingMode::from_env(Some("truecolor"), None, Some("xterm-256color")),
+        RenderingMode::Rgb
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, None, Some("xterm-direct")),
+        RenderingMode::Rgb
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, None, Some("screen-256color")),
+        RenderingMode::Ansi
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, None, Some("linux")),
+        RenderingMode::BaseColors
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, None, None),
+        RenderingMode::Rgb
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, Some(true), Some("dumb")),
+        RenderingMode::Rgb
+    );
+    assert_eq!(
+        RenderingMode::from_env(None, Some(false), None),
+        RenderingMode::BaseColors
+    );
+    assert_eq!(
+        RenderingMode::from_env(Some("24bit"), Some(false), None),
+        RenderingMode::Rgb
+    );
+}
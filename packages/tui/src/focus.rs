@@ -1,4 +1,6 @@
-use crate::{node::PreventDefault, TuiDom};
+use crate::{
+    node::PreventDefault, scroll::overflow_scrolls, style_attributes::style_declarations, TuiDom,
+};
 
 use dioxus_native_core::{
     tree::TreeView,
@@ -67,12 +69,23 @@ pub(crate) struct Focus {
 impl NodeDepState for Focus {
     type DepState = ();
     type Ctx = ();
-    const NODE_MASK: NodeMask =
-        NodeMask::new_with_attrs(AttributeMask::Static(FOCUS_ATTRIBUTES)).with_listeners();
+    const NODE_MASK: NodeMask = NodeMask::new_with_attrs(AttributeMask::Static(FOCUS_ATTRIBUTES))
+        .with_tag()
+        .with_listeners();
 
     fn reduce(&mut self, node: NodeView<'_>, _sibling: (), _: &Self::Ctx) -> bool {
         let new = Focus {
-            level: if let Some(a) = node
+            level: if node.attributes().map_or(false, |mut attributes| {
+                // disabled controls are skipped by tab and clicks, like in browsers
+                attributes.any(|a| {
+                    a.attribute.name == "disabled"
+                        && a.value
+                            .as_bool()
+                            .unwrap_or_else(|| a.value.as_text() != Some("false"))
+                })
+            }) {
+                FocusLevel::Unfocusable
+            } else if let Some(a) = node
                 .attributes()
                 .and_then(|mut a| a.find(|a| a.attribute.name == "tabindex"))
             {
@@ -91,6 +104,22 @@ impl NodeDepState for Focus {
                 } else {
                     FocusLevel::Unfocusable
                 }
+            } else if node.attributes().map_or(false, |mut attributes| {
+                // scroll containers take focus to scroll with the keyboard, like in browsers
+                attributes.any(|a| match (a.attribute.name.as_ref(), a.value.as_text()) {
+                    ("style", Some(style)) => {
+                        style_declarations(style).any(|(name, value)| overflow_scrolls(name, value))
+                    }
+                    (name, Some(value)) => overflow_scrolls(name, value),
+                    _ => false,
+                })
+            }) {
+                FocusLevel::Focusable
+            } else if node
+                .tag()
+                .map_or(false, |tag| FOCUS_TAGS.binary_search(&tag).is_ok())
+            {
+                FocusLevel::Focusable
             } else if node
                 .listeners()
                 .and_then(|mut listeners| {
@@ -115,7 +144,9 @@ impl NodeDepState for Focus {
 }
 
 const FOCUS_EVENTS: &[&str] = &sorted_str_slice!(["keydown", "keypress", "keyup"]);
-const FOCUS_ATTRIBUTES: &[&str] = &sorted_str_slice!(["tabindex"]);
+const FOCUS_ATTRIBUTES: &[&str] =
+    &sorted_str_slice!(["disabled", "overflow", "overflow-y", "style", "tabindex"]);
+const FOCUS_TAGS: &[&str] = &sorted_str_slice!(["button", "input", "select", "textarea"]);
 
 #[derive(Default)]
 pub(crate) struct FocusState {
@@ -0,0 +1,36 @@
+use crate::hooks::translate_key_event;
+use crossterm::event::Event as TermEvent;
+use dioxus::hooks::{EventForwarder, GlobalListeners, ListenerBackend, ListenerTarget};
+use std::{any::Any, rc::Rc};
+
+/// The event loop sends every terminal event to the global listeners, so there is nothing to attach
+pub(crate) struct TerminalListeners;
+
+impl ListenerBackend for TerminalListeners {
+    fn listen(&self, _: ListenerTarget, _: &str, _: EventForwarder) {}
+
+    fn unlisten(&self, _: ListenerTarget, _: &str) {}
+}
+
+/// Send a terminal event to the global listeners
+///
+/// Key presses go to the document and then the window, like a `keydown` that bubbles up in a browser. Resizing the
+/// terminal resizes the window.
+pub(crate) fn dispatch_global_event(listeners: &GlobalListeners, event: &TermEvent) {
+    match event {
+        TermEvent::Key(key) => {
+            if let Some(data) = translate_key_event(*key) {
+                let data: Rc<dyn Any> = Rc::new(data);
+                listeners.dispatch(ListenerTarget::Document, "keydown", data.clone());
+                listeners.dispatch(ListenerTarget::Window, "keydown", data);
+            }
+        }
+        TermEvent::Resize(_, _) => {
+            listeners.dispatch(ListenerTarget::Window, "resize", Rc::new(()))
+        }
+        TermEvent::Mouse(_)
+        | TermEvent::Paste(_)
+        | TermEvent::FocusGained
+        | TermEvent::FocusLost => {}
+    }
+}
@@ -1,7 +1,9 @@
 use crossterm::event::{
-    Event as TermEvent, KeyCode as TermKeyCode, KeyModifiers, MouseButton, MouseEventKind,
+    Event as TermEvent, KeyCode as TermKeyCode, KeyEventKind, KeyModifiers, MouseButton,
+    MouseEventKind,
 };
 use dioxus_core::*;
+use dioxus_native_core::node::NodeType;
 use dioxus_native_core::tree::TreeView;
 use dioxus_native_core::NodeId;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -13,7 +15,9 @@ use dioxus_html::geometry::{
 use dioxus_html::input_data::keyboard_types::{Code, Key, Location, Modifiers};
 use dioxus_html::input_data::MouseButtonSet as DioxusMouseButtons;
 use dioxus_html::input_data::{MouseButton as DioxusMouseButton, MouseButtonSet};
-use dioxus_html::{event_bubbles, FocusData, KeyboardData, MouseData, WheelData};
+use dioxus_html::{
+    event_bubbles, ClipboardData, FocusData, KeyboardData, MouseData, ResizeData, WheelData,
+};
 use std::{
     any::Any,
     cell::{RefCell, RefMut},
@@ -23,7 +27,9 @@ use std::{
 use taffy::geometry::{Point, Size};
 use taffy::{prelude::Layout, Taffy};
 
-use crate::{layout_to_screen_space, FocusState};
+use crate::node::PreventDefault;
+use crate::scroll::{containers_at, ScrollState};
+use crate::{layout_to_screen_space, screen_to_layout_space, FocusState};
 use crate::{TuiDom, TuiNode};
 
 pub(crate) struct Event {
@@ -76,14 +82,16 @@ enum EventData {
     Wheel(WheelData),
     Screen((u16, u16)),
     Keyboard(KeyboardData),
+    Clipboard(ClipboardData),
 }
 impl EventData {
-    fn into_any(self) -> Rc<dyn Any + Send + Sync> {
+    fn into_any(self) -> Rc<dyn Any> {
         match self {
             Self::Mouse(m) => Rc::new(m),
             Self::Wheel(w) => Rc::new(w),
             Self::Screen(s) => Rc::new(s),
             Self::Keyboard(k) => Rc::new(k),
+            Self::Clipboard(c) => Rc::new(c),
         }
     }
 }
@@ -96,6 +104,7 @@ pub struct InnerInputState {
     last_key_pressed: Option<(KeyboardData, Instant)>,
     screen: Option<(u16, u16)>,
     pub(crate) focus_state: FocusState,
+    pub(crate) scroll_state: ScrollState,
     // subscribers: Vec<Rc<dyn Fn() + 'static>>,
 }
 
@@ -108,6 +117,7 @@ impl InnerInputState {
             screen: None,
             // subscribers: Vec::new(),
             focus_state: FocusState::default(),
+            scroll_state: ScrollState::default(),
         }
     }
 
@@ -165,6 +175,88 @@ impl InnerInputState {
 
                 self.last_key_pressed = Some((k.clone(), Instant::now()));
             }
+            EventData::Clipboard(_) => {}
+        }
+    }
+
+    // scroll containers follow the wheel and the page up and page down keys
+    fn scroll(&mut self, evt: &EventCore, layout: &Taffy, dom: &mut TuiDom) {
+        match &evt.1 {
+            EventData::Wheel(w) => {
+                let lines = w.delta().strip_units().y.round() as i32;
+                if let Some(mouse) = &self.mouse {
+                    self.scroll_state
+                        .scroll_at(dom, layout, mouse.screen_coordinates(), lines);
+                }
+            }
+            EventData::Keyboard(k) => self.scroll_page(k, layout, dom),
+            _ => (),
+        }
+    }
+
+    fn scroll_page(&mut self, k: &KeyboardData, layout: &Taffy, dom: &mut TuiDom) {
+        let down = match k.key() {
+            Key::PageDown => true,
+            Key::PageUp => false,
+            _ => return,
+        };
+        let target = match self.focus_state.last_focused_id {
+            Some(focused) => {
+                (dom[focused].state.prevent_default != PreventDefault::KeyDown).then_some(focused)
+            }
+            // without focus, the page keys scroll the container under the mouse
+            None => self
+                .mouse
+                .as_ref()
+                .and_then(|mouse| containers_at(dom, layout, mouse.screen_coordinates()).pop()),
+        };
+        if let Some(target) = target {
+            self.scroll_state
+                .scroll_page_around(dom, layout, target, down);
+        }
+    }
+
+    // the default actions of keys wait for the keydown handlers of the focused element, which may prevent them
+    pub(crate) fn focused_listens_for_keys(&self, dom: &TuiDom) -> bool {
+        self.focus_state.last_focused_id.map_or(false, |id| {
+            matches!(
+                &dom[id].node_data.node_type,
+                NodeType::Element { listeners, .. } if listeners.contains("keydown")
+            )
+        })
+    }
+
+    // the element that loses focus hears about it first, like in browsers
+    fn focus_events(&self, old_focus: Option<NodeId>, events: &mut Vec<Event>, dom: &TuiDom) {
+        if old_focus == self.focus_state.last_focused_id {
+            return;
+        }
+        // elements with listeners will always have a element id
+        if let Some(id) = old_focus {
+            let element = dom.tree.get(id).unwrap();
+            if let Some(id) = element.node_data.element_id {
+                for name in ["blur", "focusout"] {
+                    events.push(Event {
+                        name,
+                        id,
+                        data: Rc::new(FocusData {}),
+                        bubbles: event_bubbles(name),
+                    });
+                }
+            }
+        }
+        if let Some(id) = self.focus_state.last_focused_id {
+            let element = dom.tree.get(id).unwrap();
+            if let Some(id) = element.node_data.element_id {
+                for name in ["focus", "focusin"] {
+                    events.push(Event {
+                        name,
+                        id,
+                        data: Rc::new(FocusData {}),
+                        bubbles: event_bubbles(name),
+                    });
+                }
+            }
         }
     }
 
@@ -180,9 +272,10 @@ impl InnerInputState {
         self.wheel = None;
 
         let old_focus = self.focus_state.last_focused_id;
+        let defer_keys = self.focused_listens_for_keys(dom);
 
         evts.retain(|e| match &e.1 {
-            EventData::Keyboard(k) => match k.code() {
+            EventData::Keyboard(k) if !defer_keys => match k.code() {
                 Code::Tab => !self
                     .focus_state
                     .progress(dom, !k.modifiers().contains(Modifiers::SHIFT)),
@@ -191,43 +284,17 @@ impl InnerInputState {
             _ => true,
         });
 
+        self.scroll_state.clamp(dom, layout);
         for e in evts.iter_mut() {
             self.apply_event(e);
+            if !(defer_keys && matches!(e.1, EventData::Keyboard(_))) {
+                self.scroll(e, layout, dom);
+            }
         }
 
         self.resolve_mouse_events(previous_mouse, resolved_events, layout, dom);
 
-        if old_focus != self.focus_state.last_focused_id {
-            // elements with listeners will always have a element id
-            if let Some(id) = self.focus_state.last_focused_id {
-                let element = dom.tree.get(id).unwrap();
-                if let Some(id) = element.node_data.element_id {
-                    resolved_events.push(Event {
-                        name: "focus",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focus"),
-                    });
-                    resolved_events.push(Event {
-                        name: "focusin",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focusin"),
-                    });
-                }
-            }
-            if let Some(id) = old_focus {
-                let element = dom.tree.get(id).unwrap();
-                if let Some(id) = element.node_data.element_id {
-                    resolved_events.push(Event {
-                        name: "focusout",
-                        id,
-                        data: Rc::new(FocusData {}),
-                        bubbles: event_bubbles("focusout"),
-                    });
-                }
-            }
-        }
+        self.focus_events(old_focus, resolved_events, dom);
 
         // for s in &self.subscribers {
         //     s();
@@ -583,7 +650,7 @@ impl InnerInputState {
     // }
 }
 
-fn get_abs_layout(node: &TuiNode, dom: &TuiDom, taffy: &Taffy) -> Layout {
+pub(crate) fn get_abs_layout(node: &TuiNode, dom: &TuiDom, taffy: &Taffy) -> Layout {
     let mut node_layout = *taffy.layout(node.state.layout.node.unwrap()).unwrap();
     let mut current = node;
 
@@ -591,7 +658,8 @@ fn get_abs_layout(node: &TuiNode, dom: &TuiDom, taffy: &Taffy) -> Layout {
         current = parent;
         let parent_layout = taffy.layout(parent.state.layout.node.unwrap()).unwrap();
         node_layout.location.x += parent_layout.location.x;
-        node_layout.location.y += parent_layout.location.y;
+        node_layout.location.y +=
+            parent_layout.location.y - screen_to_layout_space(parent.state.scroll_top);
     }
     node_layout
 }
@@ -599,6 +667,11 @@ fn get_abs_layout(node: &TuiNode, dom: &TuiDom, taffy: &Taffy) -> Layout {
 pub struct RinkInputHandler {
     state: Rc<RefCell<InnerInputState>>,
     queued_events: Rc<RefCell<Vec<EventCore>>>,
+    // the last size sent to each node listening for resize events
+    element_sizes: RefCell<FxHashMap<NodeId, ResizeData>>,
+    // events sent with the next batch without waiting for input, like resize events and the focus events of default
+    // actions
+    pending_events: RefCell<Vec<Event>>,
 }
 
 impl RinkInputHandler {
@@ -626,6 +699,8 @@ impl RinkInputHandler {
             Self {
                 state: state.clone(),
                 queued_events,
+                element_sizes: Default::default(),
+                pending_events: Default::default(),
             },
             state,
             regester_event,
@@ -636,8 +711,72 @@ impl RinkInputHandler {
         self.state.borrow_mut().focus_state.prune(mutations, rdom);
     }
 
+    /// Queue a resize event for every node listening to them that was laid out for the first time or changed size since
+    /// the last call. Returns `true` if any events are waiting to be sent.
+    pub(crate) fn queue_resize_events(&self, layout: &Taffy, dom: &TuiDom) -> bool {
+        let mut old_sizes = self.element_sizes.borrow_mut();
+        let mut new_sizes = FxHashMap::default();
+        let mut pending_events = self.pending_events.borrow_mut();
+
+        for node in dom.get_listening_sorted("resize") {
+            let node_layout = get_abs_layout(node, dom, layout);
+            let size = ResizeData {
+                width: layout_to_screen_space(node_layout.size.width).into(),
+                height: layout_to_screen_space(node_layout.size.height).into(),
+            };
+            let id = node.node_data.node_id;
+
+            if old_sizes.get(&id) != Some(&size) {
+                if let Some(mounted_id) = node.mounted_id() {
+                    pending_events.push(Event {
+                        name: "resize",
+                        id: mounted_id,
+                        data: Rc::new(size),
+                        bubbles: false,
+                    });
+                }
+            }
+            new_sizes.insert(id, size);
+        }
+
+        // nodes that stopped listening are forgotten, so they get a new event if they start listening again
+        *old_sizes = new_sizes;
+
+        !pending_events.is_empty()
+    }
+
+    /// Run the default action of an event the handlers didn't prevent, like moving the focus with tab
+    pub(crate) fn default_action(&self, event: &Event, layout: &Taffy, dom: &mut TuiDom) {
+        let key = match event.data.downcast_ref::<KeyboardData>() {
+            Some(key) if event.name == "keydown" => key,
+            _ => return,
+        };
+
+        let mut state = self.state.borrow_mut();
+        let old_focus = state.focus_state.last_focused_id;
+        match key.code() {
+            Code::Tab => {
+                state
+                    .focus_state
+                    .progress(dom, !key.modifiers().contains(Modifiers::SHIFT));
+            }
+            _ => state.scroll_page(key, layout, dom),
+        }
+        state.focus_events(old_focus, &mut self.pending_events.borrow_mut(), dom);
+    }
+
+    /// If the event is a ctrl+c, which quits the app unless the handlers of the focused element prevent it
+    pub(crate) fn is_ctrl_c(&self, event: &Event) -> bool {
+        match event.data.downcast_ref::<KeyboardData>() {
+            Some(key) if event.name == "keydown" => {
+                key.code() == Code::KeyC && key.modifiers().contains(Modifiers::CONTROL)
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn get_events(&self, layout: &Taffy, dom: &mut TuiDom) -> Vec<Event> {
-        let mut resolved_events = Vec::new();
+        let mut resolved_events = self.pending_events.take();
 
         (*self.state).borrow_mut().update(
             &mut (*self.queued_events).borrow_mut(),
@@ -664,12 +803,14 @@ impl RinkInputHandler {
                     "wheel",
                     "click",
                     "contextmenu",
+                    // the terminal resizing goes to global listeners, element resize events are queued separately
+                    "resize",
                 ]
                 .contains(&e.0)
             })
             .map(|evt| (evt.0, evt.1.into_any()));
 
-        let mut hm: FxHashMap<&'static str, Vec<Rc<dyn Any + Send + Sync>>> = FxHashMap::default();
+        let mut hm: FxHashMap<&'static str, Vec<Rc<dyn Any>>> = FxHashMap::default();
         for (event, data) in events {
             if let Some(v) = hm.get_mut(event) {
                 v.push(data);
@@ -705,7 +846,7 @@ impl RinkInputHandler {
 // translate crossterm events into dioxus events
 fn get_event(evt: TermEvent) -> Option<(&'static str, EventData)> {
     let (name, data): (&str, EventData) = match evt {
-        TermEvent::Key(k) => ("keydown", translate_key_event(k)?),
+        TermEvent::Key(k) => ("keydown", EventData::Keyboard(translate_key_event(k)?)),
         TermEvent::Mouse(m) => {
             let (x, y) = (m.column.into(), m.row.into());
             let alt = m.modifiers.contains(KeyModifiers::ALT);
@@ -758,7 +899,10 @@ fn get_event(evt: TermEvent) -> Option<(&'static str, EventData)> {
 
             let get_wheel_data = |up| {
                 let y = if up { -1.0 } else { 1.0 };
-                EventData::Wheel(WheelData::new(WheelDelta::lines(0., y, 0.)))
+                EventData::Wheel(
+                    WheelData::new(WheelDelta::lines(0., y, 0.))
+                        .with_modifiers(modifiers_from_crossterm_modifiers(m.modifiers)),
+                )
             };
 
             match m.kind {
@@ -771,25 +915,36 @@ fn get_event(evt: TermEvent) -> Option<(&'static str, EventData)> {
             }
         }
         TermEvent::Resize(x, y) => ("resize", EventData::Screen((x, y))),
+        // pasting goes to the focused element, like the keys typing the text would
+        TermEvent::Paste(text) => {
+            let mut data = ClipboardData::default();
+            data.text = Some(text);
+            ("paste", EventData::Clipboard(data))
+        }
+        TermEvent::FocusGained | TermEvent::FocusLost => return None,
     };
 
     Some((name, data))
 }
 
-fn translate_key_event(event: crossterm::event::KeyEvent) -> Option<EventData> {
+pub(crate) fn translate_key_event(event: crossterm::event::KeyEvent) -> Option<KeyboardData> {
+    // some terminals report releasing keys too, and only presses are keydown events
+    if event.kind == KeyEventKind::Release {
+        return None;
+    }
     let key = key_from_crossterm_key_code(event.code);
     // crossterm does not provide code. we make a guess as to which key might have been pressed
     // this is probably garbage if the user has a custom keyboard layout
     let code = guess_code_from_crossterm_key_code(event.code)?;
     let modifiers = modifiers_from_crossterm_modifiers(event.modifiers);
 
-    Some(EventData::Keyboard(KeyboardData::new(
+    Some(KeyboardData::new(
         key,
         code,
         Location::Standard,
         false,
         modifiers,
-    )))
+    ))
 }
 
 /// The crossterm key_code nicely represents the meaning of the key and we can mostly convert it without any issues
@@ -843,6 +998,15 @@ fn key_from_crossterm_key_code(key_code: TermKeyCode) -> Key {
         TermKeyCode::Char(c) => Key::Character(c.to_string()),
         TermKeyCode::Null => Key::Unidentified,
         TermKeyCode::Esc => Key::Escape,
+        TermKeyCode::CapsLock => Key::CapsLock,
+        TermKeyCode::ScrollLock => Key::ScrollLock,
+        TermKeyCode::NumLock => Key::NumLock,
+        TermKeyCode::PrintScreen => Key::PrintScreen,
+        TermKeyCode::Pause => Key::Pause,
+        TermKeyCode::Menu => Key::ContextMenu,
+        TermKeyCode::KeypadBegin => Key::Clear,
+        // media and modifier keys are only reported with the kitty keyboard protocol, which is never enabled
+        TermKeyCode::Media(_) | TermKeyCode::Modifier(_) => Key::Unidentified,
     }
 }
 
@@ -974,6 +1138,14 @@ fn guess_code_from_crossterm_key_code(key_code: TermKeyCode) -> Option<Code> {
         },
         TermKeyCode::Null => return None,
         TermKeyCode::Esc => Code::Escape,
+        TermKeyCode::CapsLock => Code::CapsLock,
+        TermKeyCode::ScrollLock => Code::ScrollLock,
+        TermKeyCode::NumLock => Code::NumLock,
+        TermKeyCode::PrintScreen => Code::PrintScreen,
+        TermKeyCode::Pause => Code::Pause,
+        TermKeyCode::Menu => Code::ContextMenu,
+        TermKeyCode::KeypadBegin => Code::Numpad5,
+        TermKeyCode::Media(_) | TermKeyCode::Modifier(_) => return None,
     };
 
     Some(code)
@@ -0,0 +1,412 @@
+//! `img` elements are drawn with the graphics protocol of the terminal, on top of the cells the layout gave them.
+//! Terminals without one, and images that are loading or can't be loaded, get a placeholder with the alt text of the
+//! image instead.
+
+use base64::Engine;
+use crossterm::{cursor::MoveTo, queue};
+use dioxus_native_core::NodeId;
+use futures::channel::mpsc::UnboundedSender;
+use image::{imageops::FilterType, DynamicImage, GenericImageView, ImageOutputFormat};
+use rustc_hash::FxHashSet;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tui::backend::Backend;
+use tui::buffer::{Buffer, Cell};
+use tui::layout::Rect;
+use tui::widgets::Widget;
+use tui::Frame;
+
+use crate::InputEvent;
+
+/// How many pixels wide and tall a cell is assumed to be, to size images in cells
+pub(crate) const CELL_SIZE: [u32; 2] = [10, 20];
+
+/// How images are drawn in the terminal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageProtocol {
+    /// The kitty graphics protocol, also supported by WezTerm and Konsole
+    Kitty,
+    /// Sixel graphics, supported by xterm, mlterm, foot and others
+    Sixel,
+    /// iTerm2 inline images, also supported by WezTerm
+    Iterm,
+    /// Draw a placeholder with the alt text of the image
+    None,
+}
+
+impl ImageProtocol {
+    /// Guess the graphics protocol of the terminal from the environment
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).ok();
+        Self::from_env(
+            var("TERM").as_deref(),
+            var("TERM_PROGRAM").as_deref(),
+            var("LC_TERMINAL").as_deref(),
+            var("KITTY_WINDOW_ID").is_some(),
+        )
+    }
+
+    fn from_env(
+        term: Option<&str>,
+        term_program: Option<&str>,
+        lc_terminal: Option<&str>,
+        kitty: bool,
+    ) -> Self {
+        if kitty || term == Some("xterm-kitty") {
+            ImageProtocol::Kitty
+        } else if matches!(term_program, Some("iTerm.app" | "WezTerm"))
+            || lc_terminal == Some("iTerm2")
+        {
+            ImageProtocol::Iterm
+        } else if matches!(term_program, Some("mlterm"))
+            || term.map_or(false, |term| {
+                term.contains("sixel") || term.starts_with("foot")
+            })
+        {
+            ImageProtocol::Sixel
+        } else {
+            ImageProtocol::None
+        }
+    }
+}
+
+/// The images of `img` elements, decoded on other threads so the layout and the frames don't wait for them. Until an
+/// image is loaded, its element is laid out without it and draws a placeholder.
+#[derive(Clone)]
+pub(crate) struct ImageCache {
+    images: Arc<Mutex<HashMap<String, CachedImage>>>,
+    loaded: UnboundedSender<InputEvent>,
+}
+
+#[derive(Default)]
+struct CachedImage {
+    /// When the file was last modified, to load it again when it changes
+    modified: Option<SystemTime>,
+    /// The last version of the image that was loaded, if it could be
+    image: Option<Arc<DynamicImage>>,
+    loading: bool,
+    /// The elements laid out while the image was loading, to lay out again once it loads
+    waiting: FxHashSet<NodeId>,
+}
+
+impl ImageCache {
+    pub fn new(loaded: UnboundedSender<InputEvent>) -> Self {
+        Self {
+            images: Arc::default(),
+            loaded,
+        }
+    }
+
+    /// The image at a path, or the version of it that loaded last while the file changes. Each version of the file is
+    /// only read once, even if it couldn't be loaded. If it is loading, `node` is laid out again once it loads.
+    pub fn load(&self, src: &str, node: Option<NodeId>) -> Option<Arc<DynamicImage>> {
+        let modified = std::fs::metadata(src)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        let mut images = self.images.lock().expect("image cache poisoned");
+        let mut start = false;
+        if !images.contains_key(src) {
+            images.insert(src.to_string(), CachedImage::default());
+            start = true;
+        }
+        let cached = images.get_mut(src).expect("the image was just cached");
+        if start || cached.modified != modified {
+            cached.modified = modified;
+            cached.loading = true;
+            self.spawn_load(src.to_string(), modified);
+        }
+        if let (true, Some(node)) = (cached.loading, node) {
+            cached.waiting.insert(node);
+        }
+        cached.image.clone()
+    }
+
+    fn spawn_load(&self, src: String, modified: Option<SystemTime>) {
+        let cache = self.clone();
+        std::thread::spawn(move || {
+            let image = image::open(&src).ok().map(Arc::new);
+            let mut images = cache.images.lock().expect("image cache poisoned");
+            // a newer version of the file is loading
+            let cached = match images.get_mut(&src) {
+                Some(cached) if cached.modified == modified => cached,
+                _ => return,
+            };
+            cached.image = image;
+            cached.loading = false;
+            let waiting = std::mem::take(&mut cached.waiting);
+            drop(images);
+            let _ = cache
+                .loaded
+                .unbounded_send(InputEvent::ImageLoaded(waiting.into_iter().collect()));
+        });
+    }
+}
+
+/// The size of an image in cells, as `[width, height]`
+pub(crate) fn size_in_cells(image: &DynamicImage) -> [u16; 2] {
+    let (width, height) = image.dimensions();
+    [
+        ((width + CELL_SIZE[0] - 1) / CELL_SIZE[0]) as u16,
+        ((height + CELL_SIZE[1] - 1) / CELL_SIZE[1]) as u16,
+    ]
+}
+
+/// An image drawn on the screen
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ImagePlacement {
+    pub src: String,
+    /// The cells the whole image covers, which can start above or left of the screen
+    pub x: i32,
+    pub y: i32,
+    pub width: u16,
+    pub height: u16,
+    /// The cells of the image that are on the screen
+    pub visible: Rect,
+}
+
+/// Draws the images after the text of each frame
+pub(crate) struct ImageRenderer {
+    protocol: ImageProtocol,
+    cache: ImageCache,
+    placed: Vec<ImagePlacement>,
+}
+
+impl ImageRenderer {
+    pub fn new(protocol: ImageProtocol, cache: ImageCache) -> Self {
+        Self {
+            protocol,
+            cache,
+            placed: Vec::new(),
+        }
+    }
+
+    /// Sixel and iTerm images are pixels in the cells they cover, so the cells of the frame under the images on the
+    /// screen are kept to paint over the images that move
+    pub fn covered_cells<B: Backend>(&self, frame: &mut Frame<B>) -> Vec<(u16, u16, Cell)> {
+        let mut cells = Vec::new();
+        if matches!(self.protocol, ImageProtocol::Sixel | ImageProtocol::Iterm) {
+            let covered = CoveredCells {
+                placed: &self.placed,
+                cells: &mut cells,
+            };
+            frame.render_widget(covered, frame.size());
+        }
+        cells
+    }
+
+    /// Draw the images, unless they are the ones already on the screen
+    pub fn draw(
+        &mut self,
+        out: &mut (impl Write + Backend),
+        placements: Vec<ImagePlacement>,
+        covered: Vec<(u16, u16, Cell)>,
+    ) -> io::Result<()> {
+        if placements == self.placed {
+            return Ok(());
+        }
+        if self.protocol == ImageProtocol::Kitty {
+            // kitty keeps the images over the text until they are deleted
+            write!(out, "\x1b_Ga=d,q=2\x1b\\")?;
+        }
+        // the text was already drawn in these cells, so the terminal only shows it again when it is written again
+        out.draw(covered.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+        for placement in &placements {
+            let image = match self.cache.load(&placement.src, None) {
+                Some(image) if image.width() > 0 && image.height() > 0 => image,
+                _ => continue,
+            };
+            let pixels = visible_pixels(&image, placement);
+            let Rect {
+                x,
+                y,
+                width,
+                height,
+            } = placement.visible;
+            queue!(out, MoveTo(x, y))?;
+            match self.protocol {
+                ImageProtocol::Kitty => kitty(out, &pixels, width, height)?,
+                ImageProtocol::Sixel => sixel(out, &pixels)?,
+                ImageProtocol::Iterm => iterm(out, &pixels, width, height)?,
+                ImageProtocol::None => (),
+            }
+        }
+        Write::flush(out)?;
+        self.placed = placements;
+        Ok(())
+    }
+}
+
+/// Copies the cells of a frame under the images on the screen, without changing the frame
+struct CoveredCells<'a> {
+    placed: &'a [ImagePlacement],
+    cells: &'a mut Vec<(u16, u16, Cell)>,
+}
+
+impl Widget for CoveredCells<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for placement in self.placed {
+            let visible = placement.visible.intersection(area);
+            for y in visible.top()..visible.bottom() {
+                for x in visible.left()..visible.right() {
+                    self.cells.push((x, y, buf.get(x, y).clone()));
+                }
+            }
+        }
+    }
+}
+
+/// The part of the image in its visible cells, scaled to the pixels of those cells
+fn visible_pixels(image: &DynamicImage, placement: &ImagePlacement) -> DynamicImage {
+    let (width, height) = image.dimensions();
+    let scale_x = width as f32 / placement.width.max(1) as f32;
+    let scale_y = height as f32 / placement.height.max(1) as f32;
+    let visible = placement.visible;
+    let left = (((visible.x as i32 - placement.x) as f32 * scale_x) as u32).min(width - 1);
+    let top = (((visible.y as i32 - placement.y) as f32 * scale_y) as u32).min(height - 1);
+    let crop_width = ((visible.width as f32 * scale_x).round() as u32).clamp(1, width - left);
+    let crop_height = ((visible.height as f32 * scale_y).round() as u32).clamp(1, height - top);
+    image
+        .crop_imm(left, top, crop_width, crop_height)
+        .resize_exact(
+            visible.width as u32 * CELL_SIZE[0],
+            visible.height as u32 * CELL_SIZE[1],
+            FilterType::Triangle,
+        )
+}
+
+fn kitty(out: &mut impl Write, image: &DynamicImage, columns: u16, rows: u16) -> io::Result<()> {
+    let rgba = image.to_rgba8();
+    let data = base64::engine::general_purpose::STANDARD.encode(rgba.as_raw());
+    // the data is sent in chunks of at most 4096 bytes
+    let chunks: Vec<_> = data.as_bytes().chunks(4096).collect();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            write!(
+                out,
+                "\x1b_Ga=T,f=32,s={},v={},c={columns},r={rows},C=1,q=2,m={more};",
+                rgba.width(),
+                rgba.height()
+            )?;
+        } else {
+            write!(out, "\x1b_Gm={more};")?;
+        }
+        out.write_all(chunk)?;
+        write!(out, "\x1b\\")?;
+    }
+    Ok(())
+}
+
+fn iterm(out: &mut impl Write, image: &DynamicImage, columns: u16, rows: u16) -> io::Result<()> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut io::Cursor::new(&mut png), ImageOutputFormat::Png)
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    let data = base64::engine::general_purpose::STANDARD.encode(&png);
+    write!(
+        out,
+        "\x1b]1337;File=inline=1;size={};width={columns};height={rows};preserveAspectRatio=0:{data}\x07",
+        png.len()
+    )
+}
+
+/// Encode an image as sixels, with a fixed palette of 6 levels of red, green and blue
+fn sixel(out: &mut impl Write, image: &DynamicImage) -> io::Result<()> {
+    let rgb = image.to_rgb8();
+    let (width, height) = rgb.dimensions();
+    let color = |x, y| {
+        let [r, g, b] = rgb.get_pixel(x, y).0.map(|c| (c as u16 * 5 + 127) / 255);
+        r * 36 + g * 6 + b
+    };
+
+    // pixels that no color covers stay as they are
+    write!(out, "\x1bP0;1;0q\"1;1;{width};{height}")?;
+    for i in 0..216 {
+        write!(
+            out,
+            "#{i};2;{};{};{}",
+            i / 36 * 20,
+            i / 6 % 6 * 20,
+            i % 6 * 20
+        )?;
+    }
+    for band in (0..height).step_by(6) {
+        // each sixel is a column of 6 pixels, drawn one color at a time
+        let mut colors: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+        for x in 0..width {
+            for dy in 0..6.min(height - band) {
+                colors
+                    .entry(color(x, band + dy))
+                    .or_insert_with(|| vec![0; width as usize])[x as usize] |= 1 << dy;
+            }
+        }
+        for (color, sixels) in colors {
+            write!(out, "#{color}")?;
+            write_sixel_runs(out, &sixels)?;
+            write!(out, "$")?;
+        }
+        write!(out, "-")?;
+    }
+    write!(out, "\x1b\\")
+}
+
+fn write_sixel_runs(out: &mut impl Write, sixels: &[u8]) -> io::Result<()> {
+    let mut i = 0;
+    while i < sixels.len() {
+        let run = sixels[i..].iter().take_while(|s| **s == sixels[i]).count();
+        let c = (63 + sixels[i]) as char;
+        if run > 3 {
+            write!(out, "!{run}{c}")?;
+        } else {
+            for _ in 0..run {
+                write!(out, "{c}")?;
+            }
+        }
+        i += run;
+    }
+    Ok(())
+}
+
+#[test]
+fn image_protocol_from_env() {
+    assert_eq!(
+        ImageProtocol::from_env(Some("xterm-kitty"), None, None, false),
+        ImageProtocol::Kitty
+    );
+    assert_eq!(
+        ImageProtocol::from_env(Some("xterm-256color"), Some("iTerm.app"), None, false),
+        ImageProtocol::Iterm
+    );
+    assert_eq!(
+        ImageProtocol::from_env(Some("foot"), None, None, false),
+        ImageProtocol::Sixel
+    );
+    assert_eq!(
+        ImageProtocol::from_env(Some("xterm-256color"), None, None, false),
+        ImageProtocol::None
+    );
+}
+
+#[test]
+fn sixel_runs_are_compressed() {
+    let mut out = Vec::new();
+    write_sixel_runs(&mut out, &[0, 0, 0, 0, 0, 1, 1]).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "!5?@@");
+}
+
+#[test]
+fn elements_are_laid_out_again_once_their_image_loads() {
+    use futures::StreamExt;
+
+    let (tx, mut rx) = futures::channel::mpsc::unbounded();
+    let cache = ImageCache::new(tx);
+    assert!(cache.load("missing.png", Some(NodeId(1))).is_none());
+    match futures::executor::block_on(rx.next()) {
+        Some(InputEvent::ImageLoaded(nodes)) => assert_eq!(nodes, vec![NodeId(1)]),
+        other => panic!("expected the image to load, got {other:?}"),
+    }
+    // the file was already tried
+    assert!(cache.load("missing.png", None).is_none());
+}
@@ -8,7 +8,15 @@ use dioxus_native_core::node_ref::{AttributeMask, NodeMask, NodeView};
 use dioxus_native_core::state::ChildDepState;
 use dioxus_native_core_macro::sorted_str_slice;
 use taffy::prelude::*;
+use taffy::style::{
+    MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction,
+    TrackSizingFunction,
+};
+use unicode_width::UnicodeWidthStr;
 
+use crate::images::{self, ImageCache};
+use crate::scroll::overflow_scrolls;
+use crate::style_attributes::{css_border_value, style_declarations};
 use crate::{screen_to_layout_space, unit_to_layout_space};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -40,10 +48,18 @@ impl<T> Default for PossiblyUninitalized<T> {
 pub(crate) struct TaffyLayout {
     pub style: Style,
     pub node: PossiblyUninitalized<Node>,
+    /// If the content of the node scrolls instead of overflowing it
+    pub scroll: bool,
+    /// How wide the content of the node is, to size the columns of tables
+    pub content_width: f32,
+    /// If the node is a table row
+    pub row: bool,
+    /// The content width of the widest cell of each column, for table rows and the tables and sections around them
+    pub columns: Vec<f32>,
 }
 
 impl ChildDepState for TaffyLayout {
-    type Ctx = Arc<Mutex<Taffy>>;
+    type Ctx = (Arc<Mutex<Taffy>>, ImageCache);
     type DepState = (Self,);
     // use tag to force this to be called when a node is built
     const NODE_MASK: NodeMask =
@@ -62,10 +78,18 @@ impl ChildDepState for TaffyLayout {
         Self::DepState: 'a,
     {
         let mut changed = false;
-        let mut taffy = ctx.lock().expect("poisoned taffy");
+        let (taffy, image_cache) = ctx;
+        let mut taffy = taffy.lock().expect("poisoned taffy");
         let mut style = Style::default();
+        let mut scroll = false;
+        let content_width;
+        let mut columns = Vec::new();
+        let tag = node.tag().filter(|_| node.namespace().is_none());
+        let row = tag == Some("tr");
         if let Some(text) = node.text() {
-            let char_len = text.chars().count();
+            // wide characters take two cells of the terminal
+            let text_width = screen_to_layout_space(text.width() as u16);
+            content_width = text_width;
 
             style = Style {
                 size: Size {
@@ -73,19 +97,30 @@ impl ChildDepState for TaffyLayout {
                     height: Dimension::Points(screen_to_layout_space(1)),
 
                     // text is as long as it is declared
-                    width: Dimension::Points(screen_to_layout_space(char_len as u16)),
+                    width: Dimension::Points(text_width),
                 },
                 ..Default::default()
             };
             if let PossiblyUninitalized::Initialized(n) = self.node {
                 if self.style != style {
-                    taffy.set_style(n, style).unwrap();
+                    taffy.set_style(n, style.clone()).unwrap();
                 }
             } else {
-                self.node = PossiblyUninitalized::Initialized(taffy.new_leaf(style).unwrap());
+                self.node =
+                    PossiblyUninitalized::Initialized(taffy.new_leaf(style.clone()).unwrap());
                 changed = true;
             }
         } else {
+            // rows stack in a table, and the cells of a row sit side by side
+            match tag {
+                Some("table" | "thead" | "tbody" | "tfoot") => {
+                    style.flex_direction = FlexDirection::Column;
+                }
+                // the columns of the table size the cells
+                Some("td" | "th") => style.flex_shrink = 0.0,
+                _ => {}
+            }
+
             // gather up all the styles from the attribute list
             if let Some(attributes) = node.attributes() {
                 for OwnedAttributeView {
@@ -96,26 +131,83 @@ impl ChildDepState for TaffyLayout {
                         .binary_search(&attribute.name.as_ref())
                         .is_ok());
                     if let Some(text) = value.as_text() {
-                        apply_layout_attributes_cfg(
-                            &attribute.name,
-                            text,
-                            &mut style,
-                            &LayoutConfigeration {
-                                border_widths: BorderWidths {
-                                    thin: 1.0,
-                                    medium: 1.0,
-                                    thick: 1.0,
-                                },
+                        let config = LayoutConfigeration {
+                            border_widths: BorderWidths {
+                                thin: 1.0,
+                                medium: 1.0,
+                                thick: 1.0,
                             },
-                        );
+                        };
+                        if attribute.name == "style" {
+                            for (name, value) in style_declarations(text) {
+                                let value = css_border_value(name, value);
+                                apply_layout_attributes_cfg(name, &value, &mut style, &config);
+                                scroll |= overflow_scrolls(name, &value);
+                            }
+                        } else {
+                            let value = css_border_value(&attribute.name, text);
+                            apply_layout_attributes_cfg(
+                                &attribute.name,
+                                &value,
+                                &mut style,
+                                &config,
+                            );
+                            scroll |= overflow_scrolls(&attribute.name, text);
+                        }
+                    }
+                }
+            }
+
+            // images are as big as their pixels unless they are sized, and keep their aspect ratio unless it is set.
+            // Until they load, they are laid out like any other element.
+            if node.tag() == Some("img") {
+                let image = node
+                    .attributes()
+                    .and_then(|mut attributes| attributes.find(|a| a.attribute.name == "src"))
+                    .and_then(|src| src.value.as_text())
+                    .and_then(|src| image_cache.load(src, Some(node.node_id())));
+                if let Some(image) = image {
+                    let [width, height] = images::size_in_cells(&image).map(|cells| cells as f32);
+                    if style.size.width == Dimension::Auto && style.size.height == Dimension::Auto {
+                        style.size.width = Dimension::Points(width);
+                        style.size.height = Dimension::Points(height);
+                    }
+                    if style.aspect_ratio.is_none() && height > 0.0 {
+                        style.aspect_ratio = Some(width / height);
                     }
                 }
             }
 
             // Set all direct nodes as our children
             let mut child_layout = vec![];
+            let mut children_width = 0.0f32;
+            let mut table_children = vec![];
             for (l,) in children {
-                child_layout.push(l.node.unwrap());
+                let child = l.node.unwrap();
+                child_layout.push(child);
+
+                children_width = match style.flex_direction {
+                    FlexDirection::Row | FlexDirection::RowReverse => {
+                        children_width + l.content_width
+                    }
+                    _ => children_width.max(l.content_width),
+                };
+                match tag {
+                    Some("tr") => columns.push(l.content_width),
+                    Some("table" | "thead" | "tbody" | "tfoot") => {
+                        widest_cells(&mut columns, &l.columns);
+                        table_children.push((child, l.row));
+                    }
+                    _ => {}
+                }
+
+                // the content of a scroll container keeps its size, and overflows it instead of shrinking
+                let flex_shrink = if scroll { 0.0 } else { l.style.flex_shrink };
+                let mut child_style = taffy.style(child).unwrap().clone();
+                if child_style.flex_shrink != flex_shrink {
+                    child_style.flex_shrink = flex_shrink;
+                    taffy.set_style(child, child_style).unwrap();
+                }
             }
 
             fn scale_dimention(d: Dimension) -> Dimension {
@@ -123,38 +215,100 @@ impl ChildDepState for TaffyLayout {
                     Dimension::Points(p) => Dimension::Points(unit_to_layout_space(p)),
                     Dimension::Percent(p) => Dimension::Percent(p),
                     Dimension::Auto => Dimension::Auto,
-                    Dimension::Undefined => Dimension::Undefined,
                 }
             }
+            fn scale_length_percentage(l: LengthPercentage) -> LengthPercentage {
+                match l {
+                    LengthPercentage::Points(p) => {
+                        LengthPercentage::Points(unit_to_layout_space(p))
+                    }
+                    LengthPercentage::Percent(p) => LengthPercentage::Percent(p),
+                }
+            }
+            fn scale_length_percentage_auto(l: LengthPercentageAuto) -> LengthPercentageAuto {
+                match l {
+                    LengthPercentageAuto::Points(p) => {
+                        LengthPercentageAuto::Points(unit_to_layout_space(p))
+                    }
+                    LengthPercentageAuto::Percent(p) => LengthPercentageAuto::Percent(p),
+                    LengthPercentageAuto::Auto => LengthPercentageAuto::Auto,
+                }
+            }
+            fn scale_track(
+                track: NonRepeatedTrackSizingFunction,
+            ) -> NonRepeatedTrackSizingFunction {
+                NonRepeatedTrackSizingFunction {
+                    min: match track.min {
+                        MinTrackSizingFunction::Fixed(l) => {
+                            MinTrackSizingFunction::Fixed(scale_length_percentage(l))
+                        }
+                        min => min,
+                    },
+                    max: match track.max {
+                        MaxTrackSizingFunction::Fixed(l) => {
+                            MaxTrackSizingFunction::Fixed(scale_length_percentage(l))
+                        }
+                        MaxTrackSizingFunction::FitContent(l) => {
+                            MaxTrackSizingFunction::FitContent(scale_length_percentage(l))
+                        }
+                        max => max,
+                    },
+                }
+            }
+            fn scale_tracks(tracks: &[TrackSizingFunction]) -> Vec<TrackSizingFunction> {
+                tracks
+                    .iter()
+                    .map(|track| match track {
+                        TrackSizingFunction::Single(track) => {
+                            TrackSizingFunction::Single(scale_track(*track))
+                        }
+                        track => track.clone(),
+                    })
+                    .collect()
+            }
             let style = Style {
-                position: Rect {
-                    left: scale_dimention(style.position.left),
-                    right: scale_dimention(style.position.right),
-                    top: scale_dimention(style.position.top),
-                    bottom: scale_dimention(style.position.bottom),
+                inset: Rect {
+                    left: scale_length_percentage_auto(style.inset.left),
+                    right: scale_length_percentage_auto(style.inset.right),
+                    top: scale_length_percentage_auto(style.inset.top),
+                    bottom: scale_length_percentage_auto(style.inset.bottom),
                 },
                 margin: Rect {
-                    left: scale_dimention(style.margin.left),
-                    right: scale_dimention(style.margin.right),
-                    top: scale_dimention(style.margin.top),
-                    bottom: scale_dimention(style.margin.bottom),
+                    left: scale_length_percentage_auto(style.margin.left),
+                    right: scale_length_percentage_auto(style.margin.right),
+                    top: scale_length_percentage_auto(style.margin.top),
+                    bottom: scale_length_percentage_auto(style.margin.bottom),
                 },
                 padding: Rect {
-                    left: scale_dimention(style.padding.left),
-                    right: scale_dimention(style.padding.right),
-                    top: scale_dimention(style.padding.top),
-                    bottom: scale_dimention(style.padding.bottom),
+                    left: scale_length_percentage(style.padding.left),
+                    right: scale_length_percentage(style.padding.right),
+                    top: scale_length_percentage(style.padding.top),
+                    bottom: scale_length_percentage(style.padding.bottom),
                 },
                 border: Rect {
-                    left: scale_dimention(style.border.left),
-                    right: scale_dimention(style.border.right),
-                    top: scale_dimention(style.border.top),
-                    bottom: scale_dimention(style.border.bottom),
+                    left: scale_length_percentage(style.border.left),
+                    right: scale_length_percentage(style.border.right),
+                    top: scale_length_percentage(style.border.top),
+                    bottom: scale_length_percentage(style.border.bottom),
                 },
                 gap: Size {
-                    width: scale_dimention(style.gap.width),
-                    height: scale_dimention(style.gap.height),
+                    width: scale_length_percentage(style.gap.width),
+                    height: scale_length_percentage(style.gap.height),
                 },
+                grid_template_rows: scale_tracks(&style.grid_template_rows),
+                grid_template_columns: scale_tracks(&style.grid_template_columns),
+                grid_auto_rows: style
+                    .grid_auto_rows
+                    .iter()
+                    .copied()
+                    .map(scale_track)
+                    .collect(),
+                grid_auto_columns: style
+                    .grid_auto_columns
+                    .iter()
+                    .copied()
+                    .map(scale_track)
+                    .collect(),
                 flex_basis: scale_dimention(style.flex_basis),
                 size: Size {
                     width: scale_dimention(style.size.width),
@@ -171,29 +325,95 @@ impl ChildDepState for TaffyLayout {
                 ..style
             };
 
+            content_width = match style.size.width {
+                Dimension::Points(width) => width,
+                _ => children_width,
+            };
+
             if let PossiblyUninitalized::Initialized(n) = self.node {
                 if self.style != style {
-                    taffy.set_style(n, style).unwrap();
+                    let mut new_style = style.clone();
+                    // the table around a cell sets its width, unless the width of the cell changed
+                    let cell = matches!(tag, Some("td" | "th"));
+                    if cell && self.style.size.width == style.size.width {
+                        new_style.size.width = taffy.style(n).unwrap().size.width;
+                    }
+                    taffy.set_style(n, new_style).unwrap();
                 }
                 if taffy.children(n).unwrap() != child_layout {
                     taffy.set_children(n, &child_layout).unwrap();
                 }
             } else {
                 self.node = PossiblyUninitalized::Initialized(
-                    taffy.new_with_children(style, &child_layout).unwrap(),
+                    taffy
+                        .new_with_children(style.clone(), &child_layout)
+                        .unwrap(),
                 );
                 changed = true;
             }
+
+            if tag == Some("table") {
+                size_columns(&mut taffy, &table_children, &columns);
+            }
         }
         if self.style != style {
             changed = true;
             self.style = style;
         }
+        if self.scroll != scroll {
+            changed = true;
+            self.scroll = scroll;
+        }
+        if self.content_width != content_width || self.row != row || self.columns != columns {
+            changed = true;
+            self.content_width = content_width;
+            self.row = row;
+            self.columns = columns;
+        }
         changed
     }
 }
 
-// these are the attributes in layout_attiributes in native-core
+/// Widen the columns to the cells of another row
+fn widest_cells(columns: &mut Vec<f32>, cells: &[f32]) {
+    for (index, width) in cells.iter().enumerate() {
+        match columns.get_mut(index) {
+            Some(column) => *column = column.max(*width),
+            None => columns.push(*width),
+        }
+    }
+}
+
+/// Size the cells of a table like browsers do for tables without a fixed layout: every column is as wide as its widest
+/// cell, with a cell of space before the next column
+fn size_columns(taffy: &mut Taffy, children: &[(Node, bool)], columns: &[f32]) {
+    let mut rows = vec![];
+    for (child, row) in children {
+        if *row {
+            rows.push(*child);
+        } else {
+            // the rows of a section
+            rows.extend(taffy.children(*child).unwrap());
+        }
+    }
+
+    for row in rows {
+        for (index, cell) in taffy.children(row).unwrap().into_iter().enumerate() {
+            let mut width = columns.get(index).copied().unwrap_or_default();
+            if index + 1 < columns.len() {
+                width += screen_to_layout_space(1);
+            }
+            let mut style = taffy.style(cell).unwrap().clone();
+            if style.size.width != Dimension::Points(width) || style.flex_shrink != 0.0 {
+                style.size.width = Dimension::Points(width);
+                style.flex_shrink = 0.0;
+                taffy.set_style(cell, style).unwrap();
+            }
+        }
+    }
+}
+
+// these are the attributes in layout_attiributes in native-core, and the source of images
 const SORTED_LAYOUT_ATTRS: &[&str] = &sorted_str_slice!([
     "align-content",
     "align-items",
@@ -207,6 +427,7 @@ const SORTED_LAYOUT_ATTRS: &[&str] = &sorted_str_slice!([
     "animation-name",
     "animation-play-state",
     "animation-timing-function",
+    "aspect-ratio",
     "backface-visibility",
     "border",
     "border-bottom",
@@ -274,6 +495,18 @@ const SORTED_LAYOUT_ATTRS: &[&str] = &sorted_str_slice!([
     "flex-shrink",
     "flex-wrap",
     "float",
+    "gap",
+    "grid-auto-columns",
+    "grid-auto-flow",
+    "grid-auto-rows",
+    "grid-column",
+    "grid-column-end",
+    "grid-column-start",
+    "grid-row",
+    "grid-row-end",
+    "grid-row-start",
+    "grid-template-columns",
+    "grid-template-rows",
     "height",
     "justify-content",
     "flex-start",
@@ -328,7 +561,10 @@ const SORTED_LAYOUT_ATTRS: &[&str] = &sorted_str_slice!([
     "quotes",
     "resize",
     "right",
+    "row-gap",
+    "src",
     "tab-size",
+    "style",
     "table-layout",
     "top",
     "transform",
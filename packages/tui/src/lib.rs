@@ -1,10 +1,16 @@
+use animation::AnimationFrames;
 use anyhow::Result;
+use clipboard::TerminalClipboard;
 use crossterm::{
     cursor::{MoveTo, RestorePosition, SavePosition, Show},
-    event::{DisableMouseCapture, EnableMouseCapture, Event as TermEvent, KeyCode, KeyModifiers},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event as TermEvent, KeyCode, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use dioxus::hooks::{Clipboard, GlobalListeners, MediaQueries};
 use dioxus_core::*;
 use dioxus_native_core::{real_dom::RealDom, FxDashSet, NodeId, NodeMask, SendAnyMap};
 use focus::FocusState;
@@ -13,33 +19,47 @@ use futures::{
     pin_mut, StreamExt,
 };
 use futures_channel::mpsc::unbounded;
+use global_listeners::{dispatch_global_event, TerminalListeners};
+use images::{ImageCache, ImageRenderer};
+use media_queries::TerminalMediaQueries;
 use query::Query;
 use std::rc::Rc;
 use std::{
     cell::RefCell,
     sync::{Arc, Mutex},
 };
-use std::{io, time::Duration};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 use taffy::Taffy;
 pub use taffy::{geometry::Point, prelude::*};
 use tokio::select;
 use tui::{backend::CrosstermBackend, layout::Rect, Terminal};
 
+mod animation;
+mod clipboard;
 mod config;
 mod focus;
+mod global_listeners;
 mod hooks;
+mod images;
 mod layout;
+mod media_queries;
 mod node;
 pub mod prelude;
 pub mod query;
 mod render;
+mod scroll;
 mod style;
 mod style_attributes;
 mod widget;
 mod widgets;
 
+pub use animation::use_animation_frame;
 pub use config::*;
 pub use hooks::*;
+pub use images::ImageProtocol;
 pub(crate) use node::*;
 
 // the layout space has a multiplier of 10 to minimize rounding errors
@@ -58,6 +78,7 @@ pub(crate) fn layout_to_screen_space(layout: f32) -> f32 {
 #[derive(Clone)]
 pub struct TuiContext {
     tx: UnboundedSender<InputEvent>,
+    frames: AnimationFrames,
 }
 impl TuiContext {
     pub fn quit(&self) {
@@ -69,6 +90,12 @@ impl TuiContext {
             .unbounded_send(InputEvent::UserInput(event))
             .unwrap();
     }
+
+    /// Call `callback` with the time of the next frame, like `requestAnimationFrame` in browsers. Frames are limited
+    /// to the max fps of the [`Config`].
+    pub fn request_animation_frame(&self, callback: impl FnOnce(Instant) + 'static) {
+        self.frames.request(callback);
+    }
 }
 
 pub fn launch(app: Component<()>) {
@@ -80,6 +107,7 @@ pub fn launch_cfg(app: Component<()>, cfg: Config) {
 }
 
 pub fn launch_cfg_with_props<Props: 'static>(app: Component<Props>, props: Props, cfg: Config) {
+    let cfg = cfg.detect_terminal();
     let mut dom = VirtualDom::new_with_props(app, props);
 
     let (handler, state, register_event) = RinkInputHandler::new();
@@ -87,6 +115,7 @@ pub fn launch_cfg_with_props<Props: 'static>(app: Component<Props>, props: Props
     // Setup input handling
     let (event_tx, event_rx) = unbounded();
     let event_tx_clone = event_tx.clone();
+    let image_cache = ImageCache::new(event_tx.clone());
     if !cfg.headless {
         std::thread::spawn(move || {
             let tick_rate = Duration::from_millis(1000);
@@ -105,7 +134,19 @@ pub fn launch_cfg_with_props<Props: 'static>(app: Component<Props>, props: Props
     let rdom = Rc::new(RefCell::new(RealDom::new()));
     let taffy = Arc::new(Mutex::new(Taffy::new()));
     cx.provide_context(state);
-    cx.provide_context(TuiContext { tx: event_tx_clone });
+    let frames = AnimationFrames::default();
+    cx.provide_context(TuiContext {
+        tx: event_tx_clone,
+        frames: frames.clone(),
+    });
+    cx.provide_context(GlobalListeners::new(TerminalListeners));
+    let media_queries = Rc::new(TerminalMediaQueries::new(
+        crossterm::terminal::size().unwrap_or((80, 24)),
+    ));
+    cx.provide_context(MediaQueries::new(media_queries.clone()));
+    cx.provide_context(media_queries);
+    cx.provide_context(Clipboard::new(TerminalClipboard::new(cfg.headless)));
+    cx.provide_context(image_cache.clone());
     cx.provide_context(Query {
         rdom: rdom.clone(),
         stretch: taffy.clone(),
@@ -116,7 +157,7 @@ pub fn launch_cfg_with_props<Props: 'static>(app: Component<Props>, props: Props
         let mutations = dom.rebuild();
         let (to_update, _) = rdom.apply_mutations(mutations);
         let mut any_map = SendAnyMap::new();
-        any_map.insert(taffy.clone());
+        any_map.insert((taffy.clone(), image_cache.clone()));
         let _to_rerender = rdom.update_state(to_update, any_map);
     }
 
@@ -141,6 +182,26 @@ fn render_vdom(
     taffy: Arc<Mutex<Taffy>>,
     mut register_event: impl FnMut(crossterm::event::Event),
 ) -> Result<()> {
+    let global_listeners = vdom
+        .base_scope()
+        .consume_context::<GlobalListeners>()
+        .expect("the global listeners are provided when the app is launched");
+    let media_queries = vdom
+        .base_scope()
+        .consume_context::<Rc<TerminalMediaQueries>>()
+        .expect("the media queries are provided when the app is launched");
+    let frames = vdom
+        .base_scope()
+        .consume_context::<TuiContext>()
+        .expect("the tui context is provided when the app is launched")
+        .frames;
+    let image_cache = vdom
+        .base_scope()
+        .consume_context::<ImageCache>()
+        .expect("the image cache is provided when the app is launched");
+    let frame_interval = Duration::from_secs(1) / u32::from(cfg.max_fps.max(1));
+    let mut last_frame = Instant::now();
+
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()?
@@ -161,6 +222,7 @@ fn render_vdom(
                     stdout,
                     EnterAlternateScreen,
                     EnableMouseCapture,
+                    EnableBracketedPaste,
                     MoveTo(0, 1000)
                 )
                 .unwrap();
@@ -171,6 +233,8 @@ fn render_vdom(
                 terminal.clear().unwrap();
             }
 
+            let mut images = ImageRenderer::new(cfg.image_protocol(), image_cache.clone());
+
             let mut to_rerender = FxDashSet::default();
             to_rerender.insert(NodeId(0));
             let mut updated = true;
@@ -194,7 +258,7 @@ fn render_vdom(
 
                         // the root node fills the entire area
 
-                        let mut style = *taffy.style(root_node).unwrap();
+                        let mut style = taffy.style(root_node).unwrap().clone();
                         style.size = Size {
                             width: Dimension::Points(width),
                             height: Dimension::Points(height),
@@ -209,14 +273,26 @@ fn render_vdom(
                     }
                     if let Some(terminal) = &mut terminal {
                         execute!(terminal.backend_mut(), SavePosition).unwrap();
+                        let mut placements = Vec::new();
+                        let mut covered = Vec::new();
                         terminal.draw(|frame| {
                             let rdom = rdom.borrow();
                             let mut taffy = taffy.lock().expect("taffy lock poisoned");
                             // size is guaranteed to not change when rendering
                             resize(frame.size(), &mut taffy, &rdom);
                             let root = &rdom[NodeId(0)];
-                            render::render_vnode(frame, &taffy, &rdom, root, cfg, Point::ZERO);
+                            placements = render::render_vnode(
+                                frame,
+                                &taffy,
+                                &rdom,
+                                root,
+                                cfg,
+                                &image_cache,
+                                Point::ZERO,
+                            );
+                            covered = images.covered_cells(frame);
                         })?;
+                        images.draw(terminal.backend_mut(), placements, covered)?;
                         execute!(terminal.backend_mut(), RestorePosition, Show).unwrap();
                     } else {
                         let rdom = rdom.borrow();
@@ -233,39 +309,70 @@ fn render_vdom(
                     }
                 }
 
+                // elements that were just laid out or changed size get their resize events without waiting for input
+                let resized = handler.queue_resize_events(
+                    &taffy.lock().expect("taffy lock poisoned"),
+                    &rdom.borrow(),
+                );
+
                 #[cfg(all(feature = "hot-reload", debug_assertions))]
                 let mut hot_reload_msg = None;
-                {
+                if !resized {
                     let wait = vdom.wait_for_work();
                     #[cfg(all(feature = "hot-reload", debug_assertions))]
                     let hot_reload_wait = hot_reload_rx.recv();
                     #[cfg(not(all(feature = "hot-reload", debug_assertions)))]
                     let hot_reload_wait: std::future::Pending<Option<()>> = std::future::pending();
 
+                    // animations only wake the loop up while they are waiting for a frame
+                    let next_frame = tokio::time::Instant::from_std(last_frame + frame_interval);
+
                     pin_mut!(wait);
 
                     select! {
                         _ = wait => {
 
+                        },
+                        _ = tokio::time::sleep_until(next_frame), if frames.pending() => {
+                            last_frame = Instant::now();
+                            frames.run(last_frame);
                         },
                         evt = event_reciever.next() => {
                             match evt.as_ref().unwrap() {
                                 InputEvent::UserInput(event) => match event {
                                     TermEvent::Key(key) => {
+                                        // the focused element may prevent quitting, like text inputs do to copy
                                         if matches!(key.code, KeyCode::Char('C' | 'c'))
                                             && key.modifiers.contains(KeyModifiers::CONTROL)
                                             && cfg.ctrl_c_quit
+                                            && !handler.state().focused_listens_for_keys(&rdom.borrow())
                                         {
                                             break;
                                         }
                                     }
-                                    TermEvent::Resize(_, _) => updated = true,
-                                    TermEvent::Mouse(_) => {}
+                                    TermEvent::Resize(width, height) => {
+                                        media_queries.resize(*width, *height);
+                                        updated = true
+                                    }
+                                    TermEvent::Mouse(_)
+                                    | TermEvent::Paste(_)
+                                    | TermEvent::FocusGained
+                                    | TermEvent::FocusLost => {}
                                 },
+                                // the elements of the image are laid out with it
+                                InputEvent::ImageLoaded(nodes) => {
+                                    let mut rdom = rdom.borrow_mut();
+                                    let to_update = rdom.dirty_nodes(nodes.iter().map(|node| (*node, NodeMask::ALL)));
+                                    let mut any_map = SendAnyMap::new();
+                                    any_map.insert((taffy.clone(), image_cache.clone()));
+                                    rdom.update_state(to_update, any_map);
+                                    updated = true;
+                                }
                                 InputEvent::Close => break,
                             };
 
                             if let InputEvent::UserInput(evt) = evt.unwrap() {
+                                dispatch_global_event(&global_listeners, &evt);
                                 register_event(evt);
                             }
                         },
@@ -299,19 +406,32 @@ fn render_vdom(
                         handler.get_events(&taffy.lock().expect("taffy lock poisoned"), &mut rdom)
                     };
                     {
-                        updated |= handler.state().focus_state.clean();
+                        let mut state = handler.state();
+                        updated |= state.focus_state.clean();
+                        updated |= state.scroll_state.clean();
                     }
+                    let mut rdom = rdom.borrow_mut();
+                    let mut quit = false;
                     for e in evts {
-                        vdom.handle_event(e.name, e.data, e.id, e.bubbles)
+                        if !vdom.handle_event(e.name, e.data.clone(), e.id, e.bubbles) {
+                            quit |= cfg.ctrl_c_quit && handler.is_ctrl_c(&e);
+                            handler.default_action(
+                                &e,
+                                &taffy.lock().expect("taffy lock poisoned"),
+                                &mut rdom,
+                            );
+                        }
+                    }
+                    if quit {
+                        break;
                     }
-                    let mut rdom = rdom.borrow_mut();
                     let mutations = vdom.render_immediate();
                     handler.prune(&mutations, &rdom);
                     // updates the dom's nodes
                     let (to_update, dirty) = rdom.apply_mutations(mutations);
                     // update the style and layout
                     let mut any_map = SendAnyMap::new();
-                    any_map.insert(taffy.clone());
+                    any_map.insert((taffy.clone(), image_cache.clone()));
                     to_rerender = rdom.update_state(to_update, any_map);
                     for (id, mask) in dirty {
                         if mask.overlaps(&NodeMask::new().with_text()) {
@@ -326,7 +446,8 @@ fn render_vdom(
                 execute!(
                     terminal.backend_mut(),
                     LeaveAlternateScreen,
-                    DisableMouseCapture
+                    DisableMouseCapture,
+                    DisableBracketedPaste
                 )?;
                 terminal.show_cursor()?;
             }
@@ -338,5 +459,7 @@ fn render_vdom(
 #[derive(Debug)]
 enum InputEvent {
     UserInput(TermEvent),
+    /// The images of the elements finished loading
+    ImageLoaded(Vec<NodeId>),
     Close,
 }
@@ -0,0 +1,144 @@
+use dioxus::hooks::MediaQueryBackend;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+struct Watched {
+    query: String,
+    matches: Option<bool>,
+    on_change: Rc<dyn Fn(bool)>,
+}
+
+/// Evaluates media queries against the size of the terminal
+///
+/// The event loop calls [`TerminalMediaQueries::resize`] when the terminal is resized.
+pub(crate) struct TerminalMediaQueries {
+    size: Cell<(u16, u16)>,
+    watching: RefCell<HashMap<usize, Watched>>,
+}
+
+impl TerminalMediaQueries {
+    pub fn new(size: (u16, u16)) -> Self {
+        Self {
+            size: Cell::new(size),
+            watching: Default::default(),
+        }
+    }
+
+    /// Re-evaluate every query for the new size of the terminal
+    pub fn resize(&self, width: u16, height: u16) {
+        self.size.set((width, height));
+
+        let mut changed = Vec::new();
+        for watched in self.watching.borrow_mut().values_mut() {
+            let matches = evaluate(&watched.query, width, height);
+            if matches != watched.matches {
+                watched.matches = matches;
+                changed.push((watched.on_change.clone(), matches.unwrap_or(false)));
+            }
+        }
+
+        for (on_change, matches) in changed {
+            on_change(matches);
+        }
+    }
+}
+
+impl MediaQueryBackend for TerminalMediaQueries {
+    fn watch(&self, id: usize, query: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool> {
+        let (width, height) = self.size.get();
+        let matches = evaluate(query, width, height);
+        if matches.is_none() {
+            log::warn!("The terminal can't evaluate the media query {query:?}");
+        }
+
+        self.watching.borrow_mut().insert(
+            id,
+            Watched {
+                query: query.to_string(),
+                matches,
+                on_change,
+            },
+        );
+
+        Some(matches.unwrap_or(false))
+    }
+
+    fn unwatch(&self, id: usize) {
+        self.watching.borrow_mut().remove(&id);
+    }
+}
+
+/// Evaluate a media query for a terminal of the given size, where a `px` is one cell
+///
+/// Only the `all` and `screen` media types and the `width`, `height` and `orientation` features are supported. Returns
+/// `None` if the query uses anything else.
+fn evaluate(query: &str, width: u16, height: u16) -> Option<bool> {
+    let (width, height) = (width as f32, height as f32);
+    let mut matches = true;
+
+    for condition in query.split(" and ") {
+        let condition = condition.trim();
+        let feature = match condition
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+        {
+            Some(feature) => feature,
+            None => match condition {
+                "all" | "screen" => continue,
+                "print" => {
+                    matches = false;
+                    continue;
+                }
+                _ => return None,
+            },
+        };
+
+        let (name, value) = feature.split_once(':')?;
+        let value = value.trim();
+        let result = match name.trim() {
+            "orientation" => match value {
+                "landscape" => width >= height,
+                "portrait" => height > width,
+                _ => return None,
+            },
+            "width" => width == length(value)?,
+            "min-width" => width >= length(value)?,
+            "max-width" => width <= length(value)?,
+            "height" => height == length(value)?,
+            "min-height" => height >= length(value)?,
+            "max-height" => height <= length(value)?,
+            _ => return None,
+        };
+        matches &= result;
+    }
+
+    Some(matches)
+}
+
+fn length(value: &str) -> Option<f32> {
+    value
+        .strip_suffix("px")
+        .unwrap_or(value)
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[test]
+fn media_queries_compare_against_terminal_cells() {
+    assert_eq!(evaluate("(max-width: 100px)", 80, 24), Some(true));
+    assert_eq!(evaluate("(min-width: 100px)", 80, 24), Some(false));
+    assert_eq!(
+        evaluate(
+            "screen and (min-width: 60px) and (max-height: 30px)",
+            80,
+            24
+        ),
+        Some(true)
+    );
+    assert_eq!(evaluate("(orientation: portrait)", 80, 24), Some(false));
+    assert_eq!(evaluate("(prefers-color-scheme: dark)", 80, 24), None);
+}
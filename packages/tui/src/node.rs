@@ -18,6 +18,8 @@ pub(crate) struct NodeState {
     #[node_dep_state()]
     pub focus: Focus,
     pub focused: bool,
+    /// How many cells the content of a scroll container is scrolled down
+    pub scroll_top: u16,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -1,48 +1,103 @@
-use dioxus_native_core::tree::TreeView;
+use dioxus_native_core::{node::NodeType, tree::TreeView};
 use std::io::Stdout;
 use taffy::{
     geometry::Point,
     prelude::{Dimension, Layout, Size},
+    style::Position,
     Taffy,
 };
 use tui::{backend::CrosstermBackend, layout::Rect, style::Color};
+use unicode_width::UnicodeWidthChar;
 
 use crate::{
-    layout_to_screen_space,
+    images::{ImageCache, ImagePlacement},
+    layout_to_screen_space, screen_to_layout_space,
+    scroll::{border_widths, max_scroll},
     style::{RinkColor, RinkStyle},
     style_attributes::{BorderEdge, BorderStyle},
     widget::{RinkBuffer, RinkCell, RinkWidget, WidgetWithContext},
-    Config, TuiDom, TuiNode,
+    Config, ImageProtocol, TuiDom, TuiNode,
 };
 
 const RADIUS_MULTIPLIER: [f32; 2] = [1.0, 0.5];
 
+struct RenderState<'a> {
+    layout: &'a Taffy,
+    rdom: &'a TuiDom,
+    cfg: Config,
+    overlays: Vec<(&'a TuiNode, Point<f32>)>,
+    image_cache: &'a ImageCache,
+    images: Vec<ImagePlacement>,
+}
+
 pub(crate) fn render_vnode(
     frame: &mut tui::Frame<CrosstermBackend<Stdout>>,
     layout: &Taffy,
     rdom: &TuiDom,
     node: &TuiNode,
     cfg: Config,
+    image_cache: &ImageCache,
     parent_location: Point<f32>,
-) {
-    use dioxus_native_core::node::NodeType;
+) -> Vec<ImagePlacement> {
+    let screen = frame.size();
+    let mut state = RenderState {
+        layout,
+        rdom,
+        cfg,
+        overlays: Vec::new(),
+        image_cache,
+        images: Vec::new(),
+    };
+    render_node(frame, &mut state, node, parent_location, screen);
+
+    // absolutely positioned elements are drawn over the rest of the page, so popups like the options of a select
+    // aren't hidden by the elements after them
+    while !state.overlays.is_empty() {
+        for (node, location) in std::mem::take(&mut state.overlays) {
+            render_node(frame, &mut state, node, location, screen);
+        }
+    }
 
+    // the images are drawn over the cells after the frame
+    state.images
+}
+
+fn render_node<'a>(
+    frame: &mut tui::Frame<CrosstermBackend<Stdout>>,
+    state: &mut RenderState<'a>,
+    node: &'a TuiNode,
+    parent_location: Point<f32>,
+    clip: Rect,
+) {
     if let NodeType::Placeholder = &node.node_data.node_type {
         return;
     }
 
     let Layout {
         mut location, size, ..
-    } = layout.layout(node.state.layout.node.unwrap()).unwrap();
+    } = state
+        .layout
+        .layout(node.state.layout.node.unwrap())
+        .unwrap();
     location.x += parent_location.x;
     location.y += parent_location.y;
 
+    // the content of scroll containers can start above the screen
     let Point { x: fx, y: fy } = location;
-    let x = layout_to_screen_space(fx).round() as u16;
-    let y = layout_to_screen_space(fy).round() as u16;
+    let x = layout_to_screen_space(fx).round() as i32;
+    let y = layout_to_screen_space(fy).round() as i32;
     let Size { width, height } = *size;
-    let width = layout_to_screen_space(fx + width).round() as u16 - x;
-    let height = layout_to_screen_space(fy + height).round() as u16 - y;
+    let width = (layout_to_screen_space(fx + width).round() as i32 - x) as u16;
+    let height = (layout_to_screen_space(fy + height).round() as i32 - y) as u16;
+
+    let offset = [(-x).max(0) as u16, (-y).max(0) as u16];
+    let area = Rect::new(
+        (x + offset[0] as i32) as u16,
+        (y + offset[1] as i32) as u16,
+        width,
+        height,
+    );
+    let visible = visible_part(clip, x, y, width, height);
 
     match &node.node_data.node_type {
         NodeType::Text { text } => {
@@ -54,11 +109,18 @@ pub(crate) fn render_vnode(
 
             impl<'a> RinkWidget for Label<'a> {
                 fn render(self, area: Rect, mut buf: RinkBuffer) {
-                    for (i, c) in self.text.char_indices() {
+                    // wide characters take two cells, like the layout measures them
+                    let mut x = area.left();
+                    for c in self.text.chars() {
+                        let width = match c.width() {
+                            Some(width) if width > 0 => width as u16,
+                            _ => continue,
+                        };
                         let mut new_cell = RinkCell::default();
                         new_cell.set_style(self.style);
                         new_cell.symbol = c.to_string();
-                        buf.set(area.left() + i as u16, area.top(), new_cell);
+                        buf.set(x, area.top(), new_cell);
+                        x += width;
                     }
                 }
             }
@@ -67,29 +129,192 @@ pub(crate) fn render_vnode(
                 text,
                 style: node.state.style.core,
             };
-            let area = Rect::new(x, y, width, height);
 
             // the renderer will panic if a node is rendered out of range even if the size is zero
-            if area.width > 0 && area.height > 0 {
-                frame.render_widget(WidgetWithContext::new(label, cfg), area);
+            if visible.area() > 0 {
+                frame.render_widget(
+                    WidgetWithContext::new(label, state.cfg).with_clip(clip, offset),
+                    area,
+                );
             }
         }
-        NodeType::Element { .. } => {
-            let area = Rect::new(x, y, width, height);
-
+        NodeType::Element { tag, .. } => {
             // the renderer will panic if a node is rendered out of range even if the size is zero
-            if area.width > 0 && area.height > 0 {
-                frame.render_widget(WidgetWithContext::new(node, cfg), area);
+            if visible.area() > 0 {
+                frame.render_widget(
+                    WidgetWithContext::new(node, state.cfg).with_clip(clip, offset),
+                    area,
+                );
+            }
+
+            if tag == "img" && visible.area() > 0 {
+                let src = attribute(node, "src");
+                let drawable = state.cfg.image_protocol() != ImageProtocol::None
+                    && src
+                        .and_then(|src| state.image_cache.load(src, Some(node.node_data.node_id)))
+                        .is_some();
+                match src {
+                    Some(src) if drawable => state.images.push(ImagePlacement {
+                        src: src.to_string(),
+                        x,
+                        y,
+                        width,
+                        height,
+                        visible,
+                    }),
+                    _ => {
+                        // without a file name, the placeholder still shows there is an image
+                        let name = src.and_then(|src| src.rsplit('/').next());
+                        let placeholder = ImagePlaceholder {
+                            alt: attribute(node, "alt").or(name).unwrap_or("image"),
+                        };
+                        frame.render_widget(
+                            WidgetWithContext::new(placeholder, state.cfg).with_clip(clip, offset),
+                            area,
+                        );
+                    }
+                }
+            }
+
+            // the content of a scroll container is moved up by how far it is scrolled, and cut to the inside of its
+            // border
+            let mut child_location = location;
+            let mut child_clip = clip;
+            let mut scrollbar = None;
+            if node.state.layout.scroll {
+                let [top, right, bottom, left] = border_widths(node);
+                let inner_width = width.saturating_sub(left + right);
+                let inner_height = height.saturating_sub(top + bottom);
+                child_clip = visible_part(
+                    clip,
+                    x + left as i32,
+                    y + top as i32,
+                    inner_width,
+                    inner_height,
+                );
+
+                let max_scroll = max_scroll(node, state.rdom, state.layout);
+                let scroll_top = node.state.scroll_top.min(max_scroll);
+                child_location.y -= screen_to_layout_space(scroll_top);
+
+                if max_scroll > 0 && inner_width > 0 {
+                    let scrollbar_x = x + left as i32 + inner_width as i32 - 1;
+                    scrollbar = Some((
+                        Scrollbar {
+                            scroll_top,
+                            max_scroll,
+                            color: node.state.style.core.fg,
+                        },
+                        visible_part(clip, scrollbar_x, y + top as i32, 1, inner_height),
+                    ));
+                }
+            }
+
+            for c in state.rdom.children_ids(node.node_data.node_id).unwrap() {
+                let child = &state.rdom[*c];
+                if child.state.layout.style.position == Position::Absolute {
+                    state.overlays.push((child, child_location));
+                } else {
+                    render_node(frame, state, child, child_location, child_clip);
+                }
             }
 
-            for c in rdom.children_ids(node.node_data.node_id).unwrap() {
-                render_vnode(frame, layout, rdom, &rdom[*c], cfg, location);
+            if let Some((scrollbar, area)) = scrollbar {
+                if area.area() > 0 {
+                    frame.render_widget(WidgetWithContext::new(scrollbar, state.cfg), area);
+                }
             }
         }
         NodeType::Placeholder => unreachable!(),
     }
 }
 
+/// The part of a box, that can start above or left of the screen, inside `clip`
+fn visible_part(clip: Rect, x: i32, y: i32, width: u16, height: u16) -> Rect {
+    let left = x.max(clip.left() as i32);
+    let top = y.max(clip.top() as i32);
+    let right = (x + width as i32).min(clip.right() as i32);
+    let bottom = (y + height as i32).min(clip.bottom() as i32);
+    if right <= left || bottom <= top {
+        return Rect::default();
+    }
+    Rect::new(
+        left as u16,
+        top as u16,
+        (right - left) as u16,
+        (bottom - top) as u16,
+    )
+}
+
+fn attribute<'a>(node: &'a TuiNode, name: &str) -> Option<&'a str> {
+    match &node.node_data.node_type {
+        NodeType::Element { attributes, .. } => attributes
+            .iter()
+            .find(|(attribute, _)| attribute.name == name)
+            .and_then(|(_, value)| value.as_text()),
+        _ => None,
+    }
+}
+
+/// Stands in for images the terminal can't draw, or that couldn't be loaded
+struct ImagePlaceholder<'a> {
+    alt: &'a str,
+}
+
+impl RinkWidget for ImagePlaceholder<'_> {
+    fn render(self, area: Rect, mut buf: RinkBuffer) {
+        let color = RinkColor {
+            color: Color::DarkGray,
+            alpha: 255,
+        };
+        let alt: Vec<_> = self.alt.chars().collect();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let mut cell = RinkCell::default();
+                cell.fg = color;
+                let i = (x - area.left()) as usize;
+                cell.symbol = match alt.get(i) {
+                    Some(c) if y == area.top() => c.to_string(),
+                    _ => "░".to_string(),
+                };
+                buf.set(x, y, cell);
+            }
+        }
+    }
+}
+
+/// Shows how far a scroll container is scrolled, with a thumb the size of the visible part of its content
+struct Scrollbar {
+    scroll_top: u16,
+    max_scroll: u16,
+    color: Option<RinkColor>,
+}
+
+impl RinkWidget for Scrollbar {
+    fn render(self, area: Rect, mut buf: RinkBuffer) {
+        // the area can be cut by the screen, but the thumb is sized for the whole track
+        let track = area.height as u32;
+        let content = track + self.max_scroll as u32;
+        let thumb = (track * track / content).max(1);
+        let thumb_top = (track - thumb) * self.scroll_top as u32 / self.max_scroll as u32;
+
+        for row in 0..track {
+            let mut cell = RinkCell::default();
+            cell.fg = self.color.unwrap_or(RinkColor {
+                color: Color::Gray,
+                alpha: 255,
+            });
+            cell.symbol = if (thumb_top..thumb_top + thumb).contains(&row) {
+                "█"
+            } else {
+                "│"
+            }
+            .to_string();
+            buf.set(area.x, area.y + row as u16, cell);
+        }
+    }
+}
+
 impl RinkWidget for &TuiNode {
     fn render(self, area: Rect, mut buf: RinkBuffer<'_>) {
         use tui::symbols::line::*;
@@ -420,3 +645,13 @@ impl RinkWidget for &TuiNode {
         }
     }
 }
+
+#[test]
+fn boxes_are_cut_to_the_clip() {
+    let clip = Rect::new(2, 2, 10, 10);
+
+    assert_eq!(visible_part(clip, 4, 4, 2, 2), Rect::new(4, 4, 2, 2));
+    assert_eq!(visible_part(clip, -5, 0, 10, 5), Rect::new(2, 2, 3, 3));
+    assert_eq!(visible_part(clip, 10, 10, 5, 5), Rect::new(10, 10, 2, 2));
+    assert_eq!(visible_part(clip, 20, 0, 5, 5).area(), 0);
+}
@@ -0,0 +1,157 @@
+//! Elements with `overflow` or `overflow-y` set to `scroll` or `auto` only show the part of their content that fits
+//! in them. The mouse wheel scrolls the innermost one under the mouse, and page up and page down scroll the one around
+//! the focused element.
+
+use dioxus_html::geometry::ScreenPoint;
+use dioxus_native_core::{tree::TreeView, NodeId};
+use taffy::{prelude::LengthPercentage, Taffy};
+
+use crate::hooks::get_abs_layout;
+use crate::{layout_to_screen_space, TuiDom, TuiNode};
+
+/// Check if a style declaration makes the content of an element scroll
+pub(crate) fn overflow_scrolls(name: &str, value: &str) -> bool {
+    matches!(name, "overflow" | "overflow-y") && matches!(value.trim(), "scroll" | "auto")
+}
+
+/// How many cells the content of a scroll container can scroll down
+pub(crate) fn max_scroll(node: &TuiNode, rdom: &TuiDom, taffy: &Taffy) -> u16 {
+    let layout = taffy.layout(node.state.layout.node.unwrap()).unwrap();
+    let mut bottom: f32 = 0.0;
+    for child in rdom.children_ids(node.node_data.node_id).unwrap_or(&[]) {
+        let child_layout = taffy
+            .layout(rdom[*child].state.layout.node.unwrap())
+            .unwrap();
+        bottom = bottom.max(child_layout.location.y + child_layout.size.height);
+    }
+    let style = &node.state.layout.style;
+    bottom += points(style.padding.bottom) + points(style.border.bottom);
+    layout_to_screen_space(bottom - layout.size.height)
+        .round()
+        .max(0.0) as u16
+}
+
+/// The width of the border on each side of an element in cells, as `[top, right, bottom, left]`
+pub(crate) fn border_widths(node: &TuiNode) -> [u16; 4] {
+    let border = &node.state.layout.style.border;
+    [border.top, border.right, border.bottom, border.left]
+        .map(|side| layout_to_screen_space(points(side)).round() as u16)
+}
+
+fn points(length: LengthPercentage) -> f32 {
+    match length {
+        LengthPercentage::Points(points) => points,
+        _ => 0.0,
+    }
+}
+
+/// The scroll containers under a point, from the outermost to the innermost
+pub(crate) fn containers_at(rdom: &TuiDom, taffy: &Taffy, point: ScreenPoint) -> Vec<NodeId> {
+    let mut containers = Vec::new();
+    rdom.traverse_depth_first(|node| {
+        if node.state.layout.scroll {
+            let layout = get_abs_layout(node, rdom, taffy);
+            let x = layout_to_screen_space(layout.location.x).round();
+            let y = layout_to_screen_space(layout.location.y).round();
+            let width = layout_to_screen_space(layout.size.width).round();
+            let height = layout_to_screen_space(layout.size.height).round();
+            if (x..x + width).contains(&(point.x as f32))
+                && (y..y + height).contains(&(point.y as f32))
+            {
+                containers.push(node.node_data.node_id);
+            }
+        }
+    });
+    // containers are visited before their children
+    containers
+}
+
+#[derive(Default)]
+pub(crate) struct ScrollState {
+    pub(crate) dirty: bool,
+}
+
+impl ScrollState {
+    /// Scroll the innermost scroll container under the point that can still scroll that way, by a number of lines.
+    pub fn scroll_at(
+        &mut self,
+        rdom: &mut TuiDom,
+        taffy: &Taffy,
+        point: ScreenPoint,
+        lines: i32,
+    ) -> bool {
+        containers_at(rdom, taffy, point)
+            .into_iter()
+            .rev()
+            .any(|id| self.scroll(rdom, taffy, id, lines))
+    }
+
+    /// Scroll the innermost scroll container around a node that can still scroll that way, by a page.
+    pub fn scroll_page_around(
+        &mut self,
+        rdom: &mut TuiDom,
+        taffy: &Taffy,
+        id: NodeId,
+        down: bool,
+    ) -> bool {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            let node = &rdom[id];
+            if node.state.layout.scroll {
+                let layout = taffy.layout(node.state.layout.node.unwrap()).unwrap();
+                let [top, _, bottom, _] = border_widths(node);
+                let height = layout_to_screen_space(layout.size.height).round() as u16;
+                // keep a line of the last page in view
+                let page = height.saturating_sub(top + bottom + 1).max(1) as i32;
+                if self.scroll(rdom, taffy, id, if down { page } else { -page }) {
+                    return true;
+                }
+            }
+            current = rdom.parent_id(id);
+        }
+        false
+    }
+
+    /// Keep the scroll containers in the bounds of their content, after it changed size
+    pub fn clamp(&mut self, rdom: &mut TuiDom, taffy: &Taffy) {
+        let mut scrolled = Vec::new();
+        rdom.traverse_depth_first(|node| {
+            if node.state.scroll_top > 0 {
+                scrolled.push(node.node_data.node_id);
+            }
+        });
+        for id in scrolled {
+            self.scroll(rdom, taffy, id, 0);
+        }
+    }
+
+    fn scroll(&mut self, rdom: &mut TuiDom, taffy: &Taffy, id: NodeId, lines: i32) -> bool {
+        let max = if rdom[id].state.layout.scroll {
+            max_scroll(&rdom[id], rdom, taffy)
+        } else {
+            0
+        };
+        let state = &mut rdom[id].state;
+        let scroll_top = (state.scroll_top as i32 + lines).clamp(0, max as i32) as u16;
+        if scroll_top == state.scroll_top {
+            return false;
+        }
+        state.scroll_top = scroll_top;
+        self.dirty = true;
+        true
+    }
+
+    pub(crate) fn clean(&mut self) -> bool {
+        let old = self.dirty;
+        self.dirty = false;
+        old
+    }
+}
+
+#[test]
+fn overflow_scroll_and_auto_scroll() {
+    assert!(overflow_scrolls("overflow", "scroll"));
+    assert!(overflow_scrolls("overflow-y", " auto "));
+    assert!(!overflow_scrolls("overflow", "hidden"));
+    assert!(!overflow_scrolls("overflow-x", "scroll"));
+}
@@ -342,8 +342,7 @@ pub fn convert(mode: RenderingMode, c: Color) -> Color {
     } else {
         match mode {
             crate::RenderingMode::BaseColors => match c {
-                Color::Rgb(_, _, _) => panic!("cannot convert rgb color to base color"),
-                Color::Indexed(_) => panic!("cannot convert Ansi color to base color"),
+                Color::Rgb(_, _, _) | Color::Indexed(_) => nearest_base_color(to_rgb(c)),
                 _ => c,
             },
             crate::RenderingMode::Rgb => {
@@ -372,6 +371,59 @@ pub fn convert(mode: RenderingMode, c: Color) -> Color {
     }
 }
 
+/// The named color closest to an rgb color
+fn nearest_base_color(rgb: [u8; 3]) -> Color {
+    const BASE_COLORS: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    let distance = |color: Color| {
+        to_rgb(color)
+            .iter()
+            .zip(rgb)
+            .map(|(a, b)| (*a as i32 - b as i32).pow(2))
+            .sum::<i32>()
+    };
+    BASE_COLORS
+        .into_iter()
+        .min_by_key(|color| distance(*color))
+        .unwrap()
+}
+
+#[test]
+fn rgb_to_base_colors() {
+    assert_eq!(
+        convert(RenderingMode::BaseColors, Color::Rgb(250, 5, 5)),
+        Color::Red
+    );
+    assert_eq!(
+        convert(RenderingMode::BaseColors, Color::Rgb(10, 10, 10)),
+        Color::Black
+    );
+    assert_eq!(
+        convert(RenderingMode::BaseColors, Color::Indexed(231)),
+        Color::White
+    );
+    assert_eq!(
+        convert(RenderingMode::BaseColors, Color::LightBlue),
+        Color::LightBlue
+    );
+}
+
 #[test]
 fn rgb_to_ansi() {
     for idx in 17..=231 {
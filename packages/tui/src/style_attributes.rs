@@ -29,6 +29,8 @@
 - [ ] pub aspect_ratio: Number,
 */
 
+use std::borrow::Cow;
+
 use dioxus_native_core::{
     layout_attributes::parse_value,
     node::OwnedAttributeView,
@@ -65,6 +67,7 @@ impl ParentDepState for StyleModifier {
                 match tag {
                     "b" => apply_style_attributes("font-weight", "bold", &mut new),
                     "strong" => apply_style_attributes("font-weight", "bold", &mut new),
+                    "th" => apply_style_attributes("font-weight", "bold", &mut new),
                     "u" => apply_style_attributes("text-decoration", "underline", &mut new),
                     "ins" => apply_style_attributes("text-decoration", "underline", &mut new),
                     "del" => apply_style_attributes("text-decoration", "line-through", &mut new),
@@ -85,7 +88,13 @@ impl ParentDepState for StyleModifier {
             } in attrs
             {
                 if let Some(text) = value.as_text() {
-                    apply_style_attributes(&attribute.name, text, &mut new);
+                    if attribute.name == "style" {
+                        for (name, value) in style_declarations(text) {
+                            apply_style_attributes(name, value, &mut new);
+                        }
+                    } else {
+                        apply_style_attributes(&attribute.name, text, &mut new);
+                    }
                 }
             }
         }
@@ -158,6 +167,10 @@ pub enum BorderStyle {
     Ridge,
     Inset,
     Outset,
+    /// A solid line with rounded corners, which isn't a css border style
+    Rounded,
+    /// A solid line drawn with heavy box-drawing characters, which isn't a css border style
+    Thick,
     Hidden,
     None,
 }
@@ -184,12 +197,22 @@ impl BorderStyle {
             BorderStyle::Ridge => Some(NORMAL),
             BorderStyle::Inset => Some(NORMAL),
             BorderStyle::Outset => Some(NORMAL),
+            BorderStyle::Rounded => Some(ROUNDED),
+            BorderStyle::Thick => Some(THICK),
             BorderStyle::Hidden => None,
             BorderStyle::None => None,
         }
     }
 }
 
+/// Split the value of a `style` attribute into `(property, value)` pairs
+pub(crate) fn style_declarations(style: &str) -> impl Iterator<Item = (&str, &str)> {
+    style.split(';').filter_map(|declaration| {
+        let (name, value) = declaration.split_once(':')?;
+        Some((name.trim(), value.trim()))
+    })
+}
+
 /// applies the entire html namespace defined in dioxus-html
 pub fn apply_style_attributes(
     //
@@ -347,64 +370,150 @@ fn apply_background(name: &str, value: &str, style: &mut StyleModifier) {
     }
 }
 
-fn apply_border(name: &str, value: &str, style: &mut StyleModifier) {
-    fn parse_border_style(v: &str) -> BorderStyle {
-        match v {
-            "dotted" => BorderStyle::Dotted,
-            "dashed" => BorderStyle::Dashed,
-            "solid" => BorderStyle::Solid,
-            "double" => BorderStyle::Double,
-            "groove" => BorderStyle::Groove,
-            "ridge" => BorderStyle::Ridge,
-            "inset" => BorderStyle::Inset,
-            "outset" => BorderStyle::Outset,
-            "none" => BorderStyle::None,
-            "hidden" => BorderStyle::Hidden,
-            _ => todo!(),
+fn parse_border_style(v: &str) -> Option<BorderStyle> {
+    Some(match v {
+        "dotted" => BorderStyle::Dotted,
+        "dashed" => BorderStyle::Dashed,
+        "solid" => BorderStyle::Solid,
+        "double" => BorderStyle::Double,
+        "groove" => BorderStyle::Groove,
+        "ridge" => BorderStyle::Ridge,
+        "inset" => BorderStyle::Inset,
+        "outset" => BorderStyle::Outset,
+        "rounded" => BorderStyle::Rounded,
+        "thick" => BorderStyle::Thick,
+        "none" => BorderStyle::None,
+        "hidden" => BorderStyle::Hidden,
+        _ => return None,
+    })
+}
+
+/// Split a list of values on whitespace, keeping functions like `rgb(1, 2, 3)` together
+fn split_values(value: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut depth = 0;
+    let mut start = None;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    values.push(&value[start..i]);
+                }
+                continue;
+            }
+            _ => (),
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(start) = start {
+        values.push(&value[start..]);
+    }
+    values
+}
+
+/// Expand one to four values to the `[top, right, bottom, left]` sides they stand for, like css does
+fn sides<T: Copy>(values: &[T]) -> Option<[T; 4]> {
+    match *values {
+        [all] => Some([all; 4]),
+        [vertical, horizontal] => Some([vertical, horizontal, vertical, horizontal]),
+        [top, horizontal, bottom] => Some([top, horizontal, bottom, horizontal]),
+        [top, right, bottom, left] => Some([top, right, bottom, left]),
+        _ => None,
+    }
+}
+
+/// Replace the line styles css doesn't know with `solid`, so the layout still gives the border its width
+pub(crate) fn css_border_value<'a>(name: &str, value: &'a str) -> Cow<'a, str> {
+    let tui_only: &[&str] = match name {
+        "border-style"
+        | "border-top-style"
+        | "border-right-style"
+        | "border-bottom-style"
+        | "border-left-style" => &["rounded", "thick"],
+        // thick is a width in the shorthands
+        "border" | "border-top" | "border-right" | "border-bottom" | "border-left" => &["rounded"],
+        _ => return Cow::Borrowed(value),
+    };
+    let values = split_values(value);
+    if !values.iter().any(|v| tui_only.contains(v)) {
+        return Cow::Borrowed(value);
+    }
+    let values: Vec<_> = values
+        .into_iter()
+        .map(|v| if tui_only.contains(&v) { "solid" } else { v })
+        .collect();
+    Cow::Owned(values.join(" "))
+}
+
+/// Apply a shorthand like `1px solid red` to some edges. The `thin`, `medium` and `thick` keywords are widths here, as
+/// in css, so the thick line style can only be set with the `border-style` properties.
+fn apply_border_shorthand<'a>(value: &str, edges: impl IntoIterator<Item = &'a mut BorderEdge>) {
+    // every border width keyword is a single cell wide
+    let mut edge = BorderEdge {
+        width: Dimension::Points(1.0),
+        ..Default::default()
+    };
+    for v in split_values(value) {
+        if matches!(v, "thin" | "medium" | "thick") {
+            edge.width = Dimension::Points(1.0);
+        } else if let Some(border_style) = parse_border_style(v) {
+            edge.style = border_style;
+        } else if let Some(width) = parse_value(v) {
+            edge.width = width;
+        } else if let Ok(c) = v.parse() {
+            edge.color = Some(c);
         }
     }
+    // the shorthand resets everything but the radius
+    for e in edges {
+        *e = BorderEdge {
+            radius: e.radius,
+            ..edge.clone()
+        };
+    }
+}
+
+fn apply_border(name: &str, value: &str, style: &mut StyleModifier) {
+    let borders = &mut style.modifier.borders;
     match name {
-        "border" => {}
-        "border-bottom" => {}
+        "border" => apply_border_shorthand(value, borders.slice()),
+        "border-bottom" => apply_border_shorthand(value, [&mut borders.bottom]),
         "border-bottom-color" => {
             if let Ok(c) = value.parse() {
-                style.modifier.borders.bottom.color = Some(c);
+                borders.bottom.color = Some(c);
             }
         }
         "border-bottom-left-radius" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.left.radius = v;
+                borders.left.radius = v;
             }
         }
         "border-bottom-right-radius" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.right.radius = v;
+                borders.right.radius = v;
+            }
+        }
+        "border-bottom-style" => {
+            if let Some(border_style) = parse_border_style(value) {
+                borders.bottom.style = border_style;
             }
         }
-        "border-bottom-style" => style.modifier.borders.bottom.style = parse_border_style(value),
         "border-bottom-width" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.bottom.width = v;
+                borders.bottom.width = v;
             }
         }
         "border-collapse" => {}
         "border-color" => {
-            let values: Vec<_> = value.split(' ').collect();
-            if values.len() == 1 {
-                if let Ok(c) = values[0].parse() {
-                    style
-                        .modifier
-                        .borders
-                        .slice()
-                        .iter_mut()
-                        .for_each(|b| b.color = Some(c));
-                }
-            } else {
-                for (v, b) in values
-                    .into_iter()
-                    .zip(style.modifier.borders.slice().iter_mut())
-                {
-                    if let Ok(c) = v.parse() {
+            let colors: Vec<_> = split_values(value)
+                .into_iter()
+                .map(|v| v.parse().ok())
+                .collect();
+            if let Some(colors) = sides(&colors) {
+                for (b, c) in borders.slice().into_iter().zip(colors) {
+                    if let Some(c) = c {
                         b.color = Some(c);
                     }
                 }
@@ -416,112 +525,98 @@ fn apply_border(name: &str, value: &str, style: &mut StyleModifier) {
         "border-image-slice" => {}
         "border-image-source" => {}
         "border-image-width" => {}
-        "border-left" => {}
+        "border-left" => apply_border_shorthand(value, [&mut borders.left]),
         "border-left-color" => {
             if let Ok(c) = value.parse() {
-                style.modifier.borders.left.color = Some(c);
+                borders.left.color = Some(c);
+            }
+        }
+        "border-left-style" => {
+            if let Some(border_style) = parse_border_style(value) {
+                borders.left.style = border_style;
             }
         }
-        "border-left-style" => style.modifier.borders.left.style = parse_border_style(value),
         "border-left-width" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.left.width = v;
+                borders.left.width = v;
             }
         }
         "border-radius" => {
             let values: Vec<_> = value.split(' ').collect();
             if values.len() == 1 {
                 if let Some(r) = parse_value(values[0]) {
-                    style
-                        .modifier
-                        .borders
-                        .slice()
-                        .iter_mut()
-                        .for_each(|b| b.radius = r);
+                    borders.slice().iter_mut().for_each(|b| b.radius = r);
                 }
             } else {
-                for (v, b) in values
-                    .into_iter()
-                    .zip(style.modifier.borders.slice().iter_mut())
-                {
+                for (v, b) in values.into_iter().zip(borders.slice().iter_mut()) {
                     if let Some(r) = parse_value(v) {
                         b.radius = r;
                     }
                 }
             }
         }
-        "border-right" => {}
+        "border-right" => apply_border_shorthand(value, [&mut borders.right]),
         "border-right-color" => {
             if let Ok(c) = value.parse() {
-                style.modifier.borders.right.color = Some(c);
+                borders.right.color = Some(c);
+            }
+        }
+        "border-right-style" => {
+            if let Some(border_style) = parse_border_style(value) {
+                borders.right.style = border_style;
             }
         }
-        "border-right-style" => style.modifier.borders.right.style = parse_border_style(value),
         "border-right-width" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.right.width = v;
+                borders.right.width = v;
             }
         }
         "border-spacing" => {}
         "border-style" => {
-            let values: Vec<_> = value.split(' ').collect();
-            if values.len() == 1 {
-                let border_style = parse_border_style(values[0]);
-                style
-                    .modifier
-                    .borders
-                    .slice()
-                    .iter_mut()
-                    .for_each(|b| b.style = border_style);
-            } else {
-                for (v, b) in values
-                    .into_iter()
-                    .zip(style.modifier.borders.slice().iter_mut())
-                {
-                    b.style = parse_border_style(v);
+            let styles: Vec<_> = split_values(value)
+                .into_iter()
+                .map(parse_border_style)
+                .collect();
+            if let Some(styles) = sides(&styles) {
+                for (b, border_style) in borders.slice().into_iter().zip(styles) {
+                    if let Some(border_style) = border_style {
+                        b.style = border_style;
+                    }
                 }
             }
         }
-        "border-top" => {}
+        "border-top" => apply_border_shorthand(value, [&mut borders.top]),
         "border-top-color" => {
             if let Ok(c) = value.parse() {
-                style.modifier.borders.top.color = Some(c);
+                borders.top.color = Some(c);
             }
         }
         "border-top-left-radius" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.left.radius = v;
+                borders.left.radius = v;
             }
         }
         "border-top-right-radius" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.right.radius = v;
+                borders.right.radius = v;
+            }
+        }
+        "border-top-style" => {
+            if let Some(border_style) = parse_border_style(value) {
+                borders.top.style = border_style;
             }
         }
-        "border-top-style" => style.modifier.borders.top.style = parse_border_style(value),
         "border-top-width" => {
             if let Some(v) = parse_value(value) {
-                style.modifier.borders.top.width = v;
+                borders.top.width = v;
             }
         }
         "border-width" => {
-            let values: Vec<_> = value.split(' ').collect();
-            if values.len() == 1 {
-                if let Some(w) = parse_value(values[0]) {
-                    style
-                        .modifier
-                        .borders
-                        .slice()
-                        .iter_mut()
-                        .for_each(|b| b.width = w);
-                }
-            } else {
-                for (v, width) in values
-                    .into_iter()
-                    .zip(style.modifier.borders.slice().iter_mut())
-                {
-                    if let Some(w) = parse_value(v) {
-                        width.width = w;
+            let widths: Vec<_> = split_values(value).into_iter().map(parse_value).collect();
+            if let Some(widths) = sides(&widths) {
+                for (b, width) in borders.slice().into_iter().zip(widths) {
+                    if let Some(width) = width {
+                        b.width = width;
                     }
                 }
             }
@@ -799,5 +894,61 @@ const SORTED_STYLE_ATTRS: &[&str] = &sorted_str_slice!([
     "text-justify",
     "text-overflow",
     "text-shadow",
-    "text-transform"
+    "text-transform",
+    "style"
 ]);
+
+#[test]
+fn border_shorthands_set_each_side() {
+    let mut style = StyleModifier::default();
+    apply_style_attributes("border", "1px rounded rgb(255, 0, 0)", &mut style);
+    apply_style_attributes("border-bottom-style", "double", &mut style);
+    apply_style_attributes("border-left", "thick dashed", &mut style);
+
+    let borders = &style.modifier.borders;
+    assert_eq!(borders.top.style, BorderStyle::Rounded);
+    assert_eq!(
+        borders.right.color.map(|c| c.color),
+        Some(tui::style::Color::Rgb(255, 0, 0))
+    );
+    assert_eq!(borders.bottom.style, BorderStyle::Double);
+    assert_eq!(borders.left.style, BorderStyle::Dashed);
+    assert_eq!(borders.left.color, None);
+}
+
+#[test]
+fn border_lists_expand_to_four_sides() {
+    let mut style = StyleModifier::default();
+    apply_style_attributes("border-style", "solid thick", &mut style);
+    let borders = &style.modifier.borders;
+    assert_eq!(
+        [
+            borders.top.style,
+            borders.right.style,
+            borders.bottom.style,
+            borders.left.style
+        ],
+        [
+            BorderStyle::Solid,
+            BorderStyle::Thick,
+            BorderStyle::Solid,
+            BorderStyle::Thick
+        ]
+    );
+
+    assert_eq!(sides(&[1, 2, 3]), Some([1, 2, 3, 2]));
+    assert_eq!(sides::<u8>(&[]), None);
+}
+
+#[test]
+fn terminal_line_styles_are_solid_for_the_layout() {
+    assert_eq!(
+        css_border_value("border-style", "rounded thick"),
+        "solid solid"
+    );
+    assert_eq!(
+        css_border_value("border", "thick rounded red"),
+        "thick solid red"
+    );
+    assert_eq!(css_border_value("border-width", "thick"), "thick");
+}
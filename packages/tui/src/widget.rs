@@ -13,30 +13,47 @@ use crate::{
 pub struct RinkBuffer<'a> {
     buf: &'a mut Buffer,
     cfg: Config,
+    clip: Option<Rect>,
+    offset: [u16; 2],
 }
 
 impl<'a> RinkBuffer<'a> {
-    fn new(buf: &'a mut Buffer, cfg: Config) -> RinkBuffer<'a> {
-        Self { buf, cfg }
+    fn new(
+        buf: &'a mut Buffer,
+        cfg: Config,
+        clip: Option<Rect>,
+        offset: [u16; 2],
+    ) -> RinkBuffer<'a> {
+        Self {
+            buf,
+            cfg,
+            clip,
+            offset,
+        }
     }
 
     pub fn set(&mut self, x: u16, y: u16, new: RinkCell) {
-        let area = self.buf.area();
-        if x < area.x || x >= area.width + area.x || y < area.y || y >= area.height + area.y {
-            // panic!("({x}, {y}) is not in {area:?}");
-            return;
+        let (x, y) = match (x.checked_sub(self.offset[0]), y.checked_sub(self.offset[1])) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return,
+        };
+        for area in [Some(*self.buf.area()), self.clip].into_iter().flatten() {
+            if x < area.x || x >= area.width + area.x || y < area.y || y >= area.height + area.y {
+                // panic!("({x}, {y}) is not in {area:?}");
+                return;
+            }
         }
         let mut cell = self.buf.get_mut(x, y);
-        cell.bg = convert(self.cfg.rendering_mode, new.bg.blend(cell.bg));
+        cell.bg = convert(self.cfg.rendering_mode(), new.bg.blend(cell.bg));
         if new.symbol.is_empty() {
             if !cell.symbol.is_empty() {
                 // allows text to "shine through" transparent backgrounds
-                cell.fg = convert(self.cfg.rendering_mode, new.bg.blend(cell.fg));
+                cell.fg = convert(self.cfg.rendering_mode(), new.bg.blend(cell.fg));
             }
         } else {
             cell.modifier = new.modifier;
             cell.symbol = new.symbol;
-            cell.fg = convert(self.cfg.rendering_mode, new.fg.blend(cell.bg));
+            cell.fg = convert(self.cfg.rendering_mode(), new.fg.blend(cell.bg));
         }
     }
 }
@@ -48,17 +65,37 @@ pub trait RinkWidget {
 pub struct WidgetWithContext<T: RinkWidget> {
     widget: T,
     config: Config,
+    clip: Option<Rect>,
+    offset: [u16; 2],
 }
 
 impl<T: RinkWidget> WidgetWithContext<T> {
     pub fn new(widget: T, config: Config) -> WidgetWithContext<T> {
-        WidgetWithContext { widget, config }
+        WidgetWithContext {
+            widget,
+            config,
+            clip: None,
+            offset: [0, 0],
+        }
+    }
+
+    /// Only draw the cells of the widget inside `clip`.
+    ///
+    /// The area the widget is rendered in is shifted right and down by `offset` and shifted back when drawing, so a
+    /// widget that starts above or left of the screen can still draw the part of it on the screen.
+    pub fn with_clip(mut self, clip: Rect, offset: [u16; 2]) -> Self {
+        self.clip = Some(clip);
+        self.offset = offset;
+        self
     }
 }
 
 impl<T: RinkWidget> Widget for WidgetWithContext<T> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.widget.render(area, RinkBuffer::new(buf, self.config));
+        self.widget.render(
+            area,
+            RinkBuffer::new(buf, self.config, self.clip, self.offset),
+        );
     }
 }
 
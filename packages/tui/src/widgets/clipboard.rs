@@ -0,0 +1,71 @@
+use dioxus_html::input_data::keyboard_types::{Code, Modifiers};
+use dioxus_html::KeyboardData;
+use dioxus_native_core::utils::cursor::{Cursor, Text, TextEditable};
+
+/// The keys that copy, cut and paste in the text inputs
+///
+/// Most terminals keep ctrl+shift+c and ctrl+shift+v for themselves, so the insert and delete shortcuts are supported
+/// too. Ctrl+c only quits the app when nothing is selected, and text pasted through the terminal arrives as a `paste`
+/// event instead of a shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClipboardShortcut {
+    Copy,
+    Cut,
+    Paste,
+}
+
+impl ClipboardShortcut {
+    pub(crate) fn from_key(k: &KeyboardData) -> Option<Self> {
+        let modifiers = k.modifiers();
+        let ctrl = modifiers.contains(Modifiers::CONTROL);
+        let shift = modifiers.contains(Modifiers::SHIFT);
+        match k.code() {
+            Code::KeyC if ctrl => Some(ClipboardShortcut::Copy),
+            Code::KeyX if ctrl => Some(ClipboardShortcut::Cut),
+            Code::KeyV if ctrl => Some(ClipboardShortcut::Paste),
+            Code::Insert if ctrl => Some(ClipboardShortcut::Copy),
+            Code::Insert if shift => Some(ClipboardShortcut::Paste),
+            Code::Delete if shift => Some(ClipboardShortcut::Cut),
+            _ => None,
+        }
+    }
+}
+
+/// The selected part of the text
+pub(crate) fn selected_text(cursor: &Cursor, text: &str) -> String {
+    let first = cursor.first().idx(text);
+    let last = cursor.last().idx(text);
+    text.chars().skip(first).take(last - first).collect()
+}
+
+/// Replace the selection with pasted text, as much of it as fits in `max_len` characters. Inputs are a single line, so
+/// line breaks are dropped like browsers do.
+pub(crate) fn paste(cursor: &mut Cursor, text: &mut String, pasted: &str, max_len: usize) {
+    cursor.start.realize_col(text.as_str());
+    cursor.delete_selection(text);
+    let room = max_len.saturating_sub(text.as_str().length());
+    for c in pasted
+        .chars()
+        .filter(|c| !matches!(c, '\n' | '\r'))
+        .take(room)
+    {
+        text.insert_character(cursor.start.idx(text.as_str()), c);
+        cursor.start.right(text.as_str());
+    }
+}
+
+#[test]
+fn paste_replaces_the_selection() {
+    use dioxus_native_core::utils::cursor::Pos;
+
+    let mut text = "hello world".to_string();
+    let mut cursor = Cursor::new(Pos::new(6, 0), Pos::new(11, 0));
+    assert_eq!(selected_text(&cursor, &text), "world");
+
+    paste(&mut cursor, &mut text, "there\n", usize::MAX);
+    assert_eq!(text, "hello there");
+    assert_eq!(cursor.start, Pos::new(11, 0));
+
+    paste(&mut cursor, &mut text, "!!!", 13);
+    assert_eq!(text, "hello there!!");
+}
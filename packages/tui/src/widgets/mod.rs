@@ -1,13 +1,18 @@
 mod button;
 mod checkbox;
+mod clipboard;
 mod input;
 mod number;
 mod password;
+mod select;
 mod slider;
+mod table;
 mod textbox;
 
 use dioxus_core::{ElementId, RenderReturn, Scope};
 pub use input::*;
+pub use select::*;
+pub use table::*;
 
 pub(crate) fn get_root_id<T>(cx: Scope<T>) -> Option<ElementId> {
     if let RenderReturn::Ready(sync) = cx.root_node() {
@@ -1,3 +1,4 @@
+use crate::widgets::clipboard::{paste, ClipboardShortcut};
 use crate::widgets::get_root_id;
 use crate::Query;
 use crossterm::{cursor::*, execute};
@@ -6,6 +7,7 @@ use dioxus_elements::input_data::keyboard_types::Key;
 use dioxus_html as dioxus_elements;
 use dioxus_html::FormData;
 use dioxus_native_core::utils::cursor::{Cursor, Pos};
+use futures::FutureExt;
 use std::{collections::HashMap, io::stdout};
 use taffy::geometry::Point;
 
@@ -38,6 +40,7 @@ pub(crate) fn Password<'a>(cx: Scope<'a, PasswordProps>) -> Element<'a> {
     });
     let cursor = use_ref(cx, Cursor::default);
     let dragging = use_state(cx, || false);
+    let clipboard = use_clipboard(cx);
 
     let text = text_ref.read().clone();
     let start_highlight = cursor.read().first().idx(&*text);
@@ -88,7 +91,17 @@ pub(crate) fn Password<'a>(cx: Scope<'a, PasswordProps>) -> Element<'a> {
             return;
         }
         let mut text = text_ref.write();
-        cursor.write().handle_input(&k, &mut *text, max_len);
+        match ClipboardShortcut::from_key(&k) {
+            // like browsers, passwords can't be copied out of the input
+            Some(ClipboardShortcut::Copy | ClipboardShortcut::Cut) => return,
+            Some(ClipboardShortcut::Paste) => {
+                // the terminal clipboard answers right away, so the text is pasted before the handler returns
+                if let Some(Ok(pasted)) = clipboard.read_text().now_or_never() {
+                    paste(&mut cursor.write(), &mut text, &pasted, max_len);
+                }
+            }
+            None => cursor.write().handle_input(&k, &mut *text, max_len),
+        }
         if let Some(input_handler) = &cx.props.raw_oninput {
             input_handler.call(FormData {
                 value: text.clone(),
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_elements::input_data::keyboard_types::Key;
+use dioxus_html as dioxus_elements;
+use dioxus_html::FormData;
+
+#[derive(Props)]
+pub struct SelectProps<'a> {
+    /// The options to pick from, as `(value, label)` pairs
+    options: &'a [(&'a str, &'a str)],
+    /// The value of the selected option. Without it, the first option is selected until the user picks another one.
+    value: Option<&'a str>,
+    onchange: Option<EventHandler<'a, FormData>>,
+    oninput: Option<EventHandler<'a, FormData>>,
+    width: Option<&'a str>,
+}
+
+/// A dropdown to pick one of a list of options, like the html `select` element.
+///
+/// ```rust, ignore
+/// Select {
+///     options: &[("red", "Red"), ("green", "Green"), ("blue", "Blue")],
+///     onchange: move |data: FormData| color.set(data.value),
+/// }
+/// ```
+///
+/// Once focused, enter or space opens the options over the elements below, the arrow keys move between them and enter
+/// selects one. Escape closes the options without changing the selection. While the options are closed, the arrow
+/// keys change the selection directly.
+#[allow(non_snake_case)]
+pub fn Select<'a>(cx: Scope<'a, SelectProps<'a>>) -> Element<'a> {
+    let options = cx.props.options;
+    let selected_state = use_state(cx, || 0);
+    let open = use_state(cx, || false);
+    let highlighted = use_state(cx, || 0);
+    let width = cx.props.width.unwrap_or("20px");
+    let last = options.len().saturating_sub(1);
+
+    let selected = match cx.props.value {
+        Some(value) => options
+            .iter()
+            .position(|(option, _)| *option == value)
+            .unwrap_or(0),
+        None => *selected_state.get(),
+    };
+    let label = options.get(selected).map_or("", |(_, label)| label);
+    let arrow = if *open.get() { "▴" } else { "▾" };
+
+    let select = move |index: usize| {
+        open.set(false);
+        let value = match options.get(index) {
+            Some((value, _)) if index != selected => value,
+            _ => return,
+        };
+        selected_state.set(index);
+        let data = || FormData {
+            value: value.to_string(),
+            values: HashMap::new(),
+            files: None,
+        };
+        if let Some(oninput) = &cx.props.oninput {
+            oninput.call(data());
+        }
+        if let Some(onchange) = &cx.props.onchange {
+            onchange.call(data());
+        }
+    };
+    let show_options = move || {
+        highlighted.set(selected);
+        open.set(true);
+    };
+
+    cx.render(rsx! {
+        div {
+            width: "{width}",
+            height: "1px",
+            flex_direction: "column",
+            onkeydown: move |evt| {
+                let space = matches!(evt.key(), Key::Character(c) if c == " ");
+                if *open.get() {
+                    match evt.key() {
+                        Key::ArrowUp => highlighted.set(highlighted.get().saturating_sub(1)),
+                        Key::ArrowDown => highlighted.set((highlighted.get() + 1).min(last)),
+                        Key::Home => highlighted.set(0),
+                        Key::End => highlighted.set(last),
+                        Key::Enter => select(*highlighted.get()),
+                        Key::Escape => open.set(false),
+                        _ if space => select(*highlighted.get()),
+                        _ => (),
+                    }
+                } else {
+                    match evt.key() {
+                        Key::ArrowUp => select(selected.saturating_sub(1)),
+                        Key::ArrowDown => select((selected + 1).min(last)),
+                        Key::Enter => show_options(),
+                        _ if space => show_options(),
+                        _ => (),
+                    }
+                }
+            },
+            onfocusout: move |_| open.set(false),
+            div {
+                width: "100%",
+                height: "1px",
+                flex_direction: "row",
+                justify_content: "space-between",
+                background_color: "rgba(10,10,10,0.5)",
+                onclick: move |_| {
+                    if *open.get() {
+                        open.set(false);
+                    } else {
+                        show_options();
+                    }
+                },
+                div { "{label}" }
+                div { "{arrow}" }
+            }
+            if *open.get() {
+                rsx! {
+                    div {
+                        position: "absolute",
+                        top: "1px",
+                        left: "0px",
+                        width: "100%",
+                        flex_direction: "column",
+                        background_color: "rgb(40,40,40)",
+                        options.iter().enumerate().map(|(index, (_, label))| {
+                            let background_color = if index == *highlighted.get() {
+                                "rgb(90,90,90)"
+                            } else {
+                                "rgb(40,40,40)"
+                            };
+                            rsx! {
+                                div {
+                                    key: "{index}",
+                                    width: "100%",
+                                    height: "1px",
+                                    background_color: "{background_color}",
+                                    onmouseenter: move |_| highlighted.set(index),
+                                    onclick: move |_| select(index),
+                                    "{label}"
+                                }
+                            }
+                        })
+                    }
+                }
+            }
+        }
+    })
+}
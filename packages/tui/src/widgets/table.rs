@@ -0,0 +1,241 @@
+use std::ops::Range;
+
+use dioxus::prelude::*;
+use dioxus_elements::input_data::keyboard_types::Key;
+use dioxus_html as dioxus_elements;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// How wide a column of a [`Table`] is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnWidth {
+    /// A number of cells
+    Fixed(u16),
+    /// A percentage of the width of the table
+    Percent(f32),
+    /// As wide as the widest cell of the column, header included
+    Auto,
+}
+
+/// A column of a [`Table`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Column<'a> {
+    pub header: &'a str,
+    pub width: ColumnWidth,
+}
+
+impl<'a> Column<'a> {
+    pub const fn new(header: &'a str, width: ColumnWidth) -> Self {
+        Self { header, width }
+    }
+}
+
+#[derive(Props)]
+pub struct TableProps<'a> {
+    columns: &'a [Column<'a>],
+    /// The text of the cells, row by row
+    rows: &'a [Vec<String>],
+    width: Option<&'a str>,
+    height: Option<&'a str>,
+}
+
+/// A table with a header row, with the width of each column set by a [`ColumnWidth`].
+///
+/// ```rust, ignore
+/// const COLUMNS: &[Column] = &[
+///     Column::new("Name", ColumnWidth::Auto),
+///     Column::new("Size", ColumnWidth::Fixed(8)),
+///     Column::new("Description", ColumnWidth::Percent(50.0)),
+/// ];
+///
+/// Table {
+///     columns: COLUMNS,
+///     rows: &files,
+/// }
+/// ```
+///
+/// Cells longer than their column are cut. When the columns don't fit in the table, the left and right arrow keys
+/// scroll through them one column at a time once the table is focused.
+#[allow(non_snake_case)]
+pub fn Table<'a>(cx: Scope<'a, TableProps<'a>>) -> Element<'a> {
+    let columns = cx.props.columns;
+    let rows = cx.props.rows;
+    // the table isn't cut until it has been laid out
+    let table_width = use_state(cx, || None::<u16>);
+    let first = use_state(cx, || 0);
+    let width = cx.props.width.unwrap_or("100%");
+    let height = cx.props.height.unwrap_or("auto");
+
+    let available = table_width.get().unwrap_or(u16::MAX);
+    let widths = column_widths(columns, rows, table_width.get().unwrap_or(0));
+    let visible = visible_columns(&widths, *first.get(), available);
+    let scrolled_to_end = visible.end >= columns.len();
+    // the first visible column is cut to the table, the layout adds the space between the others
+    let cell_widths: Vec<u16> = visible
+        .clone()
+        .map(|index| {
+            if index == visible.start {
+                widths[index].min(available)
+            } else {
+                widths[index]
+            }
+        })
+        .collect();
+
+    let headers = visible.clone().zip(&cell_widths).map(|(index, width)| {
+        let header = fit(columns[index].header, *width as usize);
+        rsx! {
+            th {
+                key: "{index}",
+                width: "{width}px",
+                "{header}"
+            }
+        }
+    });
+    let lines = rows.iter().enumerate().map(|(row_index, row)| {
+        let cells = visible
+            .clone()
+            .zip(&cell_widths)
+            .map(move |(index, width)| {
+                let cell = fit(
+                    row.get(index).map_or("", |cell| cell.as_str()),
+                    *width as usize,
+                );
+                rsx! {
+                    td {
+                        key: "{index}",
+                        width: "{width}px",
+                        "{cell}"
+                    }
+                }
+            });
+        rsx! {
+            tr {
+                key: "{row_index}",
+                cells
+            }
+        }
+    });
+
+    cx.render(rsx! {
+        table {
+            width: "{width}",
+            height: "{height}",
+            onresize: move |evt| table_width.set(Some(evt.data.width as u16)),
+            onkeydown: move |evt| {
+                match evt.key() {
+                    Key::ArrowLeft => first.set(first.get().saturating_sub(1)),
+                    Key::ArrowRight if !scrolled_to_end => first.set(first.get() + 1),
+                    _ => ()
+                }
+            },
+            tr {
+                headers
+            }
+            lines
+        }
+    })
+}
+
+/// The width of each column, in cells, in a table `available` cells wide
+fn column_widths(columns: &[Column], rows: &[Vec<String>], available: u16) -> Vec<u16> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| match column.width {
+            ColumnWidth::Fixed(width) => width,
+            ColumnWidth::Percent(percent) => (available as f32 * percent / 100.0) as u16,
+            ColumnWidth::Auto => rows
+                .iter()
+                .filter_map(|row| row.get(index))
+                .map(|cell| cell.width())
+                .chain(Some(column.header.width()))
+                .max()
+                .unwrap_or(0) as u16,
+        })
+        .collect()
+}
+
+/// The columns from `first` on that fit in `available` cells, with a space between them. The first of them is
+/// always visible, even if it doesn't fit.
+fn visible_columns(widths: &[u16], first: usize, available: u16) -> Range<usize> {
+    let first = first.min(widths.len());
+    let mut used = 0u16;
+    let mut end = first;
+    for (index, width) in widths.iter().enumerate().skip(first) {
+        let needed = if index == first { *width } else { width + 1 };
+        if index > first && used.saturating_add(needed) > available {
+            break;
+        }
+        used = used.saturating_add(needed);
+        end = index + 1;
+    }
+    first..end
+}
+
+/// Cut the text to at most `width` cells, marking cut text with an ellipsis
+fn fit(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut cut = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        // leave a cell for the ellipsis
+        if used + char_width + 1 > width {
+            break;
+        }
+        used += char_width;
+        cut.push(c);
+    }
+    if width > 0 {
+        cut.push('…');
+    }
+    cut
+}
+
+#[test]
+fn columns_are_sized_by_their_width() {
+    let columns = [
+        Column::new("Name", ColumnWidth::Auto),
+        Column::new("Size", ColumnWidth::Fixed(8)),
+        Column::new("Description", ColumnWidth::Percent(50.0)),
+    ];
+    let rows = [
+        vec!["main.rs".to_string(), "1 KB".to_string(), String::new()],
+        vec!["lib.rs".to_string()],
+    ];
+
+    assert_eq!(column_widths(&columns, &rows, 40), vec![7, 8, 20]);
+    assert_eq!(column_widths(&columns, &[], 40), vec![4, 8, 20]);
+}
+
+#[test]
+fn narrow_tables_only_show_the_columns_that_fit() {
+    let widths = [5, 5, 5];
+
+    assert_eq!(visible_columns(&widths, 0, 17), 0..3);
+    assert_eq!(visible_columns(&widths, 0, 16), 0..2);
+    assert_eq!(visible_columns(&widths, 1, 11), 1..3);
+    assert_eq!(visible_columns(&widths, 0, 3), 0..1);
+    assert_eq!(visible_columns(&widths, 5, 20), 3..3);
+}
+
+#[test]
+fn cells_are_cut_to_their_column() {
+    assert_eq!(fit("abc", 3), "abc");
+    assert_eq!(fit("a", 3), "a");
+    assert_eq!(fit("abcd", 3), "ab…");
+    assert_eq!(fit("abcd", 0), "");
+}
+
+#[test]
+fn wide_characters_take_two_cells() {
+    let columns = [Column::new("名前", ColumnWidth::Auto)];
+    let rows = [vec!["日本語".to_string()]];
+
+    assert_eq!(column_widths(&columns, &rows, 40), vec![6]);
+    assert_eq!(fit("日本語", 6), "日本語");
+    assert_eq!(fit("日本語", 5), "日本…");
+    assert_eq!(fit("日本語", 4), "日…");
+}
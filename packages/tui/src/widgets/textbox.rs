@@ -1,3 +1,4 @@
+use crate::widgets::clipboard::{paste, selected_text, ClipboardShortcut};
 use crate::widgets::get_root_id;
 use crate::Query;
 use crossterm::{cursor::*, execute};
@@ -6,6 +7,7 @@ use dioxus_elements::input_data::keyboard_types::Key;
 use dioxus_html as dioxus_elements;
 use dioxus_html::FormData;
 use dioxus_native_core::utils::cursor::{Cursor, Pos};
+use futures::FutureExt;
 use std::{collections::HashMap, io::stdout};
 use taffy::geometry::Point;
 
@@ -28,6 +30,7 @@ pub(crate) struct TextBoxProps<'a> {
 pub(crate) fn TextBox<'a>(cx: Scope<'a, TextBoxProps>) -> Element<'a> {
     let tui_query: Query = cx.consume_context().unwrap();
     let tui_query_clone = tui_query.clone();
+    let tui_query_paste = tui_query.clone();
 
     let text_ref = use_ref(cx, || {
         if let Some(intial_text) = cx.props.value {
@@ -38,6 +41,15 @@ pub(crate) fn TextBox<'a>(cx: Scope<'a, TextBoxProps>) -> Element<'a> {
     });
     let cursor = use_ref(cx, Cursor::default);
     let dragging = use_state(cx, || false);
+    let clipboard = use_clipboard(cx);
+    let copy = move |selection: String| {
+        if !selection.is_empty() {
+            let write = clipboard.write_text(selection);
+            cx.spawn(async move {
+                let _ = write.await;
+            });
+        }
+    };
 
     let text = text_ref.read().clone();
     let start_highlight = cursor.read().first().idx(&*text);
@@ -90,7 +102,28 @@ pub(crate) fn TextBox<'a>(cx: Scope<'a, TextBoxProps>) -> Element<'a> {
                     return;
                 }
                 let mut text = text_ref.write();
-                cursor.write().handle_input(&k, &mut *text, max_len);
+                match ClipboardShortcut::from_key(&k) {
+                    Some(ClipboardShortcut::Copy) => {
+                        let selection = selected_text(&cursor.read(), &text);
+                        // ctrl+c copies the selection instead of quitting the app
+                        if !selection.is_empty() {
+                            k.prevent_default();
+                        }
+                        copy(selection);
+                        return;
+                    }
+                    Some(ClipboardShortcut::Cut) => {
+                        copy(selected_text(&cursor.read(), &text));
+                        cursor.write().delete_selection(&mut *text);
+                    }
+                    Some(ClipboardShortcut::Paste) => {
+                        // the terminal clipboard answers right away, so the text is pasted before the handler returns
+                        if let Some(Ok(pasted)) = clipboard.read_text().now_or_never() {
+                            paste(&mut cursor.write(), &mut text, &pasted, max_len);
+                        }
+                    }
+                    None => cursor.write().handle_input(&k, &mut *text, max_len),
+                }
                 if let Some(input_handler) = &cx.props.raw_oninput{
                     input_handler.call(FormData{
                         value: text.clone(),
@@ -114,6 +147,30 @@ pub(crate) fn TextBox<'a>(cx: Scope<'a, TextBoxProps>) -> Element<'a> {
                 }
             },
 
+            onpaste: move |evt| {
+                // terminals with bracketed paste send the pasted text at once instead of typing it
+                let pasted = match evt.get_data("text/plain") {
+                    Some(pasted) => pasted,
+                    None => return,
+                };
+                let mut text = text_ref.write();
+                paste(&mut cursor.write(), &mut text, &pasted, max_len);
+                if let Some(input_handler) = &cx.props.raw_oninput{
+                    input_handler.call(FormData{
+                        value: text.clone(),
+                        values: HashMap::new(),
+                        files: None
+                    });
+                }
+
+                let node = tui_query_paste.get(get_root_id(cx).unwrap());
+                let Point{ x, y } = node.pos().unwrap();
+
+                let Pos { col, row } = cursor.read().start;
+                let (x, y) = (col as u16 + x as u16 + u16::from(border != "none"), row as u16 + y as u16 + u16::from(border != "none"));
+                execute!(stdout(), MoveTo(x, y)).unwrap();
+            },
+
             onmousemove: move |evt| {
                 if *dragging.get() {
                     let offset = evt.data.element_coordinates();
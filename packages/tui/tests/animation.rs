@@ -0,0 +1,31 @@
+use dioxus::prelude::*;
+use dioxus_tui::{use_animation_frame, TuiContext};
+use std::time::Duration;
+
+#[test]
+fn animations_render_every_frame_while_active() {
+    dioxus_tui::launch_cfg(
+        app,
+        dioxus_tui::Config::new().with_headless().with_max_fps(100),
+    );
+
+    fn app(cx: Scope) -> Element {
+        let tui_ctx: TuiContext = cx.consume_context().unwrap();
+        let frames = cx.use_hook(|| 0);
+        *frames += 1;
+        let elapsed = use_animation_frame(cx, true);
+
+        if elapsed >= Duration::from_millis(50) {
+            // at 100 fps, 50ms take about 5 frames
+            assert!(*frames > 2, "only {frames} frames in {elapsed:?}");
+            tui_ctx.quit();
+        }
+
+        cx.render(rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+            }
+        })
+    }
+}
@@ -2,6 +2,7 @@ use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, Mous
 use dioxus::prelude::*;
 use dioxus_html::input_data::keyboard_types::Code;
 use dioxus_tui::TuiContext;
+use std::cell::Cell;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -44,14 +45,11 @@ fn key_down() {
             panic!("Event was not received");
         }
         // focus the element
-        tui_ctx.inject_event(Event::Key(KeyEvent {
-            code: KeyCode::Tab,
-            modifiers: KeyModifiers::NONE,
-        }));
-        tui_ctx.inject_event(Event::Key(KeyEvent {
-            code: KeyCode::Char('a'),
-            modifiers: KeyModifiers::NONE,
-        }));
+        tui_ctx.inject_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+        tui_ctx.inject_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
         cx.render(rsx! {
             div {
                 width: "100%",
@@ -65,6 +63,38 @@ fn key_down() {
     }
 }
 
+#[test]
+fn paste() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
+
+    fn app(cx: Scope) -> Element {
+        let render_count = use_state(cx, || 0);
+        let tui_ctx: TuiContext = cx.consume_context().unwrap();
+        let render_count_handle = render_count.clone();
+        cx.spawn(async move {
+            PollN::new(3).await;
+            render_count_handle.modify(|x| *x + 1);
+        });
+        if *render_count.get() > 2 {
+            panic!("Event was not received");
+        }
+        // focus the element
+        tui_ctx.inject_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+        tui_ctx.inject_event(Event::Paste("pasted text".to_string()));
+        cx.render(rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                onkeydown: move |_| {},
+                onpaste: move |evt| {
+                    assert_eq!(evt.get_data("text/plain").as_deref(), Some("pasted text"));
+                    tui_ctx.quit();
+                },
+            }
+        })
+    }
+}
+
 #[test]
 fn mouse_down() {
     dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
@@ -372,3 +402,130 @@ fn context_menu() {
         })
     }
 }
+
+#[test]
+fn tab_moves_focus() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
+
+    fn app(cx: Scope) -> Element {
+        let render_count = use_state(cx, || 0);
+        let blurred = &*cx.use_hook(|| Cell::new(false));
+        let tui_ctx: TuiContext = cx.consume_context().unwrap();
+        let render_count_handle = render_count.clone();
+        cx.spawn(async move {
+            PollN::new(4).await;
+            render_count_handle.modify(|x| *x + 1);
+        });
+        if *render_count.get() > 2 {
+            panic!("Event was not received");
+        }
+        // focus the first element, then move to the second
+        cx.use_hook(|| {
+            for _ in 0..2 {
+                tui_ctx.inject_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+            }
+        });
+        cx.render(rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                flex_direction: "column",
+                div {
+                    tabindex: "0",
+                    height: "1px",
+                    onblur: move |_| blurred.set(true),
+                }
+                button {
+                    height: "1px",
+                    onfocus: move |_| {
+                        assert!(blurred.get());
+                        tui_ctx.quit();
+                    },
+                }
+            }
+        })
+    }
+}
+
+#[test]
+fn click_grid_cell() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
+
+    fn app(cx: Scope) -> Element {
+        let render_count = use_state(cx, || 0);
+        let tui_ctx: TuiContext = cx.consume_context().unwrap();
+        let render_count_handle = render_count.clone();
+        cx.spawn(async move {
+            PollN::new(3).await;
+            render_count_handle.modify(|x| *x + 1);
+        });
+        if *render_count.get() > 2 {
+            panic!("Event was not received");
+        }
+        // the second column starts after the first one and the gap
+        tui_ctx.inject_event(Event::Mouse(MouseEvent {
+            column: 12,
+            row: 2,
+            kind: crossterm::event::MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+        }));
+        cx.render(rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                display: "grid",
+                grid_template_columns: "10px 1fr",
+                grid_template_rows: "5px 1fr",
+                column_gap: "1px",
+                div {
+                    onmousedown: move |_| panic!("the first cell was clicked"),
+                }
+                div {
+                    onmousedown: move |_| tui_ctx.quit(),
+                }
+                div {}
+                div {}
+            }
+        })
+    }
+}
+
+#[test]
+fn click_next_to_max_width() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
+
+    fn app(cx: Scope) -> Element {
+        let render_count = use_state(cx, || 0);
+        let tui_ctx: TuiContext = cx.consume_context().unwrap();
+        let render_count_handle = render_count.clone();
+        cx.spawn(async move {
+            PollN::new(3).await;
+            render_count_handle.modify(|x| *x + 1);
+        });
+        if *render_count.get() > 2 {
+            panic!("Event was not received");
+        }
+        // the first pane would grow to half of the screen without its max width
+        tui_ctx.inject_event(Event::Mouse(MouseEvent {
+            column: 12,
+            row: 0,
+            kind: crossterm::event::MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+        }));
+        cx.render(rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                div {
+                    flex_grow: "1",
+                    max_width: "10px",
+                    onmousedown: move |_| panic!("the first pane was clicked"),
+                }
+                div {
+                    flex_grow: "1",
+                    onmousedown: move |_| tui_ctx.quit(),
+                }
+            }
+        })
+    }
+}
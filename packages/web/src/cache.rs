@@ -228,6 +228,7 @@ pub static BUILTIN_INTERNED_STRINGS: &[&str] = &[
     "onpause",
     "onplay",
     "onplaying",
+    "onmounted",
     "onprogress",
     "onratechange",
     "onreset",
@@ -243,6 +244,7 @@ pub static BUILTIN_INTERNED_STRINGS: &[&str] = &[
     "ontimeupdate",
     "ontoggle",
     "onunload",
+    "onvisible",
     "onvolumechange",
     "onwaiting",
     "onwheel",
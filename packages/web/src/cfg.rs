@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 ///  Configuration for the WebSys renderer for the Dioxus VirtualDOM.
 ///
 /// This struct helps configure the specifics of hydration and render destination for WebSys.
@@ -10,9 +12,15 @@
 pub struct Config {
     #[cfg(feature = "hydrate")]
     pub(crate) hydrate: bool,
+    #[cfg(feature = "hydrate")]
+    pub(crate) progressive_hydration: bool,
     pub(crate) rootname: String,
     pub(crate) cached_strings: Vec<String>,
     pub(crate) default_panic_hook: bool,
+    pub(crate) panic_overlay: bool,
+    pub(crate) idle_timeout: Duration,
+    pub(crate) service_worker: Option<String>,
+    pub(crate) precache: Vec<String>,
 }
 
 impl Default for Config {
@@ -20,9 +28,15 @@ impl Default for Config {
         Self {
             #[cfg(feature = "hydrate")]
             hydrate: false,
+            #[cfg(feature = "hydrate")]
+            progressive_hydration: false,
             rootname: "main".to_string(),
             cached_strings: Vec::new(),
             default_panic_hook: true,
+            panic_overlay: cfg!(debug_assertions),
+            idle_timeout: Duration::from_millis(500),
+            service_worker: None,
+            precache: Vec::new(),
         }
     }
 }
@@ -47,6 +61,18 @@ impl Config {
         self
     }
 
+    #[cfg(feature = "hydrate")]
+    /// Make a hydrated page interactive bit by bit, instead of all at once
+    ///
+    /// The components are matched to the page and become interactive in document order while the browser is idle, so
+    /// a large page doesn't block the main thread. An interaction with a component before then makes it interactive
+    /// right away, and is dispatched again once it is. Everything is made interactive as soon as the app changes the
+    /// page.
+    pub fn with_progressive_hydration(mut self, f: bool) -> Self {
+        self.progressive_hydration = f;
+        self
+    }
+
     /// Set the name of the element that Dioxus will use as the root.
     ///
     /// This is akint to calling React.render() on the element with the specified name.
@@ -70,4 +96,42 @@ impl Config {
         self.default_panic_hook = f;
         self
     }
+
+    /// Set whether or not a panic shows an overlay with its message and location on top of the app.
+    ///
+    /// Without it, a panic leaves the page frozen, with the details only in the console. The overlay is shown in debug
+    /// builds by default, and hidden in release builds.
+    pub fn with_panic_overlay(mut self, f: bool) -> Self {
+        self.panic_overlay = f;
+        self
+    }
+
+    /// Set how long idle updates, scheduled with `schedule_idle_update`, can wait for the browser to be idle.
+    ///
+    /// Idle updates are rendered when the browser has nothing else to do, so they never delay input handling. On a
+    /// page that is never idle, they are rendered once the timeout passes. The default is 500ms.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Register a service worker, so the app keeps working offline and learns when a new version was deployed.
+    ///
+    /// Serve [`crate::SERVICE_WORKER_JS`] (or your own worker) at `url`, from the root of the site. Put the version of
+    /// the app in the url, like `/sw.js?v=1.2.0`, so the browser installs a new worker with every deploy. The app can
+    /// then prompt the user to refresh with [`crate::use_service_worker`].
+    pub fn with_service_worker(mut self, url: impl Into<String>) -> Self {
+        self.service_worker = Some(url.into());
+        self
+    }
+
+    /// Set which files the service worker caches to work offline, like `["*.wasm", "*.js", "/assets/*"]`.
+    ///
+    /// The patterns match the paths of the files the page loaded from its own origin, where `*` stands for anything.
+    /// The page itself is cached if a pattern matches its path. This has no effect without
+    /// [`Config::with_service_worker`].
+    pub fn with_precache<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.precache = patterns.into_iter().map(Into::into).collect();
+        self
+    }
 }
@@ -0,0 +1,54 @@
+use dioxus_hooks::{ClipboardBackend, ClipboardError};
+use js_sys::{Array, Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Accesses the clipboard through `navigator.clipboard`
+pub(crate) struct WebClipboard;
+
+#[async_trait::async_trait(?Send)]
+impl ClipboardBackend for WebClipboard {
+    async fn read_text(&self) -> Result<String, ClipboardError> {
+        call("readText", Array::new())
+            .await?
+            .as_string()
+            .ok_or_else(|| ClipboardError::Failed("readText did not return a string".into()))
+    }
+
+    async fn write_text(&self, text: String) -> Result<(), ClipboardError> {
+        call("writeText", Array::of1(&text.into())).await?;
+        Ok(())
+    }
+}
+
+// web-sys only exposes the clipboard API behind an unstable flag, so call it through reflection instead
+async fn call(method: &str, args: Array) -> Result<JsValue, ClipboardError> {
+    let navigator = web_sys::window()
+        .ok_or(ClipboardError::Unsupported)?
+        .navigator();
+    let clipboard = Reflect::get(&navigator, &"clipboard".into()).map_err(failed)?;
+    if clipboard.is_undefined() {
+        // only available in secure contexts
+        return Err(ClipboardError::Unsupported);
+    }
+
+    let method: Function = Reflect::get(&clipboard, &method.into())
+        .map_err(failed)?
+        .dyn_into()
+        .map_err(|_| ClipboardError::Unsupported)?;
+    let promise: Promise = method
+        .apply(&clipboard, &args)
+        .map_err(failed)?
+        .dyn_into()
+        .map_err(failed)?;
+
+    JsFuture::from(promise).await.map_err(failed)
+}
+
+fn failed(err: JsValue) -> ClipboardError {
+    let reason = match err.dyn_ref::<js_sys::Error>() {
+        Some(err) => err.message().into(),
+        None => format!("{err:?}"),
+    };
+    ClipboardError::Failed(reason)
+}
@@ -9,24 +9,42 @@
 
 use dioxus_core::{
     BorrowedAttributeValue, ElementId, Mutation, Template, TemplateAttribute, TemplateNode,
+    VirtualDom,
 };
-use dioxus_html::{event_bubbles, CompositionData, FormData};
-use dioxus_interpreter_js::{save_template, Channel};
+use dioxus_html::{
+    event_bubbles, ClipboardData, CompositionData, FileEngine, FormData, MountedData,
+};
+use dioxus_interpreter_js::{get_node, save_template, Channel};
 use futures_channel::mpsc;
 use rustc_hash::FxHashMap;
-use std::{any::Any, rc::Rc};
+use std::{any::Any, cell::RefCell, rc::Rc, sync::Arc};
 use wasm_bindgen::{closure::Closure, JsCast};
 use web_sys::{Document, Element, Event, HtmlElement};
 
-use crate::Config;
+use crate::{file_engine::WebFileEngine, Config};
 
 pub struct WebsysDom {
-    document: Document,
+    pub(crate) document: Document,
     #[allow(dead_code)]
     pub(crate) root: Element,
     templates: FxHashMap<String, u32>,
     max_template_id: u32,
     pub(crate) interpreter: Channel,
+    event_channel: mpsc::UnboundedSender<UiEvent>,
+    #[cfg(feature = "hydrate")]
+    pub(crate) pending_hydration: Option<crate::rehydrate::PendingHydration>,
+}
+
+/// Dispatches events to the VirtualDom while the browser is still dispatching them, so handlers can prevent their
+/// default action.
+///
+/// The VirtualDom is busy while the changes to the page are applied, so the events they fire go through the event
+/// channel instead. Those are handled before any new input, which can't fire until the render loop yields.
+pub(crate) struct SyncDispatch {
+    pub dom: Rc<RefCell<VirtualDom>>,
+
+    /// Wakes the render loop up to render the changes of the handlers
+    pub dispatched: mpsc::UnboundedSender<()>,
 }
 
 pub struct UiEvent {
@@ -38,7 +56,14 @@ pub struct UiEvent {
 }
 
 impl WebsysDom {
-    pub fn new(cfg: Config, event_channel: mpsc::UnboundedSender<UiEvent>) -> Self {
+    /// Create the renderer.
+    ///
+    /// Events go through `event_channel`, unless they can be dispatched synchronously with `sync_dispatch`.
+    pub(crate) fn new(
+        cfg: Config,
+        event_channel: mpsc::UnboundedSender<UiEvent>,
+        sync_dispatch: Option<SyncDispatch>,
+    ) -> Self {
         // eventually, we just want to let the interpreter do all the work of decoding events into our event type
         // a match here in order to avoid some error during runtime browser test
         let document = load_document();
@@ -48,6 +73,7 @@ impl WebsysDom {
         };
         let interpreter = Channel::default();
 
+        let handler_channel = event_channel.clone();
         let handler: Closure<dyn FnMut(&Event)> =
             Closure::wrap(Box::new(move |event: &web_sys::Event| {
                 let name = event.type_();
@@ -63,14 +89,30 @@ impl WebsysDom {
                         event.prevent_default();
                     }
 
+                    apply_drag_effects(event, &target);
+
                     let data = virtual_event_from_websys_event(event.clone(), target);
-                    let _ = event_channel.unbounded_send(UiEvent {
-                        name,
-                        bubbles,
-                        element,
-                        data,
-                        event: event.clone(),
-                    });
+                    let dom = sync_dispatch
+                        .as_ref()
+                        .and_then(|sync| Some((sync.dom.try_borrow_mut().ok()?, sync)));
+                    match dom {
+                        Some((mut dom, sync)) => {
+                            let prevented = dom.handle_event(&name, data.clone(), element, bubbles);
+                            if write_clipboard_data(event, &*data) || prevented {
+                                event.prevent_default();
+                            }
+                            let _ = sync.dispatched.unbounded_send(());
+                        }
+                        None => {
+                            let _ = handler_channel.unbounded_send(UiEvent {
+                                name,
+                                bubbles,
+                                element,
+                                data,
+                                event: event.clone(),
+                            });
+                        }
+                    }
                 }
             }));
 
@@ -85,6 +127,9 @@ impl WebsysDom {
             interpreter,
             templates: FxHashMap::default(),
             max_template_id: 0,
+            event_channel,
+            #[cfg(feature = "hydrate")]
+            pending_hydration: None,
         }
     }
 
@@ -95,14 +140,16 @@ impl WebsysDom {
     pub fn load_templates(&mut self, templates: &[Template]) {
         for template in templates {
             let mut roots = vec![];
+            let mut recyclable = vec![];
 
             for root in template.roots {
-                roots.push(self.create_template_node(root))
+                roots.push(self.create_template_node(root));
+                recyclable.push(!has_dynamic_nodes(root) as u8);
             }
 
             self.templates
                 .insert(template.name.to_owned(), self.max_template_id);
-            save_template(roots, self.max_template_id);
+            save_template(roots, self.max_template_id, recyclable);
             self.max_template_id += 1
         }
     }
@@ -153,8 +200,9 @@ impl WebsysDom {
         }
     }
 
-    pub fn apply_edits(&mut self, mut edits: Vec<Mutation>) {
+    pub fn apply_edits(&mut self, mut edits: Vec<Mutation>, freed_ids: &[ElementId]) {
         use Mutation::*;
+        let mut mounted = Vec::new();
         let i = &mut self.interpreter;
         for edit in &edits {
             match edit {
@@ -167,11 +215,10 @@ impl WebsysDom {
                 HydrateText { path, value, id } => {
                     i.hydrate_text(path.as_ptr() as u32, path.len() as u8, value, id.0 as u32)
                 }
-                LoadTemplate { name, index, id } => {
-                    if let Some(tmpl_id) = self.templates.get(*name) {
-                        i.load_template(*tmpl_id, *index as u32, id.0 as u32)
-                    }
-                }
+                LoadTemplate { name, index, id } => match self.templates.get(*name) {
+                    Some(tmpl_id) => i.load_template(*tmpl_id, *index as u32, id.0 as u32),
+                    None => log::error!("The template {name} was loaded before it was created"),
+                },
                 ReplaceWith { id, m } => i.replace_with(id.0 as u32, *m as u32),
                 ReplacePlaceholder { path, m } => {
                     i.replace_placeholder(path.as_ptr() as u32, path.len() as u8, *m as u32)
@@ -205,6 +252,9 @@ impl WebsysDom {
                     _ => unreachable!(),
                 },
                 SetText { value, id } => i.set_text(id.0 as u32, value),
+                // mounted isn't an event of the DOM, it's sent once the element exists
+                NewEventListener { name, id, .. } if *name == "mounted" => mounted.push(*id),
+                RemoveEventListener { name, .. } if *name == "mounted" => {}
                 NewEventListener { name, id, .. } => {
                     i.new_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
                 }
@@ -216,7 +266,78 @@ impl WebsysDom {
             }
         }
         edits.clear();
+        // the nodes of ids the VirtualDom freed are dropped, so the page doesn't keep removed elements alive
+        for id in freed_ids {
+            i.free_id(id.0 as u32);
+        }
         i.flush();
+
+        for id in mounted {
+            self.send_mount_event(id);
+        }
+    }
+
+    /// Send the element to the listener of its `onmounted` event
+    ///
+    /// The handler runs after the edits that created the element are mounted to the page.
+    pub(crate) fn send_mount_event(&self, id: ElementId) {
+        let element = match get_node(id.0 as u32).dyn_into::<Element>() {
+            Ok(element) => element,
+            Err(_) => return,
+        };
+        let _ = self.event_channel.unbounded_send(UiEvent {
+            name: "mounted".to_string(),
+            bubbles: false,
+            element: id,
+            data: Rc::new(MountedData::new(element)),
+            event: Event::new("mounted").unwrap(),
+        });
+    }
+}
+
+// The clipboard can only be written while the browser is dispatching the copy or cut event. The contents replace the
+// selection, so the default has to be prevented if the handlers set any
+fn write_clipboard_data(event: &Event, data: &dyn Any) -> bool {
+    let written = match data.downcast_ref::<ClipboardData>() {
+        Some(data) => data.written_data(),
+        None => return false,
+    };
+    let transfer = match event
+        .dyn_ref::<web_sys::ClipboardEvent>()
+        .and_then(|event| event.clipboard_data())
+    {
+        Some(transfer) if !written.is_empty() => transfer,
+        _ => return false,
+    };
+
+    for (format, data) in written {
+        let _ = transfer.set_data(&format, &data);
+    }
+    true
+}
+
+// Drag effects can only be set while the browser is dispatching the event, so they can't wait for the handler to run
+fn apply_drag_effects(event: &Event, target: &Element) {
+    let transfer = match event
+        .dyn_ref::<web_sys::DragEvent>()
+        .and_then(|event| event.data_transfer())
+    {
+        Some(transfer) => transfer,
+        None => return,
+    };
+
+    match event.type_().as_str() {
+        "dragstart" => {
+            if let Some(effect) = target.get_attribute("dioxus-effect-allowed") {
+                transfer.set_effect_allowed(&effect);
+            }
+        }
+        "dragenter" | "dragover" => {
+            if let Some(effect) = target.get_attribute("dioxus-drop-effect") {
+                transfer.set_drop_effect(&effect);
+            }
+        }
+        _ => {}
     }
 }
 
@@ -226,7 +347,7 @@ pub fn virtual_event_from_websys_event(event: web_sys::Event, target: Element) -
     use dioxus_html::events::*;
 
     match event.type_().as_str() {
-        "copy" | "cut" | "paste" => Rc::new(ClipboardData {}),
+        "copy" | "cut" | "paste" => Rc::new(ClipboardData::from(event)),
         "compositionend" | "compositionstart" | "compositionupdate" => {
             make_composition_event(&event)
         }
@@ -241,8 +362,14 @@ pub fn virtual_event_from_websys_event(event: web_sys::Event, target: Element) -
         }
         "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
         | "drop" => {
-            let mouse = MouseData::from(event);
-            Rc::new(DragData { mouse })
+            let event: &web_sys::DragEvent = event.unchecked_ref();
+            let mut data = DragData::from(event);
+            data.files = event
+                .data_transfer()
+                .and_then(|transfer| transfer.files())
+                .and_then(WebFileEngine::new)
+                .map(|engine| Arc::new(engine) as Arc<dyn FileEngine>);
+            Rc::new(data)
         }
 
         "pointerdown" | "pointermove" | "pointerup" | "pointercancel" | "gotpointercapture"
@@ -261,13 +388,44 @@ pub fn virtual_event_from_websys_event(event: web_sys::Event, target: Element) -
         "abort" | "canplay" | "canplaythrough" | "durationchange" | "emptied" | "encrypted"
         | "ended" | "error" | "loadeddata" | "loadedmetadata" | "loadstart" | "pause" | "play"
         | "playing" | "progress" | "ratechange" | "seeked" | "seeking" | "stalled" | "suspend"
-        | "timeupdate" | "volumechange" | "waiting" => Rc::new(MediaData {}),
+        | "timeupdate" | "volumechange" | "waiting" => Rc::new(
+            target
+                .dyn_ref::<web_sys::HtmlMediaElement>()
+                .map(MediaData::from)
+                .unwrap_or_default(),
+        ),
         "toggle" => Rc::new(ToggleData {}),
+        // Element resize events are dispatched by the interpreter's ResizeObserver with the new size as the detail.
+        // The window's own resize event has no detail and carries no data.
+        "resize" => match make_resize_event(&event) {
+            Some(data) => Rc::new(data),
+            None => Rc::new(()),
+        },
+        // Dispatched by the interpreter's IntersectionObserver, with the intersection as the detail
+        "visible" => Rc::new(make_visible_event(&event).unwrap_or_default()),
 
         _ => Rc::new(()),
     }
 }
 
+fn make_resize_event(event: &Event) -> Option<dioxus_html::ResizeData> {
+    let detail = event.dyn_ref::<web_sys::CustomEvent>()?.detail();
+    let read = |key: &str| js_sys::Reflect::get(&detail, &key.into()).ok()?.as_f64();
+    Some(dioxus_html::ResizeData {
+        width: read("width")?,
+        height: read("height")?,
+    })
+}
+
+fn make_visible_event(event: &Event) -> Option<dioxus_html::VisibleData> {
+    let detail = event.dyn_ref::<web_sys::CustomEvent>()?.detail();
+    let read = |key: &str| js_sys::Reflect::get(&detail, &key.into()).ok();
+    Some(dioxus_html::VisibleData {
+        is_intersecting: read("is_intersecting")?.as_bool()?,
+        intersection_ratio: read("intersection_ratio")?.as_f64()?,
+    })
+}
+
 fn make_composition_event(event: &Event) -> Rc<CompositionData> {
     let evt: &web_sys::CompositionEvent = event.dyn_ref().unwrap();
     Rc::new(CompositionData {
@@ -359,30 +517,43 @@ fn read_input_to_data(target: Element) -> Rc<FormData> {
         }
     }
 
+    let files = target
+        .dyn_ref::<web_sys::HtmlInputElement>()
+        .and_then(|input| input.files())
+        .and_then(WebFileEngine::new)
+        .map(|engine| Arc::new(engine) as Arc<dyn FileEngine>);
+
     Rc::new(FormData {
         value,
         values,
-        files: None,
+        files,
     })
 }
 
+/// Removed template roots are only recycled if they have no dynamic nodes, because those replace their placeholder
+/// in the template when they are created
+fn has_dynamic_nodes(node: &TemplateNode) -> bool {
+    match node {
+        TemplateNode::Element { children, .. } => children.iter().any(has_dynamic_nodes),
+        TemplateNode::Dynamic { .. } => true,
+        TemplateNode::Text { .. } | TemplateNode::DynamicText { .. } => false,
+    }
+}
+
 fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Element)> {
-    let mut target = event
-        .target()
-        .expect("missing target")
-        .dyn_into::<web_sys::Element>()
-        .expect("not a valid element");
+    // the path goes from the target up to the window, so the first element with an id is the one listening
+    for target in event.composed_path().iter() {
+        let target = match target.dyn_into::<web_sys::Element>() {
+            Ok(target) => target,
+            Err(_) => continue,
+        };
 
-    loop {
         match target.get_attribute("data-dioxus-id").map(|f| f.parse()) {
             Some(Ok(id)) => return Some((ElementId(id), target)),
             Some(Err(_)) => return None,
-
-            // walk the tree upwards until we actually find an event target
-            None => match target.parent_element() {
-                Some(parent) => target = parent,
-                None => return None,
-            },
+            None => {}
         }
     }
+
+    None
 }
@@ -0,0 +1,140 @@
+//! Save bytes generated by the app to a file on the user's device
+//!
+//! ```rust, ignore
+//! button {
+//!     onclick: move |_| {
+//!         let csv = export_csv(&rows);
+//!         cx.spawn(async move {
+//!             if let Err(err) = save_file("report.csv", "text/csv", csv.as_bytes()).await {
+//!                 log::error!("Could not save the report: {err}");
+//!             }
+//!         });
+//!     },
+//!     "Export CSV"
+//! }
+//! ```
+
+use gloo_timers::callback::Timeout;
+use js_sys::{Array, Function, Object, Promise, Reflect, Uint8Array};
+use std::fmt;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+/// Why a file couldn't be saved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    /// The user closed the save dialog without picking a file
+    Cancelled,
+    /// The browser refused to save the file
+    Failed(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Cancelled => write!(f, "the user cancelled the save"),
+            SaveError::Failed(reason) => write!(f, "saving failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+fn failed(err: JsValue) -> SaveError {
+    SaveError::Failed(format!("{err:?}"))
+}
+
+/// Save bytes to a file, letting the user pick where with a save dialog when the browser supports it.
+///
+/// Browsers with the File System Access API show a save dialog, suggesting `name`. The others download the file to
+/// the user's downloads folder under `name`, like [`download`]. Browsers only allow this shortly after the user
+/// interacted with the page, so call it from an event handler.
+pub async fn save_file(name: &str, mime: &str, bytes: &[u8]) -> Result<(), SaveError> {
+    let window = web_sys::window().ok_or_else(|| SaveError::Failed("no window".into()))?;
+    let picker = Reflect::get(&window, &"showSaveFilePicker".into()).map_err(failed)?;
+    let picker = match picker.dyn_into::<Function>() {
+        Ok(picker) => picker,
+        Err(_) => return download(name, mime, bytes),
+    };
+
+    let options = Object::new();
+    Reflect::set(&options, &"suggestedName".into(), &name.into()).map_err(failed)?;
+
+    let handle = match await_promise(picker.call1(&window, &options)).await {
+        Ok(handle) => handle,
+        Err(err) if is_abort(&err) => return Err(SaveError::Cancelled),
+        Err(err) => return Err(failed(err)),
+    };
+
+    let writable = call_method(&handle, "createWritable", Array::new()).await?;
+    let blob = blob(mime, bytes)?;
+    call_method(&writable, "write", Array::of1(&blob)).await?;
+    call_method(&writable, "close", Array::new()).await?;
+    Ok(())
+}
+
+/// Download bytes as a file named `name` to the user's downloads folder.
+///
+/// Browsers only allow this shortly after the user interacted with the page, so call it from an event handler.
+pub fn download(name: &str, mime: &str, bytes: &[u8]) -> Result<(), SaveError> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| SaveError::Failed("no document".into()))?;
+    let body = document
+        .body()
+        .ok_or_else(|| SaveError::Failed("no body".into()))?;
+
+    let url = Url::create_object_url_with_blob(&blob(mime, bytes)?).map_err(failed)?;
+
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(failed)?
+        .unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(name);
+    anchor
+        .style()
+        .set_property("display", "none")
+        .map_err(failed)?;
+
+    // Firefox only follows links that are in the document
+    body.append_child(&anchor).map_err(failed)?;
+    anchor.click();
+    anchor.remove();
+
+    // the browser reads the blob after the click returns
+    Timeout::new(1000, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+
+    Ok(())
+}
+
+fn blob(mime: &str, bytes: &[u8]) -> Result<Blob, SaveError> {
+    let mut options = BlobPropertyBag::new();
+    options.type_(mime);
+    let parts = Array::of1(&Uint8Array::from(bytes));
+    Blob::new_with_u8_array_sequence_and_options(&parts, &options).map_err(failed)
+}
+
+// web-sys only exposes the File System Access API behind an unstable flag, so call it through reflection instead
+async fn call_method(target: &JsValue, method: &str, args: Array) -> Result<JsValue, SaveError> {
+    let method: Function = Reflect::get(target, &method.into())
+        .map_err(failed)?
+        .dyn_into()
+        .map_err(failed)?;
+    await_promise(method.apply(target, &args))
+        .await
+        .map_err(failed)
+}
+
+async fn await_promise(promise: Result<JsValue, JsValue>) -> Result<JsValue, JsValue> {
+    JsFuture::from(promise?.dyn_into::<Promise>()?).await
+}
+
+fn is_abort(err: &JsValue) -> bool {
+    err.dyn_ref::<web_sys::DomException>()
+        .map_or(false, |err| err.name() == "AbortError")
+}
@@ -0,0 +1,43 @@
+use dioxus_html::FileEngine;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{File, FileList};
+
+/// A [`FileEngine`] that reads files from the browser's File API
+pub(crate) struct WebFileEngine {
+    files: Vec<File>,
+}
+
+impl WebFileEngine {
+    pub fn new(list: FileList) -> Option<Self> {
+        let files: Vec<File> = (0..list.length()).filter_map(|i| list.item(i)).collect();
+
+        if files.is_empty() {
+            return None;
+        }
+
+        Some(Self { files })
+    }
+
+    fn find(&self, name: &str) -> Option<&File> {
+        self.files.iter().find(|f| f.name() == name)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl FileEngine for WebFileEngine {
+    fn files(&self) -> Vec<String> {
+        self.files.iter().map(|f| f.name()).collect()
+    }
+
+    async fn read_file(&self, file: &str) -> Option<Vec<u8>> {
+        let buffer = JsFuture::from(self.find(file)?.array_buffer()).await.ok()?;
+        let buffer: js_sys::ArrayBuffer = buffer.dyn_into().ok()?;
+        Some(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    async fn read_file_to_string(&self, file: &str) -> Option<String> {
+        let text = JsFuture::from(self.find(file)?.text()).await.ok()?;
+        text.as_string()
+    }
+}
@@ -0,0 +1,58 @@
+use dioxus_hooks::{EventForwarder, ListenerBackend, ListenerTarget};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, EventTarget};
+
+use crate::dom::{load_document, virtual_event_from_websys_event};
+
+/// Attaches global listeners directly to the window and the document
+#[derive(Default)]
+pub(crate) struct WebListeners {
+    callbacks: RefCell<FxHashMap<(ListenerTarget, String), Closure<dyn FnMut(&Event)>>>,
+}
+
+fn event_target(target: ListenerTarget) -> EventTarget {
+    match target {
+        ListenerTarget::Window => web_sys::window()
+            .expect("should have access to the Window")
+            .into(),
+        ListenerTarget::Document => load_document().into(),
+    }
+}
+
+impl ListenerBackend for WebListeners {
+    fn listen(&self, target: ListenerTarget, event: &str, forwarder: EventForwarder) {
+        let callback: Closure<dyn FnMut(&Event)> = Closure::wrap(Box::new(move |event: &Event| {
+            // The window and document aren't elements, so read any element specific data from the root element
+            let root = load_document()
+                .document_element()
+                .expect("should have a root element");
+            forwarder.forward(virtual_event_from_websys_event(event.clone(), root));
+        }));
+
+        if event_target(target)
+            .add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())
+            .is_err()
+        {
+            log::error!("Could not listen to {event:?} on the {}", target.as_str());
+            return;
+        }
+
+        self.callbacks
+            .borrow_mut()
+            .insert((target, event.to_string()), callback);
+    }
+
+    fn unlisten(&self, target: ListenerTarget, event: &str) {
+        let callback = self
+            .callbacks
+            .borrow_mut()
+            .remove(&(target, event.to_string()));
+
+        if let Some(callback) = callback {
+            let _ = event_target(target)
+                .remove_event_listener_with_callback(event, callback.as_ref().unchecked_ref());
+        }
+    }
+}
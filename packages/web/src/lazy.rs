@@ -0,0 +1,107 @@
+//! Load heavy parts of the app on demand, rendering a placeholder while they download
+//!
+//! wasm-bindgen compiles a crate into one module and can't split it, so code that should only be downloaded when it's
+//! needed goes into a crate of its own, compiled to a second module with `wasm-bindgen --target web`. That module
+//! can't share Rust values with the app, since it has its own memory, so it exports functions that take JS values,
+//! like one that draws a chart into an element:
+//!
+//! ```rust, ignore
+//! // in the `chart` crate, compiled to /assets/chart/chart.js and chart_bg.wasm
+//! #[wasm_bindgen]
+//! pub fn draw(element: web_sys::Element, points: Vec<f64>) { /* ... */ }
+//! ```
+//!
+//! The app renders the component that needs the module with the [`Lazy`](dioxus_core::Lazy) component, which shows
+//! its fallback until the loader resolves. [`load_wasm_component`] makes a loader that downloads and initializes the
+//! module first, and the component gets the module's exports with [`wasm_module`]:
+//!
+//! ```rust, ignore
+//! const CHART: &str = "/assets/chart/chart.js";
+//!
+//! fn load_chart() -> LazyComponent {
+//!     load_wasm_component(CHART, Chart)
+//! }
+//!
+//! fn dashboard(cx: Scope) -> Element {
+//!     cx.render(rsx! {
+//!         Lazy { loader: load_chart, fallback: cx.render(rsx!("Loading the chart...")) }
+//!     })
+//! }
+//!
+//! fn Chart(cx: Scope) -> Element {
+//!     match wasm_module(CHART)? {
+//!         Ok(module) => cx.render(rsx!( /* call the exports of the module */ )),
+//!         Err(err) => cx.render(rsx!("Could not load the chart: {err}")),
+//!     }
+//! }
+//! ```
+//!
+//! Heavy JS libraries can be left out of the bundle the same way with [`import_module`].
+
+use dioxus_core::{Component, LazyComponent};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen(inline_js = "export function import_module(url) { return import(url); }")]
+extern "C" {
+    #[wasm_bindgen(js_name = import_module)]
+    fn import_module_promise(url: &str) -> js_sys::Promise;
+}
+
+/// Import the JS module at `url`, downloading it the first time, and get its exports.
+///
+/// Relative urls are resolved against the page.
+pub async fn import_module(url: &str) -> Result<JsValue, JsValue> {
+    let url = match web_sys::window().and_then(|window| window.location().href().ok()) {
+        Some(base) => web_sys::Url::new_with_base(url, &base)?.href(),
+        None => url.to_string(),
+    };
+    JsFuture::from(import_module_promise(&url)).await
+}
+
+/// Import a module compiled with `wasm-bindgen --target web` from the JS file at `url`, download and initialize its
+/// wasm, and get its exports.
+///
+/// The wasm is loaded from next to the JS file, where wasm-bindgen puts it.
+pub async fn import_wasm_module(url: &str) -> Result<JsValue, JsValue> {
+    let module = import_module(url).await?;
+    // the default export of the generated JS instantiates the wasm
+    let init: js_sys::Function =
+        js_sys::Reflect::get(&module, &JsValue::from_str("default"))?.dyn_into()?;
+    let initialized: js_sys::Promise = init.call0(&JsValue::NULL)?.dyn_into()?;
+    JsFuture::from(initialized).await?;
+    Ok(module)
+}
+
+thread_local! {
+    // The wasm modules loaded for lazy components, or why they couldn't be loaded, by url
+    static WASM_MODULES: RefCell<HashMap<String, Result<JsValue, String>>> = RefCell::new(HashMap::new());
+}
+
+/// A loader for the [`Lazy`](dioxus_core::Lazy) component that imports the wasm module at `url` with
+/// [`import_wasm_module`], and then renders `component`.
+///
+/// The module is only loaded once, later loaders for the same url resolve right away. The component is rendered even
+/// if the module couldn't be loaded, so it can show the error it gets from [`wasm_module`].
+pub fn load_wasm_component(url: &'static str, component: Component) -> LazyComponent {
+    Box::pin(async move {
+        if wasm_module(url).is_none() {
+            let module = import_wasm_module(url).await.map_err(|err| {
+                let err = err.as_string().unwrap_or_else(|| format!("{err:?}"));
+                log::error!("Could not load the wasm module {url}: {err}");
+                err
+            });
+            WASM_MODULES.with(|modules| modules.borrow_mut().insert(url.to_string(), module));
+        }
+        component
+    })
+}
+
+/// The exports of the wasm module a [`load_wasm_component`] loader loaded from `url`, or why it couldn't be loaded
+///
+/// `None` until the loader finished.
+pub fn wasm_module(url: &str) -> Option<Result<JsValue, String>> {
+    WASM_MODULES.with(|modules| modules.borrow().get(url).cloned())
+}
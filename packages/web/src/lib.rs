@@ -54,22 +54,34 @@
 //     - Do DOM work in the next requestAnimationFrame callback
 
 pub use crate::cfg::Config;
-pub use crate::util::{use_eval, EvalResult};
+pub use crate::download::{download, save_file, SaveError};
+pub use crate::lazy::{import_module, import_wasm_module, load_wasm_component, wasm_module};
+pub use crate::service_worker::{use_service_worker, ServiceWorker, SERVICE_WORKER_JS};
+pub use crate::util::{use_eval, EvalResult, WebMountedExt};
+#[cfg(feature = "worker")]
+pub use crate::worker::{in_worker, launch_in_worker, run_in_worker, run_in_worker_with_props};
 use dioxus_core::{Element, Scope, VirtualDom};
 use futures_util::{pin_mut, FutureExt, StreamExt};
+use std::{cell::RefCell, future::Future, rc::Rc};
 
 mod cache;
 mod cfg;
+mod clipboard;
 mod dom;
+mod download;
+mod file_engine;
+mod global_listeners;
 mod hot_reload;
+mod lazy;
+mod media_queries;
+mod panic_overlay;
 #[cfg(feature = "hydrate")]
 mod rehydrate;
+mod ric_raf;
+mod service_worker;
 mod util;
-
-// Currently disabled since it actually slows down immediate rendering
-// todo: only schedule non-immediate renders through ric/raf
-// mod ric_raf;
-// mod rehydrate;
+#[cfg(feature = "worker")]
+mod worker;
 
 /// Launch the VirtualDOM given a root component and a configuration.
 ///
@@ -165,13 +177,21 @@ pub fn launch_with_props<T: 'static>(
 pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_props: T, cfg: Config) {
     log::info!("Starting up");
 
-    let mut dom = VirtualDom::new_with_props(root, root_props);
+    // idle updates wait for the browser to be idle instead of competing with input handling
+    let shared_dom = Rc::new(RefCell::new(
+        VirtualDom::new_with_props(root, root_props).with_idle_lane(),
+    ));
+    let mut idle_callback = ric_raf::IdleCallback::new(cfg.idle_timeout);
 
     #[cfg(feature = "panic_hook")]
     if cfg.default_panic_hook {
         console_error_panic_hook::set_once();
     }
 
+    if cfg.panic_overlay {
+        panic_overlay::install();
+    }
+
     let mut hotreload_rx = hot_reload::init();
 
     for s in crate::cache::BUILTIN_INTERNED_STRINGS {
@@ -182,13 +202,46 @@ pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_prop
     }
 
     let (tx, mut rx) = futures_channel::mpsc::unbounded();
+    let (dispatched_tx, mut dispatched) = futures_channel::mpsc::unbounded();
 
     #[cfg(feature = "hydrate")]
     let should_hydrate = cfg.hydrate;
     #[cfg(not(feature = "hydrate"))]
     let should_hydrate = false;
 
-    let mut websys_dom = dom::WebsysDom::new(cfg, tx);
+    // the interactions held back while the page is hydrated progressively, with their targets
+    let (interaction_tx, mut interactions) =
+        futures_channel::mpsc::unbounded::<(web_sys::Node, web_sys::Event)>();
+    #[cfg(feature = "hydrate")]
+    let progressive_hydration = cfg.progressive_hydration;
+
+    let service_worker = cfg
+        .service_worker
+        .as_deref()
+        .and_then(|url| service_worker::register(url, cfg.precache.clone()));
+
+    let sync_dispatch = dom::SyncDispatch {
+        dom: shared_dom.clone(),
+        dispatched: dispatched_tx,
+    };
+    let mut websys_dom = dom::WebsysDom::new(cfg, tx, Some(sync_dispatch));
+
+    let mut dom = shared_dom.borrow_mut();
+
+    if let Some(service_worker) = service_worker {
+        dom.base_scope().provide_context(service_worker);
+    }
+
+    dom.base_scope()
+        .provide_context(dioxus_hooks::GlobalListeners::new(
+            global_listeners::WebListeners::default(),
+        ));
+    dom.base_scope()
+        .provide_context(dioxus_hooks::MediaQueries::new(
+            media_queries::WebMediaQueries::default(),
+        ));
+    dom.base_scope()
+        .provide_context(dioxus_hooks::Clipboard::new(clipboard::WebClipboard));
 
     log::info!("rebuilding app");
 
@@ -201,47 +254,96 @@ pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_prop
             let templates = dom.rebuild().templates;
             websys_dom.load_templates(&templates);
 
-            if let Err(err) = websys_dom.rehydrate(&dom) {
+            let hydrated = if progressive_hydration {
+                websys_dom.rehydrate_progressively(&dom, interaction_tx)
+            } else {
+                websys_dom.rehydrate(&dom)
+            };
+
+            if let Err(err) = hydrated {
                 log::error!(
-                    "Rehydration failed {:?}. Rebuild DOM into element from scratch",
-                    &err
+                    "Rehydration failed: {err}. Rendering the app on the client from scratch"
                 );
                 websys_dom.root.set_text_content(None);
 
                 let edits = dom.rebuild();
 
                 websys_dom.load_templates(&edits.templates);
-                websys_dom.apply_edits(edits.edits);
+                websys_dom.apply_edits(edits.edits, &edits.freed_ids);
             }
         }
     } else {
+        drop(interaction_tx);
+
         let edits = dom.rebuild();
 
         websys_dom.load_templates(&edits.templates);
-        websys_dom.apply_edits(edits.edits);
+        websys_dom.apply_edits(edits.edits, &edits.freed_ids);
     }
 
     // the mutations come back with nothing - we need to actually mount them
     websys_dom.mount();
+    drop(dom);
 
     loop {
         log::debug!("waiting for work");
 
         // if virtualdom has nothing, wait for it to have something before requesting idle time
         // if there is work then this future resolves immediately.
-        let (mut res, template) = {
-            let work = dom.wait_for_work().fuse();
+        #[cfg(feature = "hydrate")]
+        let has_idle_work = shared_dom.borrow().has_idle_work() || websys_dom.is_hydrating();
+        #[cfg(not(feature = "hydrate"))]
+        let has_idle_work = shared_dom.borrow().has_idle_work();
+        let (mut res, template, idle, interaction) = {
+            // the VirtualDom is only borrowed while it is polled, so events can be dispatched to it in between
+            let work = futures_util::future::poll_fn(|cx| {
+                let mut dom = shared_dom.borrow_mut();
+                let work = dom.wait_for_work();
+                pin_mut!(work);
+                work.poll(cx)
+            })
+            .fuse();
             pin_mut!(work);
 
+            let idle = async {
+                if has_idle_work {
+                    idle_callback.wait_for_idle_time().await
+                } else {
+                    futures_util::future::pending().await
+                }
+            }
+            .fuse();
+            pin_mut!(idle);
+
             futures_util::select! {
-                _ = work => (None, None),
+                _ = work => (None, None, false, None),
+                _ = dispatched.next() => (None, None, false, None),
                 new_template = hotreload_rx.next() => {
-                    (None, new_template)
+                    (None, new_template, false, None)
                 }
-                evt = rx.next() => (evt, None)
+                evt = rx.next() => (evt, None, false, None),
+                _ = idle => (None, None, true, None),
+                target = interactions.next() => (None, None, false, target),
             }
         };
 
+        // the VirtualDom must be free to handle the interaction once it is dispatched again
+        #[cfg(feature = "hydrate")]
+        if let Some((target, event)) = interaction {
+            websys_dom.hydrate_around(&target, &shared_dom.borrow());
+            rehydrate::redispatch(&target, &event);
+        }
+        #[cfg(not(feature = "hydrate"))]
+        let _ = interaction;
+
+        let mut dom = shared_dom.borrow_mut();
+
+        if idle {
+            dom.promote_idle_work();
+            #[cfg(feature = "hydrate")]
+            websys_dom.hydrate_for(std::time::Duration::from_millis(5), &dom);
+        }
+
         if let Some(template) = template {
             dom.replace_template(template);
         }
@@ -253,24 +355,21 @@ pub async fn run_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_prop
             res = rx.try_next().transpose().unwrap().ok();
         }
 
-        // Todo: This is currently disabled because it has a negative impact on responce times for events but it could be re-enabled for tasks
-        // Jank free rendering
-        //
-        // 1. wait for the browser to give us "idle" time
-        // 2. During idle time, diff the dom
-        // 3. Stop diffing if the deadline is exceded
-        // 4. Wait for the animation frame to patch the dom
-
-        // wait for the mainthread to schedule us in
-        // let deadline = work_loop.wait_for_idle_time().await;
+        // The rest of the page must be matched before it changes, since the changes refer to the ids of its nodes,
+        // and the ids of the listeners that are still pending may be reused by the changes
+        #[cfg(feature = "hydrate")]
+        if websys_dom.is_hydrating() {
+            dom.process_events();
+            if dom.has_dirty_scopes() {
+                websys_dom.finish_hydration(&dom);
+            }
+        }
 
-        // run the virtualdom work phase until the frame deadline is reached
+        // Regular updates are rendered right away, so events get a response as fast as possible. Idle updates only
+        // join them once the browser had idle time
         let edits = dom.render_immediate();
 
-        // wait for the animation frame to fire so we can apply our changes
-        // work_loop.wait_for_raf().await;
-
         websys_dom.load_templates(&edits.templates);
-        websys_dom.apply_edits(edits.edits);
+        websys_dom.apply_edits(edits.edits, &edits.freed_ids);
     }
 }
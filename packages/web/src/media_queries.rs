@@ -0,0 +1,40 @@
+use dioxus_hooks::MediaQueryBackend;
+use rustc_hash::FxHashMap;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, MediaQueryList};
+
+/// Evaluates media queries with `matchMedia`
+#[derive(Default)]
+pub(crate) struct WebMediaQueries {
+    watching: RefCell<FxHashMap<usize, (MediaQueryList, Closure<dyn FnMut(&Event)>)>>,
+}
+
+impl MediaQueryBackend for WebMediaQueries {
+    fn watch(&self, id: usize, query: &str, on_change: Rc<dyn Fn(bool)>) -> Option<bool> {
+        let list = match web_sys::window()?.match_media(query) {
+            Ok(Some(list)) => list,
+            _ => {
+                log::error!("Could not evaluate the media query {query:?}");
+                return None;
+            }
+        };
+
+        let callback: Closure<dyn FnMut(&Event)> = Closure::wrap(Box::new({
+            let list = list.clone();
+            move |_: &Event| on_change(list.matches())
+        }));
+        let _ = list.add_event_listener_with_callback("change", callback.as_ref().unchecked_ref());
+
+        let matches = list.matches();
+        self.watching.borrow_mut().insert(id, (list, callback));
+        Some(matches)
+    }
+
+    fn unwatch(&self, id: usize) {
+        if let Some((list, callback)) = self.watching.borrow_mut().remove(&id) {
+            let _ = list
+                .remove_event_listener_with_callback("change", callback.as_ref().unchecked_ref());
+        }
+    }
+}
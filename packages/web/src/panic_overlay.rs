@@ -0,0 +1,130 @@
+//! Show panics on top of the page, so a crashed app doesn't just look frozen
+//!
+//! The overlay is for developers: it shows the message and location of the panic and points to the console, where
+//! the panic hook of the config logs the backtrace.
+
+use std::panic::PanicInfo;
+use std::sync::Once;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Document, Element, HtmlElement};
+
+const OVERLAY_ID: &str = "dioxus-panic-overlay";
+
+const OVERLAY_STYLE: &str = "position: fixed; inset: 0; z-index: 2147483647; overflow: auto; \
+    box-sizing: border-box; padding: 2rem; background: rgba(24, 24, 27, 0.92); color: #f4f4f5; \
+    font: 14px/1.5 ui-monospace, SFMono-Regular, Menlo, Consolas, monospace;";
+
+const MESSAGE_STYLE: &str =
+    "margin: 1rem 0; padding: 1rem; white-space: pre-wrap; word-break: break-word; \
+    background: rgba(239, 68, 68, 0.15); border-left: 4px solid #ef4444; color: #fecaca;";
+
+const BUTTON_STYLE: &str = "position: absolute; top: 1rem; right: 1rem; padding: 0.25rem 0.75rem; \
+    font: inherit; color: inherit; background: transparent; border: 1px solid #71717a; cursor: pointer;";
+
+/// Show an overlay for every panic, after the panic hooks that were set before
+pub(crate) fn install() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+            show(info);
+        }));
+    });
+}
+
+fn show(info: &PanicInfo) {
+    let (message, location) = describe(info);
+    show_message(&message, location.as_deref());
+}
+
+/// The message of a panic, and where it happened
+pub(crate) fn describe(info: &PanicInfo) -> (String, Option<String>) {
+    let message = match info.payload().downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match info.payload().downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "Box<dyn Any>".to_string(),
+        },
+    };
+
+    let location = info.location().map(|location| {
+        format!(
+            "at {}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        )
+    });
+
+    (message, location)
+}
+
+/// Show the overlay for a panic, like one the worker running the app reported
+pub(crate) fn show_message(message: &str, location: Option<&str>) {
+    let document = match web_sys::window().and_then(|window| window.document()) {
+        Some(document) => document,
+        None => return,
+    };
+
+    // only the first panic is interesting, the app is broken after it
+    if document.get_element_by_id(OVERLAY_ID).is_some() {
+        return;
+    }
+
+    if let Some(overlay) = build(&document, message, location) {
+        if let Some(body) = document.body() {
+            let _ = body.append_child(&overlay);
+        }
+    }
+}
+
+/// Create an element styled through the CSSOM, which a content security policy without `unsafe-inline` allows
+fn styled(document: &Document, tag: &str, style: &str) -> Option<HtmlElement> {
+    let element: HtmlElement = document.create_element(tag).ok()?.dyn_into().ok()?;
+    element.style().set_css_text(style);
+    Some(element)
+}
+
+fn build(document: &Document, message: &str, location: Option<&str>) -> Option<Element> {
+    let overlay = styled(document, "div", OVERLAY_STYLE)?;
+    overlay.set_id(OVERLAY_ID);
+    overlay.set_attribute("role", "alert").ok()?;
+
+    let close = styled(document, "button", BUTTON_STYLE)?;
+    close.set_text_content(Some("Dismiss"));
+    // a listener rather than an `onclick` attribute, which content security policies block
+    let dismiss = Closure::once_into_js({
+        let overlay = overlay.clone();
+        move || overlay.remove()
+    });
+    close
+        .add_event_listener_with_callback("click", dismiss.unchecked_ref())
+        .ok()?;
+    overlay.append_child(&close).ok()?;
+
+    let title = styled(document, "h2", "margin: 0; color: #ef4444;")?;
+    title.set_text_content(Some("The app panicked"));
+    overlay.append_child(&title).ok()?;
+
+    // text content, since the message may contain anything
+    let text = styled(document, "pre", MESSAGE_STYLE)?;
+    text.set_text_content(Some(message));
+    overlay.append_child(&text).ok()?;
+
+    if let Some(location) = location {
+        let where_ = document.create_element("p").ok()?;
+        where_.set_text_content(Some(location));
+        overlay.append_child(&where_).ok()?;
+    }
+
+    let hint = styled(document, "p", "color: #a1a1aa;")?;
+    hint.set_text_content(Some(
+        "The app stopped responding. Open the console of the browser's developer tools \
+         (F12 or Ctrl+Shift+J, Cmd+Option+J on macOS) for the backtrace, then reload the page.",
+    ));
+    overlay.append_child(&hint).ok()?;
+
+    Some(overlay.into())
+}
@@ -1,189 +1,686 @@
 use crate::dom::WebsysDom;
 use dioxus_core::{
-    AttributeValue, DynamicNode, ElementId, ScopeState, TemplateNode, VNode, VPlaceholder, VText,
-    VirtualDom,
+    AttributeValue, DynamicNode, ElementId, RenderReturn, ScopeId, ScopeState, TemplateAttribute,
+    TemplateNode, VNode, VPlaceholder, VText, VirtualDom,
 };
 use dioxus_html::event_bubbles;
-use wasm_bindgen::JsCast;
-use web_sys::{Comment, Node};
+use dioxus_interpreter_js::get_node;
+use futures_channel::mpsc;
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{Comment, Element, Node, Text};
 
-#[derive(Debug, Copy, Clone)]
+/// The events that make an element hydrate right away during progressive hydration, since they come before the
+/// events the app listens for, like `click` after `pointerdown`
+const INTERACTIONS: &[&str] = &["pointerdown", "touchstart", "keydown", "focusin"];
+
+/// How many listeners progressive hydration registers between checks of its deadline
+const HYDRATION_CHUNK: usize = 32;
+
+/// Marks the interactions dispatched again once their target is hydrated
+const REDISPATCHED: &str = "dioxusHydrated";
+
+/// Why the DOM rendered on the server doesn't match the app
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RehydrationError {
-    NodeTypeMismatch,
-    NodeNotFound,
+    /// The DOM has another node where the app expects `expected`
+    NodeTypeMismatch { expected: String, found: String },
+    /// The DOM ends where the app expects `expected`
+    NodeNotFound { expected: String },
+    /// The VirtualDom wasn't rendered before hydrating
     VNodeNotInitialized,
 }
 use RehydrationError::*;
 
-fn set_node(hydrated: &mut Vec<bool>, id: ElementId, node: Node) {
-    let idx = id.0;
-    if idx >= hydrated.len() {
-        hydrated.resize(idx + 1, false);
+impl fmt::Display for RehydrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeTypeMismatch { expected, found } => write!(f, "expected {expected}, found {found}"),
+            NodeNotFound { expected } => write!(f, "expected {expected}, found nothing"),
+            VNodeNotInitialized => write!(f, "the VirtualDom was not rendered"),
+        }
+    }
+}
+
+/// What hydration found out so far
+struct Hydration {
+    hydrated: Vec<bool>,
+    // Registered once the whole DOM is hydrated, since subtrees that don't match are rendered again
+    listeners: Vec<(u32, String)>,
+    last_node_was_static_text: bool,
+    // The components that are matched later with the nodes the server rendered for them, if matching is lazy
+    deferred: Option<Vec<(ScopeId, Vec<Node>)>>,
+}
+
+impl Hydration {
+    fn set_node(&mut self, id: ElementId, node: Node) {
+        let idx = id.0;
+        if idx >= self.hydrated.len() {
+            self.hydrated.resize(idx + 1, false);
+        }
+        if !self.hydrated[idx] {
+            dioxus_interpreter_js::set_node(idx as u32, node);
+            self.hydrated[idx] = true;
+        }
+    }
+
+    /// Point an id at a node rendered on the client, even if it was hydrated before the mismatch was found
+    fn replace_node(&mut self, id: ElementId, node: Node) {
+        if let Some(hydrated) = self.hydrated.get_mut(id.0) {
+            *hydrated = false;
+        }
+        self.set_node(id, node);
+    }
+}
+
+fn expect_node(
+    current: &Option<Node>,
+    expected: impl Fn() -> String,
+) -> Result<Node, RehydrationError> {
+    current.clone().ok_or_else(|| NodeNotFound {
+        expected: expected(),
+    })
+}
+
+fn mismatch(expected: impl Into<String>, found: &Node) -> RehydrationError {
+    NodeTypeMismatch {
+        expected: expected.into(),
+        found: describe(found),
+    }
+}
+
+/// A short description of a node for mismatch messages, like `<div id="main">` or `text "Hello"`
+fn describe(node: &Node) -> String {
+    if let Some(element) = node.dyn_ref::<Element>() {
+        let tag = element.tag_name().to_lowercase();
+        return match element.get_attribute("id") {
+            Some(id) => format!("<{tag} id=\"{id}\">"),
+            None => format!("<{tag}>"),
+        };
+    }
+    if node.has_type::<Comment>() {
+        return "a comment".to_string();
+    }
+    let text: String = node
+        .text_content()
+        .unwrap_or_default()
+        .chars()
+        .take(20)
+        .collect();
+    format!("text {text:?}")
+}
+
+/// Tell about a value the server rendered differently from the client, which is patched to the value of the client
+fn warn_patched(what: impl FnOnce() -> String) {
+    if cfg!(debug_assertions) {
+        log::warn!(
+            "Hydration mismatch: {} differs from the server. Patching it on the client",
+            what()
+        );
     }
-    if !hydrated[idx] {
-        dioxus_interpreter_js::set_node(idx as u32, node);
-        hydrated[idx] = true;
+}
+
+/// The walk can find a listener more than once, but it must only be registered once
+fn dedup_listeners(listeners: Vec<(u32, String)>) -> VecDeque<(u32, String)> {
+    let mut registered = HashSet::new();
+    listeners
+        .into_iter()
+        .filter(|listener| registered.insert(listener.clone()))
+        .collect()
+}
+
+/// How many sibling nodes the server rendered for the roots of a vnode
+fn span(vnode: &VNode, dom: &VirtualDom) -> Option<usize> {
+    let mut count = 0;
+    for root in vnode.template.get().roots {
+        count += match root {
+            TemplateNode::Element { .. } => 1,
+            // text roots are wrapped in markers
+            TemplateNode::Text { .. } => 3,
+            TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => {
+                match &vnode.dynamic_nodes[*id] {
+                    // empty text leaves nothing between the markers
+                    DynamicNode::Text(VText { value, .. }) if value.is_empty() => 2,
+                    DynamicNode::Text(_) => 3,
+                    DynamicNode::Placeholder(_) => 1,
+                    DynamicNode::Component(comp) => {
+                        match dom.get_scope(comp.scope.get()?)?.root_node() {
+                            RenderReturn::Ready(vnode) => span(vnode, dom)?,
+                            _ => return None,
+                        }
+                    }
+                    DynamicNode::Fragment(fragment) => fragment
+                        .iter()
+                        .map(|vnode| span(vnode, dom))
+                        .sum::<Option<usize>>()?,
+                }
+            }
+        };
+    }
+    Some(count)
+}
+
+/// Take the next `count` siblings, if there are that many
+fn take_nodes(current: &mut Option<Node>, count: usize) -> Option<Vec<Node>> {
+    let mut nodes = Vec::with_capacity(count);
+    let mut next = current.clone();
+    for _ in 0..count {
+        let node = next?;
+        next = node.next_sibling();
+        nodes.push(node);
+    }
+    *current = next;
+    Some(nodes)
+}
+
+/// Dispatch an interaction again, after it was held back until its target was hydrated
+pub(crate) fn redispatch(target: &Node, event: &web_sys::Event) {
+    // events copy the properties of an event they are created from
+    let copy =
+        js_sys::Reflect::get(event, &JsValue::from_str("constructor")).and_then(|constructor| {
+            let args = js_sys::Array::of2(&JsValue::from(event.type_()), event);
+            js_sys::Reflect::construct(constructor.unchecked_ref(), &args)
+        });
+    if let Ok(copy) = copy {
+        let _ = js_sys::Reflect::set(&copy, &JsValue::from_str(REDISPATCHED), &JsValue::TRUE);
+        let _ = target.dispatch_event(copy.unchecked_ref());
+    }
+}
+
+fn advance(current: &mut Option<Node>) {
+    *current = current.as_ref().and_then(Node::next_sibling);
+}
+
+/// Skip the comment the server puts around dynamic text, so it isn't merged with its neighbors
+fn skip_marker(current: &mut Option<Node>) -> Result<(), RehydrationError> {
+    let expected = || "a <!--#--> marker".to_string();
+    let node = expect_node(current, expected)?;
+    if !node.has_type::<Comment>() {
+        return Err(mismatch(expected(), &node));
+    }
+    advance(current);
+    Ok(())
+}
+
+/// The parts of a progressively hydrated page that aren't interactive yet
+#[derive(Default)]
+struct Unhydrated {
+    // In document order, so the top of the page becomes interactive first
+    listeners: VecDeque<(u32, String)>,
+    // The components whose nodes aren't matched yet, with the nodes the server rendered for them
+    deferred: VecDeque<(ScopeId, Vec<Node>)>,
+}
+
+impl Unhydrated {
+    fn is_empty(&self) -> bool {
+        self.listeners.is_empty() && self.deferred.is_empty()
+    }
+
+    fn contains(&self, target: &Node) -> bool {
+        self.listeners
+            .iter()
+            .any(|(id, _)| get_node(*id).contains(Some(target)))
+            || self
+                .deferred
+                .iter()
+                .any(|(_, nodes)| nodes.iter().any(|node| node.contains(Some(target))))
+    }
+}
+
+/// A progressively hydrated page that isn't completely interactive yet
+pub(crate) struct PendingHydration {
+    // Shared with the listener that holds back interactions with the parts that aren't interactive
+    unhydrated: Rc<RefCell<Unhydrated>>,
+    state: Hydration,
+    root: Element,
+    on_interaction: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl Drop for PendingHydration {
+    fn drop(&mut self) {
+        for event in INTERACTIONS {
+            let _ = self.root.remove_event_listener_with_callback_and_bool(
+                event,
+                self.on_interaction.as_ref().unchecked_ref(),
+                true,
+            );
+        }
     }
 }
 
 impl WebsysDom {
     // we're streaming in patches, but the nodes already exist
     // so we're just going to write the correct IDs to the node and load them in
+    //
+    // Subtrees that don't match the app are rendered again on the client. Only a mismatch at the root fails.
     pub fn rehydrate(&mut self, dom: &VirtualDom) -> Result<(), RehydrationError> {
-        let mut root = self
-            .root
-            .clone()
-            .dyn_into::<Node>()
-            .map_err(|_| NodeTypeMismatch)?
-            .first_child()
-            .ok_or(NodeNotFound);
+        let mut root = self.root.first_child();
 
-        let root_scope = dom.base_scope();
+        let mut state = Hydration {
+            hydrated: vec![true],
+            listeners: Vec::new(),
+            last_node_was_static_text: false,
+            deferred: None,
+        };
 
-        let mut hydrated = vec![true];
+        // Recursively rehydrate the dom from the VirtualDom
+        self.rehydrate_scope(dom.base_scope(), &mut root, &mut state, dom)?;
 
-        let mut last_node_was_static_text = false;
+        let listeners = dedup_listeners(state.listeners);
+        self.register_listeners(listeners.into());
+        Ok(())
+    }
 
-        // Recursively rehydrate the dom from the VirtualDom
-        self.rehydrate_scope(
-            root_scope,
-            &mut root,
-            &mut hydrated,
-            dom,
-            &mut last_node_was_static_text,
-        )?;
+    /// Hydrate the page, but only make it interactive bit by bit
+    ///
+    /// Only the nodes of the root component are matched right away. The nodes of the other components are matched
+    /// and their listeners registered later in slices of [`Self::hydrate_for`], in document order. An interaction with
+    /// a part of the page that isn't interactive yet is held back and sent to `interactions`, to dispatch it again
+    /// after [`Self::hydrate_around`].
+    pub(crate) fn rehydrate_progressively(
+        &mut self,
+        dom: &VirtualDom,
+        interactions: mpsc::UnboundedSender<(Node, web_sys::Event)>,
+    ) -> Result<(), RehydrationError> {
+        let mut root = self.root.first_child();
+
+        let mut state = Hydration {
+            hydrated: vec![true],
+            listeners: Vec::new(),
+            last_node_was_static_text: false,
+            deferred: Some(Vec::new()),
+        };
 
+        self.rehydrate_scope(dom.base_scope(), &mut root, &mut state, dom)?;
+        // apply the values that differ from the server now, the listeners come later
         self.interpreter.flush();
+
+        let unhydrated = Rc::new(RefCell::new(Unhydrated::default()));
+        Self::defer(&unhydrated, &mut state);
+
+        // capture, so the interaction is held back before it reaches any element
+        let on_interaction: Closure<dyn FnMut(web_sys::Event)> = Closure::wrap(Box::new({
+            let unhydrated = unhydrated.clone();
+            move |event: web_sys::Event| {
+                let redispatched = js_sys::Reflect::has(&event, &JsValue::from_str(REDISPATCHED));
+                if redispatched.unwrap_or(false) {
+                    return;
+                }
+                let target = match event.composed_path().get(0).dyn_into::<Node>() {
+                    Ok(target) => target,
+                    Err(_) => return,
+                };
+                if unhydrated.borrow().contains(&target) {
+                    // the default action still happens, only the listeners of the app wait
+                    event.stop_immediate_propagation();
+                    let _ = interactions.unbounded_send((target, event));
+                }
+            }
+        }));
+        for event in INTERACTIONS {
+            let _ = self.root.add_event_listener_with_callback_and_bool(
+                event,
+                on_interaction.as_ref().unchecked_ref(),
+                true,
+            );
+        }
+
+        self.pending_hydration = Some(PendingHydration {
+            unhydrated,
+            state,
+            root: self.root.clone(),
+            on_interaction,
+        });
         Ok(())
     }
 
+    /// Queue the listeners and components a walk found
+    fn defer(unhydrated: &RefCell<Unhydrated>, state: &mut Hydration) {
+        let mut unhydrated = unhydrated.borrow_mut();
+        let listeners = dedup_listeners(std::mem::take(&mut state.listeners));
+        unhydrated.listeners.extend(listeners);
+        if let Some(deferred) = &mut state.deferred {
+            unhydrated.deferred.extend(deferred.drain(..));
+        }
+    }
+
+    /// Check if some parts of a progressively hydrated page aren't interactive yet
+    pub(crate) fn is_hydrating(&self) -> bool {
+        self.pending_hydration.is_some()
+    }
+
+    /// Make the page interactive in document order until the time is up
+    pub(crate) fn hydrate_for(&mut self, budget: Duration, dom: &VirtualDom) {
+        let deadline = js_sys::Date::now() + budget.as_millis() as f64;
+
+        let mut pending = match self.pending_hydration.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+        loop {
+            let listeners: Vec<_> = {
+                let mut unhydrated = pending.unhydrated.borrow_mut();
+                let chunk = unhydrated.listeners.len().min(HYDRATION_CHUNK);
+                unhydrated.listeners.drain(..chunk).collect()
+            };
+            if listeners.is_empty() {
+                let next = pending.unhydrated.borrow_mut().deferred.pop_front();
+                match next {
+                    Some((scope, nodes)) => self.hydrate_deferred(&mut pending, dom, scope, nodes),
+                    None => return,
+                }
+            } else {
+                self.register_listeners(listeners);
+            }
+
+            if js_sys::Date::now() >= deadline {
+                break;
+            }
+        }
+        if !pending.unhydrated.borrow().is_empty() {
+            self.pending_hydration = Some(pending);
+        }
+    }
+
+    /// Make the elements around a node the user interacts with interactive
+    pub(crate) fn hydrate_around(&mut self, target: &Node, dom: &VirtualDom) {
+        let mut pending = match self.pending_hydration.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        // matching a component can defer the components inside of it
+        loop {
+            let next = {
+                let mut unhydrated = pending.unhydrated.borrow_mut();
+                let index = unhydrated
+                    .deferred
+                    .iter()
+                    .position(|(_, nodes)| nodes.iter().any(|node| node.contains(Some(target))));
+                index.and_then(|index| unhydrated.deferred.remove(index))
+            };
+            match next {
+                Some((scope, nodes)) => self.hydrate_deferred(&mut pending, dom, scope, nodes),
+                None => break,
+            }
+        }
+
+        let listeners: Vec<_> = {
+            let mut unhydrated = pending.unhydrated.borrow_mut();
+            let (listeners, rest) = unhydrated
+                .listeners
+                .drain(..)
+                .partition(|(id, _)| get_node(*id).contains(Some(target)));
+            unhydrated.listeners = rest;
+            listeners
+        };
+        self.register_listeners(listeners);
+
+        if !pending.unhydrated.borrow().is_empty() {
+            self.pending_hydration = Some(pending);
+        }
+    }
+
+    /// Make the whole page interactive
+    ///
+    /// This must happen before the DOM is changed, since the ids of the pending listeners can be reused by then.
+    pub(crate) fn finish_hydration(&mut self, dom: &VirtualDom) {
+        let mut pending = match self.pending_hydration.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+        loop {
+            let next = pending.unhydrated.borrow_mut().deferred.pop_front();
+            match next {
+                Some((scope, nodes)) => self.hydrate_deferred(&mut pending, dom, scope, nodes),
+                None => break,
+            }
+        }
+        let listeners = std::mem::take(&mut pending.unhydrated.borrow_mut().listeners);
+        self.register_listeners(listeners.into());
+    }
+
+    /// Match the nodes of a component that was deferred, or render it again on the client if they don't match
+    fn hydrate_deferred(
+        &mut self,
+        pending: &mut PendingHydration,
+        dom: &VirtualDom,
+        scope: ScopeId,
+        nodes: Vec<Node>,
+    ) {
+        // the parent of the component didn't match, and rendered it again on the client already
+        let first = match nodes.first() {
+            Some(first) if first.is_connected() => first.clone(),
+            _ => return,
+        };
+        let scope = match dom.get_scope(scope) {
+            Some(scope) => scope,
+            None => return,
+        };
+
+        let state = &mut pending.state;
+        state.last_node_was_static_text = false;
+        if let Err(err) = self.rehydrate_scope(scope, &mut Some(first.clone()), state, dom) {
+            if cfg!(debug_assertions) {
+                log::warn!(
+                    "Hydration mismatch inside {}: {err}. Rendering it on the client instead",
+                    describe(&first)
+                );
+            }
+            let mut rendered = Vec::new();
+            let created = match scope.root_node() {
+                RenderReturn::Ready(vnode) => self.create_vnode(state, dom, vnode, &mut rendered),
+                _ => Err(VNodeNotInitialized),
+            };
+            if let (Ok(()), Some(parent)) = (created, first.parent_node()) {
+                for node in &rendered {
+                    let _ = parent.insert_before(node, Some(&first));
+                }
+                for node in &nodes {
+                    let _ = parent.remove_child(node);
+                }
+            }
+        }
+
+        self.interpreter.flush();
+        Self::defer(&pending.unhydrated, &mut pending.state);
+    }
+
+    fn register_listeners(&mut self, listeners: Vec<(u32, String)>) {
+        let mut mounted = Vec::new();
+        for (id, name) in listeners {
+            // the listener is named like the event, with the options of the listener as suffixes
+            let event = name[2..]
+                .trim_end_matches("_prevent_default")
+                .trim_end_matches("_capture");
+            if event == "mounted" {
+                mounted.push(ElementId(id as usize));
+                continue;
+            }
+            self.interpreter
+                .new_event_listener(event, id, event_bubbles(event) as u8);
+            // the server doesn't render listeners, so mark the ones that always prevent the default like creating them does
+            if name.ends_with("_prevent_default") {
+                self.interpreter.set_attribute(id, &name, "true", "");
+            }
+        }
+
+        self.interpreter.flush();
+
+        for id in mounted {
+            self.send_mount_event(id);
+        }
+    }
+
     fn rehydrate_scope(
         &mut self,
         scope: &ScopeState,
-        current_child: &mut Result<Node, RehydrationError>,
-        hydrated: &mut Vec<bool>,
+        current_child: &mut Option<Node>,
+        state: &mut Hydration,
         dom: &VirtualDom,
-        last_node_was_static_text: &mut bool,
     ) -> Result<(), RehydrationError> {
         let vnode = match scope.root_node() {
-            dioxus_core::RenderReturn::Ready(ready) => ready,
+            RenderReturn::Ready(ready) => ready,
             _ => return Err(VNodeNotInitialized),
         };
-        self.rehydrate_vnode(
-            current_child,
-            hydrated,
-            dom,
-            vnode,
-            last_node_was_static_text,
-        )
+        self.rehydrate_vnode(current_child, state, dom, vnode)
     }
 
     fn rehydrate_vnode(
         &mut self,
-        current_child: &mut Result<Node, RehydrationError>,
-        hydrated: &mut Vec<bool>,
+        current_child: &mut Option<Node>,
+        state: &mut Hydration,
         dom: &VirtualDom,
         vnode: &VNode,
-        last_node_was_static_text: &mut bool,
     ) -> Result<(), RehydrationError> {
         for (i, root) in vnode.template.get().roots.iter().enumerate() {
+            // static text roots are wrapped in markers so they don't get merged with the nodes around them
+            let text_root = matches!(root, TemplateNode::Text { .. });
+            if text_root {
+                skip_marker(current_child)?;
+                state.last_node_was_static_text = false;
+            }
+
             // make sure we set the root node ids even if the node is not dynamic
-            set_node(
-                hydrated,
-                vnode.root_ids.get(i).ok_or(VNodeNotInitialized)?,
-                current_child.clone()?,
-            );
+            let id = vnode.root_ids.get(i).ok_or(VNodeNotInitialized)?;
+            if let Some(node) = current_child.clone() {
+                state.set_node(id, node);
+            }
 
-            self.rehydrate_template_node(
-                current_child,
-                hydrated,
-                dom,
-                vnode,
-                root,
-                last_node_was_static_text,
-            )?;
+            self.rehydrate_template_node(current_child, state, dom, vnode, root)?;
+
+            if text_root {
+                skip_marker(current_child)?;
+                state.last_node_was_static_text = false;
+            }
         }
         Ok(())
     }
 
     fn rehydrate_template_node(
         &mut self,
-        current_child: &mut Result<Node, RehydrationError>,
-        hydrated: &mut Vec<bool>,
+        current_child: &mut Option<Node>,
+        state: &mut Hydration,
         dom: &VirtualDom,
         vnode: &VNode,
         node: &TemplateNode,
-        last_node_was_static_text: &mut bool,
     ) -> Result<(), RehydrationError> {
         match node {
             TemplateNode::Element {
-                children, attrs, ..
+                tag,
+                children,
+                attrs,
+                ..
             } => {
+                let expected = || format!("<{tag}>");
+                let node = expect_node(current_child, expected)?;
+                let element = match node.dyn_ref::<Element>() {
+                    Some(element) if element.tag_name().eq_ignore_ascii_case(tag) => element,
+                    _ => return Err(mismatch(expected(), &node)),
+                };
+
                 let mut mounted_id = None;
+                let mut patches = Vec::new();
                 for attr in *attrs {
-                    if let dioxus_core::TemplateAttribute::Dynamic { id } = attr {
+                    if let TemplateAttribute::Dynamic { id } = attr {
                         let attribute = &vnode.dynamic_attrs[*id];
-                        let value = &attribute.value;
                         let id = attribute.mounted_element.get();
                         mounted_id = Some(id);
-                        let name = attribute.name;
-                        if let AttributeValue::Listener(_) = value {
-                            self.interpreter.new_event_listener(
-                                &name[2..],
-                                id.0 as u32,
-                                event_bubbles(name) as u8,
-                            );
+                        let value = match &attribute.value {
+                            AttributeValue::Text(text) => Some(text.to_string()),
+                            AttributeValue::Float(f) => Some(f.to_string()),
+                            AttributeValue::Int(n) => Some(n.to_string()),
+                            AttributeValue::Bool(b) => Some(b.to_string()),
+                            AttributeValue::None => None,
+                            AttributeValue::Listener(_) => {
+                                state
+                                    .listeners
+                                    .push((id.0 as u32, attribute.name.to_string()));
+                                continue;
+                            }
+                            AttributeValue::Any(_) => continue,
+                        };
+                        // the server renders styles like plain attributes, which doesn't apply them, and skips numbers
+                        let is_style = attribute.namespace == Some("style");
+                        let is_number = matches!(
+                            attribute.value,
+                            AttributeValue::Float(_) | AttributeValue::Int(_)
+                        );
+                        if is_style || element.get_attribute(attribute.name) != value {
+                            if !is_style && !is_number {
+                                warn_patched(|| {
+                                    format!("attribute {} of {}", attribute.name, describe(&node))
+                                });
+                            }
+                            patches.push((attribute.name, value, attribute.namespace));
                         }
                     }
                 }
                 if let Some(id) = mounted_id {
-                    set_node(hydrated, id, current_child.clone()?);
+                    // pre-rendered elements carry the id the server gave them, which must be the one the client picked
+                    if let Some(hydration_id) = element.get_attribute("data-node-hydration") {
+                        if hydration_id != id.0.to_string() {
+                            return Err(mismatch(
+                                format!("<{tag} data-node-hydration=\"{}\">", id.0),
+                                &node,
+                            ));
+                        }
+                    }
+                    state.set_node(id, node.clone());
+
+                    // The interpreter knows the attributes that are properties, like `value` and `checked`
+                    for (name, value, ns) in patches {
+                        let ns = ns.unwrap_or_default();
+                        match value {
+                            Some(value) => {
+                                self.interpreter
+                                    .set_attribute(id.0 as u32, name, &value, ns)
+                            }
+                            None => self.interpreter.remove_attribute(id.0 as u32, name, ns),
+                        }
+                    }
                 }
+
                 if !children.is_empty() {
-                    let mut children_current_child = current_child
-                        .as_mut()
-                        .map_err(|e| *e)?
-                        .first_child()
-                        .ok_or(NodeNotFound)?
-                        .dyn_into::<Node>()
-                        .map_err(|_| NodeTypeMismatch);
-                    for child in *children {
+                    let mut children_current_child = node.first_child();
+                    state.last_node_was_static_text = false;
+                    let result = children.iter().try_for_each(|child| {
                         self.rehydrate_template_node(
                             &mut children_current_child,
-                            hydrated,
+                            state,
                             dom,
                             vnode,
                             child,
-                            last_node_was_static_text,
-                        )?;
+                        )
+                    });
+                    if let Err(err) = result {
+                        self.render_children(element, children, err, state, dom, vnode)?;
                     }
                 }
-                *current_child = current_child
-                    .as_mut()
-                    .map_err(|e| *e)?
-                    .next_sibling()
-                    .ok_or(NodeNotFound);
-                *last_node_was_static_text = false;
+
+                advance(current_child);
+                state.last_node_was_static_text = false;
             }
-            TemplateNode::Text { .. } => {
+            TemplateNode::Text { text } => {
                 // if the last node was static text, it got merged with this one
-                if !*last_node_was_static_text {
-                    *current_child = current_child
-                        .as_mut()
-                        .map_err(|e| *e)?
-                        .next_sibling()
-                        .ok_or(NodeNotFound);
+                if !state.last_node_was_static_text {
+                    let expected = || format!("text {text:?}");
+                    let node = expect_node(current_child, expected)?;
+                    let matches = node.has_type::<Text>()
+                        && node.text_content().unwrap_or_default().starts_with(text);
+                    if !matches {
+                        return Err(mismatch(expected(), &node));
+                    }
+                    advance(current_child);
                 }
-                *last_node_was_static_text = true;
+                state.last_node_was_static_text = true;
             }
             TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => {
-                self.rehydrate_dynamic_node(
-                    current_child,
-                    hydrated,
-                    dom,
-                    &vnode.dynamic_nodes[*id],
-                    last_node_was_static_text,
-                )?;
+                self.rehydrate_dynamic_node(current_child, state, dom, &vnode.dynamic_nodes[*id])?;
             }
         }
         Ok(())
@@ -191,85 +688,234 @@ impl WebsysDom {
 
     fn rehydrate_dynamic_node(
         &mut self,
-        current_child: &mut Result<Node, RehydrationError>,
-        hydrated: &mut Vec<bool>,
+        current_child: &mut Option<Node>,
+        state: &mut Hydration,
         dom: &VirtualDom,
         dynamic: &DynamicNode,
-        last_node_was_static_text: &mut bool,
     ) -> Result<(), RehydrationError> {
         match dynamic {
-            dioxus_core::DynamicNode::Text(VText { id, .. }) => {
+            DynamicNode::Text(VText { id, value }) => {
                 // skip comment separator before node
-                if cfg!(debug_assertions) {
-                    assert!(current_child
-                        .as_mut()
-                        .map_err(|e| *e)?
-                        .has_type::<Comment>());
+                skip_marker(current_child)?;
+
+                // Empty text leaves nothing between the markers
+                let expected = || format!("text {value:?}");
+                let node = expect_node(current_child, expected)?;
+                let text: Node = if node.has_type::<Text>() {
+                    node
+                } else if value.is_empty() && node.has_type::<Comment>() {
+                    let text = self.document.create_text_node("");
+                    node.parent_node()
+                        .and_then(|parent| parent.insert_before(&text, Some(&node)).ok())
+                        .ok_or_else(|| mismatch(expected(), &node))?
+                } else {
+                    return Err(mismatch(expected(), &node));
+                };
+
+                if text.text_content().as_deref() != Some(*value) {
+                    warn_patched(|| format!("text {value:?}"));
+                    text.set_text_content(Some(value));
                 }
-                *current_child = current_child
-                    .as_mut()
-                    .map_err(|e| *e)?
-                    .next_sibling()
-                    .ok_or(NodeNotFound);
-
-                set_node(
-                    hydrated,
-                    id.get().ok_or(VNodeNotInitialized)?,
-                    current_child.clone()?,
-                );
-                *current_child = current_child
-                    .as_mut()
-                    .map_err(|e| *e)?
-                    .next_sibling()
-                    .ok_or(NodeNotFound);
+
+                state.set_node(id.get().ok_or(VNodeNotInitialized)?, text.clone());
+                *current_child = text.next_sibling();
 
                 // skip comment separator after node
-                if cfg!(debug_assertions) {
-                    assert!(current_child
-                        .as_mut()
-                        .map_err(|e| *e)?
-                        .has_type::<Comment>());
-                }
-                *current_child = current_child
-                    .as_mut()
-                    .map_err(|e| *e)?
-                    .next_sibling()
-                    .ok_or(NodeNotFound);
+                skip_marker(current_child)?;
 
-                *last_node_was_static_text = false;
+                state.last_node_was_static_text = false;
             }
-            dioxus_core::DynamicNode::Placeholder(VPlaceholder { id, .. }) => {
-                set_node(
-                    hydrated,
-                    id.get().ok_or(VNodeNotInitialized)?,
-                    current_child.clone()?,
-                );
-                *current_child = current_child
-                    .as_mut()
-                    .map_err(|e| *e)?
-                    .next_sibling()
-                    .ok_or(NodeNotFound);
-                *last_node_was_static_text = false;
+            DynamicNode::Placeholder(VPlaceholder { id, .. }) => {
+                let expected = || "a <pre hidden> placeholder".to_string();
+                let node = expect_node(current_child, expected)?;
+                if !matches!(node.dyn_ref::<Element>(), Some(el) if el.tag_name().eq_ignore_ascii_case("pre"))
+                {
+                    return Err(mismatch(expected(), &node));
+                }
+                state.set_node(id.get().ok_or(VNodeNotInitialized)?, node);
+                advance(current_child);
+                state.last_node_was_static_text = false;
             }
-            dioxus_core::DynamicNode::Component(comp) => {
-                let scope = comp.scope.get().ok_or(VNodeNotInitialized)?;
-                self.rehydrate_scope(
-                    dom.get_scope(scope).unwrap(),
-                    current_child,
-                    hydrated,
-                    dom,
-                    last_node_was_static_text,
-                )?;
+            DynamicNode::Component(comp) => {
+                let scope = dom
+                    .get_scope(comp.scope.get().ok_or(VNodeNotInitialized)?)
+                    .unwrap();
+
+                // skip the nodes of the component for now, if it's clear which nodes they are
+                if let (Some(deferred), RenderReturn::Ready(vnode)) =
+                    (&mut state.deferred, scope.root_node())
+                {
+                    let nodes = span(vnode, dom).and_then(|count| take_nodes(current_child, count));
+                    if let Some(nodes) = nodes {
+                        deferred.push((scope.scope_id(), nodes));
+                        state.last_node_was_static_text = false;
+                        return Ok(());
+                    }
+                }
+
+                self.rehydrate_scope(scope, current_child, state, dom)?;
             }
-            dioxus_core::DynamicNode::Fragment(fragment) => {
+            DynamicNode::Fragment(fragment) => {
                 for vnode in *fragment {
-                    self.rehydrate_vnode(
-                        current_child,
-                        hydrated,
-                        dom,
-                        vnode,
-                        last_node_was_static_text,
-                    )?;
+                    self.rehydrate_vnode(current_child, state, dom, vnode)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the children of an element that don't match the app with children rendered on the client
+    fn render_children(
+        &mut self,
+        element: &Element,
+        children: &[TemplateNode],
+        err: RehydrationError,
+        state: &mut Hydration,
+        dom: &VirtualDom,
+        vnode: &VNode,
+    ) -> Result<(), RehydrationError> {
+        if cfg!(debug_assertions) {
+            log::warn!(
+                "Hydration mismatch inside {}: {err}. Rendering its children on the client instead",
+                describe(element)
+            );
+        }
+
+        element.set_text_content(None);
+        let mut nodes = Vec::new();
+        for child in children {
+            self.hydrate_template_node(state, dom, vnode, child, &mut nodes)?;
+        }
+        for node in nodes {
+            let _ = element.append_child(&node);
+        }
+        Ok(())
+    }
+
+    fn create_vnode(
+        &mut self,
+        state: &mut Hydration,
+        dom: &VirtualDom,
+        vnode: &VNode,
+        nodes: &mut Vec<Node>,
+    ) -> Result<(), RehydrationError> {
+        for (i, root) in vnode.template.get().roots.iter().enumerate() {
+            let first = nodes.len();
+            self.hydrate_template_node(state, dom, vnode, root, nodes)?;
+            if let Some(node) = nodes.get(first) {
+                let id = vnode.root_ids.get(i).ok_or(VNodeNotInitialized)?;
+                state.replace_node(id, node.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Render a node of a template on the client, with the current values of its dynamic parts
+    fn hydrate_template_node(
+        &mut self,
+        state: &mut Hydration,
+        dom: &VirtualDom,
+        vnode: &VNode,
+        node: &TemplateNode,
+        nodes: &mut Vec<Node>,
+    ) -> Result<(), RehydrationError> {
+        match node {
+            TemplateNode::Element {
+                tag,
+                namespace,
+                attrs,
+                children,
+            } => {
+                let element = match namespace {
+                    Some(ns) => self.document.create_element_ns(Some(ns), tag).unwrap(),
+                    None => self.document.create_element(tag).unwrap(),
+                };
+
+                for attr in *attrs {
+                    let (id, name, value, ns) = match attr {
+                        TemplateAttribute::Static {
+                            name,
+                            value,
+                            namespace,
+                        } => {
+                            match namespace {
+                                Some(ns) => element.set_attribute_ns(Some(ns), name, value),
+                                None => element.set_attribute(name, value),
+                            }
+                            .unwrap();
+                            continue;
+                        }
+                        TemplateAttribute::Dynamic { id } => {
+                            let attribute = &vnode.dynamic_attrs[*id];
+                            let id = attribute.mounted_element.get();
+                            state.replace_node(id, element.clone().into());
+                            let value = match &attribute.value {
+                                AttributeValue::Text(text) => text.to_string(),
+                                AttributeValue::Float(f) => f.to_string(),
+                                AttributeValue::Int(n) => n.to_string(),
+                                AttributeValue::Bool(b) => b.to_string(),
+                                AttributeValue::Listener(_) => {
+                                    state
+                                        .listeners
+                                        .push((id.0 as u32, attribute.name.to_string()));
+                                    continue;
+                                }
+                                AttributeValue::Any(_) | AttributeValue::None => continue,
+                            };
+                            (id, attribute.name, value, attribute.namespace)
+                        }
+                    };
+                    // The interpreter knows the attributes that are properties, like `value` and `checked`
+                    self.interpreter.set_attribute(
+                        id.0 as u32,
+                        name,
+                        &value,
+                        ns.unwrap_or_default(),
+                    );
+                }
+
+                let mut child_nodes = Vec::new();
+                for child in *children {
+                    self.hydrate_template_node(state, dom, vnode, child, &mut child_nodes)?;
+                }
+                for child in child_nodes {
+                    let _ = element.append_child(&child);
+                }
+
+                nodes.push(element.into());
+            }
+            TemplateNode::Text { text } => nodes.push(self.document.create_text_node(text).into()),
+            TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => {
+                match &vnode.dynamic_nodes[*id] {
+                    DynamicNode::Text(VText { id, value }) => {
+                        let text: Node = self.document.create_text_node(value).into();
+                        state.replace_node(id.get().ok_or(VNodeNotInitialized)?, text.clone());
+                        nodes.push(text);
+                    }
+                    DynamicNode::Placeholder(VPlaceholder { id, .. }) => {
+                        let placeholder = self.document.create_element("pre").unwrap();
+                        let _ = placeholder.toggle_attribute("hidden");
+                        let placeholder: Node = placeholder.into();
+                        state.replace_node(
+                            id.get().ok_or(VNodeNotInitialized)?,
+                            placeholder.clone(),
+                        );
+                        nodes.push(placeholder);
+                    }
+                    DynamicNode::Component(comp) => {
+                        let scope = comp.scope.get().ok_or(VNodeNotInitialized)?;
+                        match dom.get_scope(scope).unwrap().root_node() {
+                            RenderReturn::Ready(vnode) => {
+                                self.create_vnode(state, dom, vnode, nodes)?
+                            }
+                            _ => return Err(VNodeNotInitialized),
+                        }
+                    }
+                    DynamicNode::Fragment(fragment) => {
+                        for vnode in *fragment {
+                            self.create_vnode(state, dom, vnode, nodes)?;
+                        }
+                    }
                 }
             }
         }
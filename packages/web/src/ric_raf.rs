@@ -1,52 +1,34 @@
 //! This module provides some utilities around scheduling tasks on the main thread of the browser.
 //!
-//! The ultimate goal here is to not block the main thread during animation frames, so our animations don't result in "jank".
-//!
-//! Hence, this module provides Dioxus "Jank Free Rendering" on the web.
+//! The ultimate goal here is to not block the main thread while the user is interacting with the page, so low priority
+//! work doesn't make input handling or animations "janky".
 //!
 //! Because RIC doesn't work on Safari, we polyfill using the "ricpolyfill.js" file and use some basic detection to see
 //! if RIC is available.
 
 use futures_util::StreamExt;
-use gloo_timers::future::TimeoutFuture;
 use js_sys::Function;
+use std::time::Duration;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
-use web_sys::{window, Window};
+use web_sys::{window, IdleRequestOptions, Window};
 
-pub(crate) struct RafLoop {
+/// Waits for the browser to be idle, through `requestIdleCallback`
+pub(crate) struct IdleCallback {
     window: Window,
-    ric_receiver: futures_channel::mpsc::UnboundedReceiver<u32>,
-    raf_receiver: futures_channel::mpsc::UnboundedReceiver<()>,
+    options: IdleRequestOptions,
+    ric_receiver: futures_channel::mpsc::UnboundedReceiver<()>,
     ric_closure: Closure<dyn Fn(JsValue)>,
-    raf_closure: Closure<dyn Fn(JsValue)>,
+    requested: bool,
 }
 
-impl RafLoop {
-    pub fn new() -> Self {
-        let (raf_sender, raf_receiver) = futures_channel::mpsc::unbounded();
-
-        let raf_closure: Closure<dyn Fn(JsValue)> = Closure::wrap(Box::new(move |_v: JsValue| {
-            raf_sender.unbounded_send(()).unwrap()
-        }));
-
+impl IdleCallback {
+    /// The callback fires once the timeout has passed even if the browser never becomes idle, so the work still
+    /// happens on a busy page
+    pub fn new(timeout: Duration) -> Self {
         let (ric_sender, ric_receiver) = futures_channel::mpsc::unbounded();
 
-        let has_idle_callback = {
-            let bo = window().unwrap().dyn_into::<js_sys::Object>().unwrap();
-            bo.has_own_property(&JsValue::from_str("requestIdleCallback"))
-        };
-        let ric_closure: Closure<dyn Fn(JsValue)> = Closure::wrap(Box::new(move |v: JsValue| {
-            let time_remaining = if has_idle_callback {
-                if let Ok(deadline) = v.dyn_into::<web_sys::IdleDeadline>() {
-                    deadline.time_remaining() as u32
-                } else {
-                    10
-                }
-            } else {
-                10
-            };
-
-            ric_sender.unbounded_send(time_remaining).unwrap()
+        let ric_closure: Closure<dyn Fn(JsValue)> = Closure::wrap(Box::new(move |_: JsValue| {
+            _ = ric_sender.unbounded_send(());
         }));
 
         // execute the polyfill for safari
@@ -54,27 +36,32 @@ impl RafLoop {
             .call0(&JsValue::NULL)
             .unwrap();
 
-        let window = web_sys::window().unwrap();
+        let mut options = IdleRequestOptions::new();
+        options.timeout(timeout.as_millis() as u32);
 
         Self {
-            window,
-            raf_receiver,
-            raf_closure,
+            window: window().unwrap(),
+            options,
             ric_receiver,
             ric_closure,
+            requested: false,
         }
     }
-    /// waits for some idle time and returns a timeout future that expires after the idle time has passed
-    pub async fn wait_for_idle_time(&mut self) -> TimeoutFuture {
-        let ric_fn = self.ric_closure.as_ref().dyn_ref::<Function>().unwrap();
-        let _cb_id: u32 = self.window.request_idle_callback(ric_fn).unwrap();
-        let deadline = self.ric_receiver.next().await.unwrap();
-        TimeoutFuture::new(deadline)
-    }
 
-    pub async fn wait_for_raf(&mut self) {
-        let raf_fn = self.raf_closure.as_ref().dyn_ref::<Function>().unwrap();
-        let _id: i32 = self.window.request_animation_frame(raf_fn).unwrap();
-        self.raf_receiver.next().await.unwrap();
+    /// Wait for the browser to be idle
+    ///
+    /// This is cancel-safe: if the future is dropped, the pending callback is picked up by the next call.
+    pub async fn wait_for_idle_time(&mut self) {
+        if !self.requested {
+            let ric_fn = self.ric_closure.as_ref().dyn_ref::<Function>().unwrap();
+            let _cb_id: u32 = self
+                .window
+                .request_idle_callback_with_options(ric_fn, &self.options)
+                .unwrap();
+            self.requested = true;
+        }
+
+        self.ric_receiver.next().await;
+        self.requested = false;
     }
 }
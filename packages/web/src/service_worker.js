@@ -0,0 +1,60 @@
+// The service worker registered by `Config::with_service_worker`. Serve it from the root of the site, like
+// `/sw.js`, so it controls every page.
+//
+// Requests go to the network first, and fall back to the cache when the network is unavailable. The app sends the
+// files that should work offline once it starts.
+const CACHE = `dioxus-precache:${self.location.href}`;
+
+self.addEventListener("activate", (event) => {
+  // a new version of the worker starts with a new cache
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith("dioxus-precache:") && key !== CACHE)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("message", (event) => {
+  const message = event.data || {};
+  if (message.type === "dioxus-precache") {
+    event.waitUntil(
+      caches.open(CACHE).then((cache) => cache.addAll(message.urls))
+    );
+  } else if (message.type === "dioxus-skip-waiting") {
+    self.skipWaiting();
+  }
+});
+
+self.addEventListener("fetch", (event) => {
+  if (event.request.method !== "GET") {
+    return;
+  }
+
+  event.respondWith(
+    caches.open(CACHE).then((cache) =>
+      fetch(event.request)
+        .then((response) => {
+          // keep the files that work offline up to date
+          if (response.ok) {
+            const copy = response.clone();
+            cache.match(event.request).then((cached) => {
+              if (cached !== undefined) {
+                cache.put(event.request, copy);
+              }
+            });
+          }
+          return response;
+        })
+        .catch(() =>
+          cache.match(event.request).then((cached) => cached || Response.error())
+        )
+    )
+  );
+});
@@ -0,0 +1,254 @@
+//! Make the app work offline and tell it when a new version was deployed, through a service worker
+//!
+//! ```rust, ignore
+//! dioxus_web::launch_cfg(
+//!     app,
+//!     Config::new()
+//!         .with_service_worker("/sw.js?v=1.2.0")
+//!         .with_precache(["*.wasm", "*.js", "/assets/*"]),
+//! );
+//!
+//! fn app(cx: Scope) -> Element {
+//!     let worker = use_service_worker(cx);
+//!
+//!     cx.render(rsx! {
+//!         if let Some(worker) = worker.filter(|worker| worker.update_available()) {
+//!             rsx! { button { onclick: move |_| worker.update(), "A new version is available, refresh" } }
+//!         }
+//!     })
+//! }
+//! ```
+
+use dioxus_core::ScopeState;
+use js_sys::{Array, Object, Reflect};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::Arc;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ServiceWorkerContainer, ServiceWorkerRegistration, ServiceWorkerState};
+
+/// A service worker that caches the files added with [`crate::Config::with_precache`] and serves them when the
+/// network is unavailable.
+///
+/// Serve it from the root of the site, like `/sw.js`, and register it with
+/// [`crate::Config::with_service_worker`].
+pub const SERVICE_WORKER_JS: &str = include_str!("./service_worker.js");
+
+/// The service worker of the app, provided to the app by [`crate::Config::with_service_worker`]
+pub struct ServiceWorker {
+    waiting: RefCell<Option<web_sys::ServiceWorker>>,
+    subscribers: RefCell<Vec<Arc<dyn Fn()>>>,
+    reloading: Cell<bool>,
+}
+
+impl ServiceWorker {
+    /// Check if a new version of the service worker is installed and waiting for the page to refresh
+    ///
+    /// The browser looks for a new version when the page loads, so change the url of the service worker, like
+    /// `/sw.js?v=1.2.1`, with every deploy.
+    pub fn update_available(&self) -> bool {
+        self.waiting.borrow().is_some()
+    }
+
+    /// Switch to the new version of the service worker, and reload the page once it took over
+    pub fn update(&self) {
+        let waiting = match self.waiting.borrow().clone() {
+            Some(waiting) => waiting,
+            None => return,
+        };
+        self.reloading.set(true);
+        let _ = waiting.post_message(&message("dioxus-skip-waiting"));
+    }
+
+    fn set_waiting(&self, worker: web_sys::ServiceWorker) {
+        self.waiting.replace(Some(worker));
+        for subscriber in self.subscribers.borrow().iter() {
+            subscriber();
+        }
+    }
+}
+
+/// Get the service worker of the app, and render again when a new version of it is available
+///
+/// Returns `None` if the app wasn't launched with [`crate::Config::with_service_worker`].
+pub fn use_service_worker(cx: &ScopeState) -> Option<Rc<ServiceWorker>> {
+    cx.use_hook(|| {
+        let worker = cx.consume_context::<Rc<ServiceWorker>>()?;
+        worker.subscribers.borrow_mut().push(cx.schedule_update());
+        Some(worker)
+    })
+    .clone()
+}
+
+/// Register the service worker at `url`, and precache the files loaded so far that match `precache`
+pub(crate) fn register(url: &str, precache: Vec<String>) -> Option<Rc<ServiceWorker>> {
+    let window = web_sys::window()?;
+    let container = window.navigator().service_worker();
+    // browsers without service workers, or pages that aren't served over https
+    if JsValue::from(container.clone()).is_undefined() {
+        log::warn!("Could not register the service worker {url:?}: this browser doesn't support service workers");
+        return None;
+    }
+
+    let worker = Rc::new(ServiceWorker {
+        waiting: RefCell::new(None),
+        subscribers: RefCell::new(Vec::new()),
+        reloading: Cell::new(false),
+    });
+
+    // the new version took over after update, so the page can load its files
+    let on_controller_change: Closure<dyn FnMut()> = Closure::wrap(Box::new({
+        let worker = worker.clone();
+        move || {
+            if worker.reloading.replace(false) {
+                let _ = window.location().reload();
+            }
+        }
+    }));
+    container.set_oncontrollerchange(Some(on_controller_change.as_ref().unchecked_ref()));
+    on_controller_change.forget();
+
+    let registration = container.register(url);
+    let url = url.to_string();
+    wasm_bindgen_futures::spawn_local({
+        let worker = worker.clone();
+        async move {
+            match JsFuture::from(registration).await {
+                Ok(registration) => {
+                    watch_updates(&container, registration.unchecked_into(), worker);
+                    if !precache.is_empty() {
+                        send_precache(&container, &precache).await;
+                    }
+                }
+                Err(err) => log::error!("Could not register the service worker {url:?}: {err:?}"),
+            }
+        }
+    });
+
+    Some(worker)
+}
+
+fn watch_updates(
+    container: &ServiceWorkerContainer,
+    registration: ServiceWorkerRegistration,
+    worker: Rc<ServiceWorker>,
+) {
+    // without a controller, this is the first install and there is nothing to update
+    let has_controller = {
+        let container = container.clone();
+        move || container.controller().is_some()
+    };
+
+    if let Some(waiting) = registration.waiting() {
+        if has_controller() {
+            worker.set_waiting(waiting);
+        }
+    }
+
+    let on_update_found: Closure<dyn FnMut()> = Closure::wrap(Box::new({
+        let registration = registration.clone();
+        move || {
+            let installing = match registration.installing() {
+                Some(installing) => installing,
+                None => return,
+            };
+            let on_state_change: Closure<dyn FnMut()> = Closure::wrap(Box::new({
+                let installing = installing.clone();
+                let worker = worker.clone();
+                let has_controller = has_controller.clone();
+                move || {
+                    if installing.state() == ServiceWorkerState::Installed && has_controller() {
+                        worker.set_waiting(installing.clone());
+                    }
+                }
+            }));
+            installing.set_onstatechange(Some(on_state_change.as_ref().unchecked_ref()));
+            on_state_change.forget();
+        }
+    }));
+    registration.set_onupdatefound(Some(on_update_found.as_ref().unchecked_ref()));
+    on_update_found.forget();
+}
+
+/// Send the files loaded so far that match the patterns to the worker, once it is active
+async fn send_precache(container: &ServiceWorkerContainer, patterns: &[String]) {
+    let ready = match container.ready() {
+        Ok(ready) => ready,
+        Err(_) => return,
+    };
+    let registration: ServiceWorkerRegistration = match JsFuture::from(ready).await {
+        Ok(registration) => registration.unchecked_into(),
+        Err(_) => return,
+    };
+    let active = match registration.active() {
+        Some(active) => active,
+        None => return,
+    };
+
+    let urls = Array::new();
+    for url in loaded_urls() {
+        if patterns.iter().any(|pattern| matches(pattern, &url)) {
+            urls.push(&url.into());
+        }
+    }
+
+    let msg = message("dioxus-precache");
+    let _ = Reflect::set(&msg, &"urls".into(), &urls);
+    let _ = active.post_message(&msg);
+}
+
+/// The page and the same origin files it loaded, as paths
+fn loaded_urls() -> Vec<String> {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return Vec::new(),
+    };
+    let location = window.location();
+    let origin = location.origin().unwrap_or_default();
+
+    let mut urls = vec![location.pathname().unwrap_or_default()];
+    if let Some(performance) = window.performance() {
+        for entry in performance.get_entries_by_type("resource").iter() {
+            let entry: web_sys::PerformanceEntry = entry.unchecked_into();
+            if let Some(path) = entry.name().strip_prefix(&origin) {
+                urls.push(path.to_string());
+            }
+        }
+    }
+    urls
+}
+
+/// Match a path against a pattern where `*` stands for anything, like `*.wasm` or `/assets/*`
+fn matches(pattern: &str, path: &str) -> bool {
+    let path = path
+        .split(|c| c == '?' || c == '#')
+        .next()
+        .unwrap_or_default();
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match path.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let parts: Vec<_> = parts.collect();
+    let (last, middle) = match parts.split_last() {
+        Some(split) => split,
+        // no `*`, so the whole path must match
+        None => return rest.is_empty(),
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+fn message(kind: &str) -> JsValue {
+    let message = Object::new();
+    let _ = Reflect::set(&message, &"type".into(), &kind.into());
+    message.into()
+}
@@ -70,3 +70,25 @@ impl IntoFuture for EvalResult {
         std::future::ready(self.value)
     }
 }
+
+/// Get the `web_sys::Element` an `onmounted` listener is attached to.
+///
+/// ```rust, ignore
+/// div {
+///     onmounted: move |evt| {
+///         if let Some(element) = evt.web_element() {
+///             let _ = element.scroll_into_view();
+///         }
+///     },
+/// }
+/// ```
+pub trait WebMountedExt {
+    /// The element, or `None` if the event wasn't sent by the web renderer
+    fn web_element(&self) -> Option<&web_sys::Element>;
+}
+
+impl WebMountedExt for dioxus_html::MountedData {
+    fn web_element(&self) -> Option<&web_sys::Element> {
+        self.downcast()
+    }
+}
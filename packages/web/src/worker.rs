@@ -0,0 +1,592 @@
+//! Run the VirtualDom in a Web Worker, keeping the main thread free to handle input and paint
+//!
+//! The app is loaded twice: in the page, where it only applies edits and forwards events, and in a worker, where the
+//! VirtualDom runs. The worker sends the edits of each render as one binary buffer, and the page sends the events back
+//! as JSON.
+//!
+//! ```rust, ignore
+//! fn main() {
+//!     if dioxus_web::in_worker() {
+//!         dioxus_web::run_in_worker(app);
+//!     } else {
+//!         dioxus_web::launch_in_worker("./worker.js", Config::new());
+//!     }
+//! }
+//! ```
+//!
+//! The worker script loads the same bundle as the page. With the `no-modules` target of wasm-bindgen, `worker.js` is:
+//!
+//! ```js
+//! importScripts("./app.js");
+//! wasm_bindgen("./app_bg.wasm");
+//! ```
+//!
+//! The app can't reach the DOM from the worker, so hooks that need it, like `use_eval` and the global listeners, are
+//! not available, and the files of form and drag events stay in the page.
+
+use crate::dom::WebsysDom;
+use crate::Config;
+use dioxus_core::{
+    BorrowedAttributeValue, Element, ElementId, Mutation, Mutations, Scope, Template, VirtualDom,
+};
+use dioxus_html::{event_bubbles, events::*, HtmlEvent};
+use futures_util::{pin_mut, FutureExt, StreamExt};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::convert::TryInto;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker};
+
+/// `true` when running in a Web Worker rather than in the page
+pub fn in_worker() -> bool {
+    js_sys::global()
+        .dyn_into::<DedicatedWorkerGlobalScope>()
+        .is_ok()
+}
+
+/// Start the worker at `worker_url` and render the app it runs into the page.
+///
+/// The worker has to call [`run_in_worker`] with the app. The config is used in the page, for the root element and
+/// the panic hooks. Panics of the app happen in the worker, which reports them to the page: they are logged to the
+/// console of the page, and shown in the panic overlay if it's enabled.
+pub fn launch_in_worker(worker_url: &str, cfg: Config) {
+    #[cfg(feature = "panic_hook")]
+    if cfg.default_panic_hook {
+        console_error_panic_hook::set_once();
+    }
+
+    let panic_overlay = cfg.panic_overlay;
+    if panic_overlay {
+        crate::panic_overlay::install();
+    }
+
+    let worker = Worker::new(worker_url).expect("failed to start the worker");
+
+    let (tx, mut rx) = futures_channel::mpsc::unbounded();
+    // the VirtualDom lives in the worker, so events can only be sent to it
+    let mut websys_dom = WebsysDom::new(cfg, tx, None);
+
+    let onmessage: Closure<dyn FnMut(MessageEvent)> =
+        Closure::wrap(Box::new(move |event: MessageEvent| {
+            // the worker sends edits as buffers and panics as JSON
+            if let Some(json) = event.data().as_string() {
+                match serde_json::from_str::<WorkerPanic>(&json) {
+                    Ok(panic) => panic.report(panic_overlay),
+                    Err(err) => log::error!("Received an invalid message from the worker: {err}"),
+                }
+                return;
+            }
+
+            let message = js_sys::Uint8Array::new(&event.data()).to_vec();
+            if websys_dom.apply_encoded(&message).is_none() {
+                log::error!("Received invalid edits from the worker");
+            }
+        }));
+    worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        while let Some(event) = rx.next().await {
+            let event = WorkerEvent {
+                element: event.element,
+                data: serialize_event_data(&*event.data),
+                name: event.name,
+                bubbles: event.bubbles,
+            };
+            match serde_json::to_string(&event) {
+                Ok(json) => {
+                    let _ = worker.post_message(&JsValue::from_str(&json));
+                }
+                Err(err) => log::error!(
+                    "Could not send the {} event to the worker: {err}",
+                    event.name
+                ),
+            }
+        }
+    });
+}
+
+/// Run the app in this worker, rendering it into the page that called [`launch_in_worker`].
+pub fn run_in_worker(root: fn(Scope) -> Element) {
+    run_in_worker_with_props(root, ());
+}
+
+/// Run the app with root props in this worker, rendering it into the page that called [`launch_in_worker`].
+pub fn run_in_worker_with_props<T: 'static>(root: fn(Scope<T>) -> Element, root_props: T) {
+    wasm_bindgen_futures::spawn_local(run_worker(root, root_props));
+}
+
+async fn run_worker<T: 'static>(root: fn(Scope<T>) -> Element, root_props: T) {
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+
+    // Nobody looks at the console of the worker, so tell the page
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        let (message, location) = crate::panic_overlay::describe(info);
+        if let Ok(json) = serde_json::to_string(&WorkerPanic { message, location }) {
+            let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+            let _ = scope.post_message(&JsValue::from_str(&json));
+        }
+    }));
+
+    let (tx, mut rx) = futures_channel::mpsc::unbounded::<WorkerEvent>();
+    let onmessage: Closure<dyn FnMut(MessageEvent)> =
+        Closure::wrap(Box::new(move |event: MessageEvent| {
+            match event
+                .data()
+                .as_string()
+                .and_then(|json| serde_json::from_str(&json).ok())
+            {
+                Some(event) => {
+                    let _ = tx.unbounded_send(event);
+                }
+                None => log::error!("Received an invalid event from the page"),
+            }
+        }));
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+
+    let mut dom = VirtualDom::new_with_props(root, root_props);
+    let mut encoder = Encoder::default();
+
+    // the mutations come back with nothing - the page mounts them
+    post_edits(&scope, encoder.encode(&dom.rebuild(), true));
+
+    loop {
+        let mut res = {
+            let work = dom.wait_for_work().fuse();
+            pin_mut!(work);
+
+            futures_util::select! {
+                _ = work => None,
+                evt = rx.next() => evt,
+            }
+        };
+
+        // Dequeue all of the events from the channel in send order
+        while let Some(evt) = res {
+            let (name, data, element, bubbles) = evt.into_parts();
+            dom.handle_event(&name, data, element, bubbles);
+            res = rx.try_next().transpose().unwrap().ok();
+        }
+
+        let edits = dom.render_immediate();
+        if !edits.edits.is_empty() || !edits.templates.is_empty() || !edits.freed_ids.is_empty() {
+            post_edits(&scope, encoder.encode(&edits, false));
+        }
+    }
+}
+
+fn post_edits(scope: &DedicatedWorkerGlobalScope, message: Vec<u8>) {
+    // transfer the buffer instead of copying it
+    let message = js_sys::Uint8Array::from(&message[..]);
+    let transfer = js_sys::Array::of1(&message.buffer());
+    let _ = scope.post_message_with_transfer(&message, &transfer);
+}
+
+/// A panic of the app in the worker, sent to the page
+#[derive(Serialize, Deserialize)]
+struct WorkerPanic {
+    message: String,
+    location: Option<String>,
+}
+
+impl WorkerPanic {
+    fn report(self, overlay: bool) {
+        match &self.location {
+            Some(location) => log::error!(
+                "The app panicked in the worker: {} {location}",
+                self.message
+            ),
+            None => log::error!("The app panicked in the worker: {}", self.message),
+        }
+        if overlay {
+            crate::panic_overlay::show_message(&self.message, self.location.as_deref());
+        }
+    }
+}
+
+/// An event that happened in the page, sent to the worker
+#[derive(Serialize, Deserialize)]
+struct WorkerEvent {
+    element: ElementId,
+    name: String,
+    bubbles: bool,
+    // null for events without data
+    data: serde_json::Value,
+}
+
+impl WorkerEvent {
+    fn into_parts(self) -> (String, Rc<dyn Any>, ElementId, bool) {
+        let data: Rc<dyn Any> = if self.data.is_null() {
+            Rc::new(())
+        } else {
+            let event = serde_json::json!({
+                "element": self.element,
+                "name": self.name,
+                "bubbles": self.bubbles,
+                "data": self.data,
+            });
+            match serde_json::from_value::<HtmlEvent>(event) {
+                Ok(event) => event.data.into_any(),
+                Err(err) => {
+                    log::error!("Invalid data for the {} event: {err}", self.name);
+                    Rc::new(())
+                }
+            }
+        };
+        (self.name, data, self.element, self.bubbles)
+    }
+}
+
+/// Serialize the data the page read from an event, or null if it carries none
+fn serialize_event_data(data: &dyn Any) -> serde_json::Value {
+    macro_rules! serialize_as {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(data) = data.downcast_ref::<$ty>() {
+                    return serde_json::to_value(data).unwrap_or_default();
+                }
+            )*
+        };
+    }
+
+    serialize_as!(
+        MouseData,
+        ClipboardData,
+        CompositionData,
+        KeyboardData,
+        FocusData,
+        FormData,
+        DragData,
+        PointerData,
+        SelectionData,
+        TouchData,
+        ScrollData,
+        WheelData,
+        MediaData,
+        AnimationData,
+        TransitionData,
+        ToggleData,
+        ResizeData,
+        VisibleData
+    );
+
+    serde_json::Value::Null
+}
+
+// The opcodes of the edits in the buffers sent by the worker.
+//
+// A buffer starts with a byte that is 1 if the page should mount the edits, then the templates of the edits as JSON,
+// prefixed by their length. The edits follow, each an opcode and its operands: ids, counts and lengths of strings are
+// little endian u32s, paths are prefixed by their length as a u8, and template names are replaced by their index in
+// the order the templates were sent.
+const APPEND_CHILDREN: u8 = 0;
+const ASSIGN_ID: u8 = 1;
+const CREATE_PLACEHOLDER: u8 = 2;
+const CREATE_TEXT_NODE: u8 = 3;
+const HYDRATE_TEXT: u8 = 4;
+const LOAD_TEMPLATE: u8 = 5;
+const REPLACE_WITH: u8 = 6;
+const REPLACE_PLACEHOLDER: u8 = 7;
+const INSERT_AFTER: u8 = 8;
+const INSERT_BEFORE: u8 = 9;
+const SET_ATTRIBUTE: u8 = 10;
+const SET_TEXT: u8 = 11;
+const NEW_EVENT_LISTENER: u8 = 12;
+const REMOVE_EVENT_LISTENER: u8 = 13;
+const REMOVE: u8 = 14;
+const PUSH_ROOT: u8 = 15;
+// an id the VirtualDom freed, sent after the edits
+const FREE_ID: u8 = 16;
+
+// The kinds of values of SET_ATTRIBUTE
+const TEXT: u8 = 0;
+const FLOAT: u8 = 1;
+const INT: u8 = 2;
+const BOOL: u8 = 3;
+const NONE: u8 = 4;
+
+/// Encodes the mutations of the VirtualDom in the worker
+#[derive(Default)]
+struct Encoder {
+    // the index of each template sent to the page, which loads them in the same order
+    templates: FxHashMap<String, u32>,
+}
+
+impl Encoder {
+    fn encode(&mut self, mutations: &Mutations, mount: bool) -> Vec<u8> {
+        let mut buf = vec![mount as u8];
+
+        // every template is sent once, the page keeps the ones it loaded
+        let new_templates: Vec<&Template> = mutations
+            .templates
+            .iter()
+            .filter(|template| !self.templates.contains_key(template.name))
+            .collect();
+        let templates = if new_templates.is_empty() {
+            Vec::new()
+        } else {
+            serde_json::to_vec(&new_templates).unwrap()
+        };
+        for template in new_templates {
+            let index = self.templates.len() as u32;
+            self.templates.insert(template.name.to_string(), index);
+        }
+        write_u32(&mut buf, templates.len() as u32);
+        buf.extend_from_slice(&templates);
+
+        for edit in &mutations.edits {
+            self.encode_edit(&mut buf, edit);
+        }
+        for id in &mutations.freed_ids {
+            write_op(&mut buf, FREE_ID, &[id.0]);
+        }
+
+        buf
+    }
+
+    fn encode_edit(&self, buf: &mut Vec<u8>, edit: &Mutation) {
+        use Mutation::*;
+        match edit {
+            AppendChildren { id, m } => write_op(buf, APPEND_CHILDREN, &[id.0, *m]),
+            AssignId { path, id } => {
+                write_op(buf, ASSIGN_ID, &[id.0]);
+                write_path(buf, path);
+            }
+            CreatePlaceholder { id } => write_op(buf, CREATE_PLACEHOLDER, &[id.0]),
+            CreateTextNode { value, id } => {
+                write_op(buf, CREATE_TEXT_NODE, &[id.0]);
+                write_str(buf, value);
+            }
+            HydrateText { path, value, id } => {
+                write_op(buf, HYDRATE_TEXT, &[id.0]);
+                write_path(buf, path);
+                write_str(buf, value);
+            }
+            LoadTemplate { name, index, id } => match self.templates.get(*name) {
+                Some(template) => write_op(buf, LOAD_TEMPLATE, &[*template as usize, *index, id.0]),
+                None => {
+                    log::error!("The template {name} was loaded before it was sent to the page")
+                }
+            },
+            ReplaceWith { id, m } => write_op(buf, REPLACE_WITH, &[id.0, *m]),
+            ReplacePlaceholder { path, m } => {
+                write_op(buf, REPLACE_PLACEHOLDER, &[*m]);
+                write_path(buf, path);
+            }
+            InsertAfter { id, m } => write_op(buf, INSERT_AFTER, &[id.0, *m]),
+            InsertBefore { id, m } => write_op(buf, INSERT_BEFORE, &[id.0, *m]),
+            SetAttribute {
+                name,
+                value,
+                id,
+                ns,
+            } => {
+                write_op(buf, SET_ATTRIBUTE, &[id.0]);
+                write_str(buf, name);
+                write_str(buf, ns.unwrap_or_default());
+                match value {
+                    BorrowedAttributeValue::Text(text) => {
+                        buf.push(TEXT);
+                        write_str(buf, text);
+                    }
+                    BorrowedAttributeValue::Float(f) => {
+                        buf.push(FLOAT);
+                        buf.extend_from_slice(&f.to_le_bytes());
+                    }
+                    BorrowedAttributeValue::Int(n) => {
+                        buf.push(INT);
+                        buf.extend_from_slice(&n.to_le_bytes());
+                    }
+                    BorrowedAttributeValue::Bool(b) => {
+                        buf.push(BOOL);
+                        buf.push(*b as u8);
+                    }
+                    BorrowedAttributeValue::None => buf.push(NONE),
+                    _ => unreachable!(),
+                }
+            }
+            SetText { value, id } => {
+                write_op(buf, SET_TEXT, &[id.0]);
+                write_str(buf, value);
+            }
+            NewEventListener { name, id } => {
+                write_op(buf, NEW_EVENT_LISTENER, &[id.0]);
+                write_str(buf, name);
+            }
+            RemoveEventListener { name, id } => {
+                write_op(buf, REMOVE_EVENT_LISTENER, &[id.0]);
+                write_str(buf, name);
+            }
+            Remove { id } => write_op(buf, REMOVE, &[id.0]),
+            PushRoot { id } => write_op(buf, PUSH_ROOT, &[id.0]),
+        }
+    }
+}
+
+fn write_op(buf: &mut Vec<u8>, op: u8, operands: &[usize]) {
+    buf.push(op);
+    for operand in operands {
+        write_u32(buf, *operand as u32);
+    }
+}
+
+fn write_u32(buf: &mut Vec<u8>, n: u32) {
+    buf.extend_from_slice(&n.to_le_bytes());
+}
+
+fn write_path(buf: &mut Vec<u8>, path: &[u8]) {
+    buf.push(path.len() as u8);
+    buf.extend_from_slice(path);
+}
+
+fn write_str(buf: &mut Vec<u8>, s: &str) {
+    write_u32(buf, s.len() as u32);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+/// Reads the buffers sent by the worker
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < len {
+            return None;
+        }
+        let (taken, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Some(taken)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.take(8)
+            .map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        self.take(8)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn path(&mut self) -> Option<&'a [u8]> {
+        let len = self.u8()?;
+        self.take(len as usize)
+    }
+
+    fn str(&mut self) -> Option<&'a str> {
+        let len = self.u32()?;
+        std::str::from_utf8(self.take(len as usize)?).ok()
+    }
+}
+
+impl WebsysDom {
+    /// Apply a buffer of edits encoded by the worker
+    fn apply_encoded(&mut self, message: &[u8]) -> Option<()> {
+        let mut reader = Reader { buf: message };
+
+        let mount = reader.u8()? == 1;
+
+        let templates = reader.u32()?;
+        let templates = reader.take(templates as usize)?;
+        if !templates.is_empty() {
+            // the templates are copied into the interpreter, so they only need to live as long as the message
+            let templates: serde_json::Value = serde_json::from_slice(templates).ok()?;
+            let templates = Vec::<Template>::deserialize(&templates).ok()?;
+            self.load_templates(&templates);
+        }
+
+        let i = &mut self.interpreter;
+        while let Some(op) = reader.u8() {
+            match op {
+                APPEND_CHILDREN => i.append_children(reader.u32()?, reader.u32()?),
+                ASSIGN_ID => {
+                    let id = reader.u32()?;
+                    let path = reader.path()?;
+                    // the interpreter reads the path from memory when it flushes, while the message is still alive
+                    i.assign_id(path.as_ptr() as u32, path.len() as u8, id)
+                }
+                CREATE_PLACEHOLDER => i.create_placeholder(reader.u32()?),
+                CREATE_TEXT_NODE => {
+                    let id = reader.u32()?;
+                    i.create_text_node(reader.str()?, id)
+                }
+                HYDRATE_TEXT => {
+                    let id = reader.u32()?;
+                    let path = reader.path()?;
+                    let value = reader.str()?;
+                    i.hydrate_text(path.as_ptr() as u32, path.len() as u8, value, id)
+                }
+                LOAD_TEMPLATE => i.load_template(reader.u32()?, reader.u32()?, reader.u32()?),
+                REPLACE_WITH => i.replace_with(reader.u32()?, reader.u32()?),
+                REPLACE_PLACEHOLDER => {
+                    let m = reader.u32()?;
+                    let path = reader.path()?;
+                    i.replace_placeholder(path.as_ptr() as u32, path.len() as u8, m)
+                }
+                INSERT_AFTER => i.insert_after(reader.u32()?, reader.u32()?),
+                INSERT_BEFORE => i.insert_before(reader.u32()?, reader.u32()?),
+                SET_ATTRIBUTE => {
+                    let id = reader.u32()?;
+                    let name = reader.str()?;
+                    let ns = reader.str()?;
+                    match reader.u8()? {
+                        TEXT => i.set_attribute(id, name, reader.str()?, ns),
+                        FLOAT => i.set_attribute(id, name, &reader.f64()?.to_string(), ns),
+                        INT => i.set_attribute(id, name, &reader.i64()?.to_string(), ns),
+                        BOOL => {
+                            let value = if reader.u8()? == 1 { "true" } else { "false" };
+                            i.set_attribute(id, name, value, ns)
+                        }
+                        NONE => i.remove_attribute(id, name, ns),
+                        _ => return None,
+                    }
+                }
+                SET_TEXT => {
+                    let id = reader.u32()?;
+                    i.set_text(id, reader.str()?)
+                }
+                // the element can't be sent to the worker, so it never gets mounted events
+                NEW_EVENT_LISTENER => {
+                    let id = reader.u32()?;
+                    let name = reader.str()?;
+                    if name != "mounted" {
+                        i.new_event_listener(name, id, event_bubbles(name) as u8)
+                    }
+                }
+                REMOVE_EVENT_LISTENER => {
+                    let id = reader.u32()?;
+                    let name = reader.str()?;
+                    if name != "mounted" {
+                        i.remove_event_listener(name, id, event_bubbles(name) as u8)
+                    }
+                }
+                REMOVE => i.remove(reader.u32()?),
+                PUSH_ROOT => i.push_root(reader.u32()?),
+                FREE_ID => i.free_id(reader.u32()?),
+                _ => return None,
+            }
+        }
+        i.flush();
+
+        if mount {
+            self.mount();
+        }
+
+        Some(())
+    }
+}
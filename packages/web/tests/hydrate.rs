@@ -62,3 +62,100 @@ fn rehydrates() {
 
     dioxus_web::launch_cfg(app, Config::new().hydrate(true));
 }
+
+#[wasm_bindgen_test]
+async fn rerenders_mismatched_children() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            div {
+                id: "outer",
+                p { "client" }
+            }
+        })
+    }
+
+    let document = window().unwrap().document().unwrap();
+    document
+        .body()
+        .unwrap()
+        .set_inner_html("<div id='main'><div id=\"outer\"><span>server</span></div></div>");
+
+    dioxus_web::launch_cfg(app, Config::new().hydrate(true));
+    gloo_timers::future::TimeoutFuture::new(0).await;
+
+    let outer = document.get_element_by_id("outer").unwrap();
+    assert_eq!(outer.inner_html(), "<p>client</p>");
+}
+
+#[wasm_bindgen_test]
+async fn hydrates_progressively() {
+    fn app(cx: Scope) -> Element {
+        cx.render(rsx! {
+            button {
+                id: "progressive",
+                onclick: move |_| {},
+                "click me"
+            }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let _ = dom.rebuild();
+    let out = dioxus_ssr::render(&dom);
+
+    let document = window().unwrap().document().unwrap();
+    document
+        .body()
+        .unwrap()
+        .set_inner_html(&format!("<div id='main'>{out}</div>"));
+
+    dioxus_web::launch_cfg(
+        app,
+        Config::new().hydrate(true).with_progressive_hydration(true),
+    );
+
+    // the listeners are registered once the browser is idle, or the idle timeout passed
+    gloo_timers::future::TimeoutFuture::new(600).await;
+
+    let button = document.get_element_by_id("progressive").unwrap();
+    assert!(button.has_attribute("data-dioxus-id"));
+}
+
+#[wasm_bindgen_test]
+async fn hydrates_adjacent_dynamic_text() {
+    use wasm_bindgen::JsCast;
+
+    fn app(cx: Scope) -> Element {
+        let count = use_state(cx, || 0);
+
+        cx.render(rsx! {
+            p { id: "adjacent", "{count}" "{count}" }
+            button { id: "increment", onclick: move |_| count += 1, "+" }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let _ = dom.rebuild();
+    let out = dioxus_ssr::pre_render(&dom);
+
+    let document = window().unwrap().document().unwrap();
+    document
+        .body()
+        .unwrap()
+        .set_inner_html(&format!("<div id='main'>{out}</div>"));
+
+    dioxus_web::launch_cfg(app, Config::new().hydrate(true));
+    gloo_timers::future::TimeoutFuture::new(0).await;
+
+    // Both text nodes were found, so each of them is updated instead of one merged node
+    document
+        .get_element_by_id("increment")
+        .unwrap()
+        .dyn_into::<web_sys::HtmlElement>()
+        .unwrap()
+        .click();
+    gloo_timers::future::TimeoutFuture::new(50).await;
+
+    let adjacent = document.get_element_by_id("adjacent").unwrap();
+    assert_eq!(adjacent.text_content().unwrap(), "11");
+}